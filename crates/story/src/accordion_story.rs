@@ -3,8 +3,8 @@ use gpui::{
     VisualContext as _, WindowContext,
 };
 use ui::{
-    accordion::Accordion, button::Button, button_group::ButtonGroup, checkbox::Checkbox, h_flex,
-    switch::Switch, v_flex, IconName, Selectable, Sizable, Size,
+    accordion::Accordion, button::Button, button_group::ButtonGroup, checkbox::Checkbox,
+    collapsible::Collapsible, h_flex, switch::Switch, v_flex, IconName, Selectable, Sizable, Size,
 };
 
 pub struct AccordionStory {
@@ -12,6 +12,8 @@ pub struct AccordionStory {
     size: Size,
     bordered: bool,
     disabled: bool,
+    multiple: bool,
+    collapsible: View<Collapsible>,
     focus_handle: FocusHandle,
 }
 
@@ -36,6 +38,12 @@ impl AccordionStory {
             open_ixs: Vec::new(),
             size: Size::default(),
             disabled: false,
+            multiple: false,
+            collapsible: cx.new_view(|cx| {
+                Collapsible::new("Advanced settings", cx)
+                    .icon(IconName::Settings)
+                    .content(|_| "The content is animated when it is opened or closed.")
+            }),
             focus_handle: cx.focus_handle(),
         }
     }
@@ -116,11 +124,21 @@ impl Render for AccordionStory {
                                 this.bordered = *checked;
                                 cx.notify();
                             })),
+                    )
+                    .child(
+                        Checkbox::new("multiple")
+                            .label("Multiple")
+                            .checked(self.multiple)
+                            .on_click(cx.listener(|this, checked, cx| {
+                                this.multiple = *checked;
+                                cx.notify();
+                            })),
                     ),
             )
             .child(
                 Accordion::new("test")
                     .bordered(self.bordered)
+                    .allow_multiple(self.multiple)
                     .with_size(self.size)
                     .disabled(self.disabled)
                     .item(|this|
@@ -154,5 +172,6 @@ impl Render for AccordionStory {
                         this.toggle_accordion(open_ixs.to_vec(), cx);
                     })),
             )
+            .child(self.collapsible.clone())
     }
 }
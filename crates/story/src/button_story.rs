@@ -9,7 +9,9 @@ use ui::{
     checkbox::Checkbox,
     h_flex,
     prelude::FluentBuilder,
+    segmented_control::{Segment, SegmentedControl},
     theme::{ActiveTheme, Theme},
+    toolbar::{Toolbar, ToolbarItem},
     v_flex, Disableable as _, Icon, IconName, Selectable as _, Sizable as _,
 };
 
@@ -22,6 +24,8 @@ pub struct ButtonStory {
     selected: bool,
     compact: bool,
     toggle_multiple: bool,
+    segmented_control: View<SegmentedControl>,
+    toolbar: View<Toolbar>,
 }
 
 impl ButtonStory {
@@ -33,6 +37,46 @@ impl ButtonStory {
             selected: false,
             compact: false,
             toggle_multiple: false,
+            segmented_control: cx.new_view(|cx| {
+                SegmentedControl::new("segmented-control", cx)
+                    .segment(Segment::new().icon(IconName::LayoutDashboard).label("Grid"))
+                    .segment(Segment::new().icon(IconName::Menu).label("List"))
+                    .segment(IconName::Star)
+                    .segment(Segment::new().label("Disabled").disabled(true))
+            }),
+            toolbar: cx.new_view(|cx| {
+                Toolbar::new("toolbar", cx)
+                    .item(
+                        ToolbarItem::new("undo", "Undo")
+                            .icon(IconName::ArrowLeft)
+                            .on_click(Self::on_click),
+                    )
+                    .item(
+                        ToolbarItem::new("redo", "Redo")
+                            .icon(IconName::ArrowRight)
+                            .on_click(Self::on_click),
+                    )
+                    .separator()
+                    .item(
+                        ToolbarItem::new("copy", "Copy")
+                            .icon(IconName::Copy)
+                            .on_click(Self::on_click),
+                    )
+                    .item(
+                        ToolbarItem::new("delete", "Delete")
+                            .icon(IconName::Delete)
+                            .disabled(true),
+                    )
+                    .separator()
+                    .item(
+                        ToolbarItem::new("search", "Search")
+                            .icon(IconName::Search)
+                            .on_click(Self::on_click),
+                    )
+                    .item(ToolbarItem::new("settings", "Settings").on_click(Self::on_click))
+                    .spacer()
+                    .item(ToolbarItem::new("share", "Share").on_click(Self::on_click))
+            }),
         })
     }
 
@@ -667,5 +711,7 @@ impl Render for ButtonStory {
                             .when(compact, |this| this.compact()),
                     ),
             )
+            .child(section("Segmented Control", cx).child(self.segmented_control.clone()))
+            .child(section("Toolbar", cx).child(self.toolbar.clone()))
     }
 }
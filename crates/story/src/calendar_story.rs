@@ -1,11 +1,13 @@
-use chrono::{Days, Duration, Utc};
+use chrono::{Days, Duration, Utc, Weekday};
 use gpui::{
     px, IntoElement, ParentElement as _, Render, Styled as _, View, ViewContext,
     VisualContext as _, WindowContext,
 };
 use ui::{
     button::Button,
+    calendar::{Calendar, CalendarEvent, Date},
     date_picker::{DatePicker, DatePickerEvent, DateRangePreset},
+    theme::ActiveTheme as _,
     v_flex, Sizable as _, Size,
 };
 
@@ -17,6 +19,8 @@ pub struct CalendarStory {
     date_picker_value: Option<String>,
     date_range_picker: View<DatePicker>,
     default_range_mode_picker: View<DatePicker>,
+    calendar: View<Calendar>,
+    calendar_value: Option<String>,
 }
 
 impl super::Story for CalendarStory {
@@ -93,7 +97,9 @@ impl CalendarStory {
         let date_picker_small = cx.new_view(|cx| {
             let mut picker = DatePicker::new("date_picker_small", cx)
                 .small()
-                .width(px(180.));
+                .width(px(180.))
+                .min_date(now.checked_sub_days(Days::new(30)).unwrap())
+                .max_date(now.checked_add_days(Days::new(30)).unwrap());
             picker.set_date(now, cx);
             picker
         });
@@ -135,6 +141,37 @@ impl CalendarStory {
         })
         .detach();
 
+        let marked_dates = vec![
+            (now, cx.theme().primary),
+            (
+                now.checked_add_days(Days::new(3)).unwrap(),
+                cx.theme().danger,
+            ),
+            (
+                now.checked_add_days(Days::new(3)).unwrap(),
+                cx.theme().warning,
+            ),
+            (
+                now.checked_add_days(Days::new(12)).unwrap(),
+                cx.theme().primary,
+            ),
+        ];
+        let calendar = cx.new_view(|cx| {
+            let mut calendar = Calendar::new(cx)
+                .number_of_months(2)
+                .week_start(Weekday::Mon)
+                .marked_dates(marked_dates);
+            calendar.set_date(Date::Range(None, None), cx);
+            calendar
+        });
+        cx.subscribe(&calendar, |this, _, ev, _| match ev {
+            CalendarEvent::Selected(date) => {
+                this.calendar_value = date.format("%Y-%m-%d").map(|s| s.to_string());
+            }
+            CalendarEvent::MonthChanged(..) => {}
+        })
+        .detach();
+
         Self {
             size: Size::default(),
             date_picker,
@@ -143,6 +180,8 @@ impl CalendarStory {
             date_range_picker,
             default_range_mode_picker,
             date_picker_value: None,
+            calendar,
+            calendar_value: None,
         }
     }
 
@@ -158,6 +197,8 @@ impl CalendarStory {
             .update(cx, |picker, cx| picker.set_size(size, cx));
         self.default_range_mode_picker
             .update(cx, |picker, cx| picker.set_size(size, cx));
+        self.calendar
+            .update(cx, |calendar, cx| calendar.set_size(size, cx));
     }
 }
 
@@ -186,5 +227,17 @@ impl Render for CalendarStory {
             .child(self.date_range_picker.clone())
             .child(self.default_range_mode_picker.clone())
             .child(format!("Date picker value: {:?}", self.date_picker_value).into_element())
+            .child(
+                v_flex()
+                    .w_full()
+                    .items_start()
+                    .p_3()
+                    .gap_3()
+                    .border_1()
+                    .border_color(cx.theme().border)
+                    .rounded(px(cx.theme().radius))
+                    .child(self.calendar.clone())
+                    .child(format!("Calendar value: {:?}", self.calendar_value).into_element()),
+            )
     }
 }
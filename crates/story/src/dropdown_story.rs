@@ -179,6 +179,7 @@ impl DropdownStory {
     ) {
         match event {
             DropdownEvent::Confirm(value) => println!("Selected country: {:?}", value),
+            DropdownEvent::Changed(value) => println!("Changed country: {:?}", value),
         }
     }
 
@@ -1,14 +1,64 @@
-use gpui::{px, ParentElement as _, Render, Styled, View, VisualContext as _, WindowContext};
-use ui::{h_flex, v_flex, SvgImg};
+use std::time::Duration;
+
+use gpui::{
+    div, px, AppContext, ParentElement as _, Render, Styled, View, ViewContext, VisualContext as _,
+    WindowContext,
+};
+use ui::{
+    carousel::Carousel,
+    h_flex,
+    theme::{hsl, ActiveTheme as _},
+    v_flex,
+    virtual_grid::{VirtualGrid, VirtualGridDelegate},
+    SvgImg,
+};
 
 const GOOGLE_LOGO: &str = include_str!("./fixtures/google.svg");
 const PIE_JSON: &str = include_str!("./fixtures/pie.json");
 
+struct ThumbnailDelegate {
+    ratios: Vec<f32>,
+}
+
+impl VirtualGridDelegate for ThumbnailDelegate {
+    type Item = gpui::Div;
+
+    fn items_count(&self, _: &AppContext) -> usize {
+        self.ratios.len()
+    }
+
+    fn aspect_ratio(&self, ix: usize, _: &AppContext) -> f32 {
+        self.ratios[ix]
+    }
+
+    fn render_item(
+        &self,
+        ix: usize,
+        _: bool,
+        _: &mut ViewContext<VirtualGrid<Self>>,
+    ) -> Option<Self::Item> {
+        Some(
+            h_flex()
+                .size_full()
+                .justify_center()
+                .bg(hsl((ix * 37 % 360) as f32, 60., 70.))
+                .text_color(gpui::black())
+                .child(format!("#{}", ix)),
+        )
+    }
+
+    fn confirm(&mut self, ix: usize, _: &mut ViewContext<VirtualGrid<Self>>) {
+        println!("Open thumbnail #{}", ix);
+    }
+}
+
 pub struct ImageStory {
     focus_handle: gpui::FocusHandle,
     google_logo: SvgImg,
     pie_chart: SvgImg,
     inbox_img: SvgImg,
+    carousel: View<Carousel>,
+    thumbnails: View<VirtualGrid<ThumbnailDelegate>>,
 }
 
 impl super::Story for ImageStory {
@@ -24,12 +74,51 @@ impl super::Story for ImageStory {
 impl ImageStory {
     pub fn new(cx: &mut WindowContext) -> Self {
         let chart = charts_rs::PieChart::from_json(PIE_JSON).unwrap();
+        let google_logo = SvgImg::new().source(GOOGLE_LOGO.as_bytes(), px(300.), px(300.));
+        let pie_chart = SvgImg::new().source(chart.svg().unwrap().as_bytes(), px(600.), px(400.));
+
+        let carousel = cx.new_view(|cx| {
+            let logo = google_logo.clone();
+            let chart = pie_chart.clone();
+            Carousel::new("carousel", cx)
+                .height(px(240.))
+                .page(move |cx| {
+                    h_flex()
+                        .size_full()
+                        .justify_center()
+                        .bg(cx.theme().muted)
+                        .child(logo.clone().size(px(160.)))
+                })
+                .page(move |_| {
+                    h_flex()
+                        .size_full()
+                        .justify_center()
+                        .child(chart.clone().size_full())
+                })
+                .page(|cx| {
+                    h_flex()
+                        .size_full()
+                        .justify_center()
+                        .bg(cx.theme().accent)
+                        .child(div().text_xl().child("Welcome to GPUI Component"))
+                })
+                .autoplay(Duration::from_secs(3), cx)
+        });
+
+        let ratios = (0..1000)
+            .map(|ix| [1., 0.75, 1.5, 0.6, 1.33][ix % 5])
+            .collect();
+        let thumbnails = cx.new_view(|cx| {
+            VirtualGrid::new(ThumbnailDelegate { ratios }, cx).min_item_width(px(120.))
+        });
 
         Self {
             focus_handle: cx.focus_handle(),
-            google_logo: SvgImg::new().source(GOOGLE_LOGO.as_bytes(), px(300.), px(300.)),
-            pie_chart: SvgImg::new().source(chart.svg().unwrap().as_bytes(), px(600.), px(400.)),
+            google_logo,
+            pie_chart,
             inbox_img: SvgImg::new().source("icons/inbox.svg", px(24.), px(24.)),
+            carousel,
+            thumbnails,
         }
     }
 
@@ -50,6 +139,8 @@ impl Render for ImageStory {
             .gap_4()
             .size_full()
             .items_start()
+            .child(div().w(px(480.)).child(self.carousel.clone()))
+            .child(div().w(px(480.)).h(px(360.)).child(self.thumbnails.clone()))
             .child(
                 h_flex()
                     .size_full()
@@ -12,6 +12,7 @@ use ui::{
     label::Label,
     list::{List, ListDelegate, ListItem},
     theme::{hsl, ActiveTheme},
+    transfer_list::{TransferList, TransferListEvent},
     v_flex,
 };
 
@@ -226,6 +227,7 @@ pub struct ListStory {
     focus_handle: FocusHandle,
     company_list: View<List<CompanyListDelegate>>,
     selected_company: Option<Company>,
+    transfer_list: View<TransferList>,
 }
 
 impl super::Story for ListStory {
@@ -285,10 +287,27 @@ impl ListStory {
         })
         .detach();
 
+        let transfer_list = cx.new_view(|cx| {
+            let industries = (0..50)
+                .map(|_| fake::faker::company::en::Industry().fake::<String>().into())
+                .collect::<Vec<_>>();
+            TransferList::new("transfer-list", industries, cx)
+                .titles("Industries", "Watching")
+                .list_height(px(240.))
+        });
+        cx.subscribe(
+            &transfer_list,
+            |_, _, event: &TransferListEvent, _| match event {
+                TransferListEvent::Change(values) => println!("Watching: {:?}", values),
+            },
+        )
+        .detach();
+
         Self {
             focus_handle: cx.focus_handle(),
             company_list,
             selected_company: None,
+            transfer_list,
         }
     }
 
@@ -319,14 +338,20 @@ impl FocusableView for ListStory {
 
 impl Render for ListStory {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
-        div()
+        v_flex()
             .track_focus(&self.focus_handle)
             .on_action(cx.listener(Self::selected_company))
             .size_full()
             .gap_4()
-            .border_1()
-            .border_color(cx.theme().border)
-            .rounded_md()
-            .child(self.company_list.clone())
+            .child(
+                div()
+                    .flex_1()
+                    .min_h_0()
+                    .border_1()
+                    .border_color(cx.theme().border)
+                    .rounded_md()
+                    .child(self.company_list.clone()),
+            )
+            .child(self.transfer_list.clone())
     }
 }
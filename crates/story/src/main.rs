@@ -17,7 +17,7 @@ use ui::{
     popup_menu::PopupMenuExt,
     scroll::ScrollbarShow,
     theme::{ActiveTheme, Theme},
-    ContextModal, IconName, Root, Sizable, TitleBar,
+    ContextModal, Density, IconName, Root, Sizable, TitleBar,
 };
 
 const MAIN_DOCK_AREA: DockAreaTab = DockAreaTab {
@@ -34,6 +34,9 @@ struct SelectLocale(SharedString);
 #[derive(Clone, PartialEq, Eq, Deserialize)]
 struct SelectFont(usize);
 
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+struct SelectDensity(Density);
+
 #[derive(Clone, PartialEq, Eq, Deserialize)]
 struct AddPanel(DockPlacement);
 
@@ -45,6 +48,7 @@ impl_actions!(
     [
         SelectLocale,
         SelectFont,
+        SelectDensity,
         AddPanel,
         SelectScrollbarShow,
         TogglePanelVisible
@@ -662,6 +666,10 @@ impl FontSizeSelector {
         cx.refresh();
     }
 
+    fn on_select_density(&mut self, density: &SelectDensity, cx: &mut ViewContext<Self>) {
+        Theme::change_density(density.0, cx);
+    }
+
     fn on_select_scrollbar_show(&mut self, show: &SelectScrollbarShow, cx: &mut ViewContext<Self>) {
         Theme::global_mut(cx).scrollbar_show = show.0;
         cx.refresh();
@@ -673,11 +681,13 @@ impl Render for FontSizeSelector {
         let focus_handle = self.focus_handle.clone();
         let font_size = cx.theme().font_size as i32;
         let scroll_show = cx.theme().scrollbar_show;
+        let density = cx.theme().density;
 
         div()
             .id("font-size-selector")
             .track_focus(&focus_handle)
             .on_action(cx.listener(Self::on_select_font))
+            .on_action(cx.listener(Self::on_select_density))
             .on_action(cx.listener(Self::on_select_scrollbar_show))
             .child(
                 Button::new("btn")
@@ -693,6 +703,22 @@ impl Render for FontSizeSelector {
                         .menu_with_check("Font Default", font_size == 16, Box::new(SelectFont(16)))
                         .menu_with_check("Font Small", font_size == 14, Box::new(SelectFont(14)))
                         .separator()
+                        .menu_with_check(
+                            "Compact",
+                            density == Density::Compact,
+                            Box::new(SelectDensity(Density::Compact)),
+                        )
+                        .menu_with_check(
+                            "Comfortable",
+                            density == Density::Comfortable,
+                            Box::new(SelectDensity(Density::Comfortable)),
+                        )
+                        .menu_with_check(
+                            "Spacious",
+                            density == Density::Spacious,
+                            Box::new(SelectDensity(Density::Spacious)),
+                        )
+                        .separator()
                         .menu_with_check(
                             "Scrolling to show Scrollbar",
                             scroll_show == ScrollbarShow::Scrolling,
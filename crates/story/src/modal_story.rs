@@ -15,6 +15,7 @@ use ui::{
     h_flex,
     input::TextInput,
     list::{List, ListDelegate, ListItem},
+    modal::ConfirmDialog,
     notification::{Notification, NotificationType},
     theme::ActiveTheme as _,
     v_flex,
@@ -295,6 +296,8 @@ impl ModalStory {
         cx.open_drawer_at(placement, move |this, cx| {
             this.overlay(overlay)
                 .size(px(400.))
+                .max_size(px(800.))
+                .initial_focus(&list.focus_handle(cx))
                 .title("Drawer Title")
                 .gap_4()
                 .child(input.clone())
@@ -519,6 +522,21 @@ impl Render for ModalStory {
                                         });
                                     })),
                             )
+                            .child(
+                                Button::new("show-confirm-dialog")
+                                    .label("Confirm Dialog...")
+                                    .on_click(cx.listener(|_, _, cx| {
+                                        ConfirmDialog::new("Delete the file?")
+                                            .message("This action cannot be undone.")
+                                            .danger(true)
+                                            .on_result(|confirmed, cx| {
+                                                if confirmed {
+                                                    cx.push_notification("The file has been deleted.")
+                                                }
+                                            })
+                                            .open(cx);
+                                    })),
+                            )
                             .child(
                                 Button::new("show-drawer-left")
                                     .label("Left Drawer...")
@@ -575,6 +593,19 @@ impl Render for ModalStory {
                                         )
                                     })),
                             )
+                            .child(
+                                Button::new("show-notify-action")
+                                    .label("Notify with Action...")
+                                    .on_click(cx.listener(|_, _, cx| {
+                                        cx.push_notification(
+                                            Notification::success("The file has been moved to trash.")
+                                                .timeout(Duration::from_secs(8))
+                                                .action("Undo", |cx| {
+                                                    cx.push_notification("The file has been restored.")
+                                                }),
+                                        )
+                                    })),
+                            )
                             .child(
                                 Button::new("show-notify-error")
                                     .label("Error Notify...")
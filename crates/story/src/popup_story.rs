@@ -11,11 +11,11 @@ use ui::{
     divider::Divider,
     h_flex,
     input::TextInput,
-    popover::{Popover, PopoverContent},
+    popover::{Popover, PopoverAlign, PopoverContent},
     popup_menu::PopupMenuExt,
     switch::Switch,
     theme::ActiveTheme as _,
-    v_flex, ContextModal, IconName, Sizable,
+    v_flex, ContextModal, IconName, Placement, Sizable,
 };
 
 #[derive(Clone, PartialEq, Deserialize)]
@@ -232,6 +232,22 @@ impl Render for PopupStory {
                                 }),
                         ),
                     )
+                    .child(
+                        Popover::new("info-placement")
+                            .placement(Placement::Bottom)
+                            .align(PopoverAlign::Center)
+                            .arrow(true)
+                            .trigger(Button::new("info-placement").label("Bottom Center"))
+                            .content(|cx| {
+                                cx.new_view(|cx| {
+                                    PopoverContent::new(cx, |_| {
+                                        div()
+                                            .child("This popover flips to the top if there is no room.")
+                                            .into_any()
+                                    })
+                                })
+                            }),
+                    )
                     .child(
                         Popover::new("info-top-right")
                             .anchor(Corner::TopRight)
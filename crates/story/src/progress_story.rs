@@ -1,5 +1,5 @@
 use gpui::{
-    div, px, IntoElement, ParentElement, Render, Styled, View, ViewContext, VisualContext,
+    div, px, Axis, IntoElement, ParentElement, Render, Styled, View, ViewContext, VisualContext,
     WindowContext,
 };
 use ui::{
@@ -7,9 +7,10 @@ use ui::{
     divider::Divider,
     h_flex,
     indicator::Indicator,
-    progress::Progress,
-    skeleton::Skeleton,
+    progress::{Progress, ProgressCircle},
+    skeleton::{Skeleton, SkeletonList},
     slider::{Slider, SliderEvent},
+    steps::{Step, Steps},
     v_flex, IconName, Sizable,
 };
 
@@ -20,6 +21,7 @@ pub struct ProgressStory {
     slider1_value: f32,
     slider2: View<Slider>,
     slider2_value: f32,
+    current_step: usize,
 }
 
 impl super::Story for ProgressStory {
@@ -38,8 +40,8 @@ impl ProgressStory {
     }
 
     fn new(cx: &mut ViewContext<Self>) -> Self {
-        let slider1 = cx.new_view(|_| {
-            Slider::horizontal()
+        let slider1 = cx.new_view(|cx| {
+            Slider::horizontal(cx)
                 .min(-255.)
                 .max(255.)
                 .default_value(15.)
@@ -50,15 +52,17 @@ impl ProgressStory {
                 this.slider1_value = *value;
                 cx.notify();
             }
+            SliderEvent::Commit(value) => println!("Slider committed: {}", value),
         })
         .detach();
 
-        let slider2 = cx.new_view(|_| Slider::horizontal().min(0.).max(5.).step(1.0));
+        let slider2 = cx.new_view(|cx| Slider::horizontal(cx).min(0.).max(5.).step(1.0));
         cx.subscribe(&slider2, |this, _, event: &SliderEvent, cx| match event {
             SliderEvent::Change(value) => {
                 this.slider2_value = *value;
                 cx.notify();
             }
+            SliderEvent::Commit(_) => {}
         })
         .detach();
 
@@ -67,6 +71,7 @@ impl ProgressStory {
             value: 50.,
             slider1_value: 15.,
             slider2_value: 1.,
+            current_step: 1,
             slider1,
             slider2,
         }
@@ -112,7 +117,16 @@ impl Render for ProgressStory {
                         },
                     ))),
             )
-            .child(div().w_1_2().child(Progress::new().value(self.value)))
+            .child(
+                div()
+                    .w_1_2()
+                    .child(Progress::new().value(self.value / 100.).show_label(true)),
+            )
+            .child(
+                div()
+                    .w_1_2()
+                    .child(Progress::new().id("indeterminate").small().indeterminate()),
+            )
             .child(
                 h_flex()
                     .gap_x_2()
@@ -143,7 +157,9 @@ impl Render for ProgressStory {
                             .icon(IconName::LoaderCircle)
                             .color(ui::blue_500()),
                     )
-                    .child(Indicator::new().with_size(px(64.))),
+                    .child(Indicator::new().with_size(px(64.)))
+                    .child(ProgressCircle::new("circle-1").value(self.value / 100.))
+                    .child(ProgressCircle::new("circle-2").large()),
             )
             .child(
                 Divider::horizontal()
@@ -164,12 +180,61 @@ impl Render for ProgressStory {
                 h_flex()
                     .mt_5()
                     .gap_4()
-                    .child(Skeleton::new().size_12().rounded_full())
+                    .child(Skeleton::circle(px(48.)))
                     .child(
                         v_flex()
                             .gap_2()
-                            .child(Skeleton::new().w(px(250.)).h_4())
-                            .child(Skeleton::new().w(px(240.)).h_4()),
+                            .child(Skeleton::line(px(250.)))
+                            .child(Skeleton::line(px(240.))),
+                    )
+                    .child(Skeleton::rect(px(80.), px(48.))),
+            )
+            .child(SkeletonList::new(3, px(32.)).w(px(300.)))
+            .child(
+                Divider::horizontal()
+                    .mt_10()
+                    .label("Steps")
+                    .color(ui::gray_300()),
+            )
+            .child(
+                v_flex()
+                    .w_2_3()
+                    .gap_4()
+                    .child(
+                        Steps::new("steps")
+                            .step(Step::new("Account").description("Create an account"))
+                            .step(Step::new("Profile").description("Fill in the profile"))
+                            .step(Step::new("Payment").description("Add a payment method"))
+                            .step(Step::new("Done"))
+                            .current_step(self.current_step)
+                            .on_click(cx.listener(|this, ix: &usize, cx| {
+                                this.current_step = *ix;
+                                cx.notify();
+                            })),
+                    )
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .child(Button::new("step-prev").label("Previous").on_click(
+                                cx.listener(|this, _, cx| {
+                                    this.current_step = this.current_step.saturating_sub(1);
+                                    cx.notify();
+                                }),
+                            ))
+                            .child(Button::new("step-next").label("Next").on_click(cx.listener(
+                                |this, _, cx| {
+                                    this.current_step = (this.current_step + 1).min(4);
+                                    cx.notify();
+                                },
+                            ))),
+                    )
+                    .child(
+                        Steps::new("steps-vertical")
+                            .axis(Axis::Vertical)
+                            .step(Step::new("Build").description("Compile the sources"))
+                            .step(Step::new("Test").description("3 tests failed").error(true))
+                            .step(Step::new("Deploy"))
+                            .current_step(1),
                     ),
             )
     }
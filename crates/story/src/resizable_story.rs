@@ -4,7 +4,9 @@ use gpui::{
 };
 use ui::theme::ActiveTheme;
 use ui::{
-    resizable::{h_resizable, resizable_panel, v_resizable, ResizablePanelGroup},
+    resizable::{
+        h_resizable, resizable_panel, v_resizable, ResizablePanelGroup, SplitPane, SplitView,
+    },
     v_flex,
 };
 
@@ -12,6 +14,7 @@ pub struct ResizableStory {
     focus_handle: gpui::FocusHandle,
     group1: View<ResizablePanelGroup>,
     group2: View<ResizablePanelGroup>,
+    split_view: View<SplitView>,
 }
 
 impl super::Story for ResizableStory {
@@ -104,10 +107,33 @@ impl ResizableStory {
                     cx,
                 )
         });
+
+        let nested_split = cx.new_view(|cx| {
+            SplitView::vertical(cx)
+                .first(SplitPane::new().content(|cx| panel_box("Top", cx)))
+                .second(
+                    SplitPane::new()
+                        .min_size(px(60.))
+                        .content(|cx| panel_box("Bottom (Min 60px)", cx)),
+                )
+        });
+        let split_view = cx.new_view(|cx| {
+            SplitView::horizontal(cx)
+                .default_ratio(0.3)
+                .first(
+                    SplitPane::new()
+                        .min_size(px(120.))
+                        .max_size(px(400.))
+                        .content(|cx| panel_box("Split Left (120px - 400px)", cx)),
+                )
+                .second(SplitPane::new().content_view(nested_split.into()))
+        });
+
         Self {
             focus_handle: cx.focus_handle(),
             group1,
             group2,
+            split_view,
         }
     }
 }
@@ -118,5 +144,6 @@ impl Render for ResizableStory {
             .gap_6()
             .child(self.group1.clone())
             .child(self.group2.clone())
+            .child(div().h(px(240.)).child(self.split_view.clone()))
     }
 }
@@ -336,12 +336,14 @@ impl Render for SidebarStory {
                             .child(Divider::vertical().h_4())
                             .child(
                                 Breadcrumb::new()
-                                    .item(BreadcrumbItem::new("0", "Home").on_click(cx.listener(
-                                        |this, _, cx| {
-                                            this.active_item = Item::Playground;
-                                            cx.notify();
-                                        },
-                                    )))
+                                    .item(
+                                        BreadcrumbItem::new("0", "Home")
+                                            .icon(IconName::LayoutDashboard)
+                                            .on_click(cx.listener(|this, _, cx| {
+                                                this.active_item = Item::Playground;
+                                                cx.notify();
+                                            })),
+                                    )
                                     .item(
                                         BreadcrumbItem::new("1", self.active_item.label())
                                             .on_click(cx.listener(|this, _, cx| {
@@ -4,8 +4,8 @@ use gpui::{
 };
 
 use ui::{
-    h_flex, label::Label, switch::Switch, theme::ActiveTheme, v_flex, Disableable as _, Side,
-    Sizable, StyledExt,
+    h_flex, label::Label, rating::Rating, switch::Switch, theme::ActiveTheme, v_flex,
+    Disableable as _, Side, Sizable, StyledExt,
 };
 
 pub struct SwitchStory {
@@ -13,6 +13,9 @@ pub struct SwitchStory {
     switch1: bool,
     switch2: bool,
     switch3: bool,
+    switch4_focus: gpui::FocusHandle,
+    rating: f32,
+    rating_focus: gpui::FocusHandle,
 }
 
 impl super::Story for SwitchStory {
@@ -40,6 +43,9 @@ impl SwitchStory {
             switch1: true,
             switch2: false,
             switch3: true,
+            switch4_focus: cx.focus_handle(),
+            rating: 3.,
+            rating_focus: cx.focus_handle(),
         }
     }
 }
@@ -138,6 +144,32 @@ impl Render for SwitchStory {
                             cx.notify();
                         })),
                     )
+                )
+                .child(
+                    card(cx).v_flex()
+                        .items_start().child(title("Uncontrolled Switches")).child(
+                        h_flex().items_center()
+                        .gap_6()
+                        .child(Switch::new("switch4").label("Focusable").default_checked(true).focusable(&self.switch4_focus))
+                        .child(Switch::new("switch4_1").label("XSmall").xsmall())
+                        .child(Switch::new("switch4_2").label("Large").large().on_change(|checked, _| {
+                            println!("Switch value changed: {:?}", checked);
+                        })),
+                    )
+                )
+                .child(
+                    card(cx).v_flex()
+                        .items_start().child(title("Rating")).child(
+                        h_flex().items_center()
+                        .gap_6()
+                        .child(Rating::new("rating1").value(self.rating).allow_half(true).focusable(&self.rating_focus).on_change(cx.listener(move |view, value, cx| {
+                            view.rating = *value;
+                            cx.notify();
+                        })))
+                        .child(format!("{}", self.rating))
+                        .child(Rating::new("rating2").value(4.3).readonly(true).small())
+                        .child(Rating::new("rating3").value(2.).disabled(true)),
+                    )
                 ),
             )
         )
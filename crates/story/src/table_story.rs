@@ -687,6 +687,9 @@ impl TableStory {
             TableEvent::MoveCol(origin_idx, target_idx) => {
                 println!("Move col index: {} -> {}", origin_idx, target_idx);
             }
+            TableEvent::SortChanged(ix, ascending) => {
+                println!("Sort col: {}, ascending: {}", ix, ascending);
+            }
         }
     }
 }
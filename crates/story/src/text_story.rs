@@ -1,17 +1,22 @@
 use gpui::{
-    div, px, rems, IntoElement, ParentElement, Render, Styled, View, ViewContext,
+    div, px, rems, Axis, IntoElement, ParentElement, Render, Styled, View, ViewContext,
     VisualContext as _, WindowContext,
 };
 
 use ui::{
+    alert::Alert,
     badge::Badge,
     button::{Button, ButtonVariant, ButtonVariants as _},
     checkbox::Checkbox,
-    clipboard::Clipboard,
+    clipboard::{Clipboard, CopyButton, Snippet},
+    description_list::DescriptionList,
+    empty_state::EmptyState,
+    error_state::ErrorState,
     h_flex,
     label::Label,
     link::Link,
-    radio::Radio,
+    radio::{Radio, RadioGroup},
+    tag::Tag,
     v_flex, Disableable as _, IconName, Sizable, StyledExt,
 };
 
@@ -24,6 +29,7 @@ pub struct TextStory {
     check3: bool,
     radio_check1: bool,
     radio_check2: bool,
+    radio_group: View<RadioGroup<usize>>,
     masked: bool,
 }
 
@@ -50,6 +56,15 @@ impl TextStory {
             check3: true,
             radio_check1: false,
             radio_check2: true,
+            radio_group: cx.new_view(|cx| {
+                RadioGroup::new("radio-group", cx)
+                    .axis(Axis::Horizontal)
+                    .option(1, "One")
+                    .option(2, "Two")
+                    .disabled_option(3, "Three")
+                    .option(4, "Four")
+                    .selected(2)
+            }),
             masked: false,
         }
     }
@@ -107,6 +122,8 @@ impl Render for TextStory {
                             cx.open_url("https://google.com")
                         })))
                         .child(div().w(px(250.)).child(Link::new("link4").child("https://github.com/longbridge/gpui-component").href("https://github.com/longbridge/gpui-component")))
+                        .child(Link::new("link5").href("https://github.com/longbridge/gpui-component").external(true).child("Repository"))
+                        .child(Link::new("link6").href("https://github.com").disabled(true).child("Disabled"))
                 )
             )
             .child(
@@ -227,6 +244,7 @@ impl Render for TextStory {
                         )
                 ),
             )
+            .child(section("Radio Group", cx).child(self.radio_group.clone()))
             .child(
                 section("Clipboard", cx).child(
                     h_flex()
@@ -244,6 +262,10 @@ impl Render for TextStory {
                                 .value("https://github.com")
                                 .on_copied(|value, _| println!("Copied value: {}", value)),
                         )
+                        .child(CopyButton::new("copy1", "cargo add gpui-component").small())
+                )
+                .child(
+                    Snippet::new("snippet1", "cargo add gpui-component\n\n# Run the story with a very long command line to scroll horizontally\ncargo run --release --package story --features webview -- --theme dark")
                 ),
             )
             .child(
@@ -264,6 +286,79 @@ impl Render for TextStory {
                             .child(Badge::destructive().child("Destructive"))
                             .child(Badge::custom(ui::yellow_500(), ui::yellow_800(), ui::yellow_500()).child("Custom"))
                     )
+                    .child(
+                        h_flex().gap_2().items_center()
+                            .child(Badge::success().label("Success"))
+                            .child(Badge::warning().label("Warning"))
+                            .child(Badge::info().label("Info"))
+                            .child(Badge::color(ui::purple_500()).label("A very long label that will be truncated"))
+                            .child(Badge::destructive().count(128))
+                            .child(Badge::destructive().dot())
+                    )
+            )
+            .child(
+                section("Empty State", cx)
+                    .child(
+                        div().flex_1().min_w(px(240.)).child(
+                            EmptyState::new("No results")
+                                .icon(IconName::Search)
+                                .description("Try a different keyword or clear the filters.")
+                                .action(Button::new("empty-clear").primary().small().label("Clear filters"))
+                                .secondary_action(Button::new("empty-new").small().label("New item")),
+                        )
+                    )
+                    .child(
+                        div().flex_1().min_w(px(240.)).child(
+                            ErrorState::new("error-state", "Failed to load")
+                                .message("The server is not responding, please try again later.")
+                                .details("Error: connection timed out after 30s\n    at fetch (client.rs:42)")
+                                .on_retry(|_, _| println!("Retry")),
+                        )
+                    ),
+            )
+            .child(
+                section("Alert", cx)
+                    .child(
+                        v_flex().w_full().gap_2()
+                            .child(Alert::info("alert1", "You can use the Alert to show an inline message.").title("Info"))
+                            .child(Alert::success("alert2", "Your changes have been saved.").title("Success").on_close(|_, _| println!("Alert closed")))
+                            .child(Alert::warning("alert3", "The disk is almost full.").title("Warning").action(Button::new("clean").small().label("Clean up")))
+                            .child(Alert::danger("alert4", "This action cannot be undone.").compact(true))
+                    )
+            )
+            .child(
+                section("Description List", cx)
+                    .child(
+                        div().flex_1().min_w(px(240.)).child(
+                            DescriptionList::new("description-list1")
+                                .copyable(true)
+                                .text_item("Name", "gpui-component")
+                                .text_item("Repository", "https://github.com/huacnlee/gpui-component")
+                                .item("Status", Tag::new("status", "Active").small())
+                                .group("Build")
+                                .text_item("Commit", "3a275f5e1c9d2b8a4f6e7d0c1b2a3f4e5d6c7b8a")
+                                .text_item("Target", "aarch64-apple-darwin"),
+                        )
+                    )
+                    .child(
+                        div().flex_1().min_w(px(240.)).child(
+                            DescriptionList::new("description-list2")
+                                .vertical()
+                                .text_item("Created at", "2024-08-01 10:00")
+                                .separator()
+                                .text_item("Updated at", "2024-08-02 12:30"),
+                        )
+                    ),
+            )
+            .child(
+                section("Tag", cx)
+                    .child(
+                        h_flex().gap_2()
+                            .child(Tag::new("tag1", "Rust").icon(IconName::Star))
+                            .child(Tag::new("tag2", "GPUI").color(ui::blue_500()).on_remove(|_, _| println!("Removed GPUI")))
+                            .child(Tag::new("tag3", "Small").small().on_remove(|_, _| {}))
+                            .child(Tag::new("tag4", "Disabled").disabled(true).on_remove(|_, _| {}))
+                    )
             )
     }
 }
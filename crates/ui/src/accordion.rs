@@ -1,12 +1,41 @@
-use std::{cell::Cell, rc::Rc, sync::Arc};
+use std::{f32::consts::PI, rc::Rc, sync::Arc};
 
 use gpui::{
-    div, prelude::FluentBuilder as _, rems, AnyElement, Div, ElementId, InteractiveElement as _,
-    IntoElement, ParentElement, RenderOnce, SharedString, StatefulInteractiveElement as _, Styled,
-    WindowContext,
+    div, prelude::FluentBuilder as _, radians, rems, AnyElement, Div, ElementId, FocusHandle,
+    InteractiveElement as _, IntoElement, ParentElement, RenderOnce, SharedString,
+    StatefulInteractiveElement as _, Styled, WindowContext,
 };
 
-use crate::{h_flex, theme::ActiveTheme as _, v_flex, Icon, IconName, Sizable, Size};
+use crate::{
+    collapsible::chevron_size, h_flex, theme::ActiveTheme as _, v_flex, Icon, IconName, Sizable,
+    Size, StyleSized as _,
+};
+
+pub enum AccordionEvent {
+    /// The item at the index is opened.
+    Open(usize),
+    /// The item at the index is closed.
+    Close(usize),
+}
+
+/// Returns the open indices after toggling the item at `ix`.
+///
+/// If not `multiple`, opening an item closes the others.
+fn toggle_ixs(open_ixs: &[usize], ix: usize, multiple: bool) -> Vec<usize> {
+    let was_open = open_ixs.contains(&ix);
+    let mut open_ixs = if multiple {
+        open_ixs.to_vec()
+    } else {
+        Vec::new()
+    };
+
+    if was_open {
+        open_ixs.retain(|&i| i != ix);
+    } else {
+        open_ixs.push(ix);
+    }
+    open_ixs
+}
 
 /// An AccordionGroup is a container for multiple Accordion elements.
 #[derive(IntoElement)]
@@ -19,6 +48,7 @@ pub struct Accordion {
     disabled: bool,
     children: Vec<AccordionItem>,
     on_toggle_click: Option<Arc<dyn Fn(&[usize], &mut WindowContext) + Send + Sync>>,
+    on_toggle: Option<Rc<dyn Fn(&AccordionEvent, &mut WindowContext)>>,
 }
 
 impl Accordion {
@@ -32,9 +62,15 @@ impl Accordion {
             children: Vec::new(),
             disabled: false,
             on_toggle_click: None,
+            on_toggle: None,
         }
     }
 
+    /// Set to allow multiple items to be open at the same time, otherwise opening one closes the others.
+    pub fn allow_multiple(self, allow: bool) -> Self {
+        self.multiple(allow)
+    }
+
     pub fn multiple(mut self, multiple: bool) -> Self {
         self.multiple = multiple;
         self
@@ -69,6 +105,15 @@ impl Accordion {
         self.on_toggle_click = Some(Arc::new(on_toggle_click));
         self
     }
+
+    /// Sets the callback when an item is opened or closed, by click or keyboard.
+    pub fn on_toggle(
+        mut self,
+        on_toggle: impl Fn(&AccordionEvent, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.on_toggle = Some(Rc::new(on_toggle));
+        self
+    }
 }
 
 impl Sizable for Accordion {
@@ -80,18 +125,17 @@ impl Sizable for Accordion {
 
 impl RenderOnce for Accordion {
     fn render(self, _: &mut WindowContext) -> impl IntoElement {
-        let mut open_ixs: Vec<usize> = Vec::new();
         let multiple = self.multiple;
-        let state = Rc::new(Cell::new(None));
-
-        self.children
+        let disabled = self.disabled;
+        let open_ixs: Rc<[usize]> = self
+            .children
             .iter()
             .enumerate()
-            .for_each(|(ix, accordion)| {
-                if accordion.open {
-                    open_ixs.push(ix);
-                }
-            });
+            .filter(|(_, accordion)| accordion.open)
+            .map(|(ix, _)| ix)
+            .collect();
+        let on_toggle_click = self.on_toggle_click;
+        let on_toggle = self.on_toggle;
 
         self.base
             .id(self.id)
@@ -100,40 +144,27 @@ impl RenderOnce for Accordion {
                     .into_iter()
                     .enumerate()
                     .map(|(ix, accordion)| {
-                        let state = Rc::clone(&state);
+                        let open_ixs = open_ixs.clone();
+                        let on_toggle_click = on_toggle_click.clone();
+                        let on_toggle = on_toggle.clone();
                         accordion
                             .with_size(self.size)
                             .bordered(self.bordered)
-                            .when(self.disabled, |this| this.disabled(true))
-                            .on_toggle_click(move |_, _| {
-                                state.set(Some(ix));
-                            })
-                    }),
-            )
-            .when_some(
-                self.on_toggle_click.filter(|_| !self.disabled),
-                move |this, on_toggle_click| {
-                    this.on_click(move |_, cx| {
-                        let mut open_ixs = open_ixs.clone();
-                        if let Some(ix) = state.get() {
-                            if multiple {
-                                if let Some(pos) = open_ixs.iter().position(|&i| i == ix) {
-                                    open_ixs.remove(pos);
-                                } else {
-                                    open_ixs.push(ix);
+                            .when(disabled, |this| this.disabled(true))
+                            .on_toggle_click(move |open, cx| {
+                                if let Some(on_toggle) = &on_toggle {
+                                    let event = if *open {
+                                        AccordionEvent::Open(ix)
+                                    } else {
+                                        AccordionEvent::Close(ix)
+                                    };
+                                    on_toggle(&event, cx);
                                 }
-                            } else {
-                                let was_open = open_ixs.iter().any(|&i| i == ix);
-                                open_ixs.clear();
-                                if !was_open {
-                                    open_ixs.push(ix);
+                                if let Some(on_toggle_click) = &on_toggle_click {
+                                    on_toggle_click(&toggle_ixs(&open_ixs, ix, multiple), cx);
                                 }
-                            }
-                        }
-
-                        on_toggle_click(&open_ixs, cx);
-                    })
-                },
+                            })
+                    }),
             )
     }
 }
@@ -148,6 +179,7 @@ pub struct AccordionItem {
     size: Size,
     bordered: bool,
     disabled: bool,
+    focus_handle: Option<FocusHandle>,
     on_toggle_click: Option<Arc<dyn Fn(&bool, &mut WindowContext)>>,
 }
 
@@ -159,6 +191,7 @@ impl AccordionItem {
             content: SharedString::default().into_any_element(),
             open: false,
             disabled: false,
+            focus_handle: None,
             on_toggle_click: None,
             size: Size::default(),
             bordered: true,
@@ -195,6 +228,12 @@ impl AccordionItem {
         self
     }
 
+    /// Set the focus handle of the title, to toggle the item by Enter or Space when it is focused.
+    pub fn focus_handle(mut self, focus_handle: &FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle.clone());
+        self
+    }
+
     fn on_toggle_click(
         mut self,
         on_toggle_click: impl Fn(&bool, &mut WindowContext) + 'static,
@@ -234,6 +273,7 @@ impl RenderOnce for AccordionItem {
                         Size::XSmall => this.py_0().px_1p5(),
                         Size::Small => this.py_0p5().px_2(),
                         Size::Large => this.py_1p5().px_4(),
+                        Size::XLarge => this.py_2().px_5(),
                         _ => this.py_1().px_3(),
                     })
                     .when(self.open, |this| {
@@ -246,12 +286,7 @@ impl RenderOnce for AccordionItem {
                     })
                     .child(
                         h_flex()
-                            .items_center()
-                            .map(|this| match self.size {
-                                Size::XSmall => this.gap_1(),
-                                Size::Small => this.gap_1(),
-                                _ => this.gap_2(),
-                            })
+                            .gap_with(self.size)
                             .when_some(self.icon, |this, icon| {
                                 this.child(
                                     icon.with_size(self.size)
@@ -264,21 +299,28 @@ impl RenderOnce for AccordionItem {
                         this.cursor_pointer()
                             .hover(|this| this.bg(cx.theme().accordion_hover))
                             .child(
-                                Icon::new(if self.open {
-                                    IconName::ChevronUp
-                                } else {
-                                    IconName::ChevronDown
-                                })
-                                .xsmall()
-                                .text_color(cx.theme().muted_foreground),
+                                Icon::new(IconName::ChevronDown)
+                                    .with_size(chevron_size(self.size))
+                                    .rotate(radians(if self.open { PI } else { 0. }))
+                                    .text_color(cx.theme().muted_foreground),
                             )
                     })
+                    .when_some(self.focus_handle.as_ref(), |this, focus_handle| {
+                        this.track_focus(focus_handle)
+                            .focus(|this| this.bg(cx.theme().accordion_hover))
+                    })
                     .when_some(
                         self.on_toggle_click.filter(|_| !self.disabled),
                         |this, on_toggle_click| {
+                            let open = self.open;
                             this.on_click({
-                                move |_, cx| {
-                                    on_toggle_click(&!self.open, cx);
+                                let on_toggle_click = on_toggle_click.clone();
+                                move |_, cx| on_toggle_click(&!open, cx)
+                            })
+                            .on_key_down(move |event, cx| {
+                                if matches!(event.keystroke.key.as_str(), "enter" | "space") {
+                                    cx.stop_propagation();
+                                    on_toggle_click(&!open, cx);
                                 }
                             })
                         },
@@ -291,6 +333,7 @@ impl RenderOnce for AccordionItem {
                             Size::XSmall => this.p_1p5(),
                             Size::Small => this.p_2(),
                             Size::Large => this.p_4(),
+                            Size::XLarge => this.p_5(),
                             _ => this.p_3(),
                         })
                         .child(self.content),
@@ -298,3 +341,17 @@ impl RenderOnce for AccordionItem {
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::toggle_ixs;
+
+    #[test]
+    fn test_toggle_ixs() {
+        assert_eq!(toggle_ixs(&[], 1, false), vec![1]);
+        assert_eq!(toggle_ixs(&[0], 1, false), vec![1]);
+        assert_eq!(toggle_ixs(&[1], 1, false), Vec::<usize>::new());
+        assert_eq!(toggle_ixs(&[0], 1, true), vec![0, 1]);
+        assert_eq!(toggle_ixs(&[0, 1], 0, true), vec![1]);
+    }
+}
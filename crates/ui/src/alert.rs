@@ -0,0 +1,202 @@
+use std::rc::Rc;
+
+use gpui::{
+    div, prelude::FluentBuilder as _, px, AnyElement, ClickEvent, ElementId, Hsla,
+    InteractiveElement as _, IntoElement, ParentElement as _, RenderOnce, SharedString, Styled,
+    WindowContext,
+};
+
+use crate::{
+    button::{Button, ButtonVariants as _},
+    h_flex,
+    theme::ActiveTheme as _,
+    v_flex, Icon, IconName, Sizable as _, StyledExt as _,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AlertVariant {
+    #[default]
+    Info,
+    Success,
+    Warning,
+    Danger,
+}
+
+impl AlertVariant {
+    fn color(&self, cx: &WindowContext) -> Hsla {
+        match self {
+            Self::Info => cx.theme().info,
+            Self::Success => cx.theme().success,
+            Self::Warning => cx.theme().warning,
+            Self::Danger => cx.theme().danger,
+        }
+    }
+
+    fn icon(&self) -> IconName {
+        match self {
+            Self::Info => IconName::Info,
+            Self::Success => IconName::CircleCheck,
+            Self::Warning => IconName::TriangleAlert,
+            Self::Danger => IconName::CircleX,
+        }
+    }
+}
+
+/// An Alert element to show an inline message, with an optional title, action and close button.
+///
+/// Use `compact` to show the title and message in a single line, e.g. inside a popover.
+#[derive(IntoElement)]
+pub struct Alert {
+    id: ElementId,
+    variant: AlertVariant,
+    icon: Option<Icon>,
+    title: Option<SharedString>,
+    message: SharedString,
+    action: Option<AnyElement>,
+    on_close: Option<Rc<dyn Fn(&ClickEvent, &mut WindowContext)>>,
+    compact: bool,
+}
+
+impl Alert {
+    pub fn new(id: impl Into<ElementId>, message: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            variant: AlertVariant::default(),
+            icon: None,
+            title: None,
+            message: message.into(),
+            action: None,
+            on_close: None,
+            compact: false,
+        }
+    }
+
+    pub fn info(id: impl Into<ElementId>, message: impl Into<SharedString>) -> Self {
+        Self::new(id, message).with_variant(AlertVariant::Info)
+    }
+
+    pub fn success(id: impl Into<ElementId>, message: impl Into<SharedString>) -> Self {
+        Self::new(id, message).with_variant(AlertVariant::Success)
+    }
+
+    pub fn warning(id: impl Into<ElementId>, message: impl Into<SharedString>) -> Self {
+        Self::new(id, message).with_variant(AlertVariant::Warning)
+    }
+
+    pub fn danger(id: impl Into<ElementId>, message: impl Into<SharedString>) -> Self {
+        Self::new(id, message).with_variant(AlertVariant::Danger)
+    }
+
+    pub fn with_variant(mut self, variant: AlertVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Set the icon to replace the default icon of the variant.
+    pub fn icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    pub fn title(mut self, title: impl Into<SharedString>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the action element to show at the end, e.g. a Button.
+    pub fn action(mut self, action: impl IntoElement) -> Self {
+        self.action = Some(action.into_any_element());
+        self
+    }
+
+    /// Show a close button, and call the `handler` when it is clicked.
+    pub fn on_close(mut self, handler: impl Fn(&ClickEvent, &mut WindowContext) + 'static) -> Self {
+        self.on_close = Some(Rc::new(handler));
+        self
+    }
+
+    /// Set to show in a single line, the long text will be truncated, default: false
+    pub fn compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+}
+
+impl RenderOnce for Alert {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let color = self.variant.color(cx);
+        let icon = self
+            .icon
+            .unwrap_or_else(|| Icon::new(self.variant.icon()))
+            .text_color(color)
+            .flex_shrink_0();
+
+        h_flex()
+            .id(self.id)
+            .w_full()
+            .rounded(px(cx.theme().radius))
+            .border_1()
+            .border_color(color.opacity(0.5))
+            .bg(color.opacity(0.08))
+            .text_color(cx.theme().foreground)
+            .map(|this| {
+                if self.compact {
+                    this.gap_2().py_1p5().px_3().text_sm()
+                } else {
+                    this.items_start().gap_3().p_4()
+                }
+            })
+            .child(icon.map(|this| {
+                if self.compact {
+                    this.small()
+                } else {
+                    // Align with the first line of the text.
+                    this.mt_0p5()
+                }
+            }))
+            .map(|this| {
+                if self.compact {
+                    this.child(
+                        h_flex()
+                            .flex_1()
+                            .min_w_0()
+                            .gap_1()
+                            .whitespace_nowrap()
+                            .when_some(self.title, |this, title| {
+                                this.child(div().flex_shrink_0().font_semibold().child(title))
+                            })
+                            .child(
+                                div()
+                                    .min_w_0()
+                                    .overflow_hidden()
+                                    .text_ellipsis()
+                                    .child(self.message),
+                            ),
+                    )
+                } else {
+                    this.child(
+                        v_flex()
+                            .flex_1()
+                            .min_w_0()
+                            .gap_1()
+                            .when_some(self.title, |this, title| {
+                                this.child(div().font_semibold().child(title))
+                            })
+                            .child(div().text_sm().child(self.message)),
+                    )
+                }
+            })
+            .when_some(self.action, |this, action| {
+                this.child(div().flex_shrink_0().child(action))
+            })
+            .when_some(self.on_close, |this, on_close| {
+                this.child(
+                    Button::new("close")
+                        .ghost()
+                        .xsmall()
+                        .icon(IconName::Close)
+                        .on_click(move |event, cx| on_close(event, cx)),
+                )
+            })
+    }
+}
@@ -1,3 +1,12 @@
+use std::{
+    f32::consts::PI,
+    time::{Duration, Instant},
+};
+
+use gpui::{px, AppContext, Hsla, Pixels, WindowContext};
+
+use crate::theme::ActiveTheme as _;
+
 /// A cubic bezier function like CSS `cubic-bezier`.
 ///
 /// Builder:
@@ -17,3 +26,257 @@ pub fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32) -> impl Fn(f32) -> f32 {
         y
     }
 }
+
+/// Wraps an easing function to respect the `reduce_motion` of the theme.
+///
+/// When the reduced motion is enabled, the animation will jump to the end state immediately.
+pub fn reduced_motion(
+    easing: impl Fn(f32) -> f32 + 'static,
+    cx: &AppContext,
+) -> impl Fn(f32) -> f32 {
+    let reduce_motion = cx.theme().reduce_motion();
+    move |delta| {
+        if reduce_motion {
+            1.0
+        } else {
+            easing(delta)
+        }
+    }
+}
+
+/// A value that can be interpolated by [`Animated`].
+pub trait Lerp: Copy {
+    /// Returns the value between `self` and `to`, the `t` is in `0..=1`,
+    /// but it may be out of range for an easing with overshoot, e.g. [`Easing::Spring`].
+    fn lerp(&self, to: &Self, t: f32) -> Self;
+}
+
+impl Lerp for f32 {
+    fn lerp(&self, to: &Self, t: f32) -> Self {
+        self + (to - self) * t
+    }
+}
+
+impl Lerp for Pixels {
+    fn lerp(&self, to: &Self, t: f32) -> Self {
+        px(self.0.lerp(&to.0, t))
+    }
+}
+
+impl Lerp for Hsla {
+    fn lerp(&self, to: &Self, t: f32) -> Self {
+        Hsla {
+            h: self.h.lerp(&to.h, t),
+            s: self.s.lerp(&to.s, t),
+            l: self.l.lerp(&to.l, t),
+            a: self.a.lerp(&to.a, t),
+        }
+    }
+}
+
+/// The easing curves for [`Animated`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Easing {
+    Linear,
+    /// Start slow and accelerate, good for the fade out.
+    EaseIn,
+    /// Start fast and decelerate, this is the default.
+    #[default]
+    EaseOut,
+    /// Overshoot the target a little and settle, like a spring.
+    Spring,
+}
+
+impl Easing {
+    /// Returns the progress of the `t` in `0..=1`.
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.clamp(0., 1.);
+        match self {
+            Self::Linear => t,
+            Self::EaseIn => t * t * t,
+            Self::EaseOut => 1. - (1. - t).powi(3),
+            Self::Spring => 1. - (t * PI * 2.5).cos() * (-6. * t).exp(),
+        }
+    }
+}
+
+/// A frame driven animation of a value, e.g. the opacity of the scrollbar.
+///
+/// The state is `Copy`, so it can be stored in a `Cell` of the element state.
+/// Call [`Animated::tick`] in paint to get the current value, it requests the next frame
+/// while the animation is running.
+#[derive(Debug, Clone, Copy)]
+pub struct Animated<T: Lerp> {
+    from: T,
+    to: T,
+    started_at: Option<Instant>,
+    delay: Duration,
+    duration: Duration,
+    easing: Easing,
+}
+
+impl<T: Lerp> Animated<T> {
+    /// Create an Animated at rest with the `value`.
+    pub fn new(value: T) -> Self {
+        Self {
+            from: value,
+            to: value,
+            started_at: None,
+            delay: Duration::ZERO,
+            duration: Duration::from_millis(200),
+            easing: Easing::default(),
+        }
+    }
+
+    /// Set the duration of the animation, default: 200ms
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Set the delay before the animation starts, default: 0
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Set the easing of the animation, default: [`Easing::EaseOut`]
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Returns the target value.
+    pub fn target(&self) -> T {
+        self.to
+    }
+
+    /// Start to animate from the current value to the `to` value.
+    pub fn animate_to(&mut self, to: T) {
+        self.animate_to_at(to, Instant::now());
+    }
+
+    fn animate_to_at(&mut self, to: T, now: Instant) {
+        self.from = self.value_at(now, false);
+        self.to = to;
+        self.started_at = Some(now);
+    }
+
+    /// Jump to the `value` without animation.
+    pub fn set(&mut self, value: T) {
+        self.from = value;
+        self.to = value;
+        self.started_at = None;
+    }
+
+    /// Stop the animation at the current value.
+    pub fn cancel(&mut self) {
+        let value = self.value_at(Instant::now(), false);
+        self.set(value);
+    }
+
+    /// Returns true if the animation is running or waiting for the delay.
+    pub fn is_animating(&self) -> bool {
+        self.is_animating_at(Instant::now())
+    }
+
+    fn is_animating_at(&self, now: Instant) -> bool {
+        self.started_at.map_or(false, |started_at| {
+            now < started_at + self.delay + self.duration
+        })
+    }
+
+    /// Returns the value at the `now`.
+    ///
+    /// If `reduce_motion` is true, the value jumps to the target after the delay.
+    fn value_at(&self, now: Instant, reduce_motion: bool) -> T {
+        let Some(started_at) = self.started_at else {
+            return self.to;
+        };
+        let elapsed = now.saturating_duration_since(started_at);
+        if elapsed < self.delay {
+            return self.from;
+        }
+        if reduce_motion || self.duration.is_zero() {
+            return self.to;
+        }
+
+        let t = (elapsed - self.delay).as_secs_f32() / self.duration.as_secs_f32();
+        if t >= 1. {
+            self.to
+        } else {
+            self.from.lerp(&self.to, self.easing.apply(t))
+        }
+    }
+
+    /// Returns the current value, and request the next frame if the animation is running.
+    ///
+    /// When the `reduce_motion` of the theme is enabled, the value jumps to the target.
+    pub fn tick(&self, cx: &mut WindowContext) -> T {
+        let now = Instant::now();
+        if self.is_animating_at(now) {
+            cx.request_animation_frame();
+        }
+        self.value_at(now, cx.theme().reduce_motion())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::{Animated, Easing, Lerp as _};
+
+    #[test]
+    fn test_easing() {
+        for easing in [
+            Easing::Linear,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::Spring,
+        ] {
+            assert_eq!(easing.apply(0.), 0.);
+            assert!((easing.apply(1.) - 1.).abs() < 1e-4);
+        }
+        assert!(Easing::EaseIn.apply(0.5) < 0.5);
+        assert!(Easing::EaseOut.apply(0.5) > 0.5);
+        assert!(Easing::Linear.apply(2.) == 1.);
+    }
+
+    #[test]
+    fn test_lerp() {
+        assert_eq!(0f32.lerp(&10., 0.25), 2.5);
+        assert_eq!(10f32.lerp(&0., 0.5), 5.);
+    }
+
+    #[test]
+    fn test_animated() {
+        let now = Instant::now();
+        let ms = Duration::from_millis;
+        let mut value = Animated::new(1f32)
+            .delay(ms(100))
+            .duration(ms(100))
+            .easing(Easing::Linear);
+        assert!(!value.is_animating_at(now));
+        assert_eq!(value.value_at(now, false), 1.);
+
+        value.animate_to_at(0., now);
+        assert!(value.is_animating_at(now));
+        assert_eq!(value.value_at(now + ms(50), false), 1.);
+        assert_eq!(value.value_at(now + ms(150), false), 0.5);
+        assert_eq!(value.value_at(now + ms(200), false), 0.);
+        assert!(!value.is_animating_at(now + ms(200)));
+
+        // Reduced motion jumps to the target after the delay.
+        assert_eq!(value.value_at(now + ms(50), true), 1.);
+        assert_eq!(value.value_at(now + ms(110), true), 0.);
+
+        // Retarget from the current value.
+        value.animate_to_at(1., now + ms(150));
+        assert_eq!(value.value_at(now + ms(150), false), 0.5);
+
+        value.set(0.);
+        assert!(!value.is_animating_at(now));
+        assert_eq!(value.target(), 0.);
+    }
+}
@@ -0,0 +1,126 @@
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use gpui::{
+    div, img, prelude::FluentBuilder as _, px, relative, Div, ImageSource, IntoElement,
+    ParentElement as _, Pixels, RenderOnce, SharedString, Styled, WindowContext,
+};
+
+use crate::{
+    theme::{hsl, readable_foreground},
+    Sizable, Size,
+};
+
+/// An avatar image with the initials of the name as fallback.
+///
+/// The image is loaded asynchronously, the initials on a colored background (derived from
+/// the name) will be shown while loading or if the image failed to load.
+#[derive(IntoElement)]
+pub struct Avatar {
+    base: Div,
+    name: SharedString,
+    src: Option<ImageSource>,
+    size: Size,
+    radius: Option<Pixels>,
+}
+
+impl Avatar {
+    pub fn new(name: impl Into<SharedString>) -> Self {
+        Self {
+            base: div(),
+            name: name.into(),
+            src: None,
+            size: Size::Medium,
+            radius: None,
+        }
+    }
+
+    /// Set the image source of the avatar, e.g. a URL or an asset path.
+    pub fn src(mut self, src: impl Into<ImageSource>) -> Self {
+        self.src = Some(src.into());
+        self
+    }
+
+    /// Set the corner radius, default is a circle.
+    pub fn rounded(mut self, radius: impl Into<Pixels>) -> Self {
+        self.radius = Some(radius.into());
+        self
+    }
+
+    /// Set the avatar as a square without corner radius.
+    pub fn square(self) -> Self {
+        self.rounded(px(0.))
+    }
+}
+
+impl Sizable for Avatar {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl Styled for Avatar {
+    fn style(&mut self) -> &mut gpui::StyleRefinement {
+        self.base.style()
+    }
+}
+
+/// Returns the initials of the name, at most 2 letters, e.g.: `Jason Lee` -> `JL`.
+fn initials(name: &str) -> String {
+    name.split_whitespace()
+        .filter_map(|word| word.chars().next())
+        .take(2)
+        .flat_map(|c| c.to_uppercase())
+        .collect()
+}
+
+impl RenderOnce for Avatar {
+    fn render(self, _: &mut WindowContext) -> impl IntoElement {
+        let size = match self.size {
+            Size::Size(size) => size,
+            Size::XSmall => px(16.),
+            Size::Small => px(24.),
+            Size::Medium => px(32.),
+            Size::Large => px(48.),
+            Size::XLarge => px(64.),
+        };
+
+        let mut hasher = DefaultHasher::new();
+        self.name.hash(&mut hasher);
+        let bg = hsl((hasher.finish() % 360) as f32, 60., 45.);
+
+        self.base
+            .relative()
+            .flex_none()
+            .overflow_hidden()
+            .size(size)
+            .map(|this| match self.radius {
+                Some(radius) => this.rounded(radius),
+                None => this.rounded_full(),
+            })
+            .bg(bg)
+            .text_color(readable_foreground(bg))
+            .text_size((size * 0.4).round())
+            .flex()
+            .items_center()
+            .justify_center()
+            .child(initials(&self.name))
+            .when_some(self.src, |this, src| {
+                // The image covers the initials after it is loaded.
+                this.child(img(src).absolute().top_0().left_0().size(relative(1.)))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::initials;
+
+    #[test]
+    fn test_initials() {
+        assert_eq!(initials("Jason Lee"), "JL");
+        assert_eq!(initials("jason"), "J");
+        assert_eq!(initials("  Ada  Byron Lovelace "), "AB");
+        assert_eq!(initials(""), "");
+    }
+}
@@ -1,9 +1,15 @@
-use crate::{theme::ActiveTheme as _, Sizable, Size};
+use crate::{theme::ActiveTheme as _, tooltip::Tooltip, Sizable, Size};
 use gpui::{
-    div, prelude::FluentBuilder as _, relative, Div, Hsla, InteractiveElement as _, IntoElement,
-    ParentElement, RenderOnce, Styled,
+    div, prelude::FluentBuilder as _, px, relative, Div, Hsla, InteractiveElement as _,
+    IntoElement, ParentElement, Pixels, RenderOnce, SharedString, StatefulInteractiveElement as _,
+    Styled,
 };
 
+/// The max width of the label, the longer label will be truncated with a tooltip.
+pub(crate) const LABEL_MAX_WIDTH: Pixels = px(160.);
+/// The label longer than this is likely to be truncated, so show it in a tooltip.
+const LABEL_TOOLTIP_CHARS: usize = 20;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum BadgeVariant {
     #[default]
@@ -11,6 +17,9 @@ pub enum BadgeVariant {
     Secondary,
     Outline,
     Destructive,
+    Success,
+    Warning,
+    Info,
     Custom {
         color: Hsla,
         foreground: Hsla,
@@ -18,57 +27,100 @@ pub enum BadgeVariant {
     },
 }
 impl BadgeVariant {
-    fn bg(&self, cx: &gpui::WindowContext) -> Hsla {
+    /// A tinted variant from an arbitrary color, with the text and border in that color.
+    pub fn tinted(color: Hsla) -> Self {
+        Self::Custom {
+            color: color.opacity(0.15),
+            foreground: color,
+            border: color.opacity(0.4),
+        }
+    }
+
+    pub(crate) fn bg(&self, cx: &gpui::WindowContext) -> Hsla {
         match self {
             Self::Primary => cx.theme().primary,
             Self::Secondary => cx.theme().secondary,
             Self::Outline => gpui::transparent_black(),
             Self::Destructive => cx.theme().destructive,
+            Self::Success => cx.theme().success,
+            Self::Warning => cx.theme().warning,
+            Self::Info => cx.theme().info,
             Self::Custom { color, .. } => *color,
         }
     }
 
-    fn border(&self, cx: &gpui::WindowContext) -> Hsla {
+    pub(crate) fn border(&self, cx: &gpui::WindowContext) -> Hsla {
         match self {
             Self::Primary => cx.theme().primary,
             Self::Secondary => cx.theme().secondary,
             Self::Outline => cx.theme().border,
             Self::Destructive => cx.theme().destructive,
+            Self::Success => cx.theme().success,
+            Self::Warning => cx.theme().warning,
+            Self::Info => cx.theme().info,
             Self::Custom { border, .. } => *border,
         }
     }
 
-    fn fg(&self, cx: &gpui::WindowContext) -> Hsla {
+    pub(crate) fn fg(&self, cx: &gpui::WindowContext) -> Hsla {
         match self {
             Self::Primary => cx.theme().primary_foreground,
             Self::Secondary => cx.theme().secondary_foreground,
             Self::Outline => cx.theme().foreground,
             Self::Destructive => cx.theme().destructive_foreground,
+            Self::Success => cx.theme().success_foreground,
+            Self::Warning => cx.theme().warning_foreground,
+            Self::Info => cx.theme().info_foreground,
             Self::Custom { foreground, .. } => *foreground,
         }
     }
 }
 
+/// Returns the text of the count, e.g. "99+" if the `count` is greater than the `max`.
+fn count_text(count: usize, max: usize) -> String {
+    if count > max {
+        format!("{}+", max)
+    } else {
+        count.to_string()
+    }
+}
+
+/// Returns true if the label is likely to be truncated, to show it in a tooltip.
+pub(crate) fn is_long_label(label: &str) -> bool {
+    label.chars().count() > LABEL_TOOLTIP_CHARS
+}
+
 /// Badge is a small status indicator for UI elements.
 ///
-/// Only support: Medium, Small
+/// The content can be any children, or a `label` that is truncated when it is too long,
+/// or a `count` that shows "99+" when it exceeds the `max`.
+///
+/// Use `dot` to show a small dot indicator without content.
 #[derive(IntoElement)]
 pub struct Badge {
     base: Div,
-    veriant: BadgeVariant,
+    variant: BadgeVariant,
     size: Size,
+    label: Option<SharedString>,
+    count: Option<usize>,
+    max: usize,
+    dot: bool,
 }
 impl Badge {
     fn new() -> Self {
         Self {
             base: div().flex().items_center().rounded_md().border_1(),
-            veriant: BadgeVariant::default(),
+            variant: BadgeVariant::default(),
             size: Size::Medium,
+            label: None,
+            count: None,
+            max: 99,
+            dot: false,
         }
     }
 
     pub fn with_variant(mut self, variant: BadgeVariant) -> Self {
-        self.veriant = variant;
+        self.variant = variant;
         self
     }
 
@@ -88,6 +140,18 @@ impl Badge {
         Self::new().with_variant(BadgeVariant::Destructive)
     }
 
+    pub fn success() -> Self {
+        Self::new().with_variant(BadgeVariant::Success)
+    }
+
+    pub fn warning() -> Self {
+        Self::new().with_variant(BadgeVariant::Warning)
+    }
+
+    pub fn info() -> Self {
+        Self::new().with_variant(BadgeVariant::Info)
+    }
+
     pub fn custom(color: Hsla, foreground: Hsla, border: Hsla) -> Self {
         Self::new().with_variant(BadgeVariant::Custom {
             color,
@@ -95,6 +159,35 @@ impl Badge {
             border,
         })
     }
+
+    /// Create a tinted badge from an arbitrary color, see [`BadgeVariant::tinted`].
+    pub fn color(color: Hsla) -> Self {
+        Self::new().with_variant(BadgeVariant::tinted(color))
+    }
+
+    /// Set the label of the badge, the long label will be truncated with a tooltip.
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Show the count as the label, e.g. "99+" if the count is greater than the `max`.
+    pub fn count(mut self, count: usize) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Set the max count to show, default: 99
+    pub fn max(mut self, max: usize) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Show as a small dot, without the content.
+    pub fn dot(mut self) -> Self {
+        self.dot = true;
+        self
+    }
 }
 impl Sizable for Badge {
     fn with_size(mut self, size: impl Into<Size>) -> Self {
@@ -109,15 +202,83 @@ impl ParentElement for Badge {
 }
 impl RenderOnce for Badge {
     fn render(self, cx: &mut gpui::WindowContext) -> impl IntoElement {
+        let bg = self.variant.bg(cx);
+        let border = self.variant.border(cx);
+
+        if self.dot {
+            return div()
+                .flex_shrink_0()
+                .rounded_full()
+                .border_1()
+                .border_color(border)
+                .bg(bg)
+                .map(|this| match self.size {
+                    Size::XSmall => this.size_1p5(),
+                    Size::Small => this.size_2(),
+                    Size::Large => this.size_3(),
+                    Size::XLarge => this.size_4(),
+                    _ => this.size_2p5(),
+                })
+                .into_any_element();
+        }
+
+        let label = self
+            .count
+            .map(|count| count_text(count, self.max).into())
+            .or(self.label);
+
         self.base
             .line_height(relative(1.3))
             .map(|this| match self.size {
-                Size::XSmall | Size::Small => this.text_xs().px_1p5().py_0(),
+                Size::XSmall => this.text_xs().px_1().py_0(),
+                Size::Small => this.text_xs().px_1p5().py_0(),
+                Size::Large => this.text_sm().px_3().py_0p5(),
+                Size::XLarge => this.text_base().px_3p5().py_1(),
                 _ => this.text_xs().px_2p5().py_0p5(),
             })
-            .bg(self.veriant.bg(cx))
-            .text_color(self.veriant.fg(cx))
-            .border_color(self.veriant.border(cx))
+            .when(self.count.is_some(), |this| {
+                this.justify_center().rounded_full()
+            })
+            .bg(bg)
+            .text_color(self.variant.fg(cx))
+            .border_color(border)
             .hover(|this| this.opacity(0.9))
+            .when_some(label, |this, label| {
+                let long = is_long_label(&label);
+                this.child(
+                    div()
+                        .id(SharedString::from(format!("badge-label:{}", label)))
+                        .max_w(LABEL_MAX_WIDTH)
+                        .overflow_hidden()
+                        .text_ellipsis()
+                        .whitespace_nowrap()
+                        .child(label.clone())
+                        .when(long, |this| {
+                            this.tooltip(move |cx| Tooltip::new(label.clone(), cx))
+                        }),
+                )
+            })
+            .into_any_element()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{count_text, is_long_label};
+
+    #[test]
+    fn test_count_text() {
+        assert_eq!(count_text(0, 99), "0");
+        assert_eq!(count_text(99, 99), "99");
+        assert_eq!(count_text(100, 99), "99+");
+        assert_eq!(count_text(10, 9), "9+");
+    }
+
+    #[test]
+    fn test_is_long_label() {
+        assert!(!is_long_label("Badge"));
+        assert!(is_long_label("A very long label of the badge"));
+        // Count the chars instead of the bytes.
+        assert!(!is_long_label("标签标签标签标签标签"));
     }
 }
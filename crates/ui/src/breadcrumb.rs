@@ -1,24 +1,61 @@
-use std::rc::Rc;
+use std::{
+    cell::{Cell, RefCell},
+    ops::Range,
+    rc::Rc,
+};
 
 use gpui::{
-    div, prelude::FluentBuilder as _, ClickEvent, ElementId, InteractiveElement as _, IntoElement,
-    ParentElement, RenderOnce, SharedString, StatefulInteractiveElement, Styled, WindowContext,
+    canvas, div, prelude::FluentBuilder as _, px, AnyElement, ClickEvent, Element, ElementId,
+    GlobalElementId, InteractiveElement as _, IntoElement, LayoutId, ParentElement, Pixels,
+    RenderOnce, SharedString, StatefulInteractiveElement, Styled, WindowContext,
+};
+
+use crate::{
+    button::{Button, ButtonVariants as _},
+    h_flex,
+    popup_menu::PopupMenuExt as _,
+    theme::ActiveTheme,
+    tooltip::Tooltip,
+    Icon, IconName, Sizable as _,
 };
 
-use crate::{h_flex, theme::ActiveTheme, Icon, IconName};
+/// The max width of an item, the longer label will be truncated with a tooltip.
+const ITEM_MAX_WIDTH: Pixels = px(200.);
+/// The width of a separator with the gaps around it.
+const SEPARATOR_WIDTH: Pixels = px(26.);
+/// The width of the "…" button of the collapsed items.
+const ELLIPSIS_WIDTH: Pixels = px(24.);
 
-#[derive(IntoElement)]
+type ClickHandler = Rc<dyn Fn(&ClickEvent, &mut WindowContext)>;
+
+/// A Breadcrumb element to show the navigation path.
+///
+/// The last item is the current one, and it is not clickable.
+///
+/// The middle items will be collapsed into a "…" popup menu,
+/// when the width of the breadcrumb is not enough to show all of them.
 pub struct Breadcrumb {
+    id: ElementId,
     items: Vec<BreadcrumbItem>,
 }
 
+#[derive(Default)]
+struct BreadcrumbState {
+    /// The width of each item, measured when it was visible.
+    widths: Rc<RefCell<Vec<Pixels>>>,
+    /// The available width of the breadcrumb.
+    width: Rc<Cell<Pixels>>,
+}
+
 #[derive(IntoElement)]
 pub struct BreadcrumbItem {
     id: ElementId,
     text: SharedString,
-    on_click: Option<Rc<dyn Fn(&ClickEvent, &mut WindowContext)>>,
+    icon: Option<IconName>,
+    on_click: Option<ClickHandler>,
     disabled: bool,
     is_last: bool,
+    truncated: bool,
 }
 
 impl BreadcrumbItem {
@@ -26,12 +63,20 @@ impl BreadcrumbItem {
         Self {
             id: id.into(),
             text: text.into(),
+            icon: None,
             on_click: None,
             disabled: false,
             is_last: false,
+            truncated: false,
         }
     }
 
+    /// Set the icon to show before the text.
+    pub fn icon(mut self, icon: IconName) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
     pub fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
         self
@@ -50,23 +95,47 @@ impl BreadcrumbItem {
         self.is_last = is_last;
         self
     }
+
+    /// For internal use only, show the text in a tooltip when it is truncated.
+    fn truncated(mut self, truncated: bool) -> Self {
+        self.truncated = truncated;
+        self
+    }
 }
 
 impl RenderOnce for BreadcrumbItem {
     fn render(self, cx: &mut WindowContext) -> impl IntoElement {
-        div()
+        let text = self.text.clone();
+
+        h_flex()
             .id(self.id)
-            .child(self.text)
+            .gap_1()
+            .max_w(ITEM_MAX_WIDTH)
             .text_color(cx.theme().muted_foreground)
             .when(self.is_last, |this| this.text_color(cx.theme().foreground))
             .when(self.disabled, |this| {
                 this.text_color(cx.theme().muted_foreground)
             })
-            .when(!self.disabled, |this| {
+            .when_some(self.icon, |this, icon| {
+                this.child(Icon::new(icon).size_3p5().flex_shrink_0())
+            })
+            .child(
+                div()
+                    .overflow_hidden()
+                    .text_ellipsis()
+                    .whitespace_nowrap()
+                    .child(self.text),
+            )
+            .when(self.truncated, |this| {
+                this.tooltip(move |cx| Tooltip::new(text.clone(), cx))
+            })
+            .when(!self.disabled && !self.is_last, |this| {
                 this.when_some(self.on_click, |this, on_click| {
-                    this.cursor_pointer().on_click(move |event, cx| {
-                        on_click(event, cx);
-                    })
+                    this.cursor_pointer()
+                        .hover(|this| this.text_color(cx.theme().foreground))
+                        .on_click(move |event, cx| {
+                            on_click(event, cx);
+                        })
                 })
             })
     }
@@ -74,7 +143,16 @@ impl RenderOnce for BreadcrumbItem {
 
 impl Breadcrumb {
     pub fn new() -> Self {
-        Self { items: Vec::new() }
+        Self {
+            id: "breadcrumb".into(),
+            items: Vec::new(),
+        }
+    }
+
+    /// Set the element id, this is required when there are multiple breadcrumbs in the same view.
+    pub fn id(mut self, id: impl Into<ElementId>) -> Self {
+        self.id = id.into();
+        self
     }
 
     /// Add an item to the breadcrumb.
@@ -82,6 +160,12 @@ impl Breadcrumb {
         self.items.push(item);
         self
     }
+
+    /// Add items to the breadcrumb.
+    pub fn items(mut self, items: impl IntoIterator<Item = BreadcrumbItem>) -> Self {
+        self.items.extend(items);
+        self
+    }
 }
 
 #[derive(IntoElement)]
@@ -91,28 +175,225 @@ impl RenderOnce for BreadcrumbSeparator {
         Icon::new(IconName::ChevronRight)
             .text_color(cx.theme().muted_foreground)
             .size_3p5()
+            .flex_shrink_0()
             .into_any_element()
     }
 }
 
-impl RenderOnce for Breadcrumb {
-    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
-        let items_count = self.items.len();
+/// Returns the range of the middle items to collapse, to fit the items in the `available` width.
+///
+/// The first and the last items are always visible.
+fn collapsed_range(
+    widths: &[f32],
+    available: f32,
+    separator: f32,
+    ellipsis: f32,
+) -> Option<Range<usize>> {
+    let len = widths.len();
+    if len <= 2 || available <= 0. {
+        return None;
+    }
 
-        let mut children = vec![];
-        for (ix, item) in self.items.into_iter().enumerate() {
-            let is_last = ix == items_count - 1;
+    let mut width = widths.iter().sum::<f32>() + separator * (len - 1) as f32;
+    if width <= available {
+        return None;
+    }
 
-            children.push(item.is_last(is_last).into_any_element());
-            if !is_last {
-                children.push(BreadcrumbSeparator.into_any_element());
-            }
+    // Collapse from the second item, until the rest of the items can fit.
+    width += ellipsis + separator;
+    for end in 2..len - 1 {
+        width -= widths[end - 1] + separator;
+        if width <= available {
+            return Some(1..end);
         }
+    }
+    Some(1..len - 1)
+}
 
-        h_flex()
-            .gap_1p5()
-            .text_sm()
-            .text_color(cx.theme().muted_foreground)
-            .children(children)
+/// Render the "…" button with a popup menu of the collapsed items.
+fn render_ellipsis(items: Vec<BreadcrumbItem>) -> AnyElement {
+    let items = items
+        .into_iter()
+        .map(|item| {
+            let on_click = item.on_click.filter(|_| !item.disabled);
+            (item.text, item.icon, on_click)
+        })
+        .collect::<Vec<_>>();
+
+    Button::new("ellipsis")
+        .ghost()
+        .xsmall()
+        .icon(IconName::Ellipsis)
+        .popup_menu(move |mut menu, _| {
+            for (text, icon, on_click) in items.iter() {
+                let on_click = on_click.clone();
+                menu =
+                    menu.menu_with_handler(text.clone(), icon.clone().map(Icon::new), move |cx| {
+                        if let Some(on_click) = on_click.as_ref() {
+                            on_click(&ClickEvent::default(), cx);
+                        }
+                    });
+            }
+            menu
+        })
+        .into_any_element()
+}
+
+impl IntoElement for Breadcrumb {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for Breadcrumb {
+    type RequestLayoutState = AnyElement;
+
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        Some(self.id.clone())
+    }
+
+    fn request_layout(
+        &mut self,
+        global_id: Option<&GlobalElementId>,
+        cx: &mut WindowContext,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let items = std::mem::take(&mut self.items);
+
+        cx.with_element_state::<BreadcrumbState, _>(global_id.unwrap(), move |state, cx| {
+            let state = state.unwrap_or_default();
+            let items_count = items.len();
+
+            state.widths.borrow_mut().resize(items_count, px(0.));
+            let widths = state
+                .widths
+                .borrow()
+                .iter()
+                .map(|width| width.0)
+                .collect::<Vec<_>>();
+            let collapsed = collapsed_range(
+                &widths,
+                state.width.get().0,
+                SEPARATOR_WIDTH.0,
+                ELLIPSIS_WIDTH.0,
+            )
+            .unwrap_or(0..0);
+
+            let mut children = vec![];
+            let mut collapsed_items = vec![];
+            for (ix, item) in items.into_iter().enumerate() {
+                let is_last = ix == items_count - 1;
+
+                if collapsed.contains(&ix) {
+                    collapsed_items.push(item);
+                    if ix == collapsed.end - 1 {
+                        children.push(render_ellipsis(std::mem::take(&mut collapsed_items)));
+                        children.push(BreadcrumbSeparator.into_any_element());
+                    }
+                    continue;
+                }
+
+                let item_widths = state.widths.clone();
+                children.push(
+                    div()
+                        .relative()
+                        .flex_shrink_0()
+                        .child(
+                            item.is_last(is_last)
+                                .truncated(widths[ix] >= ITEM_MAX_WIDTH.0),
+                        )
+                        .child(
+                            canvas(
+                                move |bounds, cx| {
+                                    let mut widths = item_widths.borrow_mut();
+                                    if widths.get(ix) != Some(&bounds.size.width) {
+                                        if let Some(width) = widths.get_mut(ix) {
+                                            *width = bounds.size.width;
+                                        }
+                                        cx.refresh();
+                                    }
+                                },
+                                |_, _, _| {},
+                            )
+                            .absolute()
+                            .size_full(),
+                        )
+                        .into_any_element(),
+                );
+                if !is_last {
+                    children.push(BreadcrumbSeparator.into_any_element());
+                }
+            }
+
+            let available_width = state.width.clone();
+            let mut element = h_flex()
+                .relative()
+                .w_full()
+                .min_w_0()
+                .overflow_hidden()
+                .gap_1p5()
+                .text_sm()
+                .text_color(cx.theme().muted_foreground)
+                .children(children)
+                .child(
+                    canvas(
+                        move |bounds, cx| {
+                            if available_width.get() != bounds.size.width {
+                                available_width.set(bounds.size.width);
+                                cx.refresh();
+                            }
+                        },
+                        |_, _, _| {},
+                    )
+                    .absolute()
+                    .size_full(),
+                )
+                .into_any_element();
+
+            ((element.request_layout(cx), element), state)
+        })
+    }
+
+    fn prepaint(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        _: gpui::Bounds<Pixels>,
+        element: &mut Self::RequestLayoutState,
+        cx: &mut WindowContext,
+    ) {
+        element.prepaint(cx);
+    }
+
+    fn paint(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        _: gpui::Bounds<Pixels>,
+        element: &mut Self::RequestLayoutState,
+        _: &mut Self::PrepaintState,
+        cx: &mut WindowContext,
+    ) {
+        element.paint(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::collapsed_range;
+
+    #[test]
+    fn test_collapsed_range() {
+        let widths = [50., 100., 100., 100., 50.];
+        // 400 for the items, and 4 separators.
+        assert_eq!(collapsed_range(&widths, 500., 20., 20.), None);
+        assert_eq!(collapsed_range(&widths, 0., 20., 20.), None);
+        // Collapse the second item into the ellipsis.
+        assert_eq!(collapsed_range(&widths, 400., 20., 20.), Some(1..2));
+        assert_eq!(collapsed_range(&widths, 300., 20., 20.), Some(1..3));
+        // Keep the first and the last items at least.
+        assert_eq!(collapsed_range(&widths, 100., 20., 20.), Some(1..4));
+        assert_eq!(collapsed_range(&[100., 100.], 50., 20., 20.), None);
     }
 }
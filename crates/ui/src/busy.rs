@@ -0,0 +1,245 @@
+use std::{
+    cell::Cell,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+use gpui::{
+    div, prelude::FluentBuilder as _, AnyElement, Element, ElementId, GlobalElementId,
+    InteractiveElement as _, IntoElement, LayoutId, ParentElement as _, SharedString, Styled as _,
+    WindowContext,
+};
+
+use crate::{
+    progress::ProgressCircle,
+    theme::{ActiveTheme as _, Colorize as _},
+    v_flex, Sizable as _, StyledExt as _,
+};
+
+/// The minimum duration to show the overlay, to avoid flicker.
+const MIN_DURATION: Duration = Duration::from_millis(300);
+
+/// The times to decide the visibility of the overlay.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+struct BusyTimes {
+    /// When the busy state has started.
+    busy_since: Option<Instant>,
+    /// When the overlay has been shown.
+    shown_at: Option<Instant>,
+}
+
+impl BusyTimes {
+    /// Update the times with the `busy` state, returns `(visible, waiting)`,
+    /// the `waiting` is true if the visibility may change without a new state, so a frame is needed.
+    fn update(&mut self, busy: bool, now: Instant, delay: Duration, min: Duration) -> (bool, bool) {
+        if busy {
+            let busy_since = *self.busy_since.get_or_insert(now);
+            if self.shown_at.is_none() && now.duration_since(busy_since) >= delay {
+                self.shown_at = Some(now);
+            }
+            return (self.shown_at.is_some(), self.shown_at.is_none());
+        }
+
+        self.busy_since = None;
+        match self.shown_at {
+            Some(shown_at) if now.duration_since(shown_at) < min => (true, true),
+            _ => {
+                self.shown_at = None;
+                (false, false)
+            }
+        }
+    }
+}
+
+/// A Busy overlay to show over a content while a long operation is running, e.g. "Indexing...".
+///
+/// The content is dimmed and the interaction is blocked, with a ProgressCircle and a message in the center.
+/// The overlay is shown for at least 300ms (see [`Busy::min_duration`]) to avoid flicker.
+pub struct Busy {
+    id: ElementId,
+    content: Option<AnyElement>,
+    busy: bool,
+    message: Option<SharedString>,
+    delay: Duration,
+    min_duration: Duration,
+}
+
+impl Busy {
+    /// Create a Busy overlay only, it should be placed in a `relative` container.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            content: None,
+            busy: false,
+            message: None,
+            delay: Duration::ZERO,
+            min_duration: MIN_DURATION,
+        }
+    }
+
+    /// Wrap the `content` with a busy overlay.
+    pub fn overlay(id: impl Into<ElementId>, content: impl IntoElement) -> Self {
+        Self {
+            content: Some(content.into_any_element()),
+            ..Self::new(id)
+        }
+    }
+
+    /// Set the busy state, default: false
+    pub fn busy(mut self, busy: bool) -> Self {
+        self.busy = busy;
+        self
+    }
+
+    /// Set the message to show below the ProgressCircle.
+    pub fn message(mut self, message: impl Into<SharedString>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// Set the delay to show the overlay, so the short operations will not show it, default: 0
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Set the minimum duration to show the overlay once it is shown, default: 300ms
+    pub fn min_duration(mut self, duration: Duration) -> Self {
+        self.min_duration = duration;
+        self
+    }
+}
+
+impl IntoElement for Busy {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+#[derive(Default)]
+pub struct BusyState {
+    times: Rc<Cell<BusyTimes>>,
+}
+
+impl Element for Busy {
+    type RequestLayoutState = AnyElement;
+
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        Some(self.id.clone())
+    }
+
+    fn request_layout(
+        &mut self,
+        global_id: Option<&GlobalElementId>,
+        cx: &mut WindowContext,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let content = self.content.take();
+
+        cx.with_element_state::<BusyState, _>(global_id.unwrap(), |state, cx| {
+            let state = state.unwrap_or_default();
+            let mut times = state.times.get();
+            let (visible, waiting) =
+                times.update(self.busy, Instant::now(), self.delay, self.min_duration);
+            state.times.set(times);
+            if waiting {
+                cx.request_animation_frame();
+            }
+
+            let overlay = visible.then(|| {
+                v_flex()
+                    .id("busy-overlay")
+                    .absolute()
+                    .top_0()
+                    .left_0()
+                    .size_full()
+                    .center()
+                    .gap_2()
+                    .bg(cx.theme().background.opacity(0.6))
+                    .occlude()
+                    .child(ProgressCircle::new("busy").large())
+                    .when_some(self.message.clone(), |this, message| {
+                        this.child(
+                            div()
+                                .text_sm()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(message),
+                        )
+                    })
+            });
+
+            let mut element = match content {
+                Some(content) => div()
+                    .relative()
+                    .size_full()
+                    .child(content)
+                    .children(overlay)
+                    .into_any_element(),
+                None => match overlay {
+                    Some(overlay) => overlay.into_any_element(),
+                    None => div().into_any_element(),
+                },
+            };
+
+            ((element.request_layout(cx), element), state)
+        })
+    }
+
+    fn prepaint(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        _: gpui::Bounds<gpui::Pixels>,
+        element: &mut Self::RequestLayoutState,
+        cx: &mut WindowContext,
+    ) {
+        element.prepaint(cx);
+    }
+
+    fn paint(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        _: gpui::Bounds<gpui::Pixels>,
+        element: &mut Self::RequestLayoutState,
+        _: &mut Self::PrepaintState,
+        cx: &mut WindowContext,
+    ) {
+        element.paint(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::BusyTimes;
+
+    #[test]
+    fn test_busy_times() {
+        let ms = Duration::from_millis;
+        let now = Instant::now();
+        let (delay, min) = (ms(100), ms(300));
+
+        let mut times = BusyTimes::default();
+        assert_eq!(times.update(false, now, delay, min), (false, false));
+        // Wait for the delay.
+        assert_eq!(times.update(true, now, delay, min), (false, true));
+        assert_eq!(times.update(true, now + ms(100), delay, min), (true, false));
+        // Keep visible for the min duration.
+        assert_eq!(times.update(false, now + ms(200), delay, min), (true, true));
+        assert_eq!(
+            times.update(false, now + ms(400), delay, min),
+            (false, false)
+        );
+
+        // A short operation finished in the delay is never shown.
+        let mut times = BusyTimes::default();
+        assert_eq!(times.update(true, now, delay, min), (false, true));
+        assert_eq!(
+            times.update(false, now + ms(50), delay, min),
+            (false, false)
+        );
+    }
+}
@@ -3,13 +3,14 @@ use crate::{
     indicator::Indicator,
     theme::{ActiveTheme, Colorize as _},
     tooltip::Tooltip,
-    Disableable, Icon, Selectable, Sizable, Size,
+    Disableable, Icon, Selectable, Sizable, Size, StyledExt as _,
 };
 use gpui::{
     div, prelude::FluentBuilder as _, px, relative, AnyElement, ClickEvent, Corners, Div, Edges,
-    ElementId, Hsla, InteractiveElement, IntoElement, MouseButton, ParentElement, Pixels,
-    RenderOnce, SharedString, StatefulInteractiveElement as _, Styled, WindowContext,
+    ElementId, FocusHandle, Hsla, InteractiveElement, IntoElement, MouseButton, ParentElement,
+    Pixels, RenderOnce, SharedString, StatefulInteractiveElement as _, Styled, WindowContext,
 };
+use std::rc::Rc;
 
 pub enum ButtonRounded {
     None,
@@ -150,12 +151,24 @@ impl ButtonVariant {
     }
 }
 
+/// The position of the icon in the Button with a label.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub enum IconPosition {
+    /// Before the label.
+    #[default]
+    Leading,
+    /// After the label.
+    Trailing,
+}
+
 /// A Button element.
 #[derive(IntoElement)]
 pub struct Button {
     pub base: Div,
     id: ElementId,
     icon: Option<Icon>,
+    icon_position: IconPosition,
+    focus_handle: Option<FocusHandle>,
     label: Option<SharedString>,
     children: Vec<AnyElement>,
     disabled: bool,
@@ -164,10 +177,10 @@ pub struct Button {
     rounded: ButtonRounded,
     border_corners: Corners<bool>,
     border_edges: Edges<bool>,
-    size: Size,
+    size: Option<Size>,
     compact: bool,
     tooltip: Option<SharedString>,
-    on_click: Option<Box<dyn Fn(&ClickEvent, &mut WindowContext) + 'static>>,
+    on_click: Option<Rc<dyn Fn(&ClickEvent, &mut WindowContext) + 'static>>,
     pub(crate) stop_propagation: bool,
     loading: bool,
     loading_icon: Option<Icon>,
@@ -185,6 +198,8 @@ impl Button {
             base: div().flex_shrink_0(),
             id: id.into(),
             icon: None,
+            icon_position: IconPosition::default(),
+            focus_handle: None,
             label: None,
             disabled: false,
             selected: false,
@@ -192,7 +207,7 @@ impl Button {
             rounded: ButtonRounded::Medium,
             border_corners: Corners::all(true),
             border_edges: Edges::all(true),
-            size: Size::Medium,
+            size: None,
             tooltip: None,
             on_click: None,
             stop_propagation: true,
@@ -233,6 +248,19 @@ impl Button {
         self
     }
 
+    /// Set the position of the icon when the button has a label, default: [`IconPosition::Leading`]
+    pub fn icon_position(mut self, position: IconPosition) -> Self {
+        self.icon_position = position;
+        self
+    }
+
+    /// Set the focus handle, then the button can be clicked by Enter or Space when it is focused,
+    /// and shows the focus ring.
+    pub fn focusable(mut self, focus_handle: &FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle.clone());
+        self
+    }
+
     /// Set the tooltip of the button.
     pub fn tooltip(mut self, tooltip: impl Into<SharedString>) -> Self {
         self.tooltip = Some(tooltip.into());
@@ -252,7 +280,7 @@ impl Button {
     }
 
     pub fn on_click(mut self, handler: impl Fn(&ClickEvent, &mut WindowContext) + 'static) -> Self {
-        self.on_click = Some(Box::new(handler));
+        self.on_click = Some(Rc::new(handler));
         self
     }
 
@@ -287,7 +315,7 @@ impl Selectable for Button {
 
 impl Sizable for Button {
     fn with_size(mut self, size: impl Into<Size>) -> Self {
-        self.size = size.into();
+        self.size = Some(size.into());
         self
     }
 }
@@ -321,9 +349,11 @@ impl RenderOnce for Button {
     fn render(self, cx: &mut WindowContext) -> impl IntoElement {
         let style: ButtonVariant = self.variant;
         let normal_style = style.normal(cx);
-        let icon_size = match self.size {
+        // Follow the density of the theme if the size is not set.
+        let size = self.size.unwrap_or_else(|| cx.theme().default_size());
+        let icon_size = match size {
             Size::Size(v) => Size::Size(v * 0.75),
-            _ => self.size,
+            _ => size,
         };
 
         self.base
@@ -339,18 +369,20 @@ impl RenderOnce for Button {
             .when(!style.no_padding(), |this| {
                 if self.label.is_none() && self.children.is_empty() {
                     // Icon Button
-                    match self.size {
+                    match size {
                         Size::Size(px) => this.size(px),
                         Size::XSmall => this.size_5(),
                         Size::Small => this.size_6(),
                         Size::Large | Size::Medium => this.size_8(),
+                        Size::XLarge => this.size_10(),
                     }
                 } else {
                     // Normal Button
-                    match self.size {
-                        Size::Size(size) => this.px(size * 0.2),
+                    match size {
+                        Size::Size(size) => this.h(size).px(size * 0.2),
                         Size::XSmall => this.h_5().px_1(),
                         Size::Small => this.h_6().px_3().when(self.compact, |this| this.px_1p5()),
+                        Size::XLarge => this.h_10().px_5().when(self.compact, |this| this.px_2p5()),
                         _ => this.h_8().px_4().when(self.compact, |this| this.px_2()),
                     }
                 }
@@ -394,7 +426,7 @@ impl RenderOnce for Button {
                         let hover_style = style.hovered(cx);
                         this.bg(hover_style.bg)
                             .border_color(hover_style.border)
-                            .text_color(crate::red_400())
+                            .text_color(hover_style.fg)
                     })
                     .active(|this| {
                         let active_style = style.active(cx);
@@ -413,11 +445,25 @@ impl RenderOnce for Button {
                             cx.stop_propagation();
                         }
                     })
-                    .on_click(move |event, cx| {
-                        (on_click)(event, cx);
+                    .on_click({
+                        let on_click = on_click.clone();
+                        move |event, cx| {
+                            (on_click)(event, cx);
+                        }
+                    })
+                    .when(self.focus_handle.is_some(), |this| {
+                        this.on_key_down(move |event, cx| {
+                            if matches!(event.keystroke.key.as_str(), "enter" | "space") {
+                                cx.stop_propagation();
+                                (on_click)(&ClickEvent::default(), cx);
+                            }
+                        })
                     })
                 },
             )
+            .when_some(self.focus_handle.as_ref(), |this, focus_handle| {
+                this.track_focus(focus_handle).focus_ring(focus_handle, cx)
+            })
             .when(self.disabled, |this| {
                 let disabled_style = style.disabled(cx);
                 this.cursor_not_allowed()
@@ -431,20 +477,24 @@ impl RenderOnce for Button {
                     .id("label")
                     .items_center()
                     .justify_center()
-                    .map(|this| match self.size {
+                    .map(|this| match size {
                         Size::XSmall => this.gap_1().text_xs(),
                         Size::Small => this.gap_1().text_sm(),
+                        Size::XLarge => this.gap_2().text_lg(),
                         _ => this.gap_2().text_base(),
                     })
-                    .when(!self.loading, |this| {
-                        this.when_some(self.icon, |this, icon| {
-                            this.child(icon.with_size(icon_size))
-                        })
-                    })
+                    .when(
+                        !self.loading && self.icon_position == IconPosition::Leading,
+                        |this| {
+                            this.when_some(self.icon.clone(), |this, icon| {
+                                this.child(icon.with_size(icon_size))
+                            })
+                        },
+                    )
                     .when(self.loading, |this| {
                         this.child(
                             Indicator::new()
-                                .with_size(self.size)
+                                .with_size(size)
                                 .when_some(self.loading_icon, |this, icon| this.icon(icon)),
                         )
                     })
@@ -452,6 +502,14 @@ impl RenderOnce for Button {
                         this.child(div().flex_none().line_height(relative(1.)).child(label))
                     })
                     .children(self.children)
+                    .when(
+                        !self.loading && self.icon_position == IconPosition::Trailing,
+                        |this| {
+                            this.when_some(self.icon, |this, icon| {
+                                this.child(icon.with_size(icon_size))
+                            })
+                        },
+                    )
             })
             .when(self.loading, |this| this.bg(normal_style.bg.opacity(0.8)))
             .when_some(self.tooltip.clone(), |this, tooltip| {
@@ -460,6 +518,16 @@ impl RenderOnce for Button {
     }
 }
 
+/// A square Button with only an icon, for the toolbars and the suffix of the inputs.
+pub struct IconButton;
+
+impl IconButton {
+    /// Create a ghost Button with the icon, use the [`ButtonVariants`] to change the variant.
+    pub fn new(id: impl Into<ElementId>, icon: impl Into<Icon>) -> Button {
+        Button::new(id).icon(icon).ghost()
+    }
+}
+
 struct ButtonVariantStyle {
     bg: Hsla,
     border: Hsla,
@@ -0,0 +1,426 @@
+use std::{
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+use gpui::{
+    actions, canvas, div, prelude::FluentBuilder as _, px, Animation, AnimationExt as _,
+    AnyElement, AppContext, ElementId, EventEmitter, FocusHandle, FocusableView,
+    InteractiveElement as _, IntoElement, KeyBinding, MouseButton, MouseDownEvent, MouseMoveEvent,
+    MouseUpEvent, ParentElement as _, Pixels, Render, StatefulInteractiveElement as _, Styled,
+    Task, ViewContext, WindowContext,
+};
+use smol::Timer;
+
+use crate::{
+    animation::{cubic_bezier, reduced_motion},
+    button::{Button, ButtonVariants as _},
+    h_flex,
+    theme::ActiveTheme as _,
+    Disableable as _, IconName, Sizable as _,
+};
+
+actions!(carousel, [PrevPage, NextPage]);
+
+const CONTEXT: &str = "Carousel";
+/// The duration of the slide animation.
+const DURATION: Duration = Duration::from_millis(300);
+/// The velocity (px/ms) of the drag to flick to the next page.
+const FLICK_VELOCITY: f32 = 0.5;
+/// The min distance of the drag to flick to the next page.
+const FLICK_DISTANCE: f32 = 8.;
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("left", PrevPage, Some(CONTEXT)),
+        KeyBinding::new("right", NextPage, Some(CONTEXT)),
+    ]);
+}
+
+pub enum CarouselEvent {
+    /// The current page is changed, with the index of the page.
+    PageChanged(usize),
+}
+
+/// Returns the page to snap to after dragging the `current` page by the `offset`.
+///
+/// Go to the adjacent page when dragged over the half of the `width`, or flicked by the `velocity` (px/ms).
+fn snap_page(current: usize, count: usize, offset: f32, width: f32, velocity: f32) -> usize {
+    if count == 0 || width <= 0. {
+        return current;
+    }
+
+    let flick = velocity.abs() > FLICK_VELOCITY && offset.abs() > FLICK_DISTANCE;
+    if offset.abs() <= width / 2. && !flick {
+        return current;
+    }
+
+    if offset < 0. {
+        (current + 1).min(count - 1)
+    } else {
+        current.saturating_sub(1)
+    }
+}
+
+struct DragState {
+    start_x: Pixels,
+    started_at: Instant,
+}
+
+type PageBuilder = Rc<dyn Fn(&mut WindowContext) -> AnyElement>;
+
+/// A Carousel to show the pages one by one, e.g. the onboarding steps or media previews.
+///
+/// The pages are sized to the carousel, and changed by the chevron buttons, the dots,
+/// dragging or Left/Right when focused. Only the current and adjacent pages are rendered.
+pub struct Carousel {
+    id: ElementId,
+    focus_handle: FocusHandle,
+    pages: Vec<PageBuilder>,
+    height: Pixels,
+    current: usize,
+    /// The previous page, it is rendered while sliding from it.
+    prev: Option<usize>,
+    /// The offset of the strip to slide from.
+    slide_from: Pixels,
+    /// Incremented for each slide, to restart the animation.
+    slide_count: usize,
+    width: Pixels,
+    drag: Option<DragState>,
+    drag_offset: Pixels,
+    hovered: bool,
+    _autoplay_task: Option<Task<()>>,
+}
+
+impl Carousel {
+    pub fn new(id: impl Into<ElementId>, cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            id: id.into(),
+            focus_handle: cx.focus_handle(),
+            pages: Vec::new(),
+            height: px(200.),
+            current: 0,
+            prev: None,
+            slide_from: px(0.),
+            slide_count: 0,
+            width: px(0.),
+            drag: None,
+            drag_offset: px(0.),
+            hovered: false,
+            _autoplay_task: None,
+        }
+    }
+
+    /// Add a page, the `render` is called only when the page is current or adjacent.
+    pub fn page<E: IntoElement>(
+        mut self,
+        render: impl Fn(&mut WindowContext) -> E + 'static,
+    ) -> Self {
+        self.pages
+            .push(Rc::new(move |cx| render(cx).into_any_element()));
+        self
+    }
+
+    /// Set the height of the carousel, default: 200px
+    pub fn height(mut self, height: impl Into<Pixels>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Go to the next page by every `interval`, and wrap to the first page at the end.
+    ///
+    /// The autoplay is paused while hovered, and disabled when the reduced motion is enabled.
+    pub fn autoplay(mut self, interval: Duration, cx: &mut ViewContext<Self>) -> Self {
+        self.set_autoplay(Some(interval), cx);
+        self
+    }
+
+    pub fn set_autoplay(&mut self, interval: Option<Duration>, cx: &mut ViewContext<Self>) {
+        self._autoplay_task = interval.map(|interval| {
+            cx.spawn(|view, mut cx| async move {
+                loop {
+                    Timer::after(interval).await;
+                    if view
+                        .update(&mut cx, |this, cx| this.autoplay_tick(cx))
+                        .is_err()
+                    {
+                        break;
+                    }
+                }
+            })
+        });
+    }
+
+    /// Returns the index of the current page.
+    pub fn current_page(&self) -> usize {
+        self.current
+    }
+
+    /// Set the current page without emitting the event.
+    pub fn set_current_page(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        self.slide_to(ix, cx);
+    }
+
+    fn offset_of(&self, ix: usize) -> Pixels {
+        -(self.width * ix as f32)
+    }
+
+    fn slide_to(&mut self, ix: usize, cx: &mut ViewContext<Self>) -> bool {
+        let ix = ix.min(self.pages.len().saturating_sub(1));
+        let from = self.offset_of(self.current) + self.drag_offset;
+        self.drag_offset = px(0.);
+        if ix == self.current && from == self.offset_of(ix) {
+            return false;
+        }
+
+        self.slide_from = from;
+        self.slide_count += 1;
+        self.prev = Some(self.current).filter(|prev| *prev != ix);
+        let changed = self.current != ix;
+        self.current = ix;
+        cx.notify();
+        changed
+    }
+
+    fn go_to(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        if self.slide_to(ix, cx) {
+            cx.emit(CarouselEvent::PageChanged(self.current));
+        }
+    }
+
+    fn prev_page(&mut self, _: &PrevPage, cx: &mut ViewContext<Self>) {
+        if self.current > 0 {
+            self.go_to(self.current - 1, cx);
+        }
+    }
+
+    fn next_page(&mut self, _: &NextPage, cx: &mut ViewContext<Self>) {
+        self.go_to(self.current + 1, cx);
+    }
+
+    fn autoplay_tick(&mut self, cx: &mut ViewContext<Self>) {
+        if self.hovered || self.drag.is_some() || self.pages.len() < 2 || cx.theme().reduce_motion()
+        {
+            return;
+        }
+
+        self.go_to((self.current + 1) % self.pages.len(), cx);
+    }
+
+    fn update_width(&mut self, width: Pixels, cx: &mut ViewContext<Self>) {
+        if self.width != width {
+            self.width = width;
+            cx.notify();
+        }
+    }
+
+    fn on_mouse_down(&mut self, event: &MouseDownEvent, cx: &mut ViewContext<Self>) {
+        self.focus_handle.focus(cx);
+        self.drag = Some(DragState {
+            start_x: event.position.x,
+            started_at: Instant::now(),
+        });
+        self.drag_offset = px(0.);
+    }
+
+    fn on_mouse_move(&mut self, event: &MouseMoveEvent, cx: &mut ViewContext<Self>) {
+        let Some(drag) = self.drag.as_ref() else {
+            return;
+        };
+        if event.pressed_button != Some(MouseButton::Left) {
+            return;
+        }
+
+        let mut offset = event.position.x - drag.start_x;
+        // Resist to drag over the first or the last page.
+        if (self.current == 0 && offset > px(0.))
+            || (self.current + 1 >= self.pages.len() && offset < px(0.))
+        {
+            offset = px(offset.0 / 3.);
+        }
+        self.drag_offset = offset;
+        cx.notify();
+    }
+
+    fn on_mouse_up(&mut self, _: &MouseUpEvent, cx: &mut ViewContext<Self>) {
+        let Some(drag) = self.drag.take() else {
+            return;
+        };
+
+        let elapsed = drag.started_at.elapsed().as_secs_f32() * 1000.;
+        let offset = self.drag_offset.0;
+        let velocity = if elapsed > 0. { offset / elapsed } else { 0. };
+        let ix = snap_page(
+            self.current,
+            self.pages.len(),
+            offset,
+            self.width.0,
+            velocity,
+        );
+        self.go_to(ix, cx);
+    }
+
+    fn render_pages(&self, cx: &mut ViewContext<Self>) -> AnyElement {
+        let current = self.current;
+        let last = self.pages.len().saturating_sub(1);
+        let mut visible = (current.saturating_sub(1)..=(current + 1).min(last)).collect::<Vec<_>>();
+        if let Some(prev) = self.prev.filter(|prev| !visible.contains(prev)) {
+            visible.push(prev);
+        }
+
+        let strip = div().absolute().top_0().h_full().w(self.width).children(
+            visible
+                .into_iter()
+                .filter_map(|ix| Some((ix, self.pages.get(ix)?.clone())))
+                .map(|(ix, render)| {
+                    div()
+                        .absolute()
+                        .top_0()
+                        .left(self.width * ix as f32)
+                        .w(self.width)
+                        .h_full()
+                        .overflow_hidden()
+                        .child(render(cx))
+                }),
+        );
+
+        let to = self.offset_of(current);
+        if self.drag.is_some() {
+            strip.left(to + self.drag_offset).into_any_element()
+        } else {
+            let from = self.slide_from;
+            strip
+                .with_animation(
+                    ElementId::NamedInteger("slide".into(), self.slide_count),
+                    Animation::new(DURATION)
+                        .with_easing(reduced_motion(cubic_bezier(0.4, 0., 0.2, 1.), cx)),
+                    move |this, delta| this.left(from + (to - from) * delta),
+                )
+                .into_any_element()
+        }
+    }
+}
+
+impl EventEmitter<CarouselEvent> for Carousel {}
+
+impl FocusableView for Carousel {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for Carousel {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let view = cx.view().clone();
+        let count = self.pages.len();
+        let current = self.current;
+
+        div()
+            .id(self.id.clone())
+            .key_context(CONTEXT)
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::prev_page))
+            .on_action(cx.listener(Self::next_page))
+            .on_hover(cx.listener(|this, hovered: &bool, _| this.hovered = *hovered))
+            .relative()
+            .w_full()
+            .h(self.height)
+            .rounded(px(cx.theme().radius))
+            .overflow_hidden()
+            .child(
+                div()
+                    .id("viewport")
+                    .relative()
+                    .size_full()
+                    .overflow_hidden()
+                    .on_mouse_down(MouseButton::Left, cx.listener(Self::on_mouse_down))
+                    .on_mouse_move(cx.listener(Self::on_mouse_move))
+                    .on_mouse_up(MouseButton::Left, cx.listener(Self::on_mouse_up))
+                    .on_mouse_up_out(MouseButton::Left, cx.listener(Self::on_mouse_up))
+                    .child(
+                        canvas(
+                            move |bounds, cx| {
+                                view.update(cx, |r, cx| r.update_width(bounds.size.width, cx))
+                            },
+                            |_, _, _| {},
+                        )
+                        .absolute()
+                        .size_full(),
+                    )
+                    .child(self.render_pages(cx)),
+            )
+            .when(count > 1, |this| {
+                this.child(
+                    h_flex().absolute().left_2().top_0().bottom_0().child(
+                        Button::new("prev")
+                            .outline()
+                            .small()
+                            .icon(IconName::ChevronLeft)
+                            .disabled(current == 0)
+                            .on_click(cx.listener(|this, _, cx| this.prev_page(&PrevPage, cx))),
+                    ),
+                )
+                .child(
+                    h_flex().absolute().right_2().top_0().bottom_0().child(
+                        Button::new("next")
+                            .outline()
+                            .small()
+                            .icon(IconName::ChevronRight)
+                            .disabled(current + 1 >= count)
+                            .on_click(cx.listener(|this, _, cx| this.next_page(&NextPage, cx))),
+                    ),
+                )
+                .child(
+                    h_flex()
+                        .absolute()
+                        .bottom_2()
+                        .left_0()
+                        .right_0()
+                        .justify_center()
+                        .gap_1p5()
+                        .children((0..count).map(|ix| {
+                            div()
+                                .id(("dot", ix))
+                                .size_2()
+                                .rounded_full()
+                                .cursor_pointer()
+                                .map(|this| {
+                                    if ix == current {
+                                        this.bg(cx.theme().primary)
+                                    } else {
+                                        this.bg(cx.theme().muted_foreground.opacity(0.4)).hover(
+                                            |this| {
+                                                this.bg(cx.theme().muted_foreground.opacity(0.7))
+                                            },
+                                        )
+                                    }
+                                })
+                                .on_click(cx.listener(move |this, _, cx| this.go_to(ix, cx)))
+                        })),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::snap_page;
+
+    #[test]
+    fn test_snap_page() {
+        // Not dragged enough.
+        assert_eq!(snap_page(1, 3, -40., 100., 0.1), 1);
+        assert_eq!(snap_page(1, 3, 40., 100., 0.1), 1);
+        // Dragged over the half.
+        assert_eq!(snap_page(1, 3, -60., 100., 0.1), 2);
+        assert_eq!(snap_page(1, 3, 60., 100., 0.1), 0);
+        // Flicked.
+        assert_eq!(snap_page(1, 3, -20., 100., -1.), 2);
+        assert_eq!(snap_page(1, 3, 4., 100., 1.), 1);
+        // Clamped at the ends.
+        assert_eq!(snap_page(2, 3, -80., 100., 0.), 2);
+        assert_eq!(snap_page(0, 3, 80., 100., 0.), 0);
+        // Not measured yet.
+        assert_eq!(snap_page(1, 3, -80., 0., 0.), 1);
+    }
+}
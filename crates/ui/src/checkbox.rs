@@ -1,8 +1,13 @@
-use crate::{h_flex, theme::ActiveTheme, v_flex, Disableable, IconName, Selectable};
+use std::rc::Rc;
+
+use crate::{
+    h_flex, theme::ActiveTheme, v_flex, Disableable, IconName, Selectable, Sizable, Size,
+    StyledExt as _,
+};
 use gpui::{
-    div, prelude::FluentBuilder as _, relative, svg, ElementId, InteractiveElement, IntoElement,
-    ParentElement, RenderOnce, SharedString, StatefulInteractiveElement as _, Styled as _,
-    WindowContext,
+    div, prelude::FluentBuilder as _, relative, svg, ElementId, EventEmitter, FocusHandle,
+    InteractiveElement, IntoElement, ParentElement, Render, RenderOnce, SharedString,
+    StatefulInteractiveElement as _, Styled as _, ViewContext, WindowContext,
 };
 
 /// A Checkbox element.
@@ -11,8 +16,11 @@ pub struct Checkbox {
     id: ElementId,
     label: Option<SharedString>,
     checked: bool,
+    indeterminate: bool,
     disabled: bool,
-    on_click: Option<Box<dyn Fn(&bool, &mut WindowContext) + 'static>>,
+    size: Size,
+    focus_handle: Option<FocusHandle>,
+    on_click: Option<Rc<dyn Fn(&bool, &mut WindowContext) + 'static>>,
 }
 
 impl Checkbox {
@@ -21,7 +29,10 @@ impl Checkbox {
             id: id.into(),
             label: None,
             checked: false,
+            indeterminate: false,
             disabled: false,
+            size: Size::default(),
+            focus_handle: None,
             on_click: None,
         }
     }
@@ -36,10 +47,29 @@ impl Checkbox {
         self
     }
 
+    /// Set to show the indeterminate state, e.g. some of the children are checked.
+    ///
+    /// This takes precedence over `checked`, and the click will change it to checked.
+    pub fn indeterminate(mut self, indeterminate: bool) -> Self {
+        self.indeterminate = indeterminate;
+        self
+    }
+
+    /// Set the focus handle, to toggle by Space when it is focused, and show the focus ring.
+    pub fn focusable(mut self, focus_handle: &FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle.clone());
+        self
+    }
+
     pub fn on_click(mut self, handler: impl Fn(&bool, &mut WindowContext) + 'static) -> Self {
-        self.on_click = Some(Box::new(handler));
+        self.on_click = Some(Rc::new(handler));
         self
     }
+
+    /// The same as `on_click`, the argument is the new checked state.
+    pub fn on_change(self, handler: impl Fn(&bool, &mut WindowContext) + 'static) -> Self {
+        self.on_click(handler)
+    }
 }
 
 impl Disableable for Checkbox {
@@ -59,31 +89,48 @@ impl Selectable for Checkbox {
     }
 }
 
+impl Sizable for Checkbox {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
 impl RenderOnce for Checkbox {
     fn render(self, cx: &mut WindowContext) -> impl IntoElement {
-        let (color, icon_color) = if self.disabled {
-            (
-                cx.theme().primary.opacity(0.5),
-                cx.theme().primary_foreground.opacity(0.5),
-            )
-        } else {
-            (cx.theme().primary, cx.theme().primary_foreground)
-        };
+        let color = cx.theme().primary;
+        let icon_color = cx.theme().primary_foreground;
+        let filled = self.checked || self.indeterminate;
+        // Click on the indeterminate state to check all.
+        let new_checked = self.indeterminate || !self.checked;
 
         h_flex()
             .id(self.id)
             .gap_2()
             .items_center()
             .line_height(relative(1.))
+            .map(|this| match self.size {
+                Size::XSmall => this.gap_1().text_xs(),
+                Size::Small => this.gap_1p5().text_sm(),
+                Size::Large => this.text_lg(),
+                Size::XLarge => this.gap_2p5().text_xl(),
+                _ => this,
+            })
             .child(
                 v_flex()
                     .relative()
                     .border_1()
                     .border_color(color)
                     .rounded_sm()
-                    .size_4()
+                    .map(|this| match self.size {
+                        Size::XSmall => this.size_3(),
+                        Size::Small => this.size_3p5(),
+                        Size::Large => this.size_5(),
+                        Size::XLarge => this.size_6(),
+                        _ => this.size_4(),
+                    })
                     .flex_shrink_0()
-                    .map(|this| match self.checked {
+                    .map(|this| match filled {
                         false => this.bg(cx.theme().transparent),
                         _ => this.bg(color),
                     })
@@ -92,11 +139,22 @@ impl RenderOnce for Checkbox {
                             .absolute()
                             .top_px()
                             .left_px()
-                            .size_3()
+                            .map(|this| match self.size {
+                                Size::XSmall => this.size_2(),
+                                Size::Small => this.size_2p5(),
+                                Size::Large => this.size_4(),
+                                Size::XLarge => this.size_5(),
+                                _ => this.size_3(),
+                            })
                             .text_color(icon_color)
-                            .map(|this| match self.checked {
-                                true => this.path(IconName::Check.path()),
-                                _ => this,
+                            .map(|this| {
+                                if self.indeterminate {
+                                    this.path(IconName::Minus.path())
+                                } else if self.checked {
+                                    this.path(IconName::Check.path())
+                                } else {
+                                    this
+                                }
                             }),
                     ),
             )
@@ -114,18 +172,169 @@ impl RenderOnce for Checkbox {
                     this
                 }
             })
-            .when(self.disabled, |this| {
-                this.cursor_not_allowed()
-                    .text_color(cx.theme().muted_foreground)
+            .disabled_style(self.disabled, cx)
+            .when_some(self.focus_handle.as_ref(), |this, focus_handle| {
+                this.track_focus(focus_handle).focus_ring(focus_handle, cx)
             })
             .when_some(
                 self.on_click.filter(|_| !self.disabled),
                 |this, on_click| {
-                    this.on_click(move |_, cx| {
-                        let checked = !self.checked;
-                        on_click(&checked, cx);
+                    this.on_click({
+                        let on_click = on_click.clone();
+                        move |_, cx| on_click(&new_checked, cx)
+                    })
+                    .when(self.focus_handle.is_some(), |this| {
+                        this.on_key_down(move |event, cx| {
+                            if event.keystroke.key == "space" {
+                                cx.stop_propagation();
+                                on_click(&new_checked, cx);
+                            }
+                        })
                     })
                 },
             )
     }
 }
+
+pub enum CheckboxGroupEvent {
+    /// The checked values are changed.
+    Change(Vec<SharedString>),
+}
+
+/// Returns the `(checked, indeterminate)` state of the "select all" checkbox.
+fn select_all_state(checked: &[bool]) -> (bool, bool) {
+    let count = checked.iter().filter(|checked| **checked).count();
+    (
+        count > 0 && count == checked.len(),
+        count > 0 && count < checked.len(),
+    )
+}
+
+/// A group of checkboxes for the options, with an optional "select all" checkbox.
+pub struct CheckboxGroup {
+    id: ElementId,
+    options: Vec<SharedString>,
+    checked: Vec<bool>,
+    select_all: Option<SharedString>,
+    disabled: bool,
+    size: Size,
+}
+
+impl CheckboxGroup {
+    pub fn new(
+        id: impl Into<ElementId>,
+        options: impl Into<Vec<SharedString>>,
+        _: &mut ViewContext<Self>,
+    ) -> Self {
+        let options = options.into();
+        Self {
+            id: id.into(),
+            checked: vec![false; options.len()],
+            options,
+            select_all: None,
+            disabled: false,
+            size: Size::default(),
+        }
+    }
+
+    /// Show a "select all" checkbox with the `label` above the options,
+    /// it shows indeterminate when some of the options are checked.
+    pub fn select_all(mut self, label: impl Into<SharedString>) -> Self {
+        self.select_all = Some(label.into());
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Returns the checked options.
+    pub fn values(&self) -> Vec<SharedString> {
+        self.options
+            .iter()
+            .zip(self.checked.iter())
+            .filter(|(_, checked)| **checked)
+            .map(|(option, _)| option.clone())
+            .collect()
+    }
+
+    /// Set the checked options, the unknown values are ignored.
+    pub fn set_values(&mut self, values: &[SharedString], cx: &mut ViewContext<Self>) {
+        self.checked = self
+            .options
+            .iter()
+            .map(|option| values.contains(option))
+            .collect();
+        cx.notify();
+    }
+
+    fn set_checked(&mut self, ix: Option<usize>, checked: bool, cx: &mut ViewContext<Self>) {
+        match ix {
+            Some(ix) => self.checked[ix] = checked,
+            None => self.checked.fill(checked),
+        }
+        cx.emit(CheckboxGroupEvent::Change(self.values()));
+        cx.notify();
+    }
+}
+
+impl Sizable for CheckboxGroup {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl EventEmitter<CheckboxGroupEvent> for CheckboxGroup {}
+
+impl Render for CheckboxGroup {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let (all_checked, indeterminate) = select_all_state(&self.checked);
+
+        v_flex()
+            .id(self.id.clone())
+            .gap_2()
+            .when_some(self.select_all.clone(), |this, label| {
+                this.child(
+                    Checkbox::new("select-all")
+                        .label(label)
+                        .checked(all_checked)
+                        .indeterminate(indeterminate)
+                        .disabled(self.disabled)
+                        .with_size(self.size)
+                        .on_change(
+                            cx.listener(|this, checked, cx| this.set_checked(None, *checked, cx)),
+                        ),
+                )
+            })
+            .child(
+                v_flex()
+                    .gap_2()
+                    .when(self.select_all.is_some(), |this| this.pl_6())
+                    .children(self.options.iter().enumerate().map(|(ix, option)| {
+                        Checkbox::new(("option", ix))
+                            .label(option.clone())
+                            .checked(self.checked[ix])
+                            .disabled(self.disabled)
+                            .with_size(self.size)
+                            .on_change(cx.listener(move |this, checked, cx| {
+                                this.set_checked(Some(ix), *checked, cx)
+                            }))
+                    })),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::select_all_state;
+
+    #[test]
+    fn test_select_all_state() {
+        assert_eq!(select_all_state(&[false, false]), (false, false));
+        assert_eq!(select_all_state(&[true, false]), (false, true));
+        assert_eq!(select_all_state(&[true, true]), (true, false));
+        assert_eq!(select_all_state(&[]), (false, false));
+    }
+}
@@ -1,15 +1,22 @@
-use std::{cell::RefCell, rc::Rc, time::Duration};
+use std::{cell::Cell, rc::Rc, time::Duration};
 
 use gpui::{
-    prelude::FluentBuilder, AnyElement, ClipboardItem, Element, ElementId, GlobalElementId,
-    IntoElement, LayoutId, ParentElement, SharedString, Styled, WindowContext,
+    div, prelude::FluentBuilder, px, AnyElement, ClipboardItem, Element, ElementId,
+    GlobalElementId, InteractiveElement as _, IntoElement, LayoutId, ParentElement, RenderOnce,
+    SharedString, StatefulInteractiveElement as _, Styled, WindowContext,
 };
 
 use crate::{
     button::{Button, ButtonVariants as _},
-    h_flex, IconName, Sizable as _,
+    h_flex,
+    notification::Notification,
+    theme::ActiveTheme as _,
+    ContextModal as _, IconName, Sizable, Size,
 };
 
+/// The duration to show the check icon after copied.
+const COPIED_DURATION: Duration = Duration::from_millis(1500);
+
 pub struct Clipboard {
     id: ElementId,
     value: SharedString,
@@ -58,12 +65,121 @@ impl IntoElement for Clipboard {
     }
 }
 
+impl Element for Clipboard {
+    type RequestLayoutState = AnyElement;
+
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        Some(self.id.clone())
+    }
+
+    fn request_layout(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        cx: &mut WindowContext,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let content_element = self
+            .content_builder
+            .as_ref()
+            .map(|builder| builder(cx).into_any_element());
+        let copied_callback = self.copied_callback.clone();
+
+        let mut element = h_flex()
+            .gap_1()
+            .items_center()
+            .when_some(content_element, |this, element| this.child(element))
+            .child(
+                CopyButton::new(self.id.clone(), self.value.clone())
+                    .when_some(copied_callback, |this, callback| {
+                        this.on_copied(move |value, cx| callback(value, cx))
+                    }),
+            )
+            .into_any_element();
+
+        (element.request_layout(cx), element)
+    }
+
+    fn prepaint(
+        &mut self,
+        _: Option<&gpui::GlobalElementId>,
+        _: gpui::Bounds<gpui::Pixels>,
+        element: &mut Self::RequestLayoutState,
+        cx: &mut WindowContext,
+    ) {
+        element.prepaint(cx);
+    }
+
+    fn paint(
+        &mut self,
+        _: Option<&gpui::GlobalElementId>,
+        _: gpui::Bounds<gpui::Pixels>,
+        element: &mut Self::RequestLayoutState,
+        _: &mut Self::PrepaintState,
+        cx: &mut WindowContext,
+    ) {
+        element.paint(cx)
+    }
+}
+
+/// A ghost icon button to copy the text to the clipboard,
+/// the icon changes to a check with a "Copied" tooltip for a moment after copied.
+pub struct CopyButton {
+    id: ElementId,
+    value: SharedString,
+    size: Size,
+    copied_callback: Option<Rc<dyn Fn(SharedString, &mut WindowContext)>>,
+}
+
+impl CopyButton {
+    pub fn new(id: impl Into<ElementId>, value: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            value: value.into(),
+            size: Size::XSmall,
+            copied_callback: None,
+        }
+    }
+
+    /// Set the handler after the value is copied.
+    pub fn on_copied<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(SharedString, &mut WindowContext) + 'static,
+    {
+        self.copied_callback = Some(Rc::new(handler));
+        self
+    }
+}
+
+impl Sizable for CopyButton {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl IntoElement for CopyButton {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
 #[derive(Default)]
-pub struct ClipboardState {
-    copied: Rc<RefCell<bool>>,
+pub struct CopyButtonState {
+    copied: Rc<Cell<bool>>,
 }
 
-impl Element for Clipboard {
+/// Write the `value` to the clipboard, returns false if it is not in the clipboard after written.
+fn write_to_clipboard(value: &SharedString, cx: &mut WindowContext) -> bool {
+    cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+    cx.read_from_clipboard()
+        .and_then(|item| item.text())
+        .map_or(false, |text| text == value.as_ref())
+}
+
+impl Element for CopyButton {
     type RequestLayoutState = AnyElement;
 
     type PrepaintState = ();
@@ -77,52 +193,49 @@ impl Element for Clipboard {
         global_id: Option<&GlobalElementId>,
         cx: &mut WindowContext,
     ) -> (LayoutId, Self::RequestLayoutState) {
-        cx.with_element_state::<ClipboardState, _>(global_id.unwrap(), |state, cx| {
+        cx.with_element_state::<CopyButtonState, _>(global_id.unwrap(), |state, cx| {
             let state = state.unwrap_or_default();
 
-            let content_element = self
-                .content_builder
-                .as_ref()
-                .map(|builder| builder(cx).into_any_element());
             let value = self.value.clone();
-            let clipboard_id = self.id.clone();
-            let copied_callback = self.copied_callback.as_ref().map(|c| c.clone());
+            let copied_callback = self.copied_callback.clone();
             let copied = state.copied.clone();
-            let copide_value = *copied.borrow();
-
-            let mut element = h_flex()
-                .gap_1()
-                .items_center()
-                .when_some(content_element, |this, element| this.child(element))
-                .child(
-                    Button::new(clipboard_id)
-                        .icon(if copide_value {
-                            IconName::Check
-                        } else {
-                            IconName::Copy
+            let is_copied = copied.get();
+
+            let mut element = Button::new(self.id.clone())
+                .icon(if is_copied {
+                    IconName::Check
+                } else {
+                    IconName::Copy
+                })
+                .ghost()
+                .with_size(self.size)
+                .tooltip(if is_copied { "Copied" } else { "Copy" })
+                .when(!is_copied, |this| {
+                    this.on_click(move |_, cx| {
+                        cx.stop_propagation();
+                        if !write_to_clipboard(&value, cx) {
+                            cx.push_notification(Notification::error(
+                                "Failed to copy to the clipboard.",
+                            ));
+                            return;
+                        }
+
+                        copied.set(true);
+                        cx.refresh();
+                        let copied = copied.clone();
+                        cx.spawn(|mut cx| async move {
+                            cx.background_executor().timer(COPIED_DURATION).await;
+
+                            copied.set(false);
+                            cx.update(|cx| cx.refresh()).ok();
                         })
-                        .ghost()
-                        .xsmall()
-                        .when(!copide_value, |this| {
-                            this.on_click(move |_, cx| {
-                                cx.stop_propagation();
-                                cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
-                                *copied.borrow_mut() = true;
-
-                                let copied = copied.clone();
-                                cx.spawn(|cx| async move {
-                                    cx.background_executor().timer(Duration::from_secs(2)).await;
-
-                                    *copied.borrow_mut() = false;
-                                })
-                                .detach();
-
-                                if let Some(callback) = &copied_callback {
-                                    callback(value.clone(), cx);
-                                }
-                            })
-                        }),
-                )
+                        .detach();
+
+                        if let Some(callback) = &copied_callback {
+                            callback(value.clone(), cx);
+                        }
+                    })
+                })
                 .into_any_element();
 
             ((element.request_layout(cx), element), state)
@@ -150,3 +263,82 @@ impl Element for Clipboard {
         element.paint(cx)
     }
 }
+
+/// A code snippet in the monospace font, with a [`CopyButton`] at the top-right corner.
+///
+/// The long lines are not wrapped, the snippet can be scrolled horizontally.
+#[derive(IntoElement)]
+pub struct Snippet {
+    id: ElementId,
+    code: SharedString,
+    copyable: bool,
+}
+
+impl Snippet {
+    pub fn new(id: impl Into<ElementId>, code: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            code: code.into(),
+            copyable: true,
+        }
+    }
+
+    /// Set to show the copy button, default: true
+    pub fn copyable(mut self, copyable: bool) -> Self {
+        self.copyable = copyable;
+        self
+    }
+}
+
+impl RenderOnce for Snippet {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let lines = self
+            .code
+            .lines()
+            .map(|line| {
+                // Keep the height of the empty lines.
+                SharedString::from(if line.is_empty() { " " } else { line }.to_string())
+            })
+            .collect::<Vec<_>>();
+
+        div()
+            .id(self.id)
+            .relative()
+            .w_full()
+            .rounded(px(cx.theme().radius))
+            .border_1()
+            .border_color(cx.theme().border)
+            .bg(cx.theme().muted.opacity(0.5))
+            .text_color(cx.theme().foreground)
+            .child(
+                div()
+                    .id("code")
+                    .overflow_x_scroll()
+                    .py_2()
+                    .pl_3()
+                    .map(|this| {
+                        if self.copyable {
+                            this.pr_10()
+                        } else {
+                            this.pr_3()
+                        }
+                    })
+                    .font_family(cx.theme().mono_font_family.clone())
+                    .text_sm()
+                    .children(
+                        lines
+                            .into_iter()
+                            .map(|line| div().whitespace_nowrap().child(line)),
+                    ),
+            )
+            .when(self.copyable, |this| {
+                this.child(
+                    div()
+                        .absolute()
+                        .top_1()
+                        .right_1()
+                        .child(CopyButton::new("copy", self.code)),
+                )
+            })
+    }
+}
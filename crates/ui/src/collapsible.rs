@@ -0,0 +1,296 @@
+use std::{cell::Cell, f32::consts::PI, rc::Rc, time::Duration};
+
+use gpui::{
+    actions, canvas, div, prelude::FluentBuilder as _, px, radians, rems, Animation,
+    AnimationExt as _, AnyElement, AppContext, ElementId, EventEmitter, FocusHandle, FocusableView,
+    InteractiveElement as _, IntoElement, KeyBinding, ParentElement as _, Pixels, Render,
+    SharedString, StatefulInteractiveElement as _, Styled as _, Task, ViewContext, WindowContext,
+};
+use smol::Timer;
+
+use crate::{
+    animation::cubic_bezier, h_flex, theme::ActiveTheme as _, v_flex, Icon, IconName, Sizable, Size,
+};
+
+actions!(collapsible, [Toggle]);
+
+const CONTEXT: &str = "Collapsible";
+const DURATION: Duration = Duration::from_millis(200);
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("enter", Toggle, Some(CONTEXT)),
+        KeyBinding::new("space", Toggle, Some(CONTEXT)),
+    ]);
+}
+
+pub enum CollapsibleEvent {
+    Open,
+    Close,
+}
+
+/// Returns the size of the chevron icon for the size of the section.
+pub(crate) fn chevron_size(size: Size) -> Size {
+    match size {
+        Size::XSmall | Size::Small => Size::XSmall,
+        Size::Large | Size::XLarge => Size::Medium,
+        _ => Size::Small,
+    }
+}
+
+/// A single expandable section, with a header to toggle the content.
+///
+/// The header can be focused and toggled by Enter or Space.
+pub struct Collapsible {
+    focus_handle: FocusHandle,
+    title: SharedString,
+    icon: Option<Icon>,
+    content: Option<Rc<dyn Fn(&mut WindowContext) -> AnyElement>>,
+    open: bool,
+    disabled: bool,
+    animated: bool,
+    size: Size,
+    /// Increase on every toggle, to restart the animations.
+    toggle_count: usize,
+    /// Keep the content until the close animation is finished.
+    closing: bool,
+    /// The height of the content from the last layout, for the height animation.
+    content_height: Rc<Cell<Pixels>>,
+    _closing_task: Option<Task<()>>,
+}
+
+impl Collapsible {
+    pub fn new(title: impl Into<SharedString>, cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            title: title.into(),
+            icon: None,
+            content: None,
+            open: false,
+            disabled: false,
+            animated: true,
+            size: Size::default(),
+            toggle_count: 0,
+            closing: false,
+            content_height: Rc::new(Cell::new(px(0.))),
+            _closing_task: None,
+        }
+    }
+
+    pub fn icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Set the content, it is only rendered when the section is open.
+    pub fn content<E, F>(mut self, builder: F) -> Self
+    where
+        E: IntoElement,
+        F: Fn(&mut WindowContext) -> E + 'static,
+    {
+        self.content = Some(Rc::new(move |cx| builder(cx).into_any_element()));
+        self
+    }
+
+    /// Set the initial open state, default: false
+    pub fn open(mut self, open: bool) -> Self {
+        self.open = open;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set to animate the height of the content and the chevron, default: true
+    ///
+    /// The animations are always disabled when the reduced motion is enabled.
+    pub fn animated(mut self, animated: bool) -> Self {
+        self.animated = animated;
+        self
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    pub fn set_open(&mut self, open: bool, cx: &mut ViewContext<Self>) {
+        if self.open == open {
+            return;
+        }
+
+        self.open = open;
+        self.toggle_count += 1;
+        self.closing = false;
+        self._closing_task = None;
+        if !open && self.is_animated(cx) {
+            self.closing = true;
+            self._closing_task = Some(cx.spawn(|view, mut cx| async move {
+                Timer::after(DURATION).await;
+                _ = view.update(&mut cx, |view, cx| {
+                    view.closing = false;
+                    cx.notify();
+                });
+            }));
+        }
+
+        cx.emit(if open {
+            CollapsibleEvent::Open
+        } else {
+            CollapsibleEvent::Close
+        });
+        cx.notify();
+    }
+
+    pub fn toggle(&mut self, cx: &mut ViewContext<Self>) {
+        self.set_open(!self.open, cx);
+    }
+
+    fn on_toggle(&mut self, _: &Toggle, cx: &mut ViewContext<Self>) {
+        if !self.disabled {
+            self.toggle(cx);
+        }
+    }
+
+    fn is_animated(&self, cx: &AppContext) -> bool {
+        self.animated && !cx.theme().reduce_motion()
+    }
+
+    fn render_chevron(&self, cx: &WindowContext) -> AnyElement {
+        let open = self.open;
+        let icon = Icon::new(IconName::ChevronDown)
+            .with_size(chevron_size(self.size))
+            .text_color(cx.theme().muted_foreground);
+
+        if !self.is_animated(cx) || self.toggle_count == 0 {
+            return icon
+                .rotate(radians(if open { PI } else { 0. }))
+                .into_any_element();
+        }
+
+        icon.with_animation(
+            ElementId::NamedInteger("chevron".into(), self.toggle_count),
+            Animation::new(DURATION).with_easing(cubic_bezier(0.4, 0., 0.2, 1.)),
+            move |icon, delta| {
+                let progress = if open { delta } else { 1. - delta };
+                icon.rotate(radians(PI * progress))
+            },
+        )
+        .into_any_element()
+    }
+}
+
+impl Sizable for Collapsible {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl EventEmitter<CollapsibleEvent> for Collapsible {}
+
+impl FocusableView for Collapsible {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for Collapsible {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let text_size = match self.size {
+            Size::XSmall | Size::Small => rems(0.875),
+            _ => rems(1.0),
+        };
+        let open = self.open;
+        let animated = self.is_animated(cx) && self.toggle_count > 0;
+        let content_height = self.content_height.clone();
+        let height = content_height.get();
+        let content = self
+            .content
+            .clone()
+            .filter(|_| self.open || self.closing)
+            .map(|builder| builder(cx));
+
+        v_flex()
+            .text_size(text_size)
+            .child(
+                h_flex()
+                    .id("header")
+                    .key_context(CONTEXT)
+                    .track_focus(&self.focus_handle)
+                    .on_action(cx.listener(Self::on_toggle))
+                    .justify_between()
+                    .gap_2()
+                    .rounded_md()
+                    .map(|this| match self.size {
+                        Size::XSmall => this.py_0().px_1p5(),
+                        Size::Small => this.py_0p5().px_2(),
+                        Size::Large => this.py_1p5().px_4(),
+                        Size::XLarge => this.py_2().px_5(),
+                        _ => this.py_1().px_3(),
+                    })
+                    .focus(|this| this.bg(cx.theme().accordion_hover))
+                    .when(self.disabled, |this| {
+                        this.text_color(cx.theme().muted_foreground)
+                    })
+                    .when(!self.disabled, |this| {
+                        this.cursor_pointer()
+                            .hover(|this| this.bg(cx.theme().accordion_hover))
+                            .on_click(cx.listener(|this, _, cx| {
+                                this.focus_handle.focus(cx);
+                                this.toggle(cx);
+                            }))
+                    })
+                    .child(
+                        h_flex()
+                            .items_center()
+                            .gap_2()
+                            .when_some(self.icon.clone(), |this, icon| {
+                                this.child(
+                                    icon.with_size(self.size)
+                                        .text_color(cx.theme().muted_foreground),
+                                )
+                            })
+                            .child(self.title.clone()),
+                    )
+                    .child(self.render_chevron(cx)),
+            )
+            .when_some(content, |this, content| {
+                let body = div().overflow_hidden().child(
+                    div()
+                        .relative()
+                        .map(|this| match self.size {
+                            Size::XSmall => this.p_1p5(),
+                            Size::Small => this.p_2(),
+                            Size::Large => this.p_4(),
+                            Size::XLarge => this.p_5(),
+                            _ => this.p_3(),
+                        })
+                        .child(content)
+                        .child(
+                            canvas(
+                                move |bounds, _| content_height.set(bounds.size.height),
+                                |_, _, _| {},
+                            )
+                            .absolute()
+                            .size_full(),
+                        ),
+                );
+
+                // The height is unknown before the first layout, then show without the animation.
+                if animated && height > px(0.) {
+                    this.child(body.with_animation(
+                        ElementId::NamedInteger("body".into(), self.toggle_count),
+                        Animation::new(DURATION).with_easing(cubic_bezier(0.4, 0., 0.2, 1.)),
+                        move |this, delta| {
+                            let progress = if open { delta } else { 1. - delta };
+                            this.h(height * progress)
+                        },
+                    ))
+                } else {
+                    this.child(body)
+                }
+            })
+    }
+}
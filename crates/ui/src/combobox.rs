@@ -0,0 +1,395 @@
+use std::ops::Range;
+
+use gpui::{
+    actions, anchored, canvas, deferred, div, prelude::FluentBuilder, px, rems, AppContext, Bounds,
+    ElementId, EventEmitter, FocusHandle, FocusableView, FontWeight, HighlightStyle,
+    InteractiveElement, IntoElement, KeyBinding, ParentElement, Pixels, Render, SharedString,
+    Styled, StyledText, Subscription, Task, View, ViewContext, VisualContext, WeakView,
+    WindowContext,
+};
+
+use crate::{
+    input::{InputEvent, TextInput},
+    list::{List, ListDelegate, ListItem},
+    theme::ActiveTheme,
+    v_flex, Sizable, Size, StyleSized,
+};
+
+actions!(combobox, [Up, Down, Escape]);
+
+const CONTEXT: &str = "Combobox";
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("up", Up, Some(CONTEXT)),
+        KeyBinding::new("down", Down, Some(CONTEXT)),
+        KeyBinding::new("escape", Escape, Some(CONTEXT)),
+    ])
+}
+
+pub enum ComboboxEvent {
+    /// An option is picked, the index is of the items.
+    Selected(usize),
+    /// The free text is committed by Enter, only when `allow_custom_values` is enabled.
+    Committed(String),
+}
+
+/// Returns the range of the first case-insensitive match of the `query` in the `text`.
+fn match_range(text: &str, query: &str) -> Option<Range<usize>> {
+    if query.is_empty() {
+        return None;
+    }
+
+    // Only the ASCII case folding keeps the byte offsets the same as the original text.
+    let ix = text
+        .to_ascii_lowercase()
+        .find(&query.to_ascii_lowercase())?;
+    Some(ix..ix + query.len())
+}
+
+struct ComboboxListDelegate {
+    combobox: WeakView<Combobox>,
+    items: Vec<SharedString>,
+    /// The indexes of the items that match the query.
+    matches: Vec<usize>,
+    query: SharedString,
+    selected_index: Option<usize>,
+    size: Size,
+}
+
+impl ComboboxListDelegate {
+    fn filter(&mut self, query: &str) {
+        self.query = query.to_string().into();
+        self.matches = self
+            .items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| query.is_empty() || match_range(item, query).is_some())
+            .map(|(ix, _)| ix)
+            .collect();
+        self.selected_index = None;
+    }
+}
+
+impl ListDelegate for ComboboxListDelegate {
+    type Item = ListItem;
+
+    fn items_count(&self, _: &AppContext) -> usize {
+        self.matches.len()
+    }
+
+    fn render_item(&self, ix: usize, cx: &mut ViewContext<List<Self>>) -> Option<Self::Item> {
+        let item = self.items.get(*self.matches.get(ix)?)?.clone();
+        let highlights = match_range(&item, &self.query).map(|range| {
+            (
+                range,
+                HighlightStyle {
+                    font_weight: Some(FontWeight::SEMIBOLD),
+                    color: Some(cx.theme().foreground),
+                    ..Default::default()
+                },
+            )
+        });
+
+        Some(
+            ListItem::new(("combobox-item", ix))
+                .cursor_pointer()
+                .selected(self.selected_index == Some(ix))
+                .list_size(self.size)
+                .child(
+                    div()
+                        .whitespace_nowrap()
+                        .child(StyledText::new(item).with_highlights(&cx.text_style(), highlights)),
+                ),
+        )
+    }
+
+    fn perform_search(&mut self, query: &str, _: &mut ViewContext<List<Self>>) -> Task<()> {
+        self.filter(query);
+        Task::ready(())
+    }
+
+    fn set_selected_index(&mut self, ix: Option<usize>, _: &mut ViewContext<List<Self>>) {
+        self.selected_index = ix;
+    }
+
+    fn confirm(&mut self, ix: Option<usize>, cx: &mut ViewContext<List<Self>>) {
+        let Some(item_ix) = ix.and_then(|ix| self.matches.get(ix).copied()) else {
+            return;
+        };
+
+        let combobox = self.combobox.clone();
+        cx.defer(move |_, cx| {
+            _ = combobox.update(cx, |this, cx| this.select(item_ix, cx));
+        });
+    }
+}
+
+/// An editable input with a dropdown list of the options filtered by the input text.
+///
+/// Unlike the [`crate::dropdown::Dropdown`], the user can keep the free text
+/// if `allow_custom_values` is enabled.
+pub struct Combobox {
+    id: ElementId,
+    input: View<TextInput>,
+    list: View<List<ComboboxListDelegate>>,
+    open: bool,
+    allow_custom_values: bool,
+    /// The text set by picking an option, to not open the menu again by its change event.
+    picked_text: Option<SharedString>,
+    size: Size,
+    /// Store the bounds of the input
+    bounds: Bounds<Pixels>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl Combobox {
+    pub fn new(
+        id: impl Into<ElementId>,
+        items: impl Into<Vec<SharedString>>,
+        cx: &mut ViewContext<Self>,
+    ) -> Self {
+        let items = items.into();
+        let input = cx.new_view(TextInput::new);
+        let mut delegate = ComboboxListDelegate {
+            combobox: cx.view().downgrade(),
+            matches: vec![],
+            items,
+            query: SharedString::default(),
+            selected_index: None,
+            size: Size::Medium,
+        };
+        delegate.filter("");
+        let list = cx.new_view(|cx| List::new(delegate, cx).no_query().max_h(rems(20.)));
+
+        let _subscriptions = vec![
+            cx.subscribe(&input, Self::on_input_event),
+            cx.on_blur(&input.focus_handle(cx), Self::on_blur),
+            cx.on_blur(&list.focus_handle(cx), Self::on_blur),
+        ];
+
+        Self {
+            id: id.into(),
+            input,
+            list,
+            open: false,
+            allow_custom_values: false,
+            picked_text: None,
+            size: Size::Medium,
+            bounds: Bounds::default(),
+            _subscriptions,
+        }
+    }
+
+    /// Set to allow the free text that is not in the options, default: false.
+    pub fn allow_custom_values(mut self, allow: bool) -> Self {
+        self.allow_custom_values = allow;
+        self
+    }
+
+    /// Set the placeholder of the input.
+    pub fn placeholder(self, placeholder: impl Into<SharedString>, cx: &mut WindowContext) -> Self {
+        let placeholder = placeholder.into();
+        self.input
+            .update(cx, |input, _| input.set_placeholder(placeholder));
+        self
+    }
+
+    /// Return the text of the input.
+    pub fn text(&self, cx: &AppContext) -> SharedString {
+        self.input.read(cx).text()
+    }
+
+    pub fn focus(&self, cx: &mut WindowContext) {
+        self.input.focus_handle(cx).focus(cx);
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: View<TextInput>,
+        event: &InputEvent,
+        cx: &mut ViewContext<Self>,
+    ) {
+        match event {
+            InputEvent::Change(text) => {
+                if self.picked_text.take().as_ref() == Some(text) {
+                    return;
+                }
+
+                let allow_custom_values = self.allow_custom_values;
+                let text = text.trim().to_string();
+                self.list.update(cx, |list, cx| {
+                    list.delegate_mut().filter(&text);
+                    // Highlight the first option to pick by Enter, unless the free text is allowed.
+                    let has_matches = list.delegate().items_count(cx) > 0;
+                    let ix = (!allow_custom_values && has_matches).then_some(0);
+                    list.set_selected_index(ix, cx);
+                    cx.notify();
+                });
+                self.open = true;
+                cx.notify();
+            }
+            InputEvent::PressEnter => self.confirm(cx),
+            _ => {}
+        }
+    }
+
+    fn on_blur(&mut self, cx: &mut ViewContext<Self>) {
+        // When the input and the menu are both not focused, close the menu.
+        if self.input.focus_handle(cx).is_focused(cx) || self.list.focus_handle(cx).is_focused(cx) {
+            return;
+        }
+
+        self.open = false;
+        cx.notify();
+    }
+
+    /// Pick the highlighted option, or commit the free text.
+    fn confirm(&mut self, cx: &mut ViewContext<Self>) {
+        let list = self.list.read(cx);
+        let selected = list
+            .selected_index()
+            .filter(|_| self.open)
+            .and_then(|ix| list.delegate().matches.get(ix).copied());
+
+        if let Some(item_ix) = selected {
+            self.select(item_ix, cx);
+        } else if self.allow_custom_values {
+            let text = self.text(cx).to_string();
+            self.open = false;
+            cx.emit(ComboboxEvent::Committed(text));
+            cx.notify();
+        }
+    }
+
+    fn select(&mut self, item_ix: usize, cx: &mut ViewContext<Self>) {
+        let Some(item) = self.list.read(cx).delegate().items.get(item_ix).cloned() else {
+            return;
+        };
+
+        self.picked_text = Some(item.clone());
+        self.input.update(cx, |input, cx| input.set_text(item, cx));
+        self.open = false;
+        self.focus(cx);
+        cx.emit(ComboboxEvent::Selected(item_ix));
+        cx.notify();
+    }
+
+    fn up(&mut self, _: &Up, cx: &mut ViewContext<Self>) {
+        if !self.open {
+            return;
+        }
+
+        self.list.update(cx, |list, cx| list.select_prev(cx));
+    }
+
+    fn down(&mut self, _: &Down, cx: &mut ViewContext<Self>) {
+        if !self.open {
+            self.open = true;
+            cx.notify();
+            return;
+        }
+
+        self.list.update(cx, |list, cx| list.select_next(cx));
+    }
+
+    fn escape(&mut self, _: &Escape, cx: &mut ViewContext<Self>) {
+        if !self.open {
+            // Propagate the event to the parent view, for example to the Modal to support ESC to close.
+            cx.propagate();
+            return;
+        }
+
+        self.open = false;
+        cx.notify();
+    }
+}
+
+impl Sizable for Combobox {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl EventEmitter<ComboboxEvent> for Combobox {}
+
+impl FocusableView for Combobox {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.input.focus_handle(cx)
+    }
+}
+
+impl Render for Combobox {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let view = cx.view().clone();
+        let bounds = self.bounds;
+        let size = self.size;
+        // If the size has change, set size to the input and the list items.
+        if self.list.read(cx).delegate().size != size {
+            self.input.update(cx, |input, cx| input.set_size(size, cx));
+            self.list
+                .update(cx, |list, _| list.delegate_mut().size = size);
+        }
+        let has_matches = self.list.read(cx).delegate().items_count(cx) > 0;
+
+        div()
+            .id(self.id.clone())
+            .key_context(CONTEXT)
+            .on_action(cx.listener(Self::up))
+            .on_action(cx.listener(Self::down))
+            .on_action(cx.listener(Self::escape))
+            .w_full()
+            .relative()
+            .child(self.input.clone())
+            .child(
+                canvas(
+                    move |bounds, cx| view.update(cx, |r, _| r.bounds = bounds),
+                    |_, _, _| {},
+                )
+                .absolute()
+                .size_full(),
+            )
+            .when(self.open && has_matches, |this| {
+                this.child(
+                    deferred(
+                        anchored().snap_to_window_with_margin(px(8.)).child(
+                            div()
+                                .occlude()
+                                .w(bounds.size.width)
+                                .child(
+                                    v_flex()
+                                        .occlude()
+                                        .mt_1p5()
+                                        .bg(cx.theme().popover_background())
+                                        .border_1()
+                                        .border_color(cx.theme().border)
+                                        .rounded(px(cx.theme().radius))
+                                        .shadow_md()
+                                        .child(self.list.clone()),
+                                )
+                                .on_mouse_down_out(cx.listener(|this, _, cx| {
+                                    this.open = false;
+                                    cx.notify();
+                                })),
+                        ),
+                    )
+                    .with_priority(1),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::match_range;
+
+    #[test]
+    fn test_match_range() {
+        assert_eq!(match_range("United States", "sta"), Some(7..10));
+        assert_eq!(match_range("United States", "UNI"), Some(0..3));
+        assert_eq!(match_range("United States", "xyz"), None);
+        assert_eq!(match_range("United States", ""), None);
+        assert_eq!(match_range("Zürich", "rich"), Some(3..7));
+    }
+}
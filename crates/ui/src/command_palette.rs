@@ -0,0 +1,317 @@
+use std::rc::Rc;
+
+use gpui::{
+    div, prelude::FluentBuilder as _, px, rems, Action, AppContext, FocusHandle, FocusableView,
+    IntoElement, Keystroke, ParentElement as _, Render, SharedString, Styled as _, Task, View,
+    ViewContext, VisualContext as _, WindowContext,
+};
+use rust_i18n::t;
+
+use crate::{
+    h_flex,
+    kbd::Kbd,
+    list::{List, ListDelegate, ListItem},
+    theme::ActiveTheme as _,
+    ContextModal as _,
+};
+
+/// The max number of the recently used commands.
+const MAX_RECENT: usize = 5;
+
+/// A command in the [`CommandPalette`].
+#[derive(Clone)]
+pub struct Command {
+    pub id: SharedString,
+    pub title: SharedString,
+    keystroke: Option<Keystroke>,
+    action: Option<Rc<Box<dyn Action>>>,
+    handler: Rc<dyn Fn(&mut WindowContext)>,
+}
+
+impl Command {
+    /// Create a command that runs the `handler` when it is chosen.
+    pub fn new(
+        id: impl Into<SharedString>,
+        title: impl Into<SharedString>,
+        handler: impl Fn(&mut WindowContext) + 'static,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            keystroke: None,
+            action: None,
+            handler: Rc::new(handler),
+        }
+    }
+
+    /// Create a command that dispatches the `action` when it is chosen.
+    ///
+    /// The key binding of the action will be shown as the shortcut hint.
+    pub fn action(
+        id: impl Into<SharedString>,
+        title: impl Into<SharedString>,
+        action: Box<dyn Action>,
+    ) -> Self {
+        let action = Rc::new(action);
+        let mut this = Self::new(id, title, {
+            let action = action.clone();
+            move |cx| cx.dispatch_action(action.boxed_clone())
+        });
+        this.action = Some(action);
+        this
+    }
+
+    /// Set the keyboard shortcut hint of the command.
+    pub fn keystroke(mut self, keystroke: Keystroke) -> Self {
+        self.keystroke = Some(keystroke);
+        self
+    }
+
+    fn keystrokes(&self, cx: &WindowContext) -> Vec<Keystroke> {
+        if let Some(keystroke) = &self.keystroke {
+            return vec![keystroke.clone()];
+        }
+
+        self.action
+            .as_ref()
+            .and_then(|action| {
+                cx.bindings_for_action(action.as_ref().as_ref())
+                    .first()
+                    .cloned()
+            })
+            .map(|binding| binding.keystrokes().to_vec())
+            .unwrap_or_default()
+    }
+}
+
+/// A delegate to supply the commands of the [`CommandPalette`].
+pub trait CommandPaletteDelegate: 'static {
+    /// Returns the commands, this is called every time the palette is opened.
+    fn commands(&self, cx: &WindowContext) -> Vec<Command>;
+
+    /// Load the ids of the recently used commands, the most recent first.
+    fn load_recent(&self, _cx: &WindowContext) -> Vec<SharedString> {
+        Vec::new()
+    }
+
+    /// Save the ids of the recently used commands, for example to a file to persist them.
+    fn save_recent(&mut self, _ids: &[SharedString], _cx: &mut WindowContext) {}
+}
+
+impl CommandPaletteDelegate for Vec<Command> {
+    fn commands(&self, _: &WindowContext) -> Vec<Command> {
+        self.clone()
+    }
+}
+
+struct CommandListDelegate {
+    delegate: Box<dyn CommandPaletteDelegate>,
+    commands: Vec<Command>,
+    /// The ids of the recently used commands, the most recent first.
+    recent: Vec<SharedString>,
+    /// The indexes of the matched commands, the recently used ones first.
+    matches: Vec<usize>,
+    query: String,
+    selected_index: Option<usize>,
+}
+
+impl CommandListDelegate {
+    fn is_recent(&self, command: &Command) -> bool {
+        self.recent.contains(&command.id)
+    }
+
+    fn update_matches(&mut self) {
+        let query = self.query.to_lowercase();
+        let is_match = |command: &Command| command.title.to_lowercase().contains(&query);
+
+        let recent = self.recent.iter().filter_map(|id| {
+            self.commands
+                .iter()
+                .position(|command| &command.id == id)
+                .filter(|ix| is_match(&self.commands[*ix]))
+        });
+        let others = self
+            .commands
+            .iter()
+            .enumerate()
+            .filter(|(_, command)| !self.is_recent(command) && is_match(command))
+            .map(|(ix, _)| ix);
+
+        self.matches = recent.chain(others).collect();
+        self.selected_index = (!self.matches.is_empty()).then_some(0);
+    }
+}
+
+impl ListDelegate for CommandListDelegate {
+    type Item = ListItem;
+
+    fn items_count(&self, _: &AppContext) -> usize {
+        self.matches.len()
+    }
+
+    fn render_item(&self, ix: usize, cx: &mut ViewContext<List<Self>>) -> Option<Self::Item> {
+        let command = self.commands.get(*self.matches.get(ix)?)?;
+        let is_recent = self.is_recent(command);
+        let keystrokes = command.keystrokes(cx);
+
+        Some(
+            ListItem::new(("command", ix))
+                .cursor_pointer()
+                .selected(self.selected_index == Some(ix))
+                .child(
+                    h_flex()
+                        .w_full()
+                        .gap_2()
+                        .justify_between()
+                        .child(div().whitespace_nowrap().child(command.title.clone()))
+                        .child(
+                            h_flex()
+                                .gap_1()
+                                .when(is_recent, |this| {
+                                    this.child(
+                                        div()
+                                            .text_xs()
+                                            .text_color(cx.theme().muted_foreground)
+                                            .child(t!("CommandPalette.recent").to_string()),
+                                    )
+                                })
+                                .children(keystrokes.into_iter().map(Kbd::new)),
+                        ),
+                ),
+        )
+    }
+
+    fn perform_search(&mut self, query: &str, _: &mut ViewContext<List<Self>>) -> Task<()> {
+        self.query = query.to_string();
+        self.update_matches();
+        Task::ready(())
+    }
+
+    fn set_selected_index(&mut self, ix: Option<usize>, _: &mut ViewContext<List<Self>>) {
+        self.selected_index = ix;
+    }
+
+    fn confirm(&mut self, ix: Option<usize>, cx: &mut ViewContext<List<Self>>) {
+        let Some(command) = ix
+            .and_then(|ix| self.matches.get(ix))
+            .and_then(|ix| self.commands.get(*ix))
+            .cloned()
+        else {
+            return;
+        };
+
+        self.recent.retain(|id| id != &command.id);
+        self.recent.insert(0, command.id.clone());
+        self.recent.truncate(MAX_RECENT);
+        self.delegate.save_recent(&self.recent, cx);
+
+        // Close the palette first to focus back, so the action is dispatched to the previous focused view.
+        cx.close_modal();
+        (command.handler)(cx);
+    }
+
+    fn cancel(&mut self, cx: &mut ViewContext<List<Self>>) {
+        cx.close_modal();
+    }
+}
+
+/// A command palette in a modal, like the `Cmd+K` in many apps.
+///
+/// ```ignore
+/// let palette = cx.new_view(|cx| CommandPalette::new(vec![
+///     Command::new("new-file", "New File", |cx| { ... }),
+///     Command::action("quit", "Quit", Box::new(Quit)),
+/// ], cx));
+///
+/// CommandPalette::open(&palette, cx);
+/// ```
+pub struct CommandPalette {
+    list: View<List<CommandListDelegate>>,
+}
+
+impl CommandPalette {
+    pub fn new(delegate: impl CommandPaletteDelegate, cx: &mut ViewContext<Self>) -> Self {
+        let delegate = CommandListDelegate {
+            delegate: Box::new(delegate),
+            commands: Vec::new(),
+            recent: Vec::new(),
+            matches: Vec::new(),
+            query: String::new(),
+            selected_index: None,
+        };
+
+        let list = cx.new_view(|cx| {
+            let mut list = List::new(delegate, cx).max_h(rems(20.));
+            list.set_query_placeholder(t!("CommandPalette.placeholder"), cx);
+            list
+        });
+
+        Self { list }
+    }
+
+    /// Open the command palette in a modal, and reload the commands from the delegate.
+    pub fn open(palette: &View<Self>, cx: &mut WindowContext) {
+        palette.update(cx, |this, cx| {
+            this.list.update(cx, |list, cx| {
+                let delegate = list.delegate_mut();
+                delegate.commands = delegate.delegate.commands(cx);
+                delegate.recent = delegate.delegate.load_recent(cx);
+                delegate.query.clear();
+                delegate.update_matches();
+                let selected_index = delegate.selected_index;
+
+                list.set_query("", cx);
+                list.set_selected_index(selected_index, cx);
+            });
+        });
+
+        let view = palette.clone();
+        cx.open_modal(move |modal, _| modal.show_close(false).width(px(560.)).child(view.clone()));
+        palette.focus_handle(cx).focus(cx);
+    }
+}
+
+impl FocusableView for CommandPalette {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.list.focus_handle(cx)
+    }
+}
+
+impl Render for CommandPalette {
+    fn render(&mut self, _: &mut ViewContext<Self>) -> impl IntoElement {
+        div().w_full().child(self.list.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Command, CommandListDelegate};
+
+    #[test]
+    fn test_recent_commands_first() {
+        let mut delegate = CommandListDelegate {
+            delegate: Box::new(Vec::<Command>::new()),
+            commands: ["Open File", "Save File", "Close Window"]
+                .into_iter()
+                .map(|title| Command::new(title.to_lowercase(), title, |_| {}))
+                .collect(),
+            recent: vec!["close window".into(), "unknown".into()],
+            matches: vec![],
+            query: String::new(),
+            selected_index: None,
+        };
+
+        delegate.update_matches();
+        assert_eq!(delegate.matches, vec![2, 0, 1]);
+        assert_eq!(delegate.selected_index, Some(0));
+
+        delegate.query = "file".into();
+        delegate.update_matches();
+        assert_eq!(delegate.matches, vec![0, 1]);
+
+        delegate.query = "xyz".into();
+        delegate.update_matches();
+        assert!(delegate.matches.is_empty());
+        assert_eq!(delegate.selected_index, None);
+    }
+}
@@ -0,0 +1,305 @@
+use std::{cell::Cell, rc::Rc};
+
+use gpui::{
+    canvas, div, prelude::FluentBuilder as _, px, AnyElement, Axis, Element, ElementId,
+    GlobalElementId, InteractiveElement as _, IntoElement, LayoutId, ParentElement as _, Pixels,
+    SharedString, StatefulInteractiveElement as _, Styled as _, WindowContext,
+};
+
+use crate::{
+    badge::is_long_label, clipboard::CopyButton, divider::Divider, h_flex, theme::ActiveTheme as _,
+    tooltip::Tooltip, v_flex,
+};
+
+/// The width of the labels in the horizontal layout.
+const LABEL_WIDTH: Pixels = px(120.);
+/// Collapse to the vertical layout when the width is less than this.
+const COLLAPSE_WIDTH: Pixels = px(360.);
+
+enum DescriptionValue {
+    Text(SharedString),
+    Element(AnyElement),
+}
+
+enum DescriptionEntry {
+    Item {
+        label: SharedString,
+        value: DescriptionValue,
+    },
+    /// A group title, use `None` for a plain separator.
+    Group(Option<SharedString>),
+}
+
+/// Returns the layout by the `width` of the list, collapse to the vertical layout if it is too narrow.
+fn layout_axis(axis: Axis, width: Pixels, collapse_width: Pixels) -> Axis {
+    if axis == Axis::Horizontal && width > px(0.) && width < collapse_width {
+        Axis::Vertical
+    } else {
+        axis
+    }
+}
+
+/// A DescriptionList to show the label and value pairs, e.g. the details in an inspector.
+///
+/// The horizontal layout is collapsed to the vertical layout when the width is not enough.
+pub struct DescriptionList {
+    id: ElementId,
+    entries: Vec<DescriptionEntry>,
+    axis: Axis,
+    label_width: Pixels,
+    collapse_width: Pixels,
+    copyable: bool,
+}
+
+impl DescriptionList {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            entries: Vec::new(),
+            axis: Axis::Horizontal,
+            label_width: LABEL_WIDTH,
+            collapse_width: COLLAPSE_WIDTH,
+            copyable: false,
+        }
+    }
+
+    /// Show the labels at the left of the values, this is the default.
+    pub fn horizontal(mut self) -> Self {
+        self.axis = Axis::Horizontal;
+        self
+    }
+
+    /// Show the labels above the values.
+    pub fn vertical(mut self) -> Self {
+        self.axis = Axis::Vertical;
+        self
+    }
+
+    /// Set the width of the labels in the horizontal layout, default: 120px
+    pub fn label_width(mut self, width: impl Into<Pixels>) -> Self {
+        self.label_width = width.into();
+        self
+    }
+
+    /// Set the width to collapse the horizontal layout to vertical, default: 360px
+    pub fn collapse_width(mut self, width: impl Into<Pixels>) -> Self {
+        self.collapse_width = width.into();
+        self
+    }
+
+    /// Set to show a copy button for the text values, default: false
+    pub fn copyable(mut self, copyable: bool) -> Self {
+        self.copyable = copyable;
+        self
+    }
+
+    /// Add an item with a custom value element.
+    pub fn item(mut self, label: impl Into<SharedString>, value: impl IntoElement) -> Self {
+        self.entries.push(DescriptionEntry::Item {
+            label: label.into(),
+            value: DescriptionValue::Element(value.into_any_element()),
+        });
+        self
+    }
+
+    /// Add an item with a text value, the long text is truncated with a tooltip.
+    pub fn text_item(
+        mut self,
+        label: impl Into<SharedString>,
+        value: impl Into<SharedString>,
+    ) -> Self {
+        self.entries.push(DescriptionEntry::Item {
+            label: label.into(),
+            value: DescriptionValue::Text(value.into()),
+        });
+        self
+    }
+
+    /// Start a new group with the `title`, separated from the previous items by a divider.
+    pub fn group(mut self, title: impl Into<SharedString>) -> Self {
+        self.entries
+            .push(DescriptionEntry::Group(Some(title.into())));
+        self
+    }
+
+    /// Add a divider between the items.
+    pub fn separator(mut self) -> Self {
+        self.entries.push(DescriptionEntry::Group(None));
+        self
+    }
+}
+
+impl IntoElement for DescriptionList {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+#[derive(Default)]
+pub struct DescriptionListState {
+    width: Rc<Cell<Pixels>>,
+}
+
+impl Element for DescriptionList {
+    type RequestLayoutState = AnyElement;
+
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        Some(self.id.clone())
+    }
+
+    fn request_layout(
+        &mut self,
+        global_id: Option<&GlobalElementId>,
+        cx: &mut WindowContext,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let entries = std::mem::take(&mut self.entries);
+
+        cx.with_element_state::<DescriptionListState, _>(global_id.unwrap(), move |state, cx| {
+            let state = state.unwrap_or_default();
+            let axis = layout_axis(self.axis, state.width.get(), self.collapse_width);
+
+            let children = entries.into_iter().enumerate().map(|(ix, entry)| {
+                let (label, value) = match entry {
+                    DescriptionEntry::Group(title) => {
+                        return Divider::horizontal()
+                            .my_1()
+                            .when_some(title, |this, title| this.label(title))
+                            .into_any_element();
+                    }
+                    DescriptionEntry::Item { label, value } => (label, value),
+                };
+
+                let value = match value {
+                    DescriptionValue::Element(element) => {
+                        div().min_w_0().overflow_hidden().child(element)
+                    }
+                    DescriptionValue::Text(text) => {
+                        let long = is_long_label(&text);
+                        div().min_w_0().child(
+                            h_flex()
+                                .id(("value", ix))
+                                .gap_1()
+                                .child(
+                                    div()
+                                        .min_w_0()
+                                        .overflow_hidden()
+                                        .text_ellipsis()
+                                        .whitespace_nowrap()
+                                        .child(text.clone()),
+                                )
+                                .when(long, |this| {
+                                    let text = text.clone();
+                                    this.tooltip(move |cx| Tooltip::new(text.clone(), cx))
+                                })
+                                .when(self.copyable, |this| {
+                                    this.child(CopyButton::new(("copy", ix), text))
+                                }),
+                        )
+                    }
+                };
+
+                let label = div()
+                    .text_color(cx.theme().muted_foreground)
+                    .overflow_hidden()
+                    .text_ellipsis()
+                    .whitespace_nowrap()
+                    .child(label);
+
+                match axis {
+                    Axis::Horizontal => h_flex()
+                        .items_start()
+                        .gap_3()
+                        .child(label.w(self.label_width).flex_shrink_0())
+                        .child(value.flex_1().text_color(cx.theme().foreground))
+                        .into_any_element(),
+                    Axis::Vertical => v_flex()
+                        .gap_0p5()
+                        .child(label.text_xs())
+                        .child(value.text_color(cx.theme().foreground))
+                        .into_any_element(),
+                }
+            });
+
+            let width = state.width.clone();
+            let mut element = v_flex()
+                .relative()
+                .w_full()
+                .min_w_0()
+                .text_sm()
+                .map(|this| match axis {
+                    Axis::Horizontal => this.gap_2(),
+                    Axis::Vertical => this.gap_3(),
+                })
+                .children(children)
+                .child(
+                    canvas(
+                        move |bounds, cx| {
+                            if width.get() != bounds.size.width {
+                                width.set(bounds.size.width);
+                                cx.refresh();
+                            }
+                        },
+                        |_, _, _| {},
+                    )
+                    .absolute()
+                    .size_full(),
+                )
+                .into_any_element();
+
+            ((element.request_layout(cx), element), state)
+        })
+    }
+
+    fn prepaint(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        _: gpui::Bounds<Pixels>,
+        element: &mut Self::RequestLayoutState,
+        cx: &mut WindowContext,
+    ) {
+        element.prepaint(cx);
+    }
+
+    fn paint(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        _: gpui::Bounds<Pixels>,
+        element: &mut Self::RequestLayoutState,
+        _: &mut Self::PrepaintState,
+        cx: &mut WindowContext,
+    ) {
+        element.paint(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::layout_axis;
+    use gpui::{px, Axis};
+
+    #[test]
+    fn test_layout_axis() {
+        let collapse_width = px(360.);
+        assert_eq!(
+            layout_axis(Axis::Horizontal, px(500.), collapse_width),
+            Axis::Horizontal
+        );
+        assert_eq!(
+            layout_axis(Axis::Horizontal, px(300.), collapse_width),
+            Axis::Vertical
+        );
+        // Not measured yet.
+        assert_eq!(
+            layout_axis(Axis::Horizontal, px(0.), collapse_width),
+            Axis::Horizontal
+        );
+        assert_eq!(
+            layout_axis(Axis::Vertical, px(500.), collapse_width),
+            Axis::Vertical
+        );
+    }
+}
@@ -1,17 +1,18 @@
 use gpui::{
-    div, prelude::FluentBuilder as _, px, Axis, Div, Hsla, IntoElement, ParentElement, RenderOnce,
-    SharedString, Styled,
+    div, prelude::FluentBuilder as _, px, AlignSelf, Axis, DefiniteLength, Div, Hsla, IntoElement,
+    ParentElement, RenderOnce, SharedString, Styled,
 };
 
 use crate::theme::ActiveTheme;
 
-/// A divider that can be either vertical or horizontal.
+/// A divider that can be either vertical or horizontal, with an optional label in the center.
 #[derive(IntoElement)]
 pub struct Divider {
     base: Div,
     label: Option<SharedString>,
     axis: Axis,
     color: Option<Hsla>,
+    inset: Option<DefiniteLength>,
 }
 
 impl Divider {
@@ -21,6 +22,7 @@ impl Divider {
             axis: Axis::Vertical,
             label: None,
             color: None,
+            inset: None,
         }
     }
 
@@ -30,6 +32,7 @@ impl Divider {
             axis: Axis::Horizontal,
             label: None,
             color: None,
+            inset: None,
         }
     }
 
@@ -42,6 +45,14 @@ impl Divider {
         self.color = Some(color.into());
         self
     }
+
+    /// Set the inset of the line from the both ends, default: None
+    ///
+    /// Use the margin methods of [`Styled`] to set the space around the divider.
+    pub fn inset(mut self, inset: impl Into<DefiniteLength>) -> Self {
+        self.inset = Some(inset.into());
+        self
+    }
 }
 
 impl Styled for Divider {
@@ -51,34 +62,49 @@ impl Styled for Divider {
 }
 
 impl RenderOnce for Divider {
-    fn render(self, cx: &mut gpui::WindowContext) -> impl IntoElement {
-        let theme = cx.theme();
+    fn render(mut self, cx: &mut gpui::WindowContext) -> impl IntoElement {
+        let color = self.color.unwrap_or(cx.theme().border);
+        let axis = self.axis;
+        let line = move || {
+            div()
+                .flex_1()
+                .map(|this| match axis {
+                    Axis::Vertical => this.w(px(1.)),
+                    Axis::Horizontal => this.h(px(1.)),
+                })
+                .bg(color)
+        };
+
+        // Stretch to the cross axis of the parent, e.g. the height of a `h_flex`.
+        self.base.style().align_self = Some(AlignSelf::Stretch);
 
         self.base
             .flex()
             .flex_shrink_0()
             .items_center()
             .justify_center()
-            .child(
-                div()
-                    .absolute()
-                    .map(|this| match self.axis {
-                        Axis::Vertical => this.w(px(1.)).h_full(),
-                        Axis::Horizontal => this.h(px(1.)).w_full(),
-                    })
-                    .bg(self.color.unwrap_or(cx.theme().border)),
-            )
+            .map(|this| match axis {
+                Axis::Vertical => this.flex_col(),
+                Axis::Horizontal => this.flex_row(),
+            })
+            .when_some(self.inset, |this, inset| match axis {
+                Axis::Vertical => this.py(inset),
+                Axis::Horizontal => this.px(inset),
+            })
+            .child(line())
             .when_some(self.label, |this, label| {
                 this.child(
                     div()
-                        .px_2()
-                        .py_1()
-                        .mx_auto()
+                        .flex_shrink_0()
+                        .map(|this| match axis {
+                            Axis::Vertical => this.py_2(),
+                            Axis::Horizontal => this.px_2(),
+                        })
                         .text_xs()
-                        .bg(cx.theme().background)
-                        .text_color(theme.muted_foreground)
+                        .text_color(cx.theme().muted_foreground)
                         .child(label),
                 )
+                .child(line())
             })
     }
 }
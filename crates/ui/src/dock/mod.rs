@@ -9,7 +9,7 @@ mod tiles;
 use anyhow::Result;
 use gpui::{
     actions, canvas, div, prelude::FluentBuilder, AnyElement, AnyView, AppContext, Axis, Bounds,
-    Edges, Entity as _, EntityId, EventEmitter, InteractiveElement as _, IntoElement,
+    Edges, Entity as _, EntityId, EventEmitter, InteractiveElement as _, IntoElement, KeyBinding,
     ParentElement as _, Pixels, Render, SharedString, Styled, Subscription, View, ViewContext,
     VisualContext, WeakView, WindowContext,
 };
@@ -22,12 +22,37 @@ pub use state::*;
 pub use tab_panel::*;
 pub use tiles::*;
 
+actions!(
+    dock,
+    [
+        ToggleZoom,
+        ClosePanel,
+        ToggleLeftDock,
+        ToggleRightDock,
+        ToggleBottomDock
+    ]
+);
+
+const CONTEXT: &str = "DockArea";
+
 pub fn init(cx: &mut AppContext) {
     cx.set_global(PanelRegistry::new());
+    cx.bind_keys([
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-b", ToggleLeftDock, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-b", ToggleLeftDock, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-alt-b", ToggleRightDock, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-alt-b", ToggleRightDock, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-j", ToggleBottomDock, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-j", ToggleBottomDock, Some(CONTEXT)),
+    ]);
 }
 
-actions!(dock, [ToggleZoom, ClosePanel]);
-
 pub enum DockEvent {
     /// The layout of the dock has changed, subscribers this to save the layout.
     ///
@@ -604,10 +629,23 @@ impl DockArea {
         if let Some(dock) = dock {
             dock.update(cx, |view, cx| {
                 view.toggle_open(cx);
-            })
+            });
+            cx.emit(DockEvent::LayoutChanged);
         }
     }
 
+    fn on_action_toggle_left_dock(&mut self, _: &ToggleLeftDock, cx: &mut ViewContext<Self>) {
+        self.toggle_dock(DockPlacement::Left, cx);
+    }
+
+    fn on_action_toggle_right_dock(&mut self, _: &ToggleRightDock, cx: &mut ViewContext<Self>) {
+        self.toggle_dock(DockPlacement::Right, cx);
+    }
+
+    fn on_action_toggle_bottom_dock(&mut self, _: &ToggleBottomDock, cx: &mut ViewContext<Self>) {
+        self.toggle_dock(DockPlacement::Bottom, cx);
+    }
+
     /// Add a panel item to the dock area at the given placement.
     ///
     /// If the left, bottom, right dock is not present, it will set the dock at the placement.
@@ -849,6 +887,10 @@ impl Render for DockArea {
 
         div()
             .id("dock-area")
+            .key_context(CONTEXT)
+            .on_action(cx.listener(Self::on_action_toggle_left_dock))
+            .on_action(cx.listener(Self::on_action_toggle_right_dock))
+            .on_action(cx.listener(Self::on_action_toggle_bottom_dock))
             .relative()
             .size_full()
             .overflow_hidden()
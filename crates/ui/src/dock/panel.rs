@@ -1,6 +1,6 @@
 use std::{collections::HashMap, sync::Arc};
 
-use crate::{button::Button, popup_menu::PopupMenu};
+use crate::{button::Button, popup_menu::PopupMenu, IconName};
 use gpui::{
     AnyElement, AnyView, AppContext, EventEmitter, FocusHandle, FocusableView, Global, Hsla,
     IntoElement, SharedString, View, ViewContext, WeakView, WindowContext,
@@ -44,6 +44,11 @@ pub trait Panel: EventEmitter<PanelEvent> + FocusableView {
         SharedString::from(t!("Dock.Unnamed")).into_any_element()
     }
 
+    /// The icon of the panel to show before the title, default is `None`.
+    fn icon(&self, cx: &AppContext) -> Option<IconName> {
+        None
+    }
+
     /// The theme of the panel title, default is `None`.
     fn title_style(&self, cx: &AppContext) -> Option<TitleStyle> {
         None
@@ -106,6 +111,7 @@ pub trait Panel: EventEmitter<PanelEvent> + FocusableView {
 pub trait PanelView: 'static + Send + Sync {
     fn panel_name(&self, cx: &AppContext) -> &'static str;
     fn title(&self, cx: &WindowContext) -> AnyElement;
+    fn icon(&self, cx: &AppContext) -> Option<IconName>;
     fn title_style(&self, cx: &AppContext) -> Option<TitleStyle>;
     fn closable(&self, cx: &AppContext) -> bool;
     fn zoomable(&self, cx: &AppContext) -> bool;
@@ -128,6 +134,10 @@ impl<T: Panel> PanelView for View<T> {
         self.read(cx).title(cx)
     }
 
+    fn icon(&self, cx: &AppContext) -> Option<IconName> {
+        self.read(cx).icon(cx)
+    }
+
     fn title_style(&self, cx: &AppContext) -> Option<TitleStyle> {
         self.read(cx).title_style(cx)
     }
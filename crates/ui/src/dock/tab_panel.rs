@@ -16,7 +16,7 @@ use crate::{
     popup_menu::{PopupMenu, PopupMenuExt},
     tab::{Tab, TabBar},
     theme::ActiveTheme,
-    v_flex, AxisExt, IconName, Placement, Selectable, Sizable,
+    v_flex, AxisExt, Icon, IconName, Placement, Selectable, Sizable,
 };
 
 use super::{
@@ -571,7 +571,15 @@ impl TabPanel {
                         .overflow_hidden()
                         .text_ellipsis()
                         .whitespace_nowrap()
-                        .child(panel.title(cx))
+                        .map(|this| match panel.icon(cx) {
+                            Some(icon) => this.child(
+                                h_flex()
+                                    .gap_1p5()
+                                    .child(Icon::new(icon).small())
+                                    .child(panel.title(cx)),
+                            ),
+                            None => this.child(panel.title(cx)),
+                        })
                         .when(state.draggable, |this| {
                             this.on_drag(
                                 DragPanel {
@@ -636,6 +644,9 @@ impl TabPanel {
                 Some(
                     Tab::new(("tab", ix), panel.title(cx))
                         .py_2()
+                        .when_some(panel.icon(cx), |this, icon| {
+                            this.prefix(Icon::new(icon).small().into_any_element())
+                        })
                         .selected(active)
                         .disabled(disabled)
                         .when(!disabled, |this| {
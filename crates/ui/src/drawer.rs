@@ -1,13 +1,14 @@
-use std::{rc::Rc, time::Duration};
+use std::{cell::Cell, rc::Rc, time::Duration};
 
 use gpui::{
-    actions, anchored, div, point, prelude::FluentBuilder as _, px, Animation, AnimationExt as _,
-    AnyElement, AppContext, ClickEvent, DefiniteLength, DismissEvent, Div, EventEmitter,
-    FocusHandle, InteractiveElement as _, IntoElement, KeyBinding, MouseButton, ParentElement,
-    Pixels, RenderOnce, Styled, WindowContext,
+    actions, anchored, canvas, div, linear, point, prelude::FluentBuilder as _, px, Animation,
+    AnimationExt as _, AnyElement, AppContext, ClickEvent, DefiniteLength, DismissEvent, Div,
+    EventEmitter, FocusHandle, InteractiveElement as _, IntoElement, KeyBinding, MouseButton,
+    MouseMoveEvent, MouseUpEvent, ParentElement, Pixels, RenderOnce, Styled, WindowContext,
 };
 
 use crate::{
+    animation::reduced_motion,
     button::{Button, ButtonVariants as _},
     h_flex,
     modal::overlay_color,
@@ -25,12 +26,26 @@ pub fn init(cx: &mut AppContext) {
     cx.bind_keys([KeyBinding::new("escape", Escape, Some(CONTEXT))])
 }
 
+/// The size of the resize handle at the inner edge.
+const HANDLE_SIZE: Pixels = px(4.);
+
+/// The resized size of the drawer, this is kept by the [`crate::Root`] while the drawer is open.
+#[derive(Default)]
+pub(crate) struct DrawerResizeState {
+    resizing: Cell<bool>,
+    size: Cell<Option<Pixels>>,
+}
+
 #[derive(IntoElement)]
 pub struct Drawer {
     pub(crate) focus_handle: FocusHandle,
     pub(crate) placement: Placement,
     pub(crate) size: DefiniteLength,
     resizable: bool,
+    min_size: Pixels,
+    max_size: Option<Pixels>,
+    pub(crate) initial_focus: Option<FocusHandle>,
+    pub(crate) resize_state: Rc<DrawerResizeState>,
     on_close: Rc<dyn Fn(&ClickEvent, &mut WindowContext) + 'static>,
     title: Option<AnyElement>,
     footer: Option<AnyElement>,
@@ -46,6 +61,10 @@ impl Drawer {
             placement: Placement::Right,
             size: DefiniteLength::Absolute(px(350.).into()),
             resizable: true,
+            min_size: px(200.),
+            max_size: None,
+            initial_focus: None,
+            resize_state: Rc::new(DrawerResizeState::default()),
             title: None,
             footer: None,
             content: v_flex().px_4().py_3(),
@@ -87,6 +106,34 @@ impl Drawer {
         self
     }
 
+    /// Sets the min size when resizing by dragging the inner edge, default is 200px.
+    pub fn min_size(mut self, min_size: Pixels) -> Self {
+        self.min_size = min_size;
+        self
+    }
+
+    /// Sets the max size when resizing by dragging the inner edge, default is the window size.
+    pub fn max_size(mut self, max_size: Pixels) -> Self {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    /// Sets the element to focus when the drawer opened, default is the drawer itself.
+    ///
+    /// For example, give the `focus_handle` of a [`crate::list::List`] to focus its query input.
+    pub fn initial_focus(mut self, focus_handle: &FocusHandle) -> Self {
+        self.initial_focus = Some(focus_handle.clone());
+        self
+    }
+
+    /// Returns the size of the drawer, the resized size if it has been resized by dragging.
+    pub(crate) fn current_size(&self) -> DefiniteLength {
+        match self.resize_state.size.get() {
+            Some(size) if self.resizable => size.into(),
+            _ => self.size,
+        }
+    }
+
     /// Set whether the drawer should have an overlay, default is `true`.
     pub fn overlay(mut self, overlay: bool) -> Self {
         self.overlay = overlay;
@@ -126,6 +173,9 @@ impl RenderOnce for Drawer {
                 window_paddings.top + window_paddings.bottom,
             );
         let on_close = self.on_close.clone();
+        let drawer_size = self.current_size();
+        let resize_state = self.resize_state.clone();
+        let (min_size, max_size) = (self.min_size, self.max_size);
 
         anchored()
             .position(point(
@@ -148,6 +198,64 @@ impl RenderOnce for Drawer {
                             }
                         })
                     })
+                    .when(self.resizable, |this| {
+                        // Track the mouse in the whole window while dragging the resize handle.
+                        let resize_state = resize_state.clone();
+                        this.child(
+                            canvas(
+                                |_, _| {},
+                                move |_, _, cx| {
+                                    cx.on_mouse_event({
+                                        let resize_state = resize_state.clone();
+                                        move |e: &MouseMoveEvent, phase, cx| {
+                                            if !phase.bubble() || !resize_state.resizing.get() {
+                                                return;
+                                            }
+
+                                            let (new_size, window_size) = match placement {
+                                                Placement::Right => (
+                                                    window_paddings.left + size.width
+                                                        - e.position.x,
+                                                    size.width,
+                                                ),
+                                                Placement::Left => (
+                                                    e.position.x - window_paddings.left,
+                                                    size.width,
+                                                ),
+                                                Placement::Top => (
+                                                    e.position.y
+                                                        - window_paddings.top
+                                                        - titlebar_height,
+                                                    size.height - titlebar_height,
+                                                ),
+                                                Placement::Bottom => (
+                                                    window_paddings.top + size.height
+                                                        - e.position.y,
+                                                    size.height - titlebar_height,
+                                                ),
+                                            };
+                                            let max_size =
+                                                max_size.unwrap_or(window_size).max(min_size);
+                                            resize_state
+                                                .size
+                                                .set(Some(new_size.clamp(min_size, max_size)));
+                                            cx.refresh();
+                                        }
+                                    });
+                                    cx.on_mouse_event({
+                                        let resize_state = resize_state.clone();
+                                        move |_: &MouseUpEvent, phase, _| {
+                                            if phase.bubble() {
+                                                resize_state.resizing.set(false);
+                                            }
+                                        }
+                                    });
+                                },
+                            )
+                            .absolute()
+                            .size_full(),
+                        )
+                    })
                     .child(
                         v_flex()
                             .id("drawer")
@@ -168,9 +276,9 @@ impl RenderOnce for Drawer {
                             .map(|this| {
                                 // Set the size of the drawer.
                                 if placement.is_horizontal() {
-                                    this.h_full().w(self.size)
+                                    this.h_full().w(drawer_size)
                                 } else {
-                                    this.w_full().h(self.size)
+                                    this.w_full().h(drawer_size)
                                 }
                             })
                             .map(|this| match self.placement {
@@ -181,6 +289,9 @@ impl RenderOnce for Drawer {
                                 }
                                 Placement::Left => this.top_0().left_0().bottom_0().border_r_1(),
                             })
+                            .when(self.resizable, |this| {
+                                this.child(render_resize_handle(placement, resize_state.clone()))
+                            })
                             .child(
                                 // TitleBar
                                 h_flex()
@@ -224,7 +335,8 @@ impl RenderOnce for Drawer {
                             })
                             .with_animation(
                                 "slide",
-                                Animation::new(Duration::from_secs_f64(0.15)),
+                                Animation::new(Duration::from_secs_f64(0.15))
+                                    .with_easing(reduced_motion(linear, cx)),
                                 move |this, delta| {
                                     let y = px(-100.) + delta * px(100.);
                                     this.map(|this| match placement {
@@ -239,3 +351,46 @@ impl RenderOnce for Drawer {
             )
     }
 }
+
+/// The handle at the inner edge of the drawer, to resize the drawer by dragging.
+fn render_resize_handle(
+    placement: Placement,
+    resize_state: Rc<DrawerResizeState>,
+) -> impl IntoElement {
+    let offset = -HANDLE_SIZE / 2.;
+
+    div()
+        .id("resize-handle")
+        .occlude()
+        .absolute()
+        .map(|this| match placement {
+            Placement::Right => this
+                .cursor_col_resize()
+                .top_0()
+                .left(offset)
+                .h_full()
+                .w(HANDLE_SIZE),
+            Placement::Left => this
+                .cursor_col_resize()
+                .top_0()
+                .right(offset)
+                .h_full()
+                .w(HANDLE_SIZE),
+            Placement::Top => this
+                .cursor_row_resize()
+                .left_0()
+                .bottom(offset)
+                .w_full()
+                .h(HANDLE_SIZE),
+            Placement::Bottom => this
+                .cursor_row_resize()
+                .left_0()
+                .top(offset)
+                .w_full()
+                .h(HANDLE_SIZE),
+        })
+        .on_mouse_down(MouseButton::Left, move |_, cx| {
+            cx.stop_propagation();
+            resize_state.resizing.set(true);
+        })
+}
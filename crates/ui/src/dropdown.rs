@@ -1,6 +1,6 @@
 use gpui::{
     actions, anchored, canvas, deferred, div, prelude::FluentBuilder, px, rems, AnyElement,
-    AppContext, Bounds, ClickEvent, DismissEvent, ElementId, EventEmitter, FocusHandle,
+    AppContext, Bounds, ClickEvent, Corner, DismissEvent, ElementId, EventEmitter, FocusHandle,
     FocusableView, InteractiveElement, IntoElement, KeyBinding, Length, ParentElement, Pixels,
     Render, SharedString, StatefulInteractiveElement, Styled, Task, View, ViewContext,
     VisualContext, WeakView, WindowContext,
@@ -10,9 +10,9 @@ use rust_i18n::t;
 use crate::{
     h_flex,
     input::ClearButton,
-    list::{self, List, ListDelegate, ListItem},
+    list::{self, List, ListDelegate, ListEnum, ListItem},
     theme::ActiveTheme,
-    v_flex, Disableable, Icon, IconName, Sizable, Size, StyleSized, StyledExt,
+    v_flex, Degrees, Disableable, Icon, IconName, Sizable, Size, StyleSized, StyledExt,
 };
 
 actions!(dropdown, [Up, Down, Enter, Escape]);
@@ -58,6 +58,19 @@ impl DropdownItem for SharedString {
     }
 }
 
+/// The variants of a [`ListEnum`] can be used as the Dropdown items directly, the value is the variant itself.
+impl<T: ListEnum> DropdownItem for T {
+    type Value = Self;
+
+    fn title(&self) -> SharedString {
+        self.label()
+    }
+
+    fn value(&self) -> &Self::Value {
+        self
+    }
+}
+
 pub trait DropdownDelegate: Sized {
     type Item: DropdownItem;
 
@@ -133,7 +146,9 @@ where
         let size = self
             .dropdown
             .upgrade()
-            .map_or(Size::Medium, |dropdown| dropdown.read(cx).size);
+            .map_or(cx.theme().default_size(), |dropdown| {
+                dropdown.read(cx).size(cx)
+            });
 
         if let Some(item) = self.delegate.get(ix) {
             let list_item = ListItem::new(("list-item", ix))
@@ -160,6 +175,7 @@ where
     }
 
     fn confirm(&mut self, ix: Option<usize>, cx: &mut ViewContext<List<Self>>) {
+        let changed = self.selected_index != ix;
         self.selected_index = ix;
 
         let selected_value = self
@@ -171,6 +187,9 @@ where
         cx.defer(move |_, cx| {
             _ = dropdown.update(cx, |this, cx| {
                 cx.emit(DropdownEvent::Confirm(selected_value.clone()));
+                if changed {
+                    cx.emit(DropdownEvent::Changed(selected_value.clone()));
+                }
                 this.selected_value = selected_value;
                 this.open = false;
                 this.focus(cx);
@@ -207,7 +226,10 @@ where
 }
 
 pub enum DropdownEvent<D: DropdownDelegate + 'static> {
+    /// An item is confirmed by Enter or click, even if it is already selected.
     Confirm(Option<<D::Item as DropdownItem>::Value>),
+    /// The selected value has been changed by the user.
+    Changed(Option<<D::Item as DropdownItem>::Value>),
 }
 
 /// A Dropdown element.
@@ -215,7 +237,7 @@ pub struct Dropdown<D: DropdownDelegate + 'static> {
     id: ElementId,
     focus_handle: FocusHandle,
     list: View<List<DropdownListDelegate<D>>>,
-    size: Size,
+    size: Option<Size>,
     icon: Option<IconName>,
     open: bool,
     cleanable: bool,
@@ -228,6 +250,7 @@ pub struct Dropdown<D: DropdownDelegate + 'static> {
     /// Store the bounds of the input
     bounds: Bounds<Pixels>,
     disabled: bool,
+    pub(crate) appearance: bool,
 }
 
 pub struct SearchableVec<T> {
@@ -330,7 +353,7 @@ where
             focus_handle,
             placeholder: None,
             list,
-            size: Size::Medium,
+            size: None,
             icon: None,
             selected_value: None,
             open: false,
@@ -339,6 +362,7 @@ where
             empty: None,
             width: Length::Auto,
             menu_width: Length::Auto,
+            appearance: true,
             bounds: Bounds::default(),
             disabled: false,
         };
@@ -386,7 +410,7 @@ where
         self
     }
 
-    /// Set the disable state for the dropdown.
+    /// Set the disable state for the dropdown, see also [`Disableable`].
     pub fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
         self
@@ -396,6 +420,29 @@ where
         self.disabled = disabled;
     }
 
+    /// Set the appearance of the dropdown input, the border and background are not drawn if false.
+    pub fn appearance(mut self, appearance: bool) -> Self {
+        self.appearance = appearance;
+        self
+    }
+
+    /// Set the appearance with reference, e.g. it is drawn by an [`crate::input::InputGroup`].
+    pub fn set_appearance(&mut self, appearance: bool, cx: &mut ViewContext<Self>) {
+        self.appearance = appearance;
+        cx.notify();
+    }
+
+    /// Set the size of the dropdown with reference.
+    pub fn set_size(&mut self, size: Size, cx: &mut ViewContext<Self>) {
+        self.size = Some(size);
+        cx.notify();
+    }
+
+    /// Returns the size of the dropdown, follow the density of the theme if not set.
+    pub fn size(&self, cx: &AppContext) -> Size {
+        self.size.unwrap_or_else(|| cx.theme().default_size())
+    }
+
     pub fn empty<E, F>(mut self, f: F) -> Self
     where
         E: IntoElement,
@@ -506,12 +553,16 @@ where
     }
 
     fn clean(&mut self, _: &ClickEvent, cx: &mut ViewContext<Self>) {
+        let changed = self.selected_index(cx).is_some();
         self.set_selected_index(None, cx);
         cx.emit(DropdownEvent::Confirm(None));
+        if changed {
+            cx.emit(DropdownEvent::Changed(None));
+        }
     }
 
     fn display_title(&self, cx: &WindowContext) -> impl IntoElement {
-        let title = if let Some(selected_index) = &self.selected_index(cx) {
+        if let Some(selected_index) = &self.selected_index(cx) {
             let title = self
                 .list
                 .read(cx)
@@ -525,17 +576,17 @@ where
                 .when_some(self.title_prefix.clone(), |this, prefix| this.child(prefix))
                 .child(title.clone())
         } else {
-            div().text_color(cx.theme().accent_foreground).child(
-                self.placeholder
-                    .clone()
-                    .unwrap_or_else(|| t!("Dropdown.placeholder").into()),
-            )
-        };
-
-        title.when(self.disabled, |this| {
-            this.cursor_not_allowed()
-                .text_color(cx.theme().muted_foreground)
-        })
+            // Follow the muted text of the disabled style.
+            div()
+                .when(!self.disabled, |this| {
+                    this.text_color(cx.theme().accent_foreground)
+                })
+                .child(
+                    self.placeholder
+                        .clone()
+                        .unwrap_or_else(|| t!("Dropdown.placeholder").into()),
+                )
+        }
     }
 }
 
@@ -544,7 +595,18 @@ where
     D: DropdownDelegate + 'static,
 {
     fn with_size(mut self, size: impl Into<Size>) -> Self {
-        self.size = size.into();
+        self.size = Some(size.into());
+        self
+    }
+}
+
+impl<D> Disableable for Dropdown<D>
+where
+    D: DropdownDelegate + 'static,
+{
+    /// Set the disable state for the dropdown.
+    fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
         self
     }
 }
@@ -574,13 +636,21 @@ where
         let view = cx.view().clone();
         let bounds = self.bounds;
         let allow_open = !(self.open || self.disabled);
+
+        // Flip the menu above the input if there is no room below.
+        let menu_height = rems(20.).to_pixels(cx.rem_size()) + px(8.);
+        let viewport_height = cx.viewport_size().height;
+        let space_below = viewport_height - bounds.origin.y - bounds.size.height;
+        let flip = space_below < menu_height && bounds.origin.y > space_below;
         let outline_visible = self.open || is_focused && !self.disabled;
 
         // If the size has change, set size to self.list, to change the QueryInput size.
-        if self.list.read(cx).size != self.size {
-            self.list
-                .update(cx, |this, cx| this.set_size(self.size, cx))
+        if let Some(size) = self.size {
+            if self.list.read(cx).size != Some(size) {
+                self.list.update(cx, |this, cx| this.set_size(size, cx))
+            }
         }
+        let size = self.size(cx);
 
         div()
             .id(self.id.clone())
@@ -592,7 +662,7 @@ where
             .on_action(cx.listener(Self::escape))
             .size_full()
             .relative()
-            .input_text_size(self.size)
+            .input_text_size(size)
             .child(
                 div()
                     .id("dropdown-input")
@@ -600,26 +670,23 @@ where
                     .flex()
                     .items_center()
                     .justify_between()
-                    .bg(cx.theme().background)
-                    .border_1()
-                    .border_color(cx.theme().input)
-                    .rounded(px(cx.theme().radius))
-                    .when(cx.theme().shadow, |this| this.shadow_sm())
-                    .map(|this| {
-                        if self.disabled {
-                            this.cursor_not_allowed()
-                        } else {
-                            this.cursor_pointer()
-                        }
+                    .when(self.appearance, |this| {
+                        this.bg(cx.theme().background)
+                            .border_1()
+                            .border_color(cx.theme().input)
+                            .rounded(px(cx.theme().radius))
+                            .when(cx.theme().shadow, |this| this.shadow_sm())
                     })
+                    .cursor_pointer()
+                    .disabled_style(self.disabled, cx)
                     .overflow_hidden()
-                    .input_text_size(self.size)
+                    .input_text_size(size)
                     .map(|this| match self.width {
                         Length::Definite(l) => this.flex_none().w(l),
                         Length::Auto => this.w_full(),
                     })
-                    .when(outline_visible, |this| this.outline(cx))
-                    .input_size(self.size)
+                    .when(outline_visible && self.appearance, |this| this.outline(cx))
+                    .input_size(size)
                     .when(allow_open, |this| {
                         this.on_click(cx.listener(Self::toggle_menu))
                     })
@@ -646,25 +713,12 @@ where
                             })
                             .when(!show_clean, |this| {
                                 let icon = match self.icon.clone() {
-                                    Some(icon) => icon,
-                                    None => {
-                                        if self.open {
-                                            IconName::ChevronUp
-                                        } else {
-                                            IconName::ChevronDown
-                                        }
-                                    }
+                                    Some(icon) => Icon::new(icon),
+                                    None => Icon::new(IconName::ChevronDown)
+                                        .when(self.open, |this| this.rotate(Degrees(180.))),
                                 };
 
-                                this.child(
-                                    Icon::new(icon)
-                                        .xsmall()
-                                        .text_color(match self.disabled {
-                                            true => cx.theme().muted_foreground.opacity(0.5),
-                                            false => cx.theme().muted_foreground,
-                                        })
-                                        .when(self.disabled, |this| this.cursor_not_allowed()),
-                                )
+                                this.child(icon.xsmall().text_color(cx.theme().muted_foreground))
                             }),
                     )
                     .child(
@@ -679,34 +733,76 @@ where
             .when(self.open, |this| {
                 this.child(
                     deferred(
-                        anchored().snap_to_window_with_margin(px(8.)).child(
-                            div()
-                                .occlude()
-                                .map(|this| match self.menu_width {
-                                    Length::Auto => this.w(bounds.size.width),
-                                    Length::Definite(w) => this.w(w),
-                                })
-                                .child(
-                                    v_flex()
-                                        .occlude()
-                                        .mt_1p5()
-                                        .bg(cx.theme().background)
-                                        .border_1()
-                                        .border_color(cx.theme().border)
-                                        .rounded(px(cx.theme().radius))
-                                        .shadow_md()
-                                        .on_mouse_down_out(|_, cx| {
-                                            cx.dispatch_action(Box::new(Escape));
-                                        })
-                                        .child(self.list.clone()),
-                                )
-                                .on_mouse_down_out(cx.listener(|this, _, cx| {
-                                    this.escape(&Escape, cx);
-                                })),
-                        ),
+                        anchored()
+                            .snap_to_window_with_margin(px(8.))
+                            .when(flip, |this| {
+                                this.anchor(Corner::BottomLeft).position(bounds.origin)
+                            })
+                            .child(
+                                div()
+                                    .occlude()
+                                    .map(|this| match self.menu_width {
+                                        Length::Auto => this.w(bounds.size.width),
+                                        Length::Definite(w) => this.w(w),
+                                    })
+                                    .child(
+                                        v_flex()
+                                            .occlude()
+                                            .map(
+                                                |this| {
+                                                    if flip {
+                                                        this.mb_1p5()
+                                                    } else {
+                                                        this.mt_1p5()
+                                                    }
+                                                },
+                                            )
+                                            .bg(cx.theme().background)
+                                            .border_1()
+                                            .border_color(cx.theme().border)
+                                            .rounded(px(cx.theme().radius))
+                                            .shadow_md()
+                                            .on_mouse_down_out(|_, cx| {
+                                                cx.dispatch_action(Box::new(Escape));
+                                            })
+                                            .child(self.list.clone()),
+                                    )
+                                    .on_mouse_down_out(cx.listener(|this, _, cx| {
+                                        this.escape(&Escape, cx);
+                                    })),
+                            ),
                     )
                     .with_priority(1),
                 )
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use gpui::{px, Modifiers, SharedString, TestAppContext};
+
+    use super::Dropdown;
+
+    #[gpui::test]
+    fn test_click_disabled_dropdown(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            crate::init(cx);
+        });
+        let items: Vec<SharedString> = vec!["Apple".into(), "Orange".into()];
+        let (dropdown, cx) =
+            cx.add_window_view(|cx| Dropdown::new("fruit", items, None, cx).disabled(true));
+
+        let bounds = dropdown.read_with(cx, |dropdown, _| dropdown.bounds);
+        assert!(bounds.size.width > px(0.));
+        cx.simulate_click(bounds.center(), Modifiers::none());
+        dropdown.read_with(cx, |dropdown, _| assert!(!dropdown.open));
+
+        dropdown.update(cx, |dropdown, cx| {
+            dropdown.set_disabled(false);
+            cx.notify();
+        });
+        cx.simulate_click(bounds.center(), Modifiers::none());
+        dropdown.read_with(cx, |dropdown, _| assert!(dropdown.open));
+    }
+}
@@ -0,0 +1,121 @@
+use gpui::{
+    div, prelude::FluentBuilder as _, px, AnyElement, IntoElement, ParentElement as _, RenderOnce,
+    SharedString, Styled, WindowContext,
+};
+
+use crate::{h_flex, theme::ActiveTheme as _, v_flex, Icon, StyledExt as _};
+
+/// The max width of the description, to keep the lines readable.
+pub(crate) const DESCRIPTION_MAX_WIDTH: gpui::Pixels = px(360.);
+
+/// An EmptyState to show in a panel that has nothing to show, e.g. no search results.
+///
+/// It is centered in the container, with an optional icon (or illustration), description and actions.
+#[derive(IntoElement)]
+pub struct EmptyState {
+    icon: Option<Icon>,
+    illustration: Option<AnyElement>,
+    title: SharedString,
+    description: Option<SharedString>,
+    action: Option<AnyElement>,
+    secondary_action: Option<AnyElement>,
+}
+
+impl EmptyState {
+    pub fn new(title: impl Into<SharedString>) -> Self {
+        Self {
+            icon: None,
+            illustration: None,
+            title: title.into(),
+            description: None,
+            action: None,
+            secondary_action: None,
+        }
+    }
+
+    pub fn icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Set an illustration element instead of the icon, e.g. an image.
+    pub fn illustration(mut self, illustration: impl IntoElement) -> Self {
+        self.illustration = Some(illustration.into_any_element());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set the primary action, e.g. a primary Button.
+    pub fn action(mut self, action: impl IntoElement) -> Self {
+        self.action = Some(action.into_any_element());
+        self
+    }
+
+    /// Set the secondary action, it is shown after the primary action.
+    pub fn secondary_action(mut self, action: impl IntoElement) -> Self {
+        self.secondary_action = Some(action.into_any_element());
+        self
+    }
+}
+
+impl RenderOnce for EmptyState {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let has_actions = self.action.is_some() || self.secondary_action.is_some();
+
+        v_flex()
+            .size_full()
+            .min_w_0()
+            .center()
+            .gap_2()
+            .p_6()
+            .map(|this| {
+                if let Some(illustration) = self.illustration {
+                    this.child(div().mb_2().child(illustration))
+                } else if let Some(icon) = self.icon {
+                    this.child(
+                        h_flex()
+                            .mb_2()
+                            .size_12()
+                            .justify_center()
+                            .rounded_full()
+                            .bg(cx.theme().muted)
+                            .text_color(cx.theme().muted_foreground)
+                            .child(icon.size_6()),
+                    )
+                } else {
+                    this
+                }
+            })
+            .child(
+                div()
+                    .text_base()
+                    .font_semibold()
+                    .text_color(cx.theme().foreground)
+                    .child(self.title),
+            )
+            .when_some(self.description, |this, description| {
+                this.child(
+                    div()
+                        .max_w(DESCRIPTION_MAX_WIDTH)
+                        .text_sm()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(description),
+                )
+            })
+            .when(has_actions, |this| {
+                this.child(
+                    h_flex()
+                        .mt_2()
+                        .flex_wrap()
+                        .justify_center()
+                        .gap_2()
+                        .children(self.action)
+                        .children(self.secondary_action),
+                )
+            })
+    }
+}
@@ -0,0 +1,201 @@
+use std::{cell::Cell, rc::Rc};
+
+use gpui::{
+    div, prelude::FluentBuilder as _, AnyElement, ClickEvent, Element, ElementId, GlobalElementId,
+    IntoElement, LayoutId, ParentElement as _, SharedString, Styled as _, WindowContext,
+};
+
+use crate::{
+    button::{Button, ButtonVariants as _},
+    clipboard::Snippet,
+    empty_state::DESCRIPTION_MAX_WIDTH,
+    h_flex,
+    theme::ActiveTheme as _,
+    v_flex, Icon, IconName, Sizable as _, StyledExt as _,
+};
+
+/// An ErrorState to show in a panel that failed to load, with a Retry button.
+///
+/// The `details`, e.g. the error message from the backend, is collapsed by default,
+/// and can be copied when expanded.
+pub struct ErrorState {
+    id: ElementId,
+    title: SharedString,
+    message: Option<SharedString>,
+    details: Option<SharedString>,
+    on_retry: Option<Rc<dyn Fn(&ClickEvent, &mut WindowContext)>>,
+}
+
+impl ErrorState {
+    pub fn new(id: impl Into<ElementId>, title: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            message: None,
+            details: None,
+            on_retry: None,
+        }
+    }
+
+    pub fn message(mut self, message: impl Into<SharedString>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// Set the details of the error, it is shown in a collapsible section with a copy button.
+    pub fn details(mut self, details: impl Into<SharedString>) -> Self {
+        self.details = Some(details.into());
+        self
+    }
+
+    /// Show a Retry button, and call the `handler` when it is clicked.
+    pub fn on_retry(mut self, handler: impl Fn(&ClickEvent, &mut WindowContext) + 'static) -> Self {
+        self.on_retry = Some(Rc::new(handler));
+        self
+    }
+}
+
+impl IntoElement for ErrorState {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+#[derive(Default)]
+pub struct ErrorStateState {
+    details_open: Rc<Cell<bool>>,
+}
+
+impl Element for ErrorState {
+    type RequestLayoutState = AnyElement;
+
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        Some(self.id.clone())
+    }
+
+    fn request_layout(
+        &mut self,
+        global_id: Option<&GlobalElementId>,
+        cx: &mut WindowContext,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        cx.with_element_state::<ErrorStateState, _>(global_id.unwrap(), |state, cx| {
+            let state = state.unwrap_or_default();
+            let details_open = state.details_open.get();
+            let on_retry = self.on_retry.clone();
+            let has_actions = on_retry.is_some() || self.details.is_some();
+
+            let mut element = v_flex()
+                .size_full()
+                .min_w_0()
+                .center()
+                .gap_2()
+                .p_6()
+                .child(
+                    h_flex()
+                        .mb_2()
+                        .size_12()
+                        .justify_center()
+                        .rounded_full()
+                        .bg(cx.theme().danger.opacity(0.1))
+                        .text_color(cx.theme().danger)
+                        .child(Icon::new(IconName::CircleX).size_6()),
+                )
+                .child(
+                    div()
+                        .text_base()
+                        .font_semibold()
+                        .text_color(cx.theme().foreground)
+                        .child(self.title.clone()),
+                )
+                .when_some(self.message.clone(), |this, message| {
+                    this.child(
+                        div()
+                            .max_w(DESCRIPTION_MAX_WIDTH)
+                            .text_sm()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(message),
+                    )
+                })
+                .when(has_actions, |this| {
+                    this.child(
+                        h_flex()
+                            .mt_2()
+                            .flex_wrap()
+                            .justify_center()
+                            .gap_2()
+                            .when_some(on_retry, |this, on_retry| {
+                                this.child(
+                                    Button::new("retry")
+                                        .primary()
+                                        .small()
+                                        .label("Retry")
+                                        .on_click(move |event, cx| on_retry(event, cx)),
+                                )
+                            })
+                            .when(self.details.is_some(), |this| {
+                                let open = state.details_open.clone();
+                                this.child(
+                                    Button::new("toggle-details")
+                                        .ghost()
+                                        .small()
+                                        .icon(if details_open {
+                                            IconName::ChevronUp
+                                        } else {
+                                            IconName::ChevronDown
+                                        })
+                                        .label(if details_open {
+                                            "Hide details"
+                                        } else {
+                                            "Show details"
+                                        })
+                                        .on_click(move |_, cx| {
+                                            open.set(!open.get());
+                                            cx.refresh();
+                                        }),
+                                )
+                            }),
+                    )
+                })
+                .when_some(
+                    self.details.clone().filter(|_| details_open),
+                    |this, details| {
+                        this.child(
+                            div()
+                                .w_full()
+                                .max_w(DESCRIPTION_MAX_WIDTH * 1.5)
+                                .mt_2()
+                                .child(Snippet::new("details", details)),
+                        )
+                    },
+                )
+                .into_any_element();
+
+            ((element.request_layout(cx), element), state)
+        })
+    }
+
+    fn prepaint(
+        &mut self,
+        _: Option<&gpui::GlobalElementId>,
+        _: gpui::Bounds<gpui::Pixels>,
+        element: &mut Self::RequestLayoutState,
+        cx: &mut WindowContext,
+    ) {
+        element.prepaint(cx);
+    }
+
+    fn paint(
+        &mut self,
+        _: Option<&gpui::GlobalElementId>,
+        _: gpui::Bounds<gpui::Pixels>,
+        element: &mut Self::RequestLayoutState,
+        _: &mut Self::PrepaintState,
+        cx: &mut WindowContext,
+    ) {
+        element.paint(cx)
+    }
+}
@@ -1,4 +1,4 @@
-use gpui::{FocusHandle, ViewContext};
+use gpui::{FocusHandle, ViewContext, WindowContext};
 
 /// A trait for views that can cycle focus between its children.
 ///
@@ -18,23 +18,145 @@ pub trait FocusableCycle {
     where
         Self: Sized,
     {
-        let focused_handle = cx.focused();
-        let handles = self.cycle_focus_handles(cx);
-        let handles = if is_next {
-            handles
+        let cycle = FocusCycle::new(self.cycle_focus_handles(cx));
+        if is_next {
+            cycle.next(cx);
         } else {
-            handles.into_iter().rev().collect()
+            cycle.prev(cx);
+        }
+        cx.stop_propagation();
+    }
+}
+
+/// An ordered set of focus handles, to move the focus within a composite component.
+///
+/// This is useful for the "one tab stop, arrows move within" pattern, e.g.:
+///
+/// ```ignore
+/// fn on_action_tab(&mut self, _: &Tab, cx: &mut ViewContext<Self>) {
+///     FocusCycle::new([self.input.focus_handle(cx), self.focus_handle.clone()]).step(true, cx);
+/// }
+/// ```
+#[derive(Clone)]
+pub struct FocusCycle {
+    handles: Vec<FocusHandle>,
+    wrap: bool,
+}
+
+impl FocusCycle {
+    pub fn new(handles: impl IntoIterator<Item = FocusHandle>) -> Self {
+        Self {
+            handles: handles.into_iter().collect(),
+            wrap: true,
+        }
+    }
+
+    /// Set true to wrap around at the ends, default: true
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    pub fn handles(&self) -> &[FocusHandle] {
+        &self.handles
+    }
+
+    /// Returns the index of the focused handle, or the first handle that contains the focus.
+    ///
+    /// If the handles are nested and a descendant of them is focused, put the inner ones first.
+    pub fn focused_index(&self, cx: &WindowContext) -> Option<usize> {
+        self.handles
+            .iter()
+            .position(|handle| handle.is_focused(cx))
+            .or_else(|| {
+                self.handles
+                    .iter()
+                    .position(|handle| handle.contains_focused(cx))
+            })
+    }
+
+    /// Focus the first handle, returns the index of it.
+    pub fn first(&self, cx: &mut WindowContext) -> Option<usize> {
+        self.focus_index((!self.handles.is_empty()).then_some(0), cx)
+    }
+
+    /// Focus the last handle, returns the index of it.
+    pub fn last(&self, cx: &mut WindowContext) -> Option<usize> {
+        self.focus_index(self.handles.len().checked_sub(1), cx)
+    }
+
+    /// Focus the next handle, or the first one if none is focused.
+    ///
+    /// Returns `None` if the focus is not moved, e.g.: at the end without wrap.
+    pub fn next(&self, cx: &mut WindowContext) -> Option<usize> {
+        let ix = cycle_index(self.handles.len(), self.focused_index(cx), true, self.wrap);
+        self.focus_index(ix, cx)
+    }
+
+    /// Focus the previous handle, or the last one if none is focused.
+    ///
+    /// Returns `None` if the focus is not moved, e.g.: at the start without wrap.
+    pub fn prev(&self, cx: &mut WindowContext) -> Option<usize> {
+        let ix = cycle_index(self.handles.len(), self.focused_index(cx), false, self.wrap);
+        self.focus_index(ix, cx)
+    }
+
+    /// Move the focus forward or backward, to call in the action handlers (e.g. Tab, Up).
+    ///
+    /// The propagation is stopped when the focus is moved, otherwise the action goes to the parent,
+    /// so the focus can leave the component at the ends without wrap.
+    pub fn step(&self, forward: bool, cx: &mut WindowContext) -> Option<usize> {
+        let ix = if forward {
+            self.next(cx)
+        } else {
+            self.prev(cx)
         };
+        if ix.is_some() {
+            cx.stop_propagation();
+        } else {
+            cx.propagate();
+        }
+        ix
+    }
 
-        let fallback_handle = handles[0].clone();
-        let target_focus_handle = handles
-            .into_iter()
-            .skip_while(|handle| Some(handle) != focused_handle.as_ref())
-            .skip(1)
-            .next()
-            .unwrap_or(fallback_handle);
+    fn focus_index(&self, ix: Option<usize>, cx: &mut WindowContext) -> Option<usize> {
+        let ix = ix?;
+        self.handles[ix].focus(cx);
+        Some(ix)
+    }
+}
 
-        target_focus_handle.focus(cx);
-        cx.stop_propagation();
+/// Returns the index to move to, from the `current` index in `len` items.
+fn cycle_index(len: usize, current: Option<usize>, forward: bool, wrap: bool) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+
+    match (current, forward) {
+        (None, true) => Some(0),
+        (None, false) => Some(len - 1),
+        (Some(ix), true) if ix + 1 < len => Some(ix + 1),
+        (Some(ix), false) if ix > 0 => Some(ix - 1),
+        (Some(_), true) => wrap.then_some(0),
+        (Some(_), false) => wrap.then_some(len - 1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cycle_index;
+
+    #[test]
+    fn test_cycle_index() {
+        assert_eq!(cycle_index(0, None, true, true), None);
+        assert_eq!(cycle_index(3, None, true, true), Some(0));
+        assert_eq!(cycle_index(3, None, false, true), Some(2));
+        assert_eq!(cycle_index(3, Some(0), true, true), Some(1));
+        assert_eq!(cycle_index(3, Some(2), true, true), Some(0));
+        assert_eq!(cycle_index(3, Some(0), false, true), Some(2));
+        // Without wrap, the focus stays at the ends.
+        assert_eq!(cycle_index(3, Some(2), true, false), None);
+        assert_eq!(cycle_index(3, Some(0), false, false), None);
+        assert_eq!(cycle_index(3, Some(1), false, false), Some(0));
     }
 }
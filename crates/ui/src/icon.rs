@@ -1,7 +1,11 @@
-use crate::{theme::ActiveTheme, Sizable, Size};
+use std::{sync::Arc, time::Duration};
+
+use crate::{h_flex, icon_cache::IconImage, theme::ActiveTheme, Icons, Sizable, Size};
 use gpui::{
-    prelude::FluentBuilder as _, svg, AnyElement, Hsla, IntoElement, Radians, Render, RenderOnce,
-    SharedString, StyleRefinement, Styled, Svg, Transformation, View, VisualContext, WindowContext,
+    bounce, div, ease_in_out, percentage, prelude::FluentBuilder as _, px, radians, size, svg,
+    Animation, AnimationExt as _, AnyElement, FontWeight, Hsla, IntoElement, ParentElement as _,
+    Pixels, Radians, Render, RenderOnce, SharedString, StyleRefinement, Styled, Svg,
+    Transformation, View, VisualContext, WindowContext,
 };
 
 #[derive(IntoElement, Clone)]
@@ -22,6 +26,8 @@ pub enum IconName {
     ChevronLeft,
     ChevronRight,
     ChevronUp,
+    ChevronsLeft,
+    ChevronsRight,
     ChevronsUpDown,
     CircleCheck,
     CircleUser,
@@ -32,6 +38,7 @@ pub enum IconName {
     Delete,
     Ellipsis,
     EllipsisVertical,
+    ExternalLink,
     Eye,
     EyeOff,
     Frame,
@@ -99,6 +106,8 @@ impl IconName {
             Self::ChevronLeft => "icons/chevron-left.svg",
             Self::ChevronRight => "icons/chevron-right.svg",
             Self::ChevronUp => "icons/chevron-up.svg",
+            Self::ChevronsLeft => "icons/chevrons-left.svg",
+            Self::ChevronsRight => "icons/chevrons-right.svg",
             Self::ChevronsUpDown => "icons/chevrons-up-down.svg",
             Self::CircleCheck => "icons/circle-check.svg",
             Self::CircleUser => "icons/circle-user.svg",
@@ -109,6 +118,7 @@ impl IconName {
             Self::Delete => "icons/delete.svg",
             Self::Ellipsis => "icons/ellipsis.svg",
             Self::EllipsisVertical => "icons/ellipsis-vertical.svg",
+            Self::ExternalLink => "icons/external-link.svg",
             Self::Eye => "icons/eye.svg",
             Self::EyeOff => "icons/eye-off.svg",
             Self::Frame => "icons/frame.svg",
@@ -183,23 +193,39 @@ impl RenderOnce for IconName {
     }
 }
 
+/// An svg icon, rendered with the text color of the parent element by default,
+/// use `text_color` to override it.
 #[derive(IntoElement)]
 pub struct Icon {
     base: Svg,
     path: SharedString,
     text_color: Option<Hsla>,
     size: Option<Size>,
+    transform: Option<Transformation>,
     rotation: Option<Radians>,
+    flip_x: bool,
+    flip_y: bool,
+    spin: bool,
+    speed: Duration,
+    pulse: bool,
+    badge: Option<Option<usize>>,
 }
 
 impl Default for Icon {
     fn default() -> Self {
         Self {
-            base: svg().flex_none().size_4(),
+            base: svg().flex_none(),
             path: "".into(),
             text_color: None,
             size: None,
+            transform: None,
             rotation: None,
+            flip_x: false,
+            flip_y: false,
+            spin: false,
+            speed: Duration::from_secs_f64(0.8),
+            pulse: false,
+            badge: None,
         }
     }
 }
@@ -210,6 +236,14 @@ impl Clone for Icon {
         if let Some(size) = self.size {
             this = this.with_size(size);
         }
+        this.transform = self.transform;
+        this.rotation = self.rotation;
+        this.flip_x = self.flip_x;
+        this.flip_y = self.flip_y;
+        this.spin = self.spin;
+        this.speed = self.speed;
+        this.pulse = self.pulse;
+        this.badge = self.badge;
         this
     }
 }
@@ -235,13 +269,39 @@ impl Icon {
         self
     }
 
+    /// Create an icon that registered by [`Icons::register`].
+    ///
+    /// If the icon is not registered, a placeholder will be rendered in debug builds.
+    pub fn named(name: impl Into<SharedString>) -> Self {
+        let name: SharedString = name.into();
+        match Icons::path(&name) {
+            Some(path) => Self::default().path(path),
+            None => Self::placeholder(),
+        }
+    }
+
+    /// Create an icon from the svg bytes.
+    ///
+    /// NOTE: This requires the application assets to be wrapped with [`crate::IconAssets`].
+    pub fn from_svg(data: impl Into<Arc<[u8]>>) -> Self {
+        Self::named(Icons::register_svg(data))
+    }
+
+    fn placeholder() -> Self {
+        if cfg!(debug_assertions) {
+            Self::build(IconName::CircleX).text_color(crate::red_500())
+        } else {
+            Self::empty()
+        }
+    }
+
     /// Create a new view for the icon
     pub fn view(self, cx: &mut WindowContext) -> View<Icon> {
         cx.new_view(|_| self)
     }
 
     pub fn transform(mut self, transformation: gpui::Transformation) -> Self {
-        self.base = self.base.with_transformation(transformation);
+        self.transform = Some(transformation);
         self
     }
 
@@ -249,11 +309,79 @@ impl Icon {
         Self::default()
     }
 
-    /// Rotate the icon by the given angle
+    /// Rotate the icon by the given angle, e.g. `rotate(Degrees(90.))`
+    ///
+    /// This only affects the painting, not the layout bounds.
+    /// The [`Icon::spin`] animation will take precedence over this.
     pub fn rotate(mut self, radians: impl Into<Radians>) -> Self {
-        self.base = self
-            .base
-            .with_transformation(Transformation::rotate(radians));
+        self.rotation = Some(radians.into());
+        self
+    }
+
+    /// Flip the icon horizontally.
+    pub fn flip_horizontal(mut self) -> Self {
+        self.flip_x = true;
+        self
+    }
+
+    /// Flip the icon vertically.
+    pub fn flip_vertical(mut self) -> Self {
+        self.flip_y = true;
+        self
+    }
+
+    /// Returns the transformation of the rotation and flips, if any.
+    fn transformation(&self) -> Option<Transformation> {
+        if self.rotation.is_none() && !self.flip_x && !self.flip_y {
+            return self.transform;
+        }
+
+        let scale = size(
+            if self.flip_x { -1. } else { 1. },
+            if self.flip_y { -1. } else { 1. },
+        );
+        Some(Transformation::rotate(self.rotation.unwrap_or(radians(0.))).with_scaling(scale))
+    }
+}
+
+impl Icon {
+    /// Set the exact size of the icon.
+    ///
+    /// Use [`Sizable::with_size`] to use the size of the [`Size`] enum.
+    /// If no size is set, the icon will be the same size as the current text size.
+    pub fn with_px(self, size: impl Into<Pixels>) -> Self {
+        self.with_size(Size::Size(size.into()))
+    }
+
+    /// Show a badge at the top-right corner of the icon, e.g. for the unread notifications.
+    ///
+    /// - `None`: a plain dot.
+    /// - `Some(count)`: a count pill, the count above 9 will be displayed as `9+`.
+    pub fn badge(mut self, count: Option<usize>) -> Self {
+        self.badge = Some(count);
+        self
+    }
+
+    /// Set to continuously rotate the icon, e.g. for `IconName::Loader`.
+    ///
+    /// This is disabled when the `reduce_motion` of the theme is enabled.
+    pub fn spin(mut self, spin: bool) -> Self {
+        self.spin = spin;
+        self
+    }
+
+    /// Set the duration of one turn of the [`Icon::spin`] or one breath of the [`Icon::pulse`],
+    /// default: 0.8s.
+    pub fn speed(mut self, speed: Duration) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Set to animate the opacity of the icon like breathing.
+    ///
+    /// This is disabled when the `reduce_motion` of the theme is enabled.
+    pub fn pulse(mut self) -> Self {
+        self.pulse = true;
         self
     }
 }
@@ -279,17 +407,142 @@ impl Sizable for Icon {
 impl RenderOnce for Icon {
     fn render(self, cx: &mut WindowContext) -> impl IntoElement {
         let text_color = self.text_color.unwrap_or_else(|| cx.text_style().color);
+        let transformation = self.transformation();
+        let reduce_motion = cx.theme().reduce_motion();
+        let speed = self.speed;
+
+        let mut base = self.base;
+        let size = match self.size {
+            Some(size) => Some(icon_size(size)),
+            // The size is set by the `Styled` methods, e.g.: `size_5`.
+            None if base.style().size.width.is_some() => None,
+            None => Some(cx.text_style().font_size.to_pixels(cx.rem_size())),
+        };
+
+        // The transformation is only supported by the `svg` element,
+        // otherwise paint the icon with the `IconCache` to avoid parsing the svg repeatedly.
+        let spin = self.spin && !reduce_motion;
+        let pulse = self.pulse && !reduce_motion;
+        let icon = if transformation.is_some() || spin {
+            let svg = base
+                .text_color(text_color)
+                .when_some(size, |this, size| this.size(size))
+                .when_some(transformation, |this, transformation| {
+                    this.with_transformation(transformation)
+                })
+                .path(self.path);
+
+            if spin {
+                svg.with_animation(
+                    "icon-spin",
+                    Animation::new(speed).repeat().with_easing(ease_in_out),
+                    |this, delta| {
+                        this.with_transformation(Transformation::rotate(percentage(delta)))
+                    },
+                )
+                .into_any_element()
+            } else if pulse {
+                pulse_animation(svg, speed)
+            } else {
+                svg.into_any_element()
+            }
+        } else {
+            let mut image = IconImage::new(self.path, text_color);
+            *image.style() = base.style().clone();
+            let image = image.when_some(size, |this, size| this.size(size));
+
+            if pulse {
+                pulse_animation(image, speed)
+            } else {
+                image.into_any_element()
+            }
+        };
+
+        let Some(badge) = self.badge else {
+            return icon;
+        };
 
-        self.base
-            .text_color(text_color)
-            .when_some(self.size, |this, size| match size {
-                Size::Size(px) => this.size(px),
-                Size::XSmall => this.size_3(),
-                Size::Small => this.size_3p5(),
-                Size::Medium => this.size_4(),
-                Size::Large => this.size_6(),
-            })
-            .path(self.path)
+        // Round the sizes to the whole pixels, to keep the badge crisp in small size.
+        let base_size = size
+            .unwrap_or_else(|| cx.text_style().font_size.to_pixels(cx.rem_size()))
+            .0;
+        let offset = px((base_size * 0.2).round());
+
+        div()
+            .relative()
+            .flex_none()
+            .child(icon)
+            .child(
+                h_flex()
+                    .absolute()
+                    .top(-offset)
+                    .right(-offset)
+                    .justify_center()
+                    .rounded_full()
+                    .bg(cx.theme().danger)
+                    .text_color(cx.theme().danger_foreground)
+                    .map(|this| match badge {
+                        None => this.size(px((base_size * 0.4).round().max(6.))),
+                        Some(count) => {
+                            let height = (base_size * 0.7).round().max(12.);
+                            this.h(px(height))
+                                .min_w(px(height))
+                                .px(px(3.))
+                                .text_size(px((height * 0.7).round()))
+                                .line_height(px(height))
+                                .font_weight(FontWeight::SEMIBOLD)
+                                .child(if count > 9 {
+                                    "9+".to_string()
+                                } else {
+                                    count.to_string()
+                                })
+                        }
+                    }),
+            )
+            .into_any_element()
+    }
+}
+
+fn pulse_animation<E>(element: E, speed: Duration) -> AnyElement
+where
+    E: Styled + IntoElement + 'static,
+{
+    element
+        .with_animation(
+            "icon-pulse",
+            Animation::new(speed * 2)
+                .repeat()
+                .with_easing(bounce(ease_in_out)),
+            |this, delta| this.opacity(1.0 - delta * 0.6),
+        )
+        .into_any_element()
+}
+
+/// An angle in degrees, can be used as [`Radians`], e.g.: `Icon::rotate(Degrees(90.))`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Degrees(pub f32);
+
+impl From<Degrees> for Radians {
+    fn from(degrees: Degrees) -> Self {
+        radians(degrees.0.to_radians())
+    }
+}
+
+/// Returns the pixel size of the icon for the [`Size`].
+///
+/// - XSmall: 12px
+/// - Small: 14px
+/// - Medium: 16px
+/// - Large: 24px
+/// - XLarge: 32px
+fn icon_size(size: Size) -> Pixels {
+    match size {
+        Size::Size(size) => size,
+        Size::XSmall => px(12.),
+        Size::Small => px(14.),
+        Size::Medium => px(16.),
+        Size::Large => px(24.),
+        Size::XLarge => px(32.),
     }
 }
 
@@ -301,21 +554,25 @@ impl From<Icon> for AnyElement {
 
 impl Render for Icon {
     fn render(&mut self, cx: &mut gpui::ViewContext<Self>) -> impl IntoElement {
-        let text_color = self.text_color.unwrap_or_else(|| cx.theme().foreground);
+        let text_color = self.text_color.unwrap_or_else(|| cx.text_style().color);
 
-        svg()
-            .flex_none()
-            .text_color(text_color)
-            .when_some(self.size, |this, size| match size {
-                Size::Size(px) => this.size(px),
-                Size::XSmall => this.size_3(),
-                Size::Small => this.size_3p5(),
-                Size::Medium => this.size_4(),
-                Size::Large => this.size_6(),
-            })
-            .path(self.path.clone())
-            .when_some(self.rotation, |this, rotation| {
-                this.with_transformation(Transformation::rotate(rotation))
-            })
+        let size = match self.size {
+            Some(size) => icon_size(size),
+            None => cx.text_style().font_size.to_pixels(cx.rem_size()),
+        };
+
+        match self.transformation() {
+            Some(transformation) => svg()
+                .flex_none()
+                .text_color(text_color)
+                .size(size)
+                .path(self.path.clone())
+                .with_transformation(transformation)
+                .into_any_element(),
+            None => IconImage::new(self.path.clone(), text_color)
+                .flex_none()
+                .size(size)
+                .into_any_element(),
+        }
     }
 }
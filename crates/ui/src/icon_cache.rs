@@ -0,0 +1,331 @@
+use std::{collections::HashMap, hash::Hash, sync::Arc};
+
+use gpui::{
+    px, Bounds, Element, Global, Hitbox, Hsla, InteractiveElement, Interactivity, IntoElement,
+    RenderImage, Rgba, SharedString, StyleRefinement, Styled, WindowContext,
+};
+use image::{Frame, ImageBuffer};
+use smallvec::SmallVec;
+
+/// The default max number of the rasterized icons to keep in the cache.
+const DEFAULT_CAPACITY: usize = 1024;
+
+/// A map that evicts the least recently used entries when it is full.
+struct Lru<K, V> {
+    entries: HashMap<K, (V, u64)>,
+    capacity: usize,
+    tick: u64,
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Lru<K, V> {
+    fn new(capacity: usize) -> Self {
+        Self {
+            entries: HashMap::new(),
+            capacity: capacity.max(1),
+            tick: 0,
+        }
+    }
+
+    fn get(&mut self, key: &K) -> Option<V> {
+        self.tick += 1;
+        let (value, used_at) = self.entries.get_mut(key)?;
+        *used_at = self.tick;
+        Some(value.clone())
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        if self.entries.len() >= self.capacity && !self.entries.contains_key(&key) {
+            let oldest = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, used_at))| *used_at)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                self.entries.remove(&oldest);
+            }
+        }
+
+        self.tick += 1;
+        self.entries.insert(key, (value, self.tick));
+    }
+
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct IconCacheKey {
+    path: SharedString,
+    width: u32,
+    height: u32,
+    /// The RGBA color packed into u32.
+    color: u32,
+}
+
+/// The app-global cache of the icons, to avoid parsing and rasterizing the same svg repeatedly.
+///
+/// The parsed svg is cached by the icon path, and the rasterized image is cached
+/// by the (path, size, color), both with a LRU cap.
+pub(crate) struct IconCache {
+    trees: Lru<SharedString, Option<Arc<usvg::Tree>>>,
+    images: Lru<IconCacheKey, Option<Arc<RenderImage>>>,
+    parse_count: usize,
+}
+
+impl Global for IconCache {}
+
+impl IconCache {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            trees: Lru::new(capacity),
+            images: Lru::new(capacity),
+            parse_count: 0,
+        }
+    }
+
+    /// Returns the rasterized icon in the device pixels size with the color.
+    ///
+    /// The `load` is only called when the svg of the `path` is not parsed yet.
+    fn image(
+        &mut self,
+        path: &SharedString,
+        width: u32,
+        height: u32,
+        color: Hsla,
+        load: impl FnOnce(&str) -> Option<Vec<u8>>,
+    ) -> Option<Arc<RenderImage>> {
+        let color = Rgba::from(color);
+        let key = IconCacheKey {
+            path: path.clone(),
+            width,
+            height,
+            color: pack_color(color),
+        };
+        if let Some(image) = self.images.get(&key) {
+            return image;
+        }
+
+        let tree = match self.trees.get(path) {
+            Some(tree) => tree,
+            None => {
+                self.parse_count += 1;
+                let tree = load(path)
+                    .and_then(|data| usvg::Tree::from_data(&data, &usvg::Options::default()).ok())
+                    .map(Arc::new);
+                self.trees.insert(path.clone(), tree.clone());
+                tree
+            }
+        };
+
+        let image = tree.and_then(|tree| rasterize(&tree, width, height, color));
+        self.images.insert(key, image.clone());
+        image
+    }
+}
+
+fn pack_color(color: Rgba) -> u32 {
+    let [r, g, b, a] = [color.r, color.g, color.b, color.a].map(|c| (c * 255.).round() as u32);
+    (r << 24) | (g << 16) | (b << 8) | a
+}
+
+/// Rasterize the svg as a mask and fill it with the color, like the `svg` element does.
+fn rasterize(tree: &usvg::Tree, width: u32, height: u32, color: Rgba) -> Option<Arc<RenderImage>> {
+    let mut pixmap = resvg::tiny_skia::Pixmap::new(width, height)?;
+    let transform = resvg::tiny_skia::Transform::from_scale(
+        width as f32 / tree.size().width(),
+        height as f32 / tree.size().height(),
+    );
+    resvg::render(tree, transform, &mut pixmap.as_mut());
+
+    let mut buffer = ImageBuffer::from_raw(pixmap.width(), pixmap.height(), pixmap.take())?;
+    let [r, g, b] = [color.r, color.g, color.b].map(|c| (c * 255.).round() as u8);
+    // BGRA with straight alpha.
+    for pixel in buffer.chunks_exact_mut(4) {
+        let alpha = (pixel[3] as f32 * color.a).round() as u8;
+        pixel.copy_from_slice(&[b, g, r, alpha]);
+    }
+
+    Some(Arc::new(RenderImage::new(SmallVec::from_elem(
+        Frame::new(buffer),
+        1,
+    ))))
+}
+
+/// An element to paint the icon by the [`IconCache`].
+pub(crate) struct IconImage {
+    interactivity: Interactivity,
+    path: SharedString,
+    color: Hsla,
+}
+
+impl IconImage {
+    pub(crate) fn new(path: SharedString, color: Hsla) -> Self {
+        Self {
+            interactivity: Interactivity::default(),
+            path,
+            color,
+        }
+    }
+}
+
+impl IntoElement for IconImage {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for IconImage {
+    type RequestLayoutState = ();
+    type PrepaintState = Option<Hitbox>;
+
+    fn id(&self) -> Option<gpui::ElementId> {
+        self.interactivity.element_id.clone()
+    }
+
+    fn request_layout(
+        &mut self,
+        global_id: Option<&gpui::GlobalElementId>,
+        cx: &mut WindowContext,
+    ) -> (gpui::LayoutId, Self::RequestLayoutState) {
+        let layout_id = self
+            .interactivity
+            .request_layout(global_id, cx, |style, cx| cx.request_layout(style, None));
+
+        (layout_id, ())
+    }
+
+    fn prepaint(
+        &mut self,
+        global_id: Option<&gpui::GlobalElementId>,
+        bounds: gpui::Bounds<gpui::Pixels>,
+        _: &mut Self::RequestLayoutState,
+        cx: &mut WindowContext,
+    ) -> Self::PrepaintState {
+        self.interactivity
+            .prepaint(global_id, bounds, bounds.size, cx, |_, _, hitbox, _| hitbox)
+    }
+
+    fn paint(
+        &mut self,
+        global_id: Option<&gpui::GlobalElementId>,
+        bounds: gpui::Bounds<gpui::Pixels>,
+        _: &mut Self::RequestLayoutState,
+        hitbox: &mut Self::PrepaintState,
+        cx: &mut WindowContext,
+    ) {
+        let path = self.path.clone();
+        let color = self.color;
+
+        self.interactivity
+            .paint(global_id, bounds, hitbox.as_ref(), cx, |_style, cx| {
+                if path.is_empty() || color.a == 0. {
+                    return;
+                }
+
+                let scale = cx.scale_factor();
+                let width = (bounds.size.width.0 * scale).ceil() as u32;
+                let height = (bounds.size.height.0 * scale).ceil() as u32;
+                if width == 0 || height == 0 {
+                    return;
+                }
+
+                if !cx.has_global::<IconCache>() {
+                    cx.set_global(IconCache::new(DEFAULT_CAPACITY));
+                }
+                let asset_source = cx.asset_source().clone();
+                let image = cx.update_global::<IconCache, _>(|cache, _| {
+                    cache.image(&path, width, height, color, |path| {
+                        asset_source
+                            .load(path)
+                            .ok()
+                            .flatten()
+                            .map(|data| data.to_vec())
+                    })
+                });
+
+                if let Some(image) = image {
+                    let bounds = Bounds {
+                        origin: bounds.origin.map(|origin| origin.floor()),
+                        size: bounds.size,
+                    };
+                    if let Err(err) = cx.paint_image(bounds, px(0.).into(), image, 0, false) {
+                        eprintln!("failed to paint icon: {:?}", err);
+                    }
+                }
+            })
+    }
+}
+
+impl Styled for IconImage {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.interactivity.base_style
+    }
+}
+
+impl InteractiveElement for IconImage {
+    fn interactivity(&mut self) -> &mut Interactivity {
+        &mut self.interactivity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{black, SharedString};
+
+    use super::IconCache;
+
+    const SVG: &[u8] = br#"<svg xmlns="http://www.w3.org/2000/svg" width="24" height="24" viewBox="0 0 24 24"><path d="M4 4L20 20" stroke="black" stroke-width="2"/></svg>"#;
+
+    #[test]
+    fn test_icon_cache() {
+        let mut cache = IconCache::new(16);
+        let icons: [SharedString; 2] = ["icons/a.svg".into(), "icons/b.svg".into()];
+        let mut loads = 0;
+
+        // Render a list of 1000 rows with two icons in each row twice.
+        for _ in 0..2 {
+            for _ in 0..1000 {
+                for path in &icons {
+                    let image = cache.image(path, 32, 32, black(), |_| {
+                        loads += 1;
+                        Some(SVG.to_vec())
+                    });
+                    assert!(image.is_some());
+                }
+            }
+        }
+
+        assert_eq!(loads, 2);
+        assert_eq!(cache.parse_count, 2);
+        assert_eq!(cache.images.len(), 2);
+
+        // The other size or color is rasterized from the parsed svg.
+        let white = gpui::white();
+        assert!(cache.image(&icons[0], 48, 48, white, |_| None).is_some());
+        assert_eq!(cache.parse_count, 2);
+        assert_eq!(cache.images.len(), 3);
+    }
+
+    #[test]
+    fn test_icon_cache_lru() {
+        let mut cache = IconCache::new(2);
+        let paths: [SharedString; 3] = ["a.svg".into(), "b.svg".into(), "c.svg".into()];
+        let load = |_: &str| Some(SVG.to_vec());
+
+        cache.image(&paths[0], 16, 16, black(), load);
+        cache.image(&paths[1], 16, 16, black(), load);
+        // Use `a`, then `b` is the least recently used one.
+        cache.image(&paths[0], 16, 16, black(), load);
+        cache.image(&paths[2], 16, 16, black(), load);
+        assert_eq!(cache.images.len(), 2);
+        assert_eq!(cache.parse_count, 3);
+
+        cache.image(&paths[0], 16, 16, black(), load);
+        assert_eq!(cache.parse_count, 3);
+        cache.image(&paths[1], 16, 16, black(), load);
+        assert_eq!(cache.parse_count, 4);
+    }
+}
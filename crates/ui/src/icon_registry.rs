@@ -0,0 +1,142 @@
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    hash::{DefaultHasher, Hash, Hasher},
+    sync::{Arc, LazyLock, RwLock},
+};
+
+use gpui::{AssetSource, SharedString};
+
+use crate::SvgSource;
+
+/// The asset path prefix of the icons registered with the svg bytes.
+const CUSTOM_ICON_PREFIX: &str = "icons/@custom/";
+
+static ICONS: LazyLock<RwLock<HashMap<SharedString, SvgSource>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// The registry of the custom icons, they can be used by [`crate::Icon::named`].
+///
+/// ```ignore
+/// Icons::register("my-logo", include_bytes!("../assets/my-logo.svg"));
+/// Icons::register("my-arrow", "icons/my-arrow.svg");
+///
+/// Icon::named("my-logo").small();
+/// ```
+///
+/// The icons registered with svg bytes are served by [`IconAssets`],
+/// so the application must wrap its asset source with it.
+pub struct Icons;
+
+impl Icons {
+    /// Register a custom icon by name, the `source` can be the svg bytes or an asset path.
+    ///
+    /// The icon with the same name will be replaced.
+    pub fn register(name: impl Into<SharedString>, source: impl Into<SvgSource>) {
+        ICONS.write().unwrap().insert(name.into(), source.into());
+    }
+
+    /// Register the svg bytes without a name, returns the generated name.
+    pub(crate) fn register_svg(data: impl Into<Arc<[u8]>>) -> SharedString {
+        let data = data.into();
+        let mut hasher = DefaultHasher::new();
+        data.hash(&mut hasher);
+        let name: SharedString = format!("svg-{:x}", hasher.finish()).into();
+
+        Self::register(name.clone(), data);
+        name
+    }
+
+    /// Returns true if the icon is registered.
+    pub fn contains(name: &str) -> bool {
+        ICONS.read().unwrap().contains_key(name)
+    }
+
+    /// Returns the asset path of a registered icon for render by the `svg` element.
+    pub(crate) fn path(name: &str) -> Option<SharedString> {
+        match ICONS.read().unwrap().get(name)? {
+            SvgSource::Path(path) => Some(path.clone()),
+            SvgSource::Data(_) => Some(format!("{}{}.svg", CUSTOM_ICON_PREFIX, name).into()),
+        }
+    }
+
+    fn load(path: &str) -> Option<Arc<[u8]>> {
+        let name = path
+            .strip_prefix(CUSTOM_ICON_PREFIX)?
+            .strip_suffix(".svg")?;
+
+        match ICONS.read().unwrap().get(name)? {
+            SvgSource::Data(data) => Some(data.clone()),
+            SvgSource::Path(_) => None,
+        }
+    }
+}
+
+/// An [`AssetSource`] wrapper to serve the icons registered by [`Icons::register`] with svg bytes.
+///
+/// ```ignore
+/// let app = App::new().with_assets(IconAssets::new(Assets));
+/// ```
+pub struct IconAssets<A: AssetSource> {
+    assets: A,
+}
+
+impl<A: AssetSource> IconAssets<A> {
+    pub fn new(assets: A) -> Self {
+        Self { assets }
+    }
+}
+
+impl<A: AssetSource> AssetSource for IconAssets<A> {
+    fn load(&self, path: &str) -> gpui::Result<Option<Cow<'static, [u8]>>> {
+        if let Some(data) = Icons::load(path) {
+            return Ok(Some(Cow::Owned(data.to_vec())));
+        }
+
+        self.assets.load(path)
+    }
+
+    fn list(&self, path: &str) -> gpui::Result<Vec<SharedString>> {
+        self.assets.list(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::AssetSource;
+
+    use super::{IconAssets, Icons};
+
+    struct EmptyAssets;
+
+    impl AssetSource for EmptyAssets {
+        fn load(&self, _: &str) -> gpui::Result<Option<std::borrow::Cow<'static, [u8]>>> {
+            Ok(None)
+        }
+
+        fn list(&self, _: &str) -> gpui::Result<Vec<gpui::SharedString>> {
+            Ok(vec![])
+        }
+    }
+
+    #[test]
+    fn test_register_icons() {
+        const SVG: &[u8] = br#"<svg xmlns="http://www.w3.org/2000/svg"></svg>"#;
+
+        Icons::register("test-logo", SVG);
+        Icons::register("test-arrow", "icons/arrow-up.svg");
+
+        assert!(Icons::contains("test-logo"));
+        assert!(!Icons::contains("test-unknown"));
+        assert_eq!(Icons::path("test-arrow"), Some("icons/arrow-up.svg".into()));
+
+        let path = Icons::path("test-logo").unwrap();
+        let assets = IconAssets::new(EmptyAssets);
+        assert_eq!(assets.load(&path).unwrap().as_deref(), Some(SVG));
+        assert_eq!(assets.load("icons/arrow-up.svg").unwrap(), None);
+
+        let name = Icons::register_svg(SVG);
+        assert_eq!(name, Icons::register_svg(SVG));
+        assert!(Icons::contains(&name));
+    }
+}
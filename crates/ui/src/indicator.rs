@@ -2,8 +2,8 @@ use std::time::Duration;
 
 use crate::{Icon, IconName, Sizable, Size};
 use gpui::{
-    div, ease_in_out, percentage, prelude::FluentBuilder as _, Animation, AnimationExt as _, Hsla,
-    IntoElement, ParentElement, RenderOnce, Styled as _, Transformation, WindowContext,
+    div, prelude::FluentBuilder as _, Hsla, IntoElement, ParentElement, RenderOnce, Styled as _,
+    WindowContext,
 };
 
 #[derive(IntoElement)]
@@ -49,11 +49,8 @@ impl RenderOnce for Indicator {
                 self.icon
                     .with_size(self.size)
                     .when_some(self.color, |this, color| this.text_color(color))
-                    .with_animation(
-                        "circle",
-                        Animation::new(self.speed).repeat().with_easing(ease_in_out),
-                        |this, delta| this.transform(Transformation::rotate(percentage(delta))),
-                    ),
+                    .spin(true)
+                    .speed(self.speed),
             )
             .into_element()
     }
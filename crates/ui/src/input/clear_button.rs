@@ -1,18 +1,35 @@
-use gpui::{Styled, WindowContext};
+use gpui::WindowContext;
+use rust_i18n::t;
 
 use crate::{
-    button::{Button, ButtonVariants as _},
-    theme::ActiveTheme as _,
-    Icon, IconName, Sizable as _,
+    button::{Button, ButtonCustomVariant, ButtonVariants as _, IconButton},
+    theme::{ActiveTheme as _, Colorize as _},
+    IconName, Sizable as _,
 };
 
 pub(crate) struct ClearButton {}
 
 impl ClearButton {
     pub fn new(cx: &mut WindowContext) -> Button {
-        Button::new("clean")
-            .icon(Icon::new(IconName::CircleX).text_color(cx.theme().muted_foreground))
-            .ghost()
+        let secondary = cx.theme().secondary;
+        let (hover, active) = if cx.theme().mode.is_dark() {
+            (secondary.lighten(0.1), secondary.lighten(0.2))
+        } else {
+            (secondary.darken(0.1), secondary.darken(0.2))
+        };
+
+        // The icon inherits the muted foreground from the button.
+        let style = ButtonCustomVariant::new(cx)
+            .color(cx.theme().transparent)
+            .border(cx.theme().transparent)
+            .foreground(cx.theme().muted_foreground)
+            .hover(hover.opacity(0.8))
+            .active(active.opacity(0.8))
+            .shadow(false);
+
+        IconButton::new("clean", IconName::CircleX)
+            .tooltip(t!("Input.Clear"))
+            .custom(style)
             .xsmall()
     }
 }
@@ -9,7 +9,7 @@ use crate::theme::ActiveTheme as _;
 
 use super::TextInput;
 
-const RIGHT_MARGIN: Pixels = px(5.);
+pub(super) const RIGHT_MARGIN: Pixels = px(5.);
 const BOTTOM_MARGIN: Pixels = px(20.);
 const CURSOR_INSET: Pixels = px(0.5);
 
@@ -148,7 +148,7 @@ impl TextElement {
                         ),
                         size(px(1.5), line_height),
                     ),
-                    crate::blue_500(),
+                    input.theme.caret.unwrap_or(cx.theme().caret),
                 ))
             };
         }
@@ -363,15 +363,19 @@ impl Element for TextElement {
         let style = cx.text_style();
         let mut bounds = bounds;
 
+        let foreground = input.theme.foreground.unwrap_or(cx.theme().foreground);
         let (display_text, text_color) = if text.is_empty() {
-            (placeholder, cx.theme().muted_foreground)
-        } else if input.masked {
             (
-                "*".repeat(text.chars().count()).into(),
-                cx.theme().foreground,
+                placeholder,
+                input
+                    .theme
+                    .placeholder
+                    .unwrap_or(cx.theme().muted_foreground),
             )
+        } else if input.masked {
+            ("*".repeat(text.chars().count()).into(), foreground)
         } else {
-            (text, cx.theme().foreground)
+            (text, foreground)
         };
 
         let run = TextRun {
@@ -487,7 +491,15 @@ impl Element for TextElement {
 
         // Paint selections
         if let Some(path) = prepaint.selection_path.take() {
-            cx.paint_path(path, cx.theme().selection);
+            let theme = self.input.read(cx).theme;
+            let selection = if focused {
+                theme.selection.unwrap_or(cx.theme().selection)
+            } else {
+                theme
+                    .selection_unfocused
+                    .unwrap_or(cx.theme().selection_unfocused)
+            };
+            cx.paint_path(path, selection);
         }
 
         // Paint multi line text
@@ -12,11 +12,11 @@ use unicode_segmentation::*;
 use gpui::prelude::FluentBuilder as _;
 use gpui::{
     actions, div, point, px, AnyElement, AppContext, Bounds, ClickEvent, ClipboardItem,
-    Context as _, Entity, EventEmitter, FocusHandle, FocusableView, Half, InteractiveElement as _,
-    IntoElement, KeyBinding, KeyDownEvent, Model, MouseButton, MouseDownEvent, MouseMoveEvent,
-    MouseUpEvent, ParentElement as _, Pixels, Point, Rems, Render, ScrollHandle, ScrollWheelEvent,
-    SharedString, Styled as _, UTF16Selection, ViewContext, ViewInputHandler, WindowContext,
-    WrappedLine,
+    Context as _, Entity, EventEmitter, FocusHandle, FocusableView, Half, Hsla,
+    InteractiveElement as _, IntoElement, KeyBinding, KeyDownEvent, Keystroke, Model, MouseButton,
+    MouseDownEvent, MouseMoveEvent, MouseUpEvent, ParentElement as _, Pixels, Point, Render,
+    ScrollHandle, ScrollWheelEvent, SharedString, Styled as _, UTF16Selection, ViewContext,
+    ViewInputHandler, WindowContext, WrappedLine,
 };
 
 // TODO:
@@ -25,16 +25,17 @@ use gpui::{
 
 use super::blink_cursor::BlinkCursor;
 use super::change::Change;
-use super::element::TextElement;
+use super::element::{TextElement, RIGHT_MARGIN};
 use super::ClearButton;
 
 use crate::history::History;
 use crate::indicator::Indicator;
+use crate::keymap::{bound_keymap, set_bound_keymap, KeymapBuilder};
+use crate::measure_text;
 use crate::scroll::{Scrollbar, ScrollbarAxis, ScrollbarState};
 use crate::theme::ActiveTheme;
 use crate::Size;
-use crate::StyledExt;
-use crate::{Sizable, StyleSized};
+use crate::{Disableable, Sizable, StyleSized, StyledExt as _};
 
 actions!(
     input,
@@ -71,22 +72,48 @@ actions!(
 
 #[derive(Clone)]
 pub enum InputEvent {
+    /// The text has been changed.
     Change(SharedString),
     PressEnter,
     Focus,
     Blur,
+    /// A key has been pressed in the input, e.g. to add the shortcuts for the input only.
+    ///
+    /// The event is delivered after the key has been handled, use [`TextInput::intercept_key_down`] to cancel it.
+    KeyDown(Keystroke),
+    /// The selected range has been changed, it is the cursor position if the range is empty.
+    SelectionChanged(Range<usize>),
+    /// The text has been pasted from the clipboard.
+    Paste(String),
+}
+
+/// Override the theme colors for a TextInput.
+///
+/// The `None` fields will fall back to the global theme, so they will follow the theme changes.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct InputTheme {
+    /// The background color, default: `background`.
+    pub background: Option<Hsla>,
+    /// The border color, default: `input`.
+    pub border: Option<Hsla>,
+    /// The border color when focused, default: `ring`.
+    pub ring: Option<Hsla>,
+    /// The text color, default: `foreground`.
+    pub foreground: Option<Hsla>,
+    /// The placeholder text color, default: `muted_foreground`.
+    pub placeholder: Option<Hsla>,
+    /// The selection color, default: `selection`.
+    pub selection: Option<Hsla>,
+    /// The selection color when the input is not focused, default: `selection_unfocused`.
+    pub selection_unfocused: Option<Hsla>,
+    /// The caret color, default: `caret`.
+    pub caret: Option<Hsla>,
 }
 
 const CONTEXT: &str = "Input";
 
 pub fn init(cx: &mut AppContext) {
     cx.bind_keys([
-        KeyBinding::new("backspace", Backspace, Some(CONTEXT)),
-        KeyBinding::new("delete", Delete, Some(CONTEXT)),
-        #[cfg(target_os = "macos")]
-        KeyBinding::new("cmd-backspace", DeleteToBeginningOfLine, Some(CONTEXT)),
-        #[cfg(target_os = "macos")]
-        KeyBinding::new("cmd-delete", DeleteToEndOfLine, Some(CONTEXT)),
         KeyBinding::new("enter", Enter, Some(CONTEXT)),
         KeyBinding::new("up", Up, Some(CONTEXT)),
         KeyBinding::new("down", Down, Some(CONTEXT)),
@@ -111,22 +138,6 @@ pub fn init(cx: &mut AppContext) {
         #[cfg(target_os = "macos")]
         KeyBinding::new("ctrl-cmd-space", ShowCharacterPalette, Some(CONTEXT)),
         #[cfg(target_os = "macos")]
-        KeyBinding::new("cmd-a", SelectAll, Some(CONTEXT)),
-        #[cfg(not(target_os = "macos"))]
-        KeyBinding::new("ctrl-a", SelectAll, Some(CONTEXT)),
-        #[cfg(target_os = "macos")]
-        KeyBinding::new("cmd-c", Copy, Some(CONTEXT)),
-        #[cfg(not(target_os = "macos"))]
-        KeyBinding::new("ctrl-c", Copy, Some(CONTEXT)),
-        #[cfg(target_os = "macos")]
-        KeyBinding::new("cmd-x", Cut, Some(CONTEXT)),
-        #[cfg(not(target_os = "macos"))]
-        KeyBinding::new("ctrl-x", Cut, Some(CONTEXT)),
-        #[cfg(target_os = "macos")]
-        KeyBinding::new("cmd-v", Paste, Some(CONTEXT)),
-        #[cfg(not(target_os = "macos"))]
-        KeyBinding::new("ctrl-v", Paste, Some(CONTEXT)),
-        #[cfg(target_os = "macos")]
         KeyBinding::new("ctrl-a", Home, Some(CONTEXT)),
         #[cfg(target_os = "macos")]
         KeyBinding::new("cmd-left", Home, Some(CONTEXT)),
@@ -134,15 +145,93 @@ pub fn init(cx: &mut AppContext) {
         KeyBinding::new("ctrl-e", End, Some(CONTEXT)),
         #[cfg(target_os = "macos")]
         KeyBinding::new("cmd-right", End, Some(CONTEXT)),
-        #[cfg(target_os = "macos")]
-        KeyBinding::new("cmd-z", Undo, Some(CONTEXT)),
-        #[cfg(target_os = "macos")]
-        KeyBinding::new("cmd-shift-z", Redo, Some(CONTEXT)),
-        #[cfg(not(target_os = "macos"))]
-        KeyBinding::new("ctrl-z", Undo, Some(CONTEXT)),
-        #[cfg(not(target_os = "macos"))]
-        KeyBinding::new("ctrl-y", Redo, Some(CONTEXT)),
     ]);
+
+    init_with_keymap(cx, InputKeymap::default());
+}
+
+/// The keystrokes of the editing actions of the TextInput, see [`init_with_keymap`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputKeymap {
+    pub backspace: Vec<&'static str>,
+    pub delete: Vec<&'static str>,
+    pub delete_to_beginning_of_line: Vec<&'static str>,
+    pub delete_to_end_of_line: Vec<&'static str>,
+    pub select_all: Vec<&'static str>,
+    pub copy: Vec<&'static str>,
+    pub cut: Vec<&'static str>,
+    pub paste: Vec<&'static str>,
+    pub undo: Vec<&'static str>,
+    pub redo: Vec<&'static str>,
+}
+
+#[cfg(target_os = "macos")]
+impl Default for InputKeymap {
+    fn default() -> Self {
+        Self {
+            backspace: vec!["backspace"],
+            delete: vec!["delete"],
+            delete_to_beginning_of_line: vec!["cmd-backspace"],
+            delete_to_end_of_line: vec!["cmd-delete"],
+            select_all: vec!["cmd-a"],
+            copy: vec!["cmd-c"],
+            cut: vec!["cmd-x"],
+            paste: vec!["cmd-v"],
+            undo: vec!["cmd-z"],
+            redo: vec!["cmd-shift-z"],
+        }
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+impl Default for InputKeymap {
+    fn default() -> Self {
+        Self {
+            backspace: vec!["backspace"],
+            delete: vec!["delete"],
+            delete_to_beginning_of_line: vec![],
+            delete_to_end_of_line: vec![],
+            select_all: vec!["ctrl-a"],
+            copy: vec!["ctrl-c"],
+            cut: vec!["ctrl-x"],
+            paste: vec!["ctrl-v"],
+            undo: vec!["ctrl-z"],
+            redo: vec!["ctrl-y"],
+        }
+    }
+}
+
+/// Bind the editing actions of the TextInput with the `keymap`.
+///
+/// This can be called after [`crate::init`] to override the default keybindings,
+/// the keystrokes of the last bound keymap (the defaults at first) missing in the `keymap`
+/// are removed.
+pub fn init_with_keymap(cx: &mut AppContext, keymap: InputKeymap) {
+    // Remove the keystrokes of the last keymap, not only the defaults, to replace it.
+    let previous = bound_keymap::<InputKeymap>(cx);
+    cx.bind_keys(
+        KeymapBuilder::new(Some(CONTEXT))
+            .bind(Backspace, &keymap.backspace, &previous.backspace)
+            .bind(Delete, &keymap.delete, &previous.delete)
+            .bind(
+                DeleteToBeginningOfLine,
+                &keymap.delete_to_beginning_of_line,
+                &previous.delete_to_beginning_of_line,
+            )
+            .bind(
+                DeleteToEndOfLine,
+                &keymap.delete_to_end_of_line,
+                &previous.delete_to_end_of_line,
+            )
+            .bind(SelectAll, &keymap.select_all, &previous.select_all)
+            .bind(Copy, &keymap.copy, &previous.copy)
+            .bind(Cut, &keymap.cut, &previous.cut)
+            .bind(Paste, &keymap.paste, &previous.paste)
+            .bind(Undo, &keymap.undo, &previous.undo)
+            .bind(Redo, &keymap.redo, &previous.redo)
+            .build(),
+    );
+    set_bound_keymap(keymap, cx);
 }
 
 pub struct TextInput {
@@ -171,13 +260,21 @@ pub struct TextInput {
     pub(super) last_selected_range: Option<Range<usize>>,
     pub(super) is_selecting: bool,
     pub(super) disabled: bool,
+    pub(super) invalid: bool,
     pub(super) masked: bool,
     pub(super) appearance: bool,
     pub(super) cleanable: bool,
-    pub(super) size: Size,
+    /// The size of the input, `None` to use the theme density.
+    pub(super) size: Option<Size>,
+    pub(super) theme: InputTheme,
     pub(super) rows: usize,
+    /// The `(min, max)` width to fit the text, see [`Self::auto_width`].
+    auto_width: Option<(Pixels, Pixels)>,
     pattern: Option<regex::Regex>,
     validate: Option<Box<dyn Fn(&str) -> bool + 'static>>,
+    intercept_key_down: Option<Box<dyn Fn(&Keystroke, &mut WindowContext) -> bool + 'static>>,
+    /// The selected range of the last [`InputEvent::SelectionChanged`].
+    emitted_selected_range: Range<usize>,
     pub(crate) scroll_handle: ScrollHandle,
     scrollbar_state: Rc<Cell<ScrollbarState>>,
     /// The size of the scrollable content.
@@ -207,16 +304,21 @@ impl TextInput {
             input_bounds: Bounds::default(),
             is_selecting: false,
             disabled: false,
+            invalid: false,
             masked: false,
             appearance: true,
             cleanable: false,
             loading: false,
             prefix: None,
             suffix: None,
-            size: Size::Medium,
+            size: None,
+            theme: InputTheme::default(),
             pattern: None,
             validate: None,
+            intercept_key_down: None,
+            emitted_selected_range: 0..0,
             rows: 2,
+            auto_width: None,
             last_layout: None,
             last_bounds: None,
             last_selected_range: None,
@@ -393,6 +495,21 @@ impl TextInput {
         !self.multi_line
     }
 
+    /// Measure the width of the text as it is displayed, the placeholder if it is empty.
+    fn measure_display_text(&self, size: Size, cx: &WindowContext) -> Pixels {
+        let mut font = cx.text_style().font();
+        font.family = cx.theme().font_family.clone();
+        let font_size = cx.theme().font_size_for(size);
+
+        if self.text.is_empty() {
+            measure_text(&self.placeholder, &font, font_size, cx)
+        } else if self.masked {
+            measure_text(&"*".repeat(self.text.chars().count()), &font, font_size, cx)
+        } else {
+            measure_text(&self.text, &font, font_size, cx)
+        }
+    }
+
     /// Set the number of rows for the multi-line Textarea.
     ///
     /// This is only used when `multi_line` is set to true.
@@ -403,6 +520,15 @@ impl TextInput {
         self
     }
 
+    /// Fit the width of the input to the text between `min` and `max`, instead of filling the parent.
+    ///
+    /// The text will scroll to keep the cursor visible if it is wider than `max`.
+    /// This is only used for the single-line input.
+    pub fn auto_width(mut self, min: Pixels, max: Pixels) -> Self {
+        self.auto_width = Some((min, max));
+        self
+    }
+
     /// Set the text of the input field.
     ///
     /// And the selection_range will be reset to 0..0.
@@ -428,6 +554,19 @@ impl TextInput {
         cx.notify();
     }
 
+    /// Set the invalid state of the input field, the border will be rendered with the `danger` color.
+    ///
+    /// This is used to indicate the validation error of the value.
+    pub fn set_invalid(&mut self, invalid: bool, cx: &mut ViewContext<Self>) {
+        self.invalid = invalid;
+        cx.notify();
+    }
+
+    /// Return true if the input field is in invalid state.
+    pub fn is_invalid(&self) -> bool {
+        self.invalid
+    }
+
     /// Set the masked state of the input field.
     pub fn set_masked(&mut self, masked: bool, cx: &mut ViewContext<Self>) {
         self.masked = masked;
@@ -456,16 +595,39 @@ impl TextInput {
 
     /// Set the Input size
     pub fn set_size(&mut self, size: Size, cx: &mut ViewContext<Self>) {
-        self.size = size;
+        self.size = Some(size);
         cx.notify();
     }
 
+    /// Returns the size of the input, the [`crate::Density`] of the theme is used if not set.
+    pub fn size(&self, cx: &AppContext) -> Size {
+        self.size.unwrap_or_else(|| cx.theme().default_size())
+    }
+
     /// Set the appearance of the input field.
     pub fn appearance(mut self, appearance: bool) -> Self {
         self.appearance = appearance;
         self
     }
 
+    /// Set the appearance of the input field with reference, e.g. it is drawn by an [`super::InputGroup`].
+    pub fn set_appearance(&mut self, appearance: bool, cx: &mut ViewContext<Self>) {
+        self.appearance = appearance;
+        cx.notify();
+    }
+
+    /// Override the theme colors of this input, see [`InputTheme`].
+    pub fn theme_override(mut self, theme: InputTheme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Set the theme override with reference, see [`InputTheme`].
+    pub fn set_theme_override(&mut self, theme: InputTheme, cx: &mut ViewContext<Self>) {
+        self.theme = theme;
+        cx.notify();
+    }
+
     /// Set the prefix element of the input field, for example a search Icon.
     pub fn prefix<F, E>(mut self, builder: F) -> Self
     where
@@ -520,6 +682,17 @@ impl TextInput {
         self
     }
 
+    /// Intercept the key down before the input handles it, return true to stop the input from handling the key.
+    ///
+    /// The intercepted keys are not emitted as [`InputEvent::KeyDown`].
+    pub fn intercept_key_down(
+        mut self,
+        f: impl Fn(&Keystroke, &mut WindowContext) -> bool + 'static,
+    ) -> Self {
+        self.intercept_key_down = Some(Box::new(f));
+        self
+    }
+
     /// Set true to show indicator at the input right.
     pub fn set_loading(&mut self, loading: bool, cx: &mut ViewContext<Self>) {
         self.loading = loading;
@@ -531,7 +704,8 @@ impl TextInput {
         self.text.clone()
     }
 
-    pub fn disabled(&self) -> bool {
+    /// Returns true if the input field is disabled, see [`TextInput::set_disabled`].
+    pub fn is_disabled(&self) -> bool {
         self.disabled
     }
 
@@ -540,6 +714,12 @@ impl TextInput {
         self.focus_handle.focus(cx);
     }
 
+    /// Select all the text.
+    pub fn select_all_text(&mut self, cx: &mut ViewContext<Self>) {
+        self.move_to(0, cx);
+        self.select_to(self.text.len(), cx)
+    }
+
     fn left(&mut self, _: &Left, cx: &mut ViewContext<Self>) {
         self.pause_blink_cursor(cx);
         if self.selected_range.is_empty() {
@@ -599,17 +779,25 @@ impl TextInput {
     }
 
     fn select_all(&mut self, _: &SelectAll, cx: &mut ViewContext<Self>) {
-        self.move_to(0, cx);
-        self.select_to(self.text.len(), cx)
+        self.select_all_text(cx);
     }
 
     fn home(&mut self, _: &Home, cx: &mut ViewContext<Self>) {
+        // Nothing to move, let the parent handle it, e.g. select the first item of a List.
+        if self.is_single_line() && self.text.is_empty() {
+            cx.propagate();
+            return;
+        }
         self.pause_blink_cursor(cx);
         let offset = self.start_of_line(cx);
         self.move_to(offset, cx);
     }
 
     fn end(&mut self, _: &End, cx: &mut ViewContext<Self>) {
+        if self.is_single_line() && self.text.is_empty() {
+            cx.propagate();
+            return;
+        }
         self.pause_blink_cursor(cx);
         let offset = self.end_of_line(cx);
         self.move_to(offset, cx);
@@ -797,6 +985,7 @@ impl TextInput {
             }
 
             self.replace_text_in_range(None, &new_text, cx);
+            cx.emit(InputEvent::Paste(new_text));
         }
     }
 
@@ -1074,7 +1263,8 @@ impl TextInput {
 
     /// Returns the true to let InputElement to render cursor, when Input is focused and current BlinkCursor is visible.
     pub(crate) fn show_cursor(&self, cx: &WindowContext) -> bool {
-        self.focus_handle.is_focused(cx) && self.blink_cursor.read(cx).visible()
+        self.focus_handle.is_focused(cx)
+            && (cx.theme().reduce_motion() || self.blink_cursor.read(cx).visible())
     }
 
     fn on_focus(&mut self, cx: &mut ViewContext<Self>) {
@@ -1085,7 +1275,7 @@ impl TextInput {
     }
 
     fn on_blur(&mut self, cx: &mut ViewContext<Self>) {
-        self.unselect(cx);
+        // Keep the selection, it will be painted with the `selection_unfocused` color.
         self.blink_cursor.update(cx, |cursor, cx| {
             cursor.stop(cx);
         });
@@ -1102,6 +1292,17 @@ impl TextInput {
         self.pause_blink_cursor(cx)
     }
 
+    fn on_capture_key_down(&mut self, event: &KeyDownEvent, cx: &mut ViewContext<Self>) {
+        if let Some(intercept) = self.intercept_key_down.as_ref() {
+            if intercept(&event.keystroke, cx) {
+                cx.stop_propagation();
+                return;
+            }
+        }
+
+        cx.emit(InputEvent::KeyDown(event.keystroke.clone()));
+    }
+
     pub(super) fn on_drag_move(&mut self, event: &MouseMoveEvent, cx: &mut ViewContext<Self>) {
         if self.text.is_empty() {
             return;
@@ -1141,9 +1342,17 @@ impl TextInput {
     }
 }
 
+impl Disableable for TextInput {
+    /// Set the disabled state of the input field, see also [`TextInput::set_disabled`].
+    fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
 impl Sizable for TextInput {
     fn with_size(mut self, size: impl Into<Size>) -> Self {
-        self.size = size.into();
+        self.size = Some(size.into());
         self
     }
 }
@@ -1294,11 +1503,17 @@ impl FocusableView for TextInput {
 
 impl Render for TextInput {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
-        const LINE_HEIGHT: Rems = Rems(1.25);
-        let focused = self.focus_handle.is_focused(cx);
+        // All the selection changes are rendered, so emit the change here instead of each of them.
+        if self.selected_range != self.emitted_selected_range {
+            self.emitted_selected_range = self.selected_range.clone();
+            cx.emit(InputEvent::SelectionChanged(self.selected_range.clone()));
+        }
 
         let prefix = self.prefix.as_ref().map(|build| build(cx));
         let suffix = self.suffix.as_ref().map(|build| build(cx));
+        let size = self.size(cx);
+        let auto_width = self.auto_width.filter(|_| self.is_single_line());
+        let text_width = auto_width.map(|_| self.measure_display_text(size, cx) + RIGHT_MARGIN);
 
         div()
             .flex()
@@ -1334,31 +1549,49 @@ impl Render for TextInput {
             .on_action(cx.listener(Self::undo))
             .on_action(cx.listener(Self::redo))
             .on_action(cx.listener(Self::redo))
+            .capture_key_down(cx.listener(Self::on_capture_key_down))
             .on_key_down(cx.listener(Self::on_key_down_for_blink_cursor))
             .on_mouse_down(MouseButton::Left, cx.listener(Self::on_mouse_down))
             .on_mouse_up(MouseButton::Left, cx.listener(Self::on_mouse_up))
             .on_scroll_wheel(cx.listener(Self::on_scroll_wheel))
             .size_full()
-            .line_height(LINE_HEIGHT)
-            .input_py(self.size)
-            .input_h(self.size)
+            .when_some(auto_width, |this, (min, max)| {
+                this.w_auto().flex_none().min_w(min).max_w(max)
+            })
+            .font_family(cx.theme().font_family.clone())
+            .line_height(cx.theme().line_height())
+            .input_py(size)
+            .input_h(size)
+            .text_size(cx.theme().font_size_for(size))
             .cursor_text()
+            .disabled_style(self.disabled, cx)
             .when(self.multi_line, |this| this.h_auto())
             .when(self.appearance, |this| {
-                this.bg(if self.disabled {
-                    cx.theme().muted
-                } else {
-                    cx.theme().background
-                })
-                .border_color(cx.theme().input)
-                .border_1()
-                .rounded(px(cx.theme().radius))
-                .when(cx.theme().shadow, |this| this.shadow_sm())
-                .when(focused, |this| this.outline(cx))
-                .when(prefix.is_none(), |this| this.input_pl(self.size))
-                .when(suffix.is_none(), |this| this.input_pr(self.size))
+                this.bg(self.theme.background.unwrap_or(cx.theme().background))
+                    .border_color(self.theme.border.unwrap_or(cx.theme().input))
+                    .border_1()
+                    .rounded(px(cx.theme().radius))
+                    .when(cx.theme().shadow, |this| this.shadow_sm())
+                    .focus_ring_color(
+                        &self.focus_handle,
+                        self.theme.ring.unwrap_or(cx.theme().ring),
+                        cx,
+                    )
+                    .when(self.invalid, |this| this.border_color(cx.theme().danger))
+                    .when(prefix.is_none(), |this| this.input_pl(size))
+                    .when(suffix.is_none(), |this| this.input_pr(size))
+            })
+            .when_some(prefix, |this, prefix| {
+                // The prefix (e.g. a search icon) inherits the muted foreground.
+                this.child(
+                    div()
+                        .flex()
+                        .flex_none()
+                        .items_center()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(prefix),
+                )
             })
-            .children(prefix)
             .gap_1()
             .items_center()
             .child(
@@ -1366,14 +1599,25 @@ impl Render for TextInput {
                     .id("TextElement")
                     .flex_grow()
                     .overflow_x_hidden()
+                    .when_some(text_width, |this, width| this.w(width))
                     .child(TextElement::new(cx.view().clone())),
             )
             .when(self.loading, |this| {
-                this.child(Indicator::new().color(cx.theme().muted_foreground))
+                this.child(
+                    Indicator::new()
+                        .color(cx.theme().muted_foreground)
+                        .with_size(size),
+                )
             })
             .when(
                 self.cleanable && !self.loading && !self.text.is_empty() && self.is_single_line(),
-                |this| this.child(ClearButton::new(cx).on_click(cx.listener(Self::clean))),
+                |this| {
+                    this.child(
+                        ClearButton::new(cx)
+                            .when(size == Size::Large, |this| this.small())
+                            .on_click(cx.listener(Self::clean)),
+                    )
+                },
             )
             .children(suffix)
             .when(self.is_multi_line(), |this| {
@@ -0,0 +1,243 @@
+use gpui::{
+    div, prelude::FluentBuilder as _, px, AnyElement, Corners, Div, Edges, FocusableView,
+    InteractiveElement as _, IntoElement, MouseButton, ParentElement, RenderOnce, SharedString,
+    Styled, View, WindowContext,
+};
+
+use crate::{
+    button::Button,
+    dropdown::{Dropdown, DropdownDelegate},
+    theme::ActiveTheme as _,
+    Disableable, Sizable, Size, StyleSized as _, StyledExt as _,
+};
+
+use super::TextInput;
+
+enum AddonKind {
+    Label(SharedString),
+    Button(Button),
+    Dropdown(Box<dyn FnOnce(Size, Option<bool>, &mut WindowContext) -> AnyElement>),
+    Element(AnyElement),
+}
+
+/// An addon attached before or after the input in an [`InputGroup`].
+///
+/// The Label, Button and Dropdown addons follow the size and the disabled state of the group.
+pub struct InputAddon(AddonKind);
+
+impl InputAddon {
+    /// A static text addon, e.g. "https://", click it to focus the input.
+    pub fn label(label: impl Into<SharedString>) -> Self {
+        Self(AddonKind::Label(label.into()))
+    }
+
+    /// Any element as an addon, the size and the disabled state should be set to it by yourself.
+    pub fn element(element: impl IntoElement) -> Self {
+        Self(AddonKind::Element(element.into_any_element()))
+    }
+}
+
+impl From<&'static str> for InputAddon {
+    fn from(label: &'static str) -> Self {
+        Self::label(label)
+    }
+}
+
+impl From<SharedString> for InputAddon {
+    fn from(label: SharedString) -> Self {
+        Self::label(label)
+    }
+}
+
+impl From<Button> for InputAddon {
+    fn from(button: Button) -> Self {
+        Self(AddonKind::Button(button))
+    }
+}
+
+impl<D> From<View<Dropdown<D>>> for InputAddon
+where
+    D: DropdownDelegate + 'static,
+{
+    fn from(dropdown: View<Dropdown<D>>) -> Self {
+        Self(AddonKind::Dropdown(Box::new(move |size, disabled, cx| {
+            dropdown.update(cx, |dropdown, cx| {
+                if dropdown.appearance {
+                    dropdown.set_appearance(false, cx);
+                }
+                if dropdown.size(cx) != size {
+                    dropdown.set_size(size, cx);
+                }
+                if let Some(disabled) = disabled {
+                    dropdown.set_disabled(disabled);
+                }
+            });
+            dropdown.into_any_element()
+        })))
+    }
+}
+
+/// A group to fuse a [`TextInput`] with the addons before and after it, e.g.: `[https://][ input ][Go]`.
+///
+/// The group draws the border and the focus ring of the input, the addons are separated by the border.
+#[derive(IntoElement)]
+pub struct InputGroup {
+    base: Div,
+    input: View<TextInput>,
+    prefix: Vec<InputAddon>,
+    suffix: Vec<InputAddon>,
+    size: Option<Size>,
+    disabled: Option<bool>,
+}
+
+impl InputGroup {
+    pub fn new(input: &View<TextInput>) -> Self {
+        Self {
+            base: div(),
+            input: input.clone(),
+            prefix: Vec::new(),
+            suffix: Vec::new(),
+            size: None,
+            disabled: None,
+        }
+    }
+
+    /// Add an addon before the input.
+    pub fn prefix(mut self, addon: impl Into<InputAddon>) -> Self {
+        self.prefix.push(addon.into());
+        self
+    }
+
+    /// Add an addon after the input.
+    pub fn suffix(mut self, addon: impl Into<InputAddon>) -> Self {
+        self.suffix.push(addon.into());
+        self
+    }
+}
+
+impl Sizable for InputGroup {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+}
+
+impl Disableable for InputGroup {
+    /// Set the disabled state of the input and the addons, they keep their own state if not set.
+    fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = Some(disabled);
+        self
+    }
+}
+
+impl Styled for InputGroup {
+    fn style(&mut self) -> &mut gpui::StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for InputGroup {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let input = self.input;
+        let size = self.size.unwrap_or_else(|| input.read(cx).size(cx));
+        input.update(cx, |input, cx| {
+            if input.appearance {
+                input.set_appearance(false, cx);
+            }
+            if input.size(cx) != size {
+                input.set_size(size, cx);
+            }
+            if let Some(disabled) = self.disabled.filter(|d| *d != input.disabled) {
+                input.set_disabled(disabled, cx);
+            }
+        });
+        let disabled = input.read(cx).disabled;
+        let focus_handle = input.focus_handle(cx);
+
+        let render_addon = |addon: InputAddon, is_prefix: bool, cx: &mut WindowContext| {
+            let element = match addon.0 {
+                AddonKind::Label(label) => {
+                    let focus_handle = focus_handle.clone();
+                    div()
+                        .flex()
+                        .items_center()
+                        .input_px(size)
+                        .bg(cx.theme().muted)
+                        .text_color(cx.theme().muted_foreground)
+                        .when(!disabled, |this| {
+                            // The label is not interactive, keep the focus in the input.
+                            this.on_mouse_down(MouseButton::Left, move |_, cx| {
+                                cx.prevent_default();
+                                cx.focus(&focus_handle);
+                            })
+                        })
+                        .child(label)
+                        .into_any_element()
+                }
+                AddonKind::Button(button) => button
+                    .with_size(size)
+                    .h_full()
+                    .border_corners(Corners::all(false))
+                    .border_edges(Edges::all(false))
+                    .when_some(self.disabled, |this, disabled| this.disabled(disabled))
+                    .into_any_element(),
+                AddonKind::Dropdown(render) => render(size, self.disabled, cx),
+                AddonKind::Element(element) => element,
+            };
+
+            div()
+                .flex()
+                .flex_none()
+                .h_full()
+                .items_center()
+                .border_color(cx.theme().input)
+                .map(|this| {
+                    if is_prefix {
+                        this.border_r_1()
+                    } else {
+                        this.border_l_1()
+                    }
+                })
+                .child(element)
+        };
+
+        let prefix = self
+            .prefix
+            .into_iter()
+            .map(|addon| render_addon(addon, true, cx))
+            .collect::<Vec<_>>();
+        let suffix = self
+            .suffix
+            .into_iter()
+            .map(|addon| render_addon(addon, false, cx))
+            .collect::<Vec<_>>();
+
+        self.base
+            .flex()
+            .items_center()
+            .input_h(size)
+            .overflow_hidden()
+            .text_size(cx.theme().font_size_for(size))
+            .bg(if disabled {
+                cx.theme().muted
+            } else {
+                cx.theme().background
+            })
+            .border_1()
+            .border_color(cx.theme().input)
+            .rounded(px(cx.theme().radius))
+            .when(cx.theme().shadow, |this| this.shadow_sm())
+            .focus_ring(&focus_handle, cx)
+            .when(disabled, |this| this.cursor_not_allowed())
+            .children(prefix)
+            .child(
+                div()
+                    .flex_1()
+                    .h_full()
+                    .overflow_hidden()
+                    .input_px(size)
+                    .child(input),
+            )
+            .children(suffix)
+    }
+}
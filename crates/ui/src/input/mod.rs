@@ -3,8 +3,10 @@ mod change;
 mod clear_button;
 mod element;
 mod input;
+mod input_group;
 mod otp_input;
 
 pub(crate) use clear_button::*;
 pub use input::*;
+pub use input_group::*;
 pub use otp_input::*;
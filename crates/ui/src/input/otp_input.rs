@@ -195,6 +195,7 @@ impl Render for OtpInput {
             Size::Small => px(14.),
             Size::Medium => px(16.),
             Size::Large => px(18.),
+            Size::XLarge => px(20.),
             Size::Size(v) => v * 0.5,
         };
 
@@ -230,6 +231,7 @@ impl Render for OtpInput {
                         Size::Small => this.w_6().h_6(),
                         Size::Medium => this.w_8().h_8(),
                         Size::Large => this.w_11().h_11(),
+                        Size::XLarge => this.w_12().h_12(),
                         Size::Size(px) => this.w(px).h(px),
                     })
                     .on_mouse_down(MouseButton::Left, cx.listener(Self::on_input_mouse_down))
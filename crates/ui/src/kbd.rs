@@ -0,0 +1,51 @@
+use gpui::{
+    div, px, Div, IntoElement, Keystroke, ParentElement as _, RenderOnce, Styled, WindowContext,
+};
+
+use crate::{popup_menu::key_shortcut, theme::ActiveTheme as _};
+
+/// A keyboard shortcut hint, e.g.: `⌘K` on macOS, `Ctrl+K` on other platforms.
+#[derive(IntoElement)]
+pub struct Kbd {
+    base: Div,
+    keystroke: Keystroke,
+}
+
+impl Kbd {
+    pub fn new(keystroke: Keystroke) -> Self {
+        Self {
+            base: div(),
+            keystroke,
+        }
+    }
+}
+
+impl From<Keystroke> for Kbd {
+    fn from(keystroke: Keystroke) -> Self {
+        Self::new(keystroke)
+    }
+}
+
+impl Styled for Kbd {
+    fn style(&mut self) -> &mut gpui::StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for Kbd {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        self.base
+            .flex()
+            .flex_none()
+            .justify_center()
+            .px_1()
+            .min_w(px(20.))
+            .text_xs()
+            .border_1()
+            .border_color(cx.theme().border)
+            .rounded(px(cx.theme().radius * 0.5))
+            .bg(cx.theme().muted)
+            .text_color(cx.theme().muted_foreground)
+            .child(key_shortcut(self.keystroke))
+    }
+}
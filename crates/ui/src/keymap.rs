@@ -0,0 +1,87 @@
+use gpui::{Action, AppContext, Global, KeyBinding, NoAction};
+
+/// The keymap of a component that is bound last, see [`bound_keymap`].
+struct BoundKeymap<K>(K);
+
+impl<K: 'static> Global for BoundKeymap<K> {}
+
+/// Returns the keymap bound last by the `init_with_keymap` of the component, or the default one
+/// bound by `init`.
+pub(crate) fn bound_keymap<K: Clone + Default + 'static>(cx: &AppContext) -> K {
+    cx.try_global::<BoundKeymap<K>>()
+        .map(|bound| bound.0.clone())
+        .unwrap_or_default()
+}
+
+/// Remember the `keymap` as the bound one, so the next one can remove its keystrokes.
+pub(crate) fn set_bound_keymap<K: 'static>(keymap: K, cx: &mut AppContext) {
+    cx.set_global(BoundKeymap(keymap));
+}
+
+/// A builder of the keybindings for a component keymap, e.g. [`crate::list::ListKeymap`].
+///
+/// The keystrokes of the previously bound keymap that are missing in the keymap are bound to
+/// [`NoAction`] to remove them, this works because the later bindings take precedence.
+pub(crate) struct KeymapBuilder<'a> {
+    context: Option<&'a str>,
+    removed: Vec<KeyBinding>,
+    bindings: Vec<KeyBinding>,
+}
+
+impl<'a> KeymapBuilder<'a> {
+    pub(crate) fn new(context: Option<&'a str>) -> Self {
+        Self {
+            context,
+            removed: Vec::new(),
+            bindings: Vec::new(),
+        }
+    }
+
+    /// Bind the `keystrokes` to the `action`, and remove the `previous` ones that are not in the `keystrokes`.
+    pub(crate) fn bind<A: Action + Clone>(
+        mut self,
+        action: A,
+        keystrokes: &[&str],
+        previous: &[&str],
+    ) -> Self {
+        for key in removed_keystrokes(keystrokes, previous) {
+            self.removed
+                .push(KeyBinding::new(key, NoAction, self.context));
+        }
+        for key in keystrokes {
+            self.bindings
+                .push(KeyBinding::new(key, action.clone(), self.context));
+        }
+        self
+    }
+
+    /// Returns the keybindings, the removed defaults are placed first,
+    /// so a keystroke moved to another action is not removed.
+    pub(crate) fn build(self) -> Vec<KeyBinding> {
+        self.removed.into_iter().chain(self.bindings).collect()
+    }
+}
+
+/// Returns the `previous` keystrokes that are not in the `keystrokes`.
+fn removed_keystrokes<'a>(keystrokes: &[&str], previous: &[&'a str]) -> Vec<&'a str> {
+    previous
+        .iter()
+        .filter(|key| !keystrokes.contains(key))
+        .copied()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::removed_keystrokes;
+
+    #[test]
+    fn test_removed_keystrokes() {
+        assert_eq!(
+            removed_keystrokes(&["down", "ctrl-n", "j"], &["down"]),
+            Vec::<&str>::new()
+        );
+        assert_eq!(removed_keystrokes(&["j"], &["down"]), vec!["down"]);
+        assert_eq!(removed_keystrokes(&[], &["up", "down"]), vec!["up", "down"]);
+    }
+}
@@ -2,51 +2,76 @@ mod colors;
 mod event;
 mod focusable;
 mod icon;
+mod icon_cache;
+mod icon_registry;
+mod keymap;
 mod root;
+mod selection;
 mod styled;
 mod svg_img;
+mod text;
 mod time;
 mod title_bar;
 mod window_border;
 
 pub mod accordion;
+pub mod alert;
 pub mod animation;
+pub mod avatar;
 pub mod badge;
 pub mod breadcrumb;
+pub mod busy;
 pub mod button;
 pub mod button_group;
+pub mod carousel;
 pub mod checkbox;
 pub mod clipboard;
+pub mod collapsible;
 pub mod color_picker;
+pub mod combobox;
+pub mod command_palette;
 pub mod context_menu;
+pub mod description_list;
 pub mod divider;
 pub mod dock;
 pub mod drawer;
 pub mod dropdown;
+pub mod empty_state;
+pub mod error_state;
 pub mod history;
 pub mod indicator;
 pub mod input;
+pub mod kbd;
 pub mod label;
 pub mod link;
 pub mod list;
 pub mod modal;
 pub mod notification;
 pub mod number_input;
+pub mod pagination;
 pub mod popover;
 pub mod popup_menu;
 pub mod prelude;
 pub mod progress;
 pub mod radio;
+pub mod rating;
 pub mod resizable;
 pub mod scroll;
+pub mod segmented_control;
 pub mod sidebar;
 pub mod skeleton;
 pub mod slider;
+pub mod steps;
 pub mod switch;
 pub mod tab;
 pub mod table;
+pub mod tag;
 pub mod theme;
+pub mod toolbar;
 pub mod tooltip;
+pub mod transfer_list;
+pub mod tree;
+pub mod virtual_grid;
 pub mod virtual_list;
 pub mod webview;
 
@@ -55,9 +80,10 @@ pub use wry;
 
 pub use crate::Disableable;
 pub use event::InteractiveElementExt;
-pub use focusable::FocusableCycle;
+pub use focusable::{FocusCycle, FocusableCycle};
 pub use root::{ContextModal, Root};
 pub use styled::*;
+pub use text::*;
 pub use time::*;
 pub use title_bar::*;
 pub use virtual_list::{h_virtual_list, v_virtual_list, VirtualList};
@@ -65,6 +91,7 @@ pub use window_border::{window_border, WindowBorder};
 
 pub use colors::*;
 pub use icon::*;
+pub use icon_registry::{IconAssets, Icons};
 pub use svg_img::*;
 
 use std::ops::Deref;
@@ -79,23 +106,68 @@ rust_i18n::i18n!("locales", fallback = "en");
 #[exclude = "*.DS_Store"]
 pub struct Assets;
 
+/// Call the `init` of the modules, and returns the names of them.
+macro_rules! init_modules {
+    ($cx:expr, [$($module:ident),* $(,)?]) => {{
+        $($module::init($cx);)*
+        &[$(stringify!($module)),*]
+    }};
+}
+
+/// A marker to avoid initializing the UI module twice.
+struct Initialized;
+
+impl gpui::Global for Initialized {}
+
 /// Initialize the UI module.
 ///
 /// This must be called before using any of the UI components.
 /// You can initialize the UI module at your application's entry point.
-pub fn init(cx: &mut gpui::AppContext) {
-    theme::init(cx);
-    date_picker::init(cx);
-    dock::init(cx);
-    drawer::init(cx);
-    dropdown::init(cx);
-    input::init(cx);
-    number_input::init(cx);
-    list::init(cx);
-    modal::init(cx);
-    popover::init(cx);
-    popup_menu::init(cx);
-    table::init(cx);
+///
+/// This registers the keybindings of all the components, and the built-in themes.
+/// The `init` of each module is still public, if you only need some of them.
+///
+/// Calling it again is a no-op, so the keybindings are never registered twice.
+///
+/// Returns the names of the initialized modules, or empty if it was already initialized.
+pub fn init(cx: &mut gpui::AppContext) -> &'static [&'static str] {
+    if cx.has_global::<Initialized>() {
+        return &[];
+    }
+    cx.set_global(Initialized);
+
+    init_modules!(
+        cx,
+        [
+            theme,
+            calendar,
+            carousel,
+            collapsible,
+            color_picker,
+            combobox,
+            date_picker,
+            dock,
+            drawer,
+            dropdown,
+            input,
+            number_input,
+            pagination,
+            list,
+            modal,
+            popover,
+            popup_menu,
+            radio,
+            resizable,
+            scroll,
+            segmented_control,
+            slider,
+            tab,
+            table,
+            toolbar,
+            tree,
+            virtual_grid,
+        ]
+    )
 }
 
 pub fn locale() -> impl Deref<Target = str> {
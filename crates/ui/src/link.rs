@@ -1,17 +1,25 @@
+use std::rc::Rc;
+
 use gpui::{
-    div, ClickEvent, Div, ElementId, InteractiveElement, IntoElement, MouseButton, ParentElement,
-    RenderOnce, SharedString, Stateful, StatefulInteractiveElement, Styled,
+    div, prelude::FluentBuilder as _, ClickEvent, Div, ElementId, FocusHandle, InteractiveElement,
+    IntoElement, MouseButton, ParentElement, RenderOnce, SharedString, Stateful,
+    StatefulInteractiveElement, Styled,
 };
 
-use crate::theme::ActiveTheme as _;
+use crate::{theme::ActiveTheme as _, Disableable, Icon, IconName, StyledExt as _};
 
 /// A Link element like a `<a>` tag in HTML.
+///
+/// The `href` is opened by the system, and the `on_click` handler is also called if set.
 #[derive(IntoElement)]
 pub struct Link {
     base: Stateful<Div>,
     href: Option<SharedString>,
     disabled: bool,
-    on_click: Option<Box<dyn Fn(&ClickEvent, &mut gpui::WindowContext) + 'static>>,
+    external: bool,
+    visited: bool,
+    focus_handle: Option<FocusHandle>,
+    on_click: Option<Rc<dyn Fn(&ClickEvent, &mut gpui::WindowContext) + 'static>>,
 }
 
 impl Link {
@@ -21,6 +29,9 @@ impl Link {
             href: None,
             on_click: None,
             disabled: false,
+            external: false,
+            visited: false,
+            focus_handle: None,
         }
     }
 
@@ -33,11 +44,31 @@ impl Link {
         mut self,
         handler: impl Fn(&ClickEvent, &mut gpui::WindowContext) + 'static,
     ) -> Self {
-        self.on_click = Some(Box::new(handler));
+        self.on_click = Some(Rc::new(handler));
+        self
+    }
+
+    /// Set to show an external link icon after the content, default: false
+    pub fn external(mut self, external: bool) -> Self {
+        self.external = external;
+        self
+    }
+
+    /// Set to show in the visited color, the app keeps the visited state.
+    pub fn visited(mut self, visited: bool) -> Self {
+        self.visited = visited;
         self
     }
 
-    pub fn disabled(mut self, disabled: bool) -> Self {
+    /// Set the focus handle, to activate by Enter when it is focused, and show the focus ring.
+    pub fn focusable(mut self, focus_handle: &FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle.clone());
+        self
+    }
+}
+
+impl Disableable for Link {
+    fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
         self
     }
@@ -59,35 +90,63 @@ impl RenderOnce for Link {
     fn render(self, cx: &mut gpui::WindowContext) -> impl IntoElement {
         let href = self.href.clone();
         let on_click = self.on_click;
+        let color = if self.disabled {
+            cx.theme().muted_foreground
+        } else if self.visited {
+            cx.theme().link_active
+        } else {
+            cx.theme().link
+        };
+
+        let activate = Rc::new(move |event: &ClickEvent, cx: &mut gpui::WindowContext| {
+            if let Some(href) = &href {
+                cx.open_url(&href.clone());
+            }
+            if let Some(on_click) = &on_click {
+                on_click(event, cx);
+            }
+        });
 
         div()
-            .text_color(cx.theme().link)
-            .text_decoration_1()
-            .text_decoration_color(cx.theme().link)
-            .hover(|this| {
-                this.text_color(cx.theme().link.opacity(0.8))
-                    .text_decoration_1()
+            .flex()
+            .items_center()
+            .gap_0p5()
+            .text_color(color)
+            .text_decoration_color(color)
+            .when(!self.disabled, |this| {
+                this.cursor_pointer()
+                    .hover(|this| this.text_color(cx.theme().link_hover).text_decoration_1())
             })
-            .cursor_pointer()
+            .when(self.disabled, |this| this.cursor_not_allowed())
             .child(
                 self.base
-                    .active(|this| {
-                        this.text_color(cx.theme().link.opacity(0.6))
-                            .text_decoration_1()
-                    })
-                    .on_mouse_down(MouseButton::Left, |_, cx| {
-                        cx.stop_propagation();
+                    .when_some(self.focus_handle.as_ref(), |this, focus_handle| {
+                        this.when(!self.disabled, |this| this.track_focus(focus_handle))
+                            .focus_ring(focus_handle, cx)
                     })
-                    .on_click({
-                        move |e, cx| {
-                            if let Some(href) = &href {
-                                cx.open_url(&href.clone());
-                            }
-                            if let Some(on_click) = &on_click {
-                                on_click(e, cx);
-                            }
-                        }
+                    .when(!self.disabled, |this| {
+                        this.active(|this| {
+                            this.text_color(cx.theme().link_active).text_decoration_1()
+                        })
+                        .on_mouse_down(MouseButton::Left, |_, cx| {
+                            cx.stop_propagation();
+                        })
+                        .on_click({
+                            let activate = activate.clone();
+                            move |event, cx| activate(event, cx)
+                        })
+                        .when(self.focus_handle.is_some(), |this| {
+                            this.on_key_down(move |event, cx| {
+                                if event.keystroke.key == "enter" {
+                                    cx.stop_propagation();
+                                    activate(&ClickEvent::default(), cx);
+                                }
+                            })
+                        })
                     }),
             )
+            .when(self.external, |this| {
+                this.child(Icon::new(IconName::ExternalLink).size_3().flex_shrink_0())
+            })
     }
 }
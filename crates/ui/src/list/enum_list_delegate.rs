@@ -0,0 +1,174 @@
+use gpui::{AppContext, ParentElement as _, SharedString, Task, ViewContext};
+
+use crate::{h_flex, Icon};
+
+use super::{List, ListDelegate, ListItem};
+
+/// An enum of the fixed options, e.g. the sort orders, to show in a [`List`] or a `Dropdown`.
+///
+/// ```ignore
+/// #[derive(Clone, Copy, PartialEq)]
+/// enum SortOrder {
+///     Name,
+///     Date,
+/// }
+///
+/// impl ListEnum for SortOrder {
+///     fn variants() -> &'static [Self] {
+///         &[Self::Name, Self::Date]
+///     }
+///
+///     fn label(&self) -> SharedString {
+///         match self {
+///             Self::Name => "Name".into(),
+///             Self::Date => "Date".into(),
+///         }
+///     }
+/// }
+///
+/// let list = cx.new_view(|cx| List::new(EnumListDelegate::<SortOrder>::new(), cx));
+/// let dropdown = cx.new_view(|cx| Dropdown::new("sort", SortOrder::variants().to_vec(), None, cx));
+/// ```
+pub trait ListEnum: Copy + PartialEq + 'static {
+    /// Returns all the variants in the display order.
+    fn variants() -> &'static [Self];
+
+    /// Returns the text to display, it is also used to filter the variants.
+    fn label(&self) -> SharedString;
+
+    /// Returns the icon to display before the label, default: None
+    fn icon(&self) -> Option<Icon> {
+        None
+    }
+}
+
+/// Returns the variants that the label contains the `query`, case insensitive.
+fn match_variants<T: ListEnum>(query: &str) -> Vec<T> {
+    let query = query.to_lowercase();
+    T::variants()
+        .iter()
+        .filter(|value| value.label().to_lowercase().contains(&query))
+        .copied()
+        .collect()
+}
+
+/// A [`ListDelegate`] of the variants of a [`ListEnum`], with the filtering by the label.
+pub struct EnumListDelegate<T: ListEnum> {
+    /// The matched variants.
+    matches: Vec<T>,
+    selected_index: Option<usize>,
+    confirmed: Option<T>,
+}
+
+impl<T: ListEnum> EnumListDelegate<T> {
+    pub fn new() -> Self {
+        Self {
+            matches: T::variants().to_vec(),
+            selected_index: None,
+            confirmed: None,
+        }
+    }
+
+    /// Set the confirmed value.
+    ///
+    /// Call [`List::items_changed`] if this is not called via [`List::delegate_mut`].
+    pub fn set_confirmed(&mut self, value: Option<T>) {
+        self.confirmed = value;
+    }
+
+    /// Returns the matched value at the index of the List.
+    pub fn value(&self, ix: usize) -> Option<T> {
+        self.matches.get(ix).copied()
+    }
+
+    /// Returns the selected value.
+    pub fn selected_value(&self) -> Option<T> {
+        self.value(self.selected_index?)
+    }
+
+    /// Returns the last confirmed value.
+    pub fn confirmed_value(&self) -> Option<T> {
+        self.confirmed
+    }
+}
+
+impl<T: ListEnum> Default for EnumListDelegate<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: ListEnum> ListDelegate for EnumListDelegate<T> {
+    type Item = ListItem;
+
+    fn items_count(&self, _: &AppContext) -> usize {
+        self.matches.len()
+    }
+
+    fn render_item(&self, ix: usize, _: &mut ViewContext<List<Self>>) -> Option<Self::Item> {
+        let value = self.value(ix)?;
+
+        Some(
+            ListItem::new(("item", ix))
+                .selected(self.selected_index == Some(ix))
+                .confirmed(self.confirmed == Some(value))
+                .child(h_flex().gap_2().children(value.icon()).child(value.label())),
+        )
+    }
+
+    fn perform_search(&mut self, query: &str, _: &mut ViewContext<List<Self>>) -> Task<()> {
+        self.matches = match_variants(query);
+        Task::ready(())
+    }
+
+    fn confirmed_index(&self, _: &AppContext) -> Option<usize> {
+        let confirmed = self.confirmed?;
+        self.matches.iter().position(|value| *value == confirmed)
+    }
+
+    fn set_selected_index(&mut self, ix: Option<usize>, _: &mut ViewContext<List<Self>>) {
+        self.selected_index = ix;
+    }
+
+    fn confirm(&mut self, ix: Option<usize>, _: &mut ViewContext<List<Self>>) {
+        self.confirmed = ix.and_then(|ix| self.value(ix));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::SharedString;
+
+    use super::{match_variants, ListEnum};
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum SortOrder {
+        Name,
+        Date,
+        Size,
+    }
+
+    impl ListEnum for SortOrder {
+        fn variants() -> &'static [Self] {
+            &[Self::Name, Self::Date, Self::Size]
+        }
+
+        fn label(&self) -> SharedString {
+            match self {
+                Self::Name => "Name".into(),
+                Self::Date => "Date modified".into(),
+                Self::Size => "Size".into(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_match_variants() {
+        use SortOrder::*;
+
+        assert_eq!(match_variants::<SortOrder>(""), vec![Name, Date, Size]);
+        assert_eq!(match_variants::<SortOrder>("MODIFIED"), vec![Date]);
+        assert_eq!(match_variants::<SortOrder>("a"), vec![Name, Date]);
+        assert_eq!(match_variants::<SortOrder>("color"), vec![]);
+    }
+}
@@ -1,32 +1,215 @@
 use std::time::Duration;
-use std::{cell::Cell, rc::Rc};
+use std::{any::Any, cell::Cell, rc::Rc};
 
 use crate::Icon;
 use crate::{
+    animation::Animated,
+    h_flex,
     input::{InputEvent, TextInput},
+    keymap::{bound_keymap, set_bound_keymap, KeymapBuilder},
     scroll::{Scrollbar, ScrollbarState},
-    theme::ActiveTheme,
-    v_flex, IconName, Size,
+    selection::{nearest_enabled, step_enabled},
+    skeleton::SkeletonList,
+    theme::{ActiveTheme, Colorize as _},
+    v_flex, FocusCycle, IconName, Sizable, Size, StyleSized as _, StyledExt as _,
 };
 use gpui::{
-    actions, div, prelude::FluentBuilder, uniform_list, AnyElement, AppContext, Entity,
-    FocusHandle, FocusableView, InteractiveElement, IntoElement, KeyBinding, Length,
-    ListSizingBehavior, MouseButton, ParentElement, Render, SharedString, Styled, Task,
-    UniformListScrollHandle, View, ViewContext, VisualContext, WindowContext,
+    actions, div, prelude::FluentBuilder, uniform_list, AnyElement, AppContext, Bounds, ClickEvent,
+    DragMoveEvent, Edges, Entity, EntityId, EventEmitter, FocusHandle, FocusableView, Hsla,
+    InteractiveElement, IntoElement, Length, ListSizingBehavior, Modifiers, MouseButton,
+    MouseDownEvent, ParentElement, Pixels, Point, Render, ScrollWheelEvent, SharedString,
+    StatefulInteractiveElement, Styled, Subscription, Task, UniformListScrollHandle, View,
+    ViewContext, VisualContext, WeakView, WindowContext,
 };
 use gpui::{px, ScrollStrategy};
 use smol::Timer;
 
-actions!(list, [Cancel, Confirm, SelectPrev, SelectNext]);
+actions!(
+    list,
+    [
+        Cancel,
+        Confirm,
+        SecondaryConfirm,
+        SelectPrev,
+        SelectNext,
+        SelectLeft,
+        SelectRight,
+        SelectFirst,
+        SelectLast,
+        SelectPageUp,
+        SelectPageDown,
+        Tab,
+        TabPrev,
+        FocusQuery
+    ]
+);
 
 pub fn init(cx: &mut AppContext) {
-    let context: Option<&str> = Some("List");
-    cx.bind_keys([
-        KeyBinding::new("escape", Cancel, context),
-        KeyBinding::new("enter", Confirm, context),
-        KeyBinding::new("up", SelectPrev, context),
-        KeyBinding::new("down", SelectNext, context),
-    ]);
+    init_with_keymap(cx, ListKeymap::default());
+}
+
+/// The keystrokes of the List actions, see [`init_with_keymap`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListKeymap {
+    pub cancel: Vec<&'static str>,
+    pub confirm: Vec<&'static str>,
+    /// Confirm with [`ConfirmContext::secondary`], e.g. to open the item in a new tab.
+    pub secondary_confirm: Vec<&'static str>,
+    pub select_prev: Vec<&'static str>,
+    pub select_next: Vec<&'static str>,
+    /// Move the selection by one item in the grid mode, see [`List::grid`].
+    pub select_left: Vec<&'static str>,
+    pub select_right: Vec<&'static str>,
+    /// The Home and End keys move the caret of the query input instead, unless the query is empty.
+    pub select_first: Vec<&'static str>,
+    pub select_last: Vec<&'static str>,
+    pub select_page_up: Vec<&'static str>,
+    pub select_page_down: Vec<&'static str>,
+    /// Move the focus from the query input to the items.
+    pub tab: Vec<&'static str>,
+    /// Move the focus from the items to the query input.
+    pub tab_prev: Vec<&'static str>,
+    /// Focus the query input and select its text, from the items.
+    pub focus_query: Vec<&'static str>,
+}
+
+impl Default for ListKeymap {
+    fn default() -> Self {
+        Self {
+            cancel: vec!["escape"],
+            confirm: vec!["enter"],
+            secondary_confirm: vec![if cfg!(target_os = "macos") {
+                "cmd-enter"
+            } else {
+                "ctrl-enter"
+            }],
+            select_prev: vec!["up"],
+            select_next: vec!["down"],
+            select_left: vec!["left"],
+            select_right: vec!["right"],
+            select_first: vec!["home"],
+            select_last: vec!["end"],
+            select_page_up: vec!["pageup"],
+            select_page_down: vec!["pagedown"],
+            tab: vec!["tab"],
+            tab_prev: vec!["shift-tab"],
+            focus_query: vec![
+                "/",
+                if cfg!(target_os = "macos") {
+                    "cmd-f"
+                } else {
+                    "ctrl-f"
+                },
+            ],
+        }
+    }
+}
+
+/// Bind the List actions with the `keymap`.
+///
+/// This can be called after [`crate::init`] to override the default keybindings,
+/// the keystrokes of the last bound keymap (the defaults at first) missing in the `keymap`
+/// are removed, e.g.:
+///
+/// ```ignore
+/// list::init_with_keymap(cx, ListKeymap {
+///     select_prev: vec!["up", "ctrl-p", "k"],
+///     select_next: vec!["down", "ctrl-n", "j"],
+///     ..Default::default()
+/// });
+/// ```
+pub fn init_with_keymap(cx: &mut AppContext, keymap: ListKeymap) {
+    // Remove the keystrokes of the last keymap, not only the defaults, to replace it.
+    let previous = bound_keymap::<ListKeymap>(cx);
+    cx.bind_keys(
+        KeymapBuilder::new(Some("List"))
+            .bind(Cancel, &keymap.cancel, &previous.cancel)
+            .bind(Confirm, &keymap.confirm, &previous.confirm)
+            .bind(
+                SecondaryConfirm,
+                &keymap.secondary_confirm,
+                &previous.secondary_confirm,
+            )
+            .bind(SelectPrev, &keymap.select_prev, &previous.select_prev)
+            .bind(SelectNext, &keymap.select_next, &previous.select_next)
+            .bind(SelectLeft, &keymap.select_left, &previous.select_left)
+            .bind(SelectRight, &keymap.select_right, &previous.select_right)
+            .bind(SelectFirst, &keymap.select_first, &previous.select_first)
+            .bind(SelectLast, &keymap.select_last, &previous.select_last)
+            .bind(
+                SelectPageUp,
+                &keymap.select_page_up,
+                &previous.select_page_up,
+            )
+            .bind(
+                SelectPageDown,
+                &keymap.select_page_down,
+                &previous.select_page_down,
+            )
+            .bind(Tab, &keymap.tab, &previous.tab)
+            .bind(TabPrev, &keymap.tab_prev, &previous.tab_prev)
+            .bind(FocusQuery, &keymap.focus_query, &previous.focus_query)
+            .build(),
+    );
+    set_bound_keymap(keymap, cx);
+}
+
+/// The number of items of a page, if the items have not been measured.
+const DEFAULT_PAGE_SIZE: usize = 10;
+/// The interval to scroll the items while a drag is hovering near the edges.
+const DRAG_SCROLL_INTERVAL: Duration = Duration::from_millis(16);
+/// The height of the skeleton rows before the items have been measured.
+const SKELETON_ROW_HEIGHT: Pixels = px(32.);
+/// The loading overlay is only shown if the search is slower than this, to avoid flashing.
+const LOADING_DELAY: Duration = Duration::from_millis(150);
+
+/// Returns the target of moving the selection by `delta` items in a grid of `columns`,
+/// e.g. `columns` to move one row down, without wrapping.
+///
+/// Moving down from the row above a shorter last row selects the last item,
+/// the other moves out of the items return None to keep the selection.
+fn grid_step(ix: Option<usize>, delta: isize, columns: usize, count: usize) -> Option<usize> {
+    let last = count.checked_sub(1)?;
+    let Some(ix) = ix else {
+        return Some(if delta > 0 { 0 } else { last });
+    };
+
+    let target = ix.checked_add_signed(delta)?;
+    if target <= last {
+        Some(target)
+    } else if delta > 1 && ix / columns < last / columns {
+        Some(last)
+    } else {
+        None
+    }
+}
+
+/// The events of the List in the controlled selection mode, see [`List::controlled_selection`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListEvent {
+    /// The user wants to select the item at the index, by keyboard or mouse.
+    SelectRequested(usize),
+    /// The user wants to confirm the item at the index, by Enter or click.
+    ConfirmRequested(usize),
+}
+
+/// How the item of the List is selected or confirmed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionSource {
+    Keyboard,
+    Mouse,
+}
+
+/// The context of a confirm, see [`ListDelegate::confirm_with`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfirmContext {
+    /// The index of the confirmed item.
+    pub index: Option<usize>,
+    /// The text of the query input when confirmed, None if the List has no query.
+    pub query: Option<SharedString>,
+    /// True if confirmed with the secondary modifier, e.g. `cmd-enter` or `cmd-click` on macOS.
+    pub secondary: bool,
+    pub source: SelectionSource,
 }
 
 /// A delegate for the List.
@@ -41,6 +224,10 @@ pub trait ListDelegate: Sized + 'static {
     }
 
     /// Return the number of items in the list.
+    ///
+    /// The count is cached by the List, it will be read again after [`Self::perform_search`]
+    /// and [`List::delegate_mut`]. If the count is changed in other ways (e.g. a shared model),
+    /// call [`List::items_changed`] to refresh it.
     fn items_count(&self, cx: &AppContext) -> usize;
 
     /// Render the item at the given index.
@@ -64,37 +251,338 @@ pub trait ListDelegate: Sized + 'static {
         None
     }
 
+    /// Returns the overlay to show over the items while the search is running, None to hide it.
+    ///
+    /// Default is a translucent overlay with a spinning loader in the center. It fades in if the
+    /// search takes longer than 150ms, and it doesn't block the scrolling of the stale items.
+    fn render_loading(&self, cx: &mut ViewContext<List<Self>>) -> Option<AnyElement> {
+        Some(
+            v_flex()
+                .size_full()
+                .center()
+                .bg(cx.theme().background.opacity(0.5))
+                .child(
+                    Icon::new(IconName::Loader)
+                        .spin(true)
+                        .text_color(cx.theme().muted_foreground),
+                )
+                .into_any_element(),
+        )
+    }
+
+    /// Returns the number of the pinned items, default: 0.
+    ///
+    /// The first N items are rendered above the scrolling items, so they are always visible,
+    /// they can be still selected and confirmed as the other items.
+    fn pinned_count(&self, cx: &AppContext) -> usize {
+        0
+    }
+
     /// Return the confirmed index of the selected item.
     fn confirmed_index(&self, cx: &AppContext) -> Option<usize> {
         None
     }
 
+    /// Returns true if the item at the given index is disabled.
+    ///
+    /// The disabled items are skipped by the keyboard selection, and can't be confirmed.
+    fn is_disabled(&self, ix: usize, cx: &AppContext) -> bool {
+        false
+    }
+
+    /// Returns true if the item at the given index can be dragged out, default: false
+    ///
+    /// The drop targets receive a [`ListDrag`], e.g.: `.on_drop(|drag: &ListDrag, cx| ...)`.
+    fn can_drag(&self, ix: usize, cx: &AppContext) -> bool {
+        false
+    }
+
+    /// Returns the value of the dragged item, read it by [`ListDrag::payload`].
+    ///
+    /// The item is not dragged if this is None.
+    fn drag_payload(&self, ix: usize, cx: &AppContext) -> Option<Rc<dyn Any>> {
+        None
+    }
+
+    /// Render the preview under the cursor while the item is dragged, default is the item itself.
+    fn render_drag_preview(
+        &self,
+        ix: usize,
+        cx: &mut ViewContext<List<Self>>,
+    ) -> Option<AnyElement> {
+        self.render_item(ix, cx).map(|item| item.into_any_element())
+    }
+
+    /// Returns true if the dragged item can be dropped to the `target`, default: false
+    ///
+    /// Return false for the [`DropTarget::Between`] to only accept drops onto the items, and vice versa.
+    fn can_drop(&self, drag: &ListDrag, target: DropTarget, cx: &AppContext) -> bool {
+        false
+    }
+
+    /// The dragged item has been dropped to the `target`, the `target` is accepted by [`Self::can_drop`].
+    ///
+    /// The items count is reloaded after this, like [`List::items_changed`].
+    fn drop(&mut self, drag: &ListDrag, target: DropTarget, cx: &mut ViewContext<List<Self>>) {}
+
     /// Set the selected index, just store the ix, don't confirm.
     fn set_selected_index(&mut self, ix: Option<usize>, cx: &mut ViewContext<List<Self>>);
 
     /// Set the confirm and give the selected index, this is means user have clicked the item or pressed Enter.
     fn confirm(&mut self, ix: Option<usize>, cx: &mut ViewContext<List<Self>>) {}
 
+    /// Same as [`Self::confirm`] with the [`ConfirmContext`], e.g. to know the query of the confirmed item.
+    ///
+    /// Default calls [`Self::confirm`] with the index.
+    fn confirm_with(&mut self, confirm: &ConfirmContext, cx: &mut ViewContext<List<Self>>) {
+        self.confirm(confirm.index, cx);
+    }
+
     /// Cancel the selection, e.g.: Pressed ESC.
     fn cancel(&mut self, cx: &mut ViewContext<List<Self>>) {}
 }
 
+/// Override the theme colors for a List.
+///
+/// The `None` fields will fall back to the global theme, so they will follow the theme changes.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ListTheme {
+    /// The background of the selected item, default: `list_active`.
+    pub active: Option<Hsla>,
+    /// The border of the selected item, default: `list_active_border`.
+    pub active_border: Option<Hsla>,
+    /// The border of the right clicked item, default: `list_context_border`.
+    pub context_border: Option<Hsla>,
+    /// The border between the query input and the items, default: `border`.
+    pub border: Option<Hsla>,
+    /// The background of the list, default: `background` with the `opacity` of the theme.
+    pub background: Option<Hsla>,
+}
+
+/// The geometry of the selection, right click and drop highlights, see [`List::selection_style`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum SelectionStyle {
+    /// Cover the whole item, with the `list_active_border`.
+    #[default]
+    FullWidth,
+    /// A rounded highlight inset from the edges of the item, like the pickers of macOS.
+    ///
+    /// The List paints the hover highlight in the same geometry, so the items should not paint
+    /// their own backgrounds, see [`ListItem::no_background`](super::ListItem::no_background).
+    Inset {
+        radius: Pixels,
+        inset: Pixels,
+        /// Draw the `list_active_border` around the selected item, the right clicked and
+        /// the drop target items always have the border.
+        border: bool,
+    },
+}
+
+/// How the List is sized in the parent, see [`List::sizing`].
+///
+/// The `render_initial` and the empty state take the same space as the items:
+/// they fill the List with [`ListSizing::Fill`], otherwise they are sized by their content.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ListSizing {
+    /// Fill the parent, and scroll the items in it.
+    #[default]
+    Fill,
+    /// Fit the height of all the items, it never scrolls, so only use it for a few items.
+    FitContent,
+    /// Fit the height of the items up to the max height, and scroll the items over it, e.g. in a popover.
+    MaxHeight(Length),
+}
+
+impl ListSizing {
+    fn fill(&self) -> bool {
+        matches!(self, Self::Fill)
+    }
+
+    fn max_height(&self) -> Option<Length> {
+        match self {
+            Self::MaxHeight(height) => Some(*height),
+            _ => None,
+        }
+    }
+
+    /// The `uniform_list` fills the container, or infers the height from the items.
+    fn sizing_behavior(&self) -> ListSizingBehavior {
+        match self {
+            Self::Fill => ListSizingBehavior::Auto,
+            Self::FitContent | Self::MaxHeight(_) => ListSizingBehavior::Infer,
+        }
+    }
+}
+
+/// The options to create a List, see [`List::with_options`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListOptions {
+    /// Show the query input to search the items, default: true
+    pub query: bool,
+    /// The placeholder of the query input, default: "Search..."
+    pub query_placeholder: Option<SharedString>,
+    /// The size of the List, follow the density of the theme if None.
+    pub size: Option<Size>,
+    /// See [`ListSizing`], default: Fill
+    pub sizing: ListSizing,
+    /// Show the scrollbar, default: true
+    pub scrollbar: bool,
+    /// Put the scrollbar in the tab order while it is visible, to scroll by the keyboard, default: false
+    pub scrollbar_focusable: bool,
+    /// Wait for the typing to stop before the search, default: 0
+    pub search_debounce: Duration,
+    /// See [`List::controlled_selection`], default: false
+    pub controlled_selection: bool,
+}
+
+impl Default for ListOptions {
+    fn default() -> Self {
+        Self {
+            query: true,
+            query_placeholder: None,
+            size: None,
+            sizing: ListSizing::Fill,
+            scrollbar: true,
+            scrollbar_focusable: false,
+            search_debounce: Duration::ZERO,
+            controlled_selection: false,
+        }
+    }
+}
+
+/// Where a dragged item will be dropped in a List, see [`ListDelegate::can_drop`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DropTarget {
+    /// Onto the item at the index.
+    OnItem(usize),
+    /// Insert before the item at the index, the index is the items count to insert at the end.
+    Between(usize),
+}
+
+/// Returns the drop targets in the preferred order, for the `fraction` of the item height at the pointer.
+///
+/// The edges of the item prefer to insert, the middle prefers to drop onto the item.
+fn drop_candidates(ix: usize, fraction: f32, items_count: usize) -> Vec<DropTarget> {
+    if ix >= items_count {
+        return vec![DropTarget::Between(items_count)];
+    }
+
+    let nearest = if fraction < 0.5 {
+        DropTarget::Between(ix)
+    } else {
+        DropTarget::Between(ix + 1)
+    };
+    if fraction < 0.25 || fraction > 0.75 {
+        vec![nearest, DropTarget::OnItem(ix)]
+    } else {
+        vec![DropTarget::OnItem(ix), nearest]
+    }
+}
+
+/// A dragged item hovering over the List.
+struct DragOver {
+    drag: ListDrag,
+    position: Point<Pixels>,
+    bounds: Bounds<Pixels>,
+    target: Option<DropTarget>,
+}
+
+/// The dragged item of a List, see [`ListDelegate::can_drag`].
+#[derive(Clone)]
+pub struct ListDrag {
+    /// The entity id of the List, to know where the item is from.
+    pub list_id: EntityId,
+    /// The index of the item in the List.
+    pub ix: usize,
+    payload: Rc<dyn Any>,
+}
+
+impl ListDrag {
+    /// Create a drag to drop into a List from the other views, the `list_id` is the source view.
+    pub fn new(list_id: EntityId, ix: usize, payload: impl Any) -> Self {
+        Self {
+            list_id,
+            ix,
+            payload: Rc::new(payload),
+        }
+    }
+
+    /// Returns the value from [`ListDelegate::drag_payload`] if it is a `T`.
+    pub fn payload<T: 'static>(&self) -> Option<&T> {
+        self.payload.downcast_ref()
+    }
+}
+
+struct ListDragPreview<D: ListDelegate> {
+    list: WeakView<List<D>>,
+    ix: usize,
+}
+
+impl<D: ListDelegate> Render for ListDragPreview<D> {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let ix = self.ix;
+        let preview = self
+            .list
+            .upgrade()
+            .and_then(|list| list.update(cx, |list, cx| list.delegate.render_drag_preview(ix, cx)));
+
+        div()
+            .id("list-drag-preview")
+            .cursor_grab()
+            .min_w_48()
+            .overflow_hidden()
+            .opacity(0.9)
+            .bg(cx.theme().popover)
+            .border_1()
+            .border_color(cx.theme().border)
+            .rounded(cx.theme().radius())
+            .shadow_md()
+            .children(preview)
+    }
+}
+
 pub struct List<D: ListDelegate> {
     focus_handle: FocusHandle,
     delegate: D,
-    max_height: Option<Length>,
+    sizing: ListSizing,
+    /// The space below each item, the overlays of the item don't cover it.
+    item_gap: Pixels,
+    /// The paddings around the items, inside the scroll area.
+    paddings: Edges<Pixels>,
+    selection_style: SelectionStyle,
+    /// The number of the items in a row, 1 for the list layout, see [`Self::grid`].
+    columns: usize,
     query_input: Option<View<TextInput>>,
+    /// The subscription to the `query_input`, replaced with the input to not handle the events twice.
+    _query_subscription: Option<Subscription>,
     last_query: Option<String>,
+    search_debounce: Duration,
+    /// The selection is set by the parent only, see [`Self::controlled_selection`].
+    controlled: bool,
     loading: bool,
+    /// The number of the skeleton rows while loading without items, see [`Self::skeleton_rows`].
+    skeleton_rows: usize,
+    /// The opacity of the loading overlay, see [`ListDelegate::render_loading`].
+    loading_opacity: Animated<f32>,
+    /// The cache of `delegate.items_count`, None means it is invalidated.
+    items_count: Cell<Option<usize>>,
 
     enable_scrollbar: bool,
+    scrollbar_focusable: bool,
+    scrollbar_focus_handle: FocusHandle,
     vertical_scroll_handle: UniformListScrollHandle,
     scrollbar_state: Rc<Cell<ScrollbarState>>,
 
-    pub(crate) size: Size,
+    pub(crate) size: Option<Size>,
+    theme: ListTheme,
     selected_index: Option<usize>,
     right_clicked_index: Option<usize>,
+    drag_over: Option<DragOver>,
     _search_task: Task<()>,
+    _drag_scroll_task: Option<Task<()>>,
+    /// The number of the renders, to check the redraws of an interaction in the tests.
+    #[cfg(test)]
+    render_count: usize,
 }
 
 impl<D> List<D>
@@ -102,31 +590,71 @@ where
     D: ListDelegate,
 {
     pub fn new(delegate: D, cx: &mut ViewContext<Self>) -> Self {
-        let query_input = cx.new_view(|cx| {
-            TextInput::new(cx)
-                .appearance(false)
-                .prefix(|cx| Icon::new(IconName::Search).text_color(cx.theme().muted_foreground))
-                .placeholder("Search...")
-                .cleanable()
-        });
+        Self::with_options(delegate, ListOptions::default(), cx)
+    }
 
-        cx.subscribe(&query_input, Self::on_query_input_event)
-            .detach();
+    /// Create a List with the `options`, the query input is only created if it is enabled.
+    ///
+    /// ```ignore
+    /// List::with_options(delegate, ListOptions {
+    ///     query: false,
+    ///     sizing: ListSizing::MaxHeight(px(300.).into()),
+    ///     ..Default::default()
+    /// }, cx)
+    /// ```
+    pub fn with_options(delegate: D, options: ListOptions, cx: &mut ViewContext<Self>) -> Self {
+        let (query_input, query_subscription) = options
+            .query
+            .then(|| {
+                let placeholder = options
+                    .query_placeholder
+                    .unwrap_or_else(|| "Search...".into());
+                let query_input = cx.new_view(|cx| {
+                    TextInput::new(cx)
+                        .appearance(false)
+                        .prefix(|_| Icon::new(IconName::Search))
+                        .placeholder(placeholder)
+                        .cleanable()
+                });
+
+                let subscription = cx.subscribe(&query_input, Self::on_query_input_event);
+                (query_input, subscription)
+            })
+            .unzip();
 
         Self {
             focus_handle: cx.focus_handle(),
             delegate,
-            query_input: Some(query_input),
+            query_input,
+            _query_subscription: query_subscription,
             last_query: None,
+            search_debounce: options.search_debounce,
+            controlled: options.controlled_selection,
             selected_index: None,
             right_clicked_index: None,
+            drag_over: None,
             vertical_scroll_handle: UniformListScrollHandle::new(),
             scrollbar_state: Rc::new(Cell::new(ScrollbarState::new())),
-            max_height: None,
-            enable_scrollbar: true,
+            sizing: options.sizing,
+            item_gap: px(0.),
+            paddings: Edges::default(),
+            selection_style: SelectionStyle::default(),
+            columns: 1,
+            enable_scrollbar: options.scrollbar,
+            scrollbar_focusable: options.scrollbar_focusable,
+            scrollbar_focus_handle: cx.focus_handle(),
             loading: false,
-            size: Size::default(),
+            skeleton_rows: 0,
+            loading_opacity: Animated::new(0.)
+                .delay(LOADING_DELAY)
+                .duration(Duration::from_millis(150)),
+            items_count: Cell::new(None),
+            size: options.size,
+            theme: ListTheme::default(),
             _search_task: Task::ready(()),
+            _drag_scroll_task: None,
+            #[cfg(test)]
+            render_count: 0,
         }
     }
 
@@ -137,11 +665,128 @@ where
                 input.set_size(size, cx);
             })
         }
-        self.size = size;
+        self.size = Some(size);
+        cx.notify();
     }
 
+    /// Returns the size of the List, follow the density of the theme if not set.
+    pub fn size(&self, cx: &AppContext) -> Size {
+        self.size.unwrap_or_else(|| cx.theme().default_size())
+    }
+
+    /// Set how the List is sized in the parent, see [`ListSizing`], default: Fill
+    pub fn sizing(mut self, sizing: ListSizing) -> Self {
+        self.sizing = sizing;
+        self
+    }
+
+    /// Same as `sizing(ListSizing::MaxHeight(height))`.
     pub fn max_h(mut self, height: impl Into<Length>) -> Self {
-        self.max_height = Some(height.into());
+        self.sizing = ListSizing::MaxHeight(height.into());
+        self
+    }
+
+    /// Set the gap between the items, default: 0
+    ///
+    /// The selection and the right click outlines are drawn in the item without the gap.
+    pub fn item_gap(mut self, gap: impl Into<Pixels>) -> Self {
+        self.item_gap = gap.into();
+        self
+    }
+
+    /// Set the paddings around the items, default: 0
+    ///
+    /// The paddings scroll with the items, the scrollbar stays at the edge of the List.
+    pub fn paddings(mut self, paddings: Edges<Pixels>) -> Self {
+        self.paddings = paddings;
+        self
+    }
+
+    /// Set the left and right paddings around the items, see [`Self::paddings`].
+    pub fn px(mut self, padding: impl Into<Pixels>) -> Self {
+        let padding = padding.into();
+        self.paddings.left = padding;
+        self.paddings.right = padding;
+        self
+    }
+
+    /// Set the top and bottom paddings around the items, see [`Self::paddings`].
+    pub fn py(mut self, padding: impl Into<Pixels>) -> Self {
+        let padding = padding.into();
+        self.paddings.top = padding;
+        self.paddings.bottom = padding;
+        self
+    }
+
+    /// Lay out the items in a grid of `columns`, from left to right and wrapping into rows,
+    /// e.g. for the thumbnails of the images, default: 1 (the list layout).
+    ///
+    /// The rows are virtualized like the items of the list layout, so the items should have the
+    /// same height. Left and Right move the selection by one item, Up and Down by one row.
+    pub fn grid(mut self, columns: usize) -> Self {
+        self.columns = columns.max(1);
+        self
+    }
+
+    /// Show `count` skeleton rows while loading without any items, e.g. for the first fetch
+    /// from a server, default: 0
+    ///
+    /// The rows are sized like the last rendered items, they are replaced by the items
+    /// or the `render_empty` once the loading is finished.
+    pub fn skeleton_rows(mut self, count: usize) -> Self {
+        self.skeleton_rows = count;
+        self
+    }
+
+    /// Returns true if the skeleton rows are shown instead of the items.
+    fn is_showing_skeleton(&self, cx: &AppContext) -> bool {
+        self.loading && self.skeleton_rows > 0 && self.items_count(cx) == 0
+    }
+
+    /// Set the geometry of the selection highlight, see [`SelectionStyle`], default: FullWidth
+    pub fn selection_style(mut self, style: SelectionStyle) -> Self {
+        self.selection_style = style;
+        self
+    }
+
+    /// Returns the top and bottom paddings of the scrolling items.
+    ///
+    /// The top padding is before the pinned items if any, and every item has the gap below it,
+    /// so the gap of the last item is taken from the bottom padding.
+    fn scroll_paddings(&self, pinned_count: usize) -> (Pixels, Pixels) {
+        let top = if pinned_count > 0 {
+            px(0.)
+        } else {
+            self.paddings.top
+        };
+        (top, (self.paddings.bottom - self.item_gap).max(px(0.)))
+    }
+
+    /// Override the theme colors of this list, see [`ListTheme`].
+    pub fn theme_override(mut self, theme: ListTheme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Set the theme override with reference, see [`ListTheme`].
+    pub fn set_theme_override(&mut self, theme: ListTheme, cx: &mut ViewContext<Self>) {
+        self.theme = theme;
+        cx.notify();
+    }
+
+    /// Wait for the typing to stop before the search, see [`ListOptions::search_debounce`].
+    pub fn search_debounce(mut self, debounce: Duration) -> Self {
+        self.search_debounce = debounce;
+        self
+    }
+
+    /// Let the parent control the selection, e.g. to sync it with the app state.
+    ///
+    /// The List never changes the selection itself, it emits the [`ListEvent`] instead, and the
+    /// parent calls [`Self::set_selected_index`] to move it. The keyboard navigation is relative
+    /// to the index set by the parent, and the confirm is left to the parent too.
+    pub fn controlled_selection(mut self) -> Self {
+        self.controlled = true;
         self
     }
 
@@ -152,23 +797,102 @@ where
 
     pub fn no_query(mut self) -> Self {
         self.query_input = None;
+        self._query_subscription = None;
         self
     }
 
+    /// Replace the query input, the previous one is unsubscribed.
     pub fn set_query_input(&mut self, query_input: View<TextInput>, cx: &mut ViewContext<Self>) {
-        cx.subscribe(&query_input, Self::on_query_input_event)
-            .detach();
+        self._query_subscription = Some(cx.subscribe(&query_input, Self::on_query_input_event));
         self.query_input = Some(query_input);
     }
 
+    /// Set the icon of the query input, default is [`IconName::Search`].
+    ///
+    /// The custom icons are supported, e.g.: `Icon::named("my-search")`.
+    pub fn set_query_icon(&mut self, icon: impl Into<Icon>, cx: &mut ViewContext<Self>) {
+        let Some(query_input) = &self.query_input else {
+            return;
+        };
+
+        let icon: Icon = icon.into();
+        query_input.update(cx, |input, cx| input.set_prefix(move |_| icon.clone(), cx));
+    }
+
     pub fn delegate(&self) -> &D {
         &self.delegate
     }
 
+    /// Returns the mutable delegate, the items count will be read again.
     pub fn delegate_mut(&mut self) -> &mut D {
+        self.items_count.set(None);
         &mut self.delegate
     }
 
+    /// Invalidate the cached items count, call this if the items of the delegate are changed
+    /// without [`ListDelegate::perform_search`] or [`Self::delegate_mut`].
+    pub fn items_changed(&mut self, cx: &mut ViewContext<Self>) {
+        self.items_count.set(None);
+        self.clamp_selected_index(cx);
+        cx.notify();
+    }
+
+    /// Move the selection to the last item if the items count has shrunk below it.
+    fn clamp_selected_index(&mut self, cx: &mut ViewContext<Self>) {
+        if self.controlled {
+            return;
+        }
+
+        let items_count = self.items_count(cx);
+        if self.selected_index.map_or(false, |ix| ix >= items_count) {
+            self.set_selected_index(items_count.checked_sub(1), cx);
+        }
+    }
+
+    /// Run the CPU heavy `search` on the background thread, and `apply` the result to the delegate
+    /// on the main thread, this can be returned in [`ListDelegate::perform_search`], e.g.:
+    ///
+    /// ```ignore
+    /// fn perform_search(&mut self, query: &str, cx: &mut ViewContext<List<Self>>) -> Task<()> {
+    ///     let (items, query) = (self.items.clone(), query.to_string());
+    ///     List::spawn_search(
+    ///         cx,
+    ///         move || fuzzy_match(&items, &query),
+    ///         |this, matches, _| this.matches = matches,
+    ///     )
+    /// }
+    /// ```
+    ///
+    /// The search task is dropped when the query is changed again, so the stale result is never applied.
+    pub fn spawn_search<T>(
+        cx: &mut ViewContext<Self>,
+        search: impl FnOnce() -> T + Send + 'static,
+        apply: impl FnOnce(&mut D, T, &mut ViewContext<Self>) + 'static,
+    ) -> Task<()>
+    where
+        T: Send + 'static,
+    {
+        let search = cx.background_executor().spawn(async move { search() });
+        cx.spawn(|this, mut cx| async move {
+            let result = search.await;
+            _ = this.update(&mut cx, |this, cx| {
+                apply(&mut this.delegate, result, cx);
+                this.items_changed(cx);
+            });
+        })
+    }
+
+    /// Returns the cached items count of the delegate.
+    fn items_count(&self, cx: &AppContext) -> usize {
+        if let Some(count) = self.items_count.get() {
+            return count;
+        }
+
+        let count = self.delegate.items_count(cx);
+        self.items_count.set(Some(count));
+        count
+    }
+
     pub fn focus(&mut self, cx: &mut WindowContext) {
         self.focus_handle(cx).focus(cx);
     }
@@ -176,6 +900,11 @@ where
     pub fn set_selected_index(&mut self, ix: Option<usize>, cx: &mut ViewContext<Self>) {
         self.selected_index = ix;
         self.delegate.set_selected_index(ix, cx);
+        // The keyboard navigation is done by the parent, so follow it.
+        if self.controlled {
+            self.scroll_to_selected_item(cx);
+            cx.notify();
+        }
     }
 
     pub fn selected_index(&self) -> Option<usize> {
@@ -190,6 +919,21 @@ where
         }
     }
 
+    /// Set the placeholder of the query_input.
+    pub fn set_query_placeholder(
+        &mut self,
+        placeholder: impl Into<SharedString>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        if let Some(query_input) = &self.query_input {
+            let placeholder = placeholder.into();
+            query_input.update(cx, |input, cx| {
+                input.set_placeholder(placeholder);
+                cx.notify();
+            })
+        }
+    }
+
     /// Get the query_input text
     pub fn query(&self, cx: &mut ViewContext<Self>) -> Option<SharedString> {
         self.query_input.as_ref().map(|input| input.read(cx).text())
@@ -200,159 +944,777 @@ where
             return None;
         }
 
-        Some(Scrollbar::uniform_scroll(
-            cx.view().entity_id(),
-            self.scrollbar_state.clone(),
-            self.vertical_scroll_handle.clone(),
-        ))
+        Some(
+            Scrollbar::uniform_scroll(
+                cx.view().entity_id(),
+                self.scrollbar_state.clone(),
+                self.vertical_scroll_handle.clone(),
+            )
+            .when(self.scrollbar_focusable, |this| {
+                this.focusable(&self.scrollbar_focus_handle)
+            }),
+        )
     }
 
-    fn scroll_to_selected_item(&mut self, _cx: &mut ViewContext<Self>) {
-        if let Some(ix) = self.selected_index {
-            self.vertical_scroll_handle
-                .scroll_to_item(ix, ScrollStrategy::Top);
+    /// Returns true if the scrollbar can be reached by Tab, it is skipped while hidden by the theme.
+    fn is_scrollbar_tab_stop(&self, cx: &WindowContext) -> bool {
+        if !(self.enable_scrollbar && self.scrollbar_focusable) {
+            return false;
+        }
+        if self.scrollbar_focus_handle.is_focused(cx) {
+            return true;
         }
+
+        let state = self.vertical_scroll_handle.0.borrow();
+        let overflow = state.last_item_size.map_or(false, |size| {
+            size.contents.height > state.base_handle.bounds().size.height
+        });
+        overflow && self.scrollbar_state.get().is_visible(cx)
     }
 
-    fn on_query_input_event(
-        &mut self,
-        _: View<TextInput>,
-        event: &InputEvent,
-        cx: &mut ViewContext<Self>,
-    ) {
-        match event {
-            InputEvent::Change(text) => {
-                let text = text.trim().to_string();
-                if Some(&text) == self.last_query.as_ref() {
-                    return;
-                }
+    /// Returns the pinned count of the delegate, at most the items count.
+    fn pinned_count(&self, cx: &AppContext) -> usize {
+        self.delegate.pinned_count(cx).min(self.items_count(cx))
+    }
 
-                self.set_loading(true, cx);
-                let search = self.delegate.perform_search(&text, cx);
+    fn scroll_to_selected_item(&mut self, cx: &mut ViewContext<Self>) {
+        // The pinned items are always visible, the scrolling items are indexed after them.
+        let pinned_count = self.pinned_count(cx);
+        if let Some(ix) = self.selected_index.filter(|ix| *ix >= pinned_count) {
+            // Scroll to the row of the item in the grid mode.
+            self.vertical_scroll_handle
+                .scroll_to_item((ix - pinned_count) / self.columns, ScrollStrategy::Top);
+        }
+    }
 
-                self._search_task = cx.spawn(|this, mut cx| async move {
-                    search.await;
+    /// Scroll the items by the wheel over the other parts of the List, e.g. the query input,
+    /// so the entire panel scrolls uniformly.
+    fn forward_scroll_wheel(&mut self, event: &ScrollWheelEvent, cx: &mut ViewContext<Self>) {
+        let delta = event.delta.pixel_delta(cx.line_height());
+        self.scroll_items_by(delta.y, cx);
+    }
 
-                    let _ = this.update(&mut cx, |this, _| {
-                        this.vertical_scroll_handle
-                            .scroll_to_item(0, ScrollStrategy::Top);
-                        this.last_query = Some(text);
-                    });
+    /// Scroll the items by `delta`, returns true if scrolled, false if it is already at the edge.
+    fn scroll_items_by(&mut self, delta: Pixels, cx: &mut ViewContext<Self>) -> bool {
+        let state = self.vertical_scroll_handle.0.borrow();
+        let Some(contents_height) = state.last_item_size.map(|size| size.contents.height) else {
+            return false;
+        };
+        let viewport_height = state.base_handle.bounds().size.height;
+        let max_offset = (contents_height - viewport_height).max(px(0.));
 
-                    // Always wait 100ms to avoid flicker
-                    Timer::after(Duration::from_millis(100)).await;
-                    let _ = this.update(&mut cx, |this, cx| {
-                        this.set_loading(false, cx);
-                    });
-                });
-            }
-            InputEvent::PressEnter => self.on_action_confirm(&Confirm, cx),
-            _ => {}
+        let mut offset = state.base_handle.offset();
+        let y = (offset.y + delta).clamp(-max_offset, px(0.));
+        if y == offset.y {
+            return false;
         }
+        offset.y = y;
+        state.base_handle.set_offset(offset);
+        cx.notify();
+        true
     }
 
-    fn set_loading(&mut self, loading: bool, cx: &mut ViewContext<Self>) {
-        self.loading = loading;
-        if let Some(input) = &self.query_input {
-            input.update(cx, |input, cx| input.set_loading(loading, cx))
-        }
-        cx.notify();
+    fn drop_target(&self) -> Option<DropTarget> {
+        self.drag_over
+            .as_ref()
+            .and_then(|drag_over| drag_over.target)
     }
 
-    fn on_action_cancel(&mut self, _: &Cancel, cx: &mut ViewContext<Self>) {
-        self.set_selected_index(None, cx);
-        self.delegate.cancel(cx);
-        cx.notify();
+    /// Returns the drop target under the `position` that is accepted by the delegate.
+    fn drop_target_at(
+        &self,
+        drag: &ListDrag,
+        position: Point<Pixels>,
+        cx: &AppContext,
+    ) -> Option<DropTarget> {
+        let items_count = self.items_count(cx);
+        let pinned_count = self.pinned_count(cx);
+        let candidates = {
+            let state = self.vertical_scroll_handle.0.borrow();
+            match state.last_item_size.map(|size| size.item.height) {
+                Some(item_height) if items_count > pinned_count && item_height > px(0.) => {
+                    let bounds = state.base_handle.bounds();
+                    // Over the pinned items.
+                    if position.y < bounds.top() {
+                        return None;
+                    }
+                    let (padding_top, _) = self.scroll_paddings(pinned_count);
+                    let y = position.y - bounds.top() - state.base_handle.offset().y - padding_top;
+                    let rows = y.max(px(0.)) / item_height;
+                    if self.columns > 1 {
+                        // The cells have the same width, the side of the cell decides to insert.
+                        let width = bounds.size.width - self.paddings.left - self.paddings.right;
+                        let x = position.x - bounds.left() - self.paddings.left;
+                        let cells = (x / (width / self.columns as f32)).max(0.);
+                        let column = (cells.floor() as usize).min(self.columns - 1);
+                        drop_candidates(
+                            pinned_count + rows.floor() as usize * self.columns + column,
+                            cells - column as f32,
+                            items_count,
+                        )
+                    } else {
+                        drop_candidates(
+                            pinned_count + rows.floor() as usize,
+                            rows.fract(),
+                            items_count,
+                        )
+                    }
+                }
+                _ => vec![DropTarget::Between(items_count)],
+            }
+        };
+
+        candidates
+            .into_iter()
+            .find(|target| self.delegate.can_drop(drag, *target, cx))
     }
 
-    fn on_action_confirm(&mut self, _: &Confirm, cx: &mut ViewContext<Self>) {
-        if self.delegate.items_count(cx) == 0 {
+    fn on_items_drag_move(&mut self, event: &DragMoveEvent<ListDrag>, cx: &mut ViewContext<Self>) {
+        let position = event.event.position;
+        if !event.bounds.contains(&position) {
+            if self.drag_over.take().is_some() {
+                self._drag_scroll_task = None;
+                cx.notify();
+            }
             return;
         }
 
-        self.delegate.confirm(self.selected_index, cx);
-        cx.notify();
+        let drag = event.drag(cx).clone();
+        let target = self.drop_target_at(&drag, position, cx);
+        if target != self.drop_target() {
+            cx.notify();
+        }
+        self.drag_over = Some(DragOver {
+            drag,
+            position,
+            bounds: event.bounds,
+            target,
+        });
+        self.update_drag_scroll(cx);
     }
 
-    fn on_action_select_prev(&mut self, _: &SelectPrev, cx: &mut ViewContext<Self>) {
-        let items_count = self.delegate.items_count(cx);
-        if items_count == 0 {
-            return;
-        }
+    /// Returns the delta to scroll the items if the accepted drag is hovering near the edges.
+    fn drag_scroll_delta(&self) -> Option<Pixels> {
+        let drag_over = self.drag_over.as_ref().filter(|d| d.target.is_some())?;
+        let item_height = self
+            .vertical_scroll_handle
+            .0
+            .borrow()
+            .last_item_size
+            .map(|size| size.item.height)
+            .unwrap_or_default();
+        let edge = item_height.max(px(24.));
+        let step = edge * 0.25;
 
-        let selected_index = self.selected_index.unwrap_or(0);
-        if selected_index > 0 {
-            self.selected_index = Some(selected_index - 1);
+        if drag_over.position.y < drag_over.bounds.top() + edge {
+            Some(step)
+        } else if drag_over.position.y > drag_over.bounds.bottom() - edge {
+            Some(-step)
         } else {
-            self.selected_index = Some(items_count - 1);
+            None
         }
-
-        self.delegate.set_selected_index(self.selected_index, cx);
-        self.scroll_to_selected_item(cx);
-        cx.notify();
     }
 
-    fn on_action_select_next(&mut self, _: &SelectNext, cx: &mut ViewContext<Self>) {
-        let items_count = self.delegate.items_count(cx);
-        if items_count == 0 {
+    /// Keep scrolling while the drag is hovering near the edges, the drag move is not fired if the mouse stops.
+    fn update_drag_scroll(&mut self, cx: &mut ViewContext<Self>) {
+        if self.drag_scroll_delta().is_none() {
+            self._drag_scroll_task = None;
+            return;
+        }
+        if self._drag_scroll_task.is_some() {
             return;
         }
 
-        if let Some(selected_index) = self.selected_index {
-            if selected_index < items_count - 1 {
-                self.selected_index = Some(selected_index + 1);
-            } else {
-                self.selected_index = Some(0);
+        self._drag_scroll_task = Some(cx.spawn(|this, mut cx| async move {
+            loop {
+                Timer::after(DRAG_SCROLL_INTERVAL).await;
+                let scrolling = this.update(&mut cx, |this, cx| {
+                    let delta = this.drag_scroll_delta().filter(|_| cx.has_active_drag());
+                    let Some(delta) = delta else {
+                        this._drag_scroll_task = None;
+                        return false;
+                    };
+
+                    // The items under the pointer are changed by the scroll.
+                    if this.scroll_items_by(delta, cx) {
+                        if let Some(drag_over) = this.drag_over.take() {
+                            let target =
+                                this.drop_target_at(&drag_over.drag, drag_over.position, cx);
+                            this.drag_over = Some(DragOver {
+                                target,
+                                ..drag_over
+                            });
+                        }
+                    }
+                    true
+                });
+
+                if !matches!(scrolling, Ok(true)) {
+                    break;
+                }
             }
+        }));
+    }
+
+    fn on_items_drop(&mut self, drag: &ListDrag, cx: &mut ViewContext<Self>) {
+        self._drag_scroll_task = None;
+        let Some(target) = self.drag_over.take().and_then(|drag_over| drag_over.target) else {
+            return;
+        };
+
+        self.delegate.drop(drag, target, cx);
+        self.items_changed(cx);
+    }
+
+    fn on_query_input_event(
+        &mut self,
+        _: View<TextInput>,
+        event: &InputEvent,
+        cx: &mut ViewContext<Self>,
+    ) {
+        match event {
+            InputEvent::Change(text) => {
+                let text = text.trim().to_string();
+                if Some(&text) == self.last_query.as_ref() {
+                    return;
+                }
+
+                if self.search_debounce.is_zero() {
+                    self.search(text, cx);
+                    return;
+                }
+
+                // Wait for the typing to stop, the pending search is dropped by the next change.
+                let debounce = self.search_debounce;
+                self.set_loading(true, cx);
+                self._search_task = cx.spawn(|this, mut cx| async move {
+                    Timer::after(debounce).await;
+                    _ = this.update(&mut cx, |this, cx| this.search(text, cx));
+                });
+            }
+            InputEvent::PressEnter => self.on_action_confirm(&Confirm, cx),
+            // The emacs style navigation, the single-line input doesn't handle them.
+            InputEvent::KeyDown(keystroke) if keystroke.modifiers == Modifiers::control() => {
+                match keystroke.key.as_str() {
+                    "n" => self.on_action_select_next(&SelectNext, cx),
+                    "p" => self.on_action_select_prev(&SelectPrev, cx),
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn search(&mut self, query: String, cx: &mut ViewContext<Self>) {
+        let search = self.delegate.perform_search(&query, cx);
+        self.items_count.set(None);
+
+        // The search is finished already, apply it without the loading state to avoid
+        // the redraws of toggling it.
+        if matches!(search, Task::Ready(_)) {
+            self._search_task = Task::ready(());
+            self.on_search_finished(query, cx);
+            self.set_loading(false, cx);
+            cx.notify();
+            return;
+        }
+
+        self.set_loading(true, cx);
+        // Show the overlay again, if it is hidden by the last search in the tail of its loading.
+        if self.loading_opacity.target() == 0. {
+            self.loading_opacity.animate_to(1.);
+        }
+        self._search_task = cx.spawn(|this, mut cx| async move {
+            search.await;
+
+            let _ = this.update(&mut cx, |this, cx| {
+                this.on_search_finished(query, cx);
+                cx.notify();
+            });
+
+            // Always wait 100ms to avoid flicker
+            Timer::after(Duration::from_millis(100)).await;
+            let _ = this.update(&mut cx, |this, cx| {
+                this.set_loading(false, cx);
+            });
+        });
+    }
+
+    fn on_search_finished(&mut self, query: String, cx: &mut ViewContext<Self>) {
+        self.items_count.set(None);
+        self.clamp_selected_index(cx);
+        self.vertical_scroll_handle
+            .scroll_to_item(0, ScrollStrategy::Top);
+        self.last_query = Some(query);
+        // The loading state is kept a bit longer for the spinner of the query input,
+        // but the overlay should not cover the results.
+        self.loading_opacity.set(0.);
+    }
+
+    /// Set the loading state, it is managed by the search, but it can be set for the loading
+    /// outside of [`ListDelegate::perform_search`], e.g. the first fetch of the items.
+    pub fn set_loading(&mut self, loading: bool, cx: &mut ViewContext<Self>) {
+        if self.loading == loading {
+            return;
+        }
+
+        self.loading = loading;
+        if loading {
+            self.loading_opacity.animate_to(1.);
         } else {
-            self.selected_index = Some(0);
+            // Hide at once, so a search finished in the delay never shows the overlay.
+            self.loading_opacity.set(0.);
+        }
+        if let Some(input) = &self.query_input {
+            input.update(cx, |input, cx| input.set_loading(loading, cx))
+        }
+        cx.notify();
+    }
+
+    fn on_action_cancel(&mut self, _: &Cancel, cx: &mut ViewContext<Self>) {
+        // Escape from the empty query returns to the items, so both can be reached by keyboard.
+        if let Some(input) = &self.query_input {
+            if input.focus_handle(cx).is_focused(cx) && input.read(cx).text().is_empty() {
+                self.focus_handle.focus(cx);
+                return;
+            }
+        }
+
+        if !self.controlled {
+            self.set_selected_index(None, cx);
+        }
+        self.delegate.cancel(cx);
+        cx.notify();
+    }
+
+    fn on_action_confirm(&mut self, _: &Confirm, cx: &mut ViewContext<Self>) {
+        self.confirm_selected(false, SelectionSource::Keyboard, cx);
+    }
+
+    fn on_action_secondary_confirm(&mut self, _: &SecondaryConfirm, cx: &mut ViewContext<Self>) {
+        self.confirm_selected(true, SelectionSource::Keyboard, cx);
+    }
+
+    /// Confirm the selected item, the Enter and click must fill the [`ConfirmContext`] in the same way.
+    fn confirm_selected(
+        &mut self,
+        secondary: bool,
+        source: SelectionSource,
+        cx: &mut ViewContext<Self>,
+    ) {
+        // Read the count again, it may be changed without `items_changed`,
+        // so an out of range index is never confirmed.
+        self.items_count.set(None);
+        self.clamp_selected_index(cx);
+        if self.items_count(cx) == 0 {
+            return;
         }
+        if let Some(ix) = self.selected_index {
+            if self.delegate.is_disabled(ix, cx) {
+                return;
+            }
+        }
+
+        if self.controlled {
+            if let Some(ix) = self.selected_index.filter(|ix| *ix < self.items_count(cx)) {
+                cx.emit(ListEvent::ConfirmRequested(ix));
+            }
+            return;
+        }
+
+        let confirm = ConfirmContext {
+            index: self.selected_index,
+            query: self.query(cx),
+            secondary,
+            source,
+        };
+        self.delegate.confirm_with(&confirm, cx);
+        cx.notify();
+    }
+
+    /// Move the focus between the query input and the items,
+    /// the action is propagated to the parent to leave the List at the ends.
+    fn on_action_tab(&mut self, _: &Tab, cx: &mut ViewContext<Self>) {
+        self.focus_cycle(cx).step(true, cx);
+    }
+
+    fn on_action_tab_prev(&mut self, _: &TabPrev, cx: &mut ViewContext<Self>) {
+        self.focus_cycle(cx).step(false, cx);
+    }
+
+    fn on_action_focus_query(&mut self, _: &FocusQuery, cx: &mut ViewContext<Self>) {
+        // Let the keystroke input the text if the query is focused already.
+        let Some(input) = self
+            .query_input
+            .clone()
+            .filter(|input| !input.focus_handle(cx).is_focused(cx))
+        else {
+            cx.propagate();
+            return;
+        };
+
+        input.update(cx, |input, cx| {
+            input.focus(cx);
+            input.select_all_text(cx);
+        });
+    }
+
+    fn focus_cycle(&self, cx: &WindowContext) -> FocusCycle {
+        let input_handle = self
+            .query_input
+            .as_ref()
+            .map(|input| input.focus_handle(cx));
+        let scrollbar_handle = self
+            .is_scrollbar_tab_stop(cx)
+            .then(|| self.scrollbar_focus_handle.clone());
+        // The query input and the scrollbar are inside of the List,
+        // the focused one is found before the List that contains the focus.
+        FocusCycle::new(
+            input_handle
+                .into_iter()
+                .chain([self.focus_handle.clone()])
+                .chain(scrollbar_handle),
+        )
+        .wrap(false)
+    }
+
+    fn on_action_select_prev(&mut self, _: &SelectPrev, cx: &mut ViewContext<Self>) {
+        self.select_prev(cx);
+    }
+
+    fn on_action_select_next(&mut self, _: &SelectNext, cx: &mut ViewContext<Self>) {
+        self.select_next(cx);
+    }
+
+    fn on_action_select_left(&mut self, _: &SelectLeft, cx: &mut ViewContext<Self>) {
+        self.select_in_grid(-1, cx);
+    }
+
+    fn on_action_select_right(&mut self, _: &SelectRight, cx: &mut ViewContext<Self>) {
+        self.select_in_grid(1, cx);
+    }
+
+    fn on_action_select_first(&mut self, _: &SelectFirst, cx: &mut ViewContext<Self>) {
+        let ix = self.nearest_enabled(0, true, cx);
+        self.select_and_scroll(ix, cx);
+    }
+
+    fn on_action_select_last(&mut self, _: &SelectLast, cx: &mut ViewContext<Self>) {
+        let ix = self.nearest_enabled(usize::MAX, false, cx);
+        self.select_and_scroll(ix, cx);
+    }
+
+    fn on_action_select_page_up(&mut self, _: &SelectPageUp, cx: &mut ViewContext<Self>) {
+        let page_size = self.page_size();
+        let target = self
+            .selected_index
+            .map_or(0, |ix| ix.saturating_sub(page_size));
+        let ix = self.nearest_enabled(target, false, cx);
+        self.select_and_scroll(ix, cx);
+    }
+
+    fn on_action_select_page_down(&mut self, _: &SelectPageDown, cx: &mut ViewContext<Self>) {
+        let page_size = self.page_size();
+        let target = self
+            .selected_index
+            .map_or(page_size - 1, |ix| ix.saturating_add(page_size));
+        let ix = self.nearest_enabled(target, true, cx);
+        self.select_and_scroll(ix, cx);
+    }
+
+    /// Returns the number of the items that fit the visible height of the scrolling items.
+    fn page_size(&self) -> usize {
+        let state = self.vertical_scroll_handle.0.borrow();
+        let viewport_height = state.base_handle.bounds().size.height;
+        match state.last_item_size.map(|size| size.item.height) {
+            Some(item_height) if item_height > px(0.) => {
+                ((viewport_height / item_height).floor() as usize).max(1) * self.columns
+            }
+            _ => DEFAULT_PAGE_SIZE,
+        }
+    }
+
+    /// Move the selection by `delta` items in the grid mode, the keys are left to the parent
+    /// in the list layout.
+    fn select_in_grid(&mut self, delta: isize, cx: &mut ViewContext<Self>) {
+        if self.columns == 1 {
+            cx.propagate();
+            return;
+        }
+
+        let ix = grid_step(
+            self.selected_index,
+            delta,
+            self.columns,
+            self.items_count(cx),
+        )
+        .and_then(|target| self.nearest_enabled(target, delta > 0, cx));
+        self.select_and_scroll(ix, cx);
+    }
+
+    /// Select the previous item, or the last item if the first one is selected.
+    ///
+    /// In the grid mode, select the item in the previous row.
+    pub fn select_prev(&mut self, cx: &mut ViewContext<Self>) {
+        let items_count = self.items_count(cx);
+        if items_count == 0 {
+            return;
+        }
+        if self.columns > 1 {
+            self.select_in_grid(-(self.columns as isize), cx);
+            return;
+        }
+
+        let ix = step_enabled(items_count, self.selected_index, false, |ix| {
+            self.delegate.is_disabled(ix, cx)
+        });
+        self.select_and_scroll(ix, cx);
+    }
+
+    /// Select the next item, or the first item if the last one is selected.
+    ///
+    /// In the grid mode, select the item in the next row.
+    pub fn select_next(&mut self, cx: &mut ViewContext<Self>) {
+        let items_count = self.items_count(cx);
+        if items_count == 0 {
+            return;
+        }
+        if self.columns > 1 {
+            self.select_in_grid(self.columns as isize, cx);
+            return;
+        }
+
+        let ix = step_enabled(items_count, self.selected_index, true, |ix| {
+            self.delegate.is_disabled(ix, cx)
+        });
+        self.select_and_scroll(ix, cx);
+    }
+
+    /// Select the `ix` by keyboard, and scroll to it. The None is ignored, e.g. all items are disabled.
+    fn select_and_scroll(&mut self, ix: Option<usize>, cx: &mut ViewContext<Self>) {
+        // Skip the redraw if the item is selected already, e.g. holding Down on the only enabled item.
+        let Some(ix) = ix.filter(|ix| self.selected_index != Some(*ix)) else {
+            return;
+        };
+        if self.controlled {
+            cx.emit(ListEvent::SelectRequested(ix));
+            return;
+        }
+        self.selected_index = Some(ix);
 
         self.delegate.set_selected_index(self.selected_index, cx);
         self.scroll_to_selected_item(cx);
         cx.notify();
     }
 
+    /// Returns the enabled item nearest to the `target`, see [`nearest_enabled`].
+    ///
+    /// The items are probed from the `target`, so a keypress doesn't visit all the items.
+    fn nearest_enabled(&self, target: usize, forward: bool, cx: &AppContext) -> Option<usize> {
+        nearest_enabled(self.items_count(cx), target, forward, |ix| {
+            self.delegate.is_disabled(ix, cx)
+        })
+    }
+
+    fn on_item_click(&mut self, ix: usize, secondary: bool, cx: &mut ViewContext<Self>) {
+        self.right_clicked_index = None;
+        if self.delegate.is_disabled(ix, cx) {
+            return;
+        }
+        if self.controlled {
+            cx.emit(ListEvent::SelectRequested(ix));
+            cx.emit(ListEvent::ConfirmRequested(ix));
+            return;
+        }
+        self.set_selected_index(Some(ix), cx);
+        self.confirm_selected(secondary, SelectionSource::Mouse, cx);
+    }
+
+    /// Render the row of the items from `start`, a row has `columns` items in the grid mode,
+    /// the items from `end` are not included, e.g. the scrolling items after the pinned items.
+    fn render_row(&mut self, start: usize, end: usize, cx: &mut ViewContext<Self>) -> AnyElement {
+        if self.columns == 1 {
+            return self.render_list_item(start, cx).into_any_element();
+        }
+
+        let columns = self.columns;
+        let end = (start + columns).min(end);
+        h_flex()
+            .w_full()
+            .items_start()
+            .gap(self.item_gap)
+            .children(
+                (start..end)
+                    .map(|ix| {
+                        div()
+                            .flex_1()
+                            .min_w_0()
+                            .child(self.render_list_item(ix, cx))
+                    })
+                    .collect::<Vec<_>>(),
+            )
+            // Keep the width of the cells in the last row.
+            .children((end - start..columns).map(|_| div().flex_1()))
+            .into_any_element()
+    }
+
     fn render_list_item(&mut self, ix: usize, cx: &mut ViewContext<Self>) -> impl IntoElement {
         let selected = self.selected_index == Some(ix);
         let right_clicked = self.right_clicked_index == Some(ix);
+        let drag_payload = if self.delegate.can_drag(ix, cx) {
+            self.delegate.drag_payload(ix, cx)
+        } else {
+            None
+        };
+        let drop_target = self.drop_target();
+        let drop_on = drop_target == Some(DropTarget::OnItem(ix));
+        let insert_before = drop_target == Some(DropTarget::Between(ix));
+        let insert_after =
+            ix + 1 == self.items_count(cx) && drop_target == Some(DropTarget::Between(ix + 1));
+        let gap = self.item_gap;
+        let grid = self.columns > 1;
+        let (inset, radius, border) = match self.selection_style {
+            SelectionStyle::FullWidth => (px(0.), cx.theme().radius(), true),
+            SelectionStyle::Inset {
+                radius,
+                inset,
+                border,
+            } => (inset, radius, border),
+        };
+        let highlight = || {
+            div()
+                .absolute()
+                .top(inset)
+                .left(inset)
+                .right(inset)
+                .bottom(gap + inset)
+                .rounded(radius)
+        };
+        let highlighted = selected || right_clicked || drop_on;
+        let disabled = self.delegate.is_disabled(ix, cx);
+        let hover_highlight = !highlighted
+            && matches!(self.selection_style, SelectionStyle::Inset { .. })
+            && !disabled;
 
-        div()
+        let item = div()
             .id("list-item")
+            .group("list-item")
             .w_full()
             .relative()
-            .children(self.delegate.render_item(ix, cx))
-            .when(selected || right_clicked, |this| {
+            .pb(gap)
+            .disabled_style(disabled, cx)
+            // Paint the hover highlight below the content of the item.
+            .when(hover_highlight, |this| {
                 this.child(
-                    div()
-                        .absolute()
-                        .top(px(0.))
-                        .left(px(0.))
-                        .right(px(0.))
-                        .bottom(px(0.))
-                        .when(selected, |this| this.bg(cx.theme().list_active))
-                        .border_1()
-                        .border_color(cx.theme().list_active_border),
+                    highlight().group_hover("list-item", |this| this.bg(cx.theme().list_hover)),
                 )
             })
-            .on_mouse_down(
-                MouseButton::Left,
-                cx.listener(move |this, _, cx| {
-                    this.right_clicked_index = None;
-                    this.selected_index = Some(ix);
-                    this.on_action_confirm(&Confirm, cx);
-                }),
-            )
+            .children(self.delegate.render_item(ix, cx))
+            .when(highlighted, |this| {
+                let overlay = highlight()
+                    .when(selected, |this| {
+                        // Follow the panel opacity, to avoid covering a translucent background
+                        // with a opaque active color.
+                        let active = self.theme.active.unwrap_or(cx.theme().list_active);
+                        this.bg(active.opacity(cx.theme().opacity))
+                    })
+                    .when(border || right_clicked || drop_on, |this| {
+                        this.border_w(cx.theme().border_width())
+                    })
+                    .border_color(if right_clicked {
+                        // Keep the target of the context menu distinct from the selected item.
+                        self.theme
+                            .context_border
+                            .unwrap_or(cx.theme().list_context_border)
+                    } else {
+                        self.theme
+                            .active_border
+                            .unwrap_or(cx.theme().list_active_border)
+                    })
+                    .when(drop_on, |this| {
+                        this.bg(cx.theme().drop_target)
+                            .border_color(cx.theme().drag_border)
+                    });
+                #[cfg(test)]
+                let overlay = overlay.debug_selector(|| format!("list-item-{}-highlight", ix));
+                this.child(overlay)
+            })
+            .when(insert_before || insert_after, |this| {
+                this.child(div().absolute().bg(cx.theme().drag_border).map(|this| {
+                    // The items are inserted in the row in the grid mode.
+                    if grid {
+                        let this = this.top(px(0.)).bottom(gap).w(px(2.));
+                        if insert_before {
+                            this.left(px(0.))
+                        } else {
+                            this.right(px(0.))
+                        }
+                    } else {
+                        let this = this.left(px(0.)).right(px(0.)).h(px(2.));
+                        if insert_before {
+                            this.top(px(0.))
+                        } else {
+                            this.bottom(gap)
+                        }
+                    }
+                }))
+            })
+            .map(|this| match drag_payload {
+                // Confirm on click, it is not fired if dragged, so a sloppy click doesn't do both.
+                Some(payload) => {
+                    let list = cx.view().downgrade();
+                    this.on_drag(
+                        ListDrag {
+                            list_id: cx.view().entity_id(),
+                            ix,
+                            payload,
+                        },
+                        move |_, _, cx| {
+                            cx.stop_propagation();
+                            let list = list.clone();
+                            cx.new_view(|_| ListDragPreview { list, ix })
+                        },
+                    )
+                    .on_click(cx.listener(
+                        move |this, event: &ClickEvent, cx| {
+                            this.on_item_click(ix, event.up.modifiers.secondary(), cx)
+                        },
+                    ))
+                }
+                None => this.on_mouse_down(
+                    MouseButton::Left,
+                    cx.listener(move |this, event: &MouseDownEvent, cx| {
+                        this.on_item_click(ix, event.modifiers.secondary(), cx)
+                    }),
+                ),
+            })
             .on_mouse_down(
                 MouseButton::Right,
                 cx.listener(move |this, _, cx| {
                     this.right_clicked_index = Some(ix);
                     cx.notify();
                 }),
-            )
+            );
+
+        #[cfg(test)]
+        let item = item.debug_selector(|| format!("list-item-{}", ix));
+        item
     }
 }
 
+impl<D> Sizable for List<D>
+where
+    D: ListDelegate,
+{
+    /// Set the size of the List, the query input will follow it in the next render.
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+}
+
+impl<D> EventEmitter<ListEvent> for List<D> where D: ListDelegate {}
+
 impl<D> FocusableView for List<D>
 where
     D: ListDelegate,
@@ -371,14 +1733,49 @@ where
     D: ListDelegate,
 {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        #[cfg(test)]
+        {
+            self.render_count += 1;
+        }
+
+        // The drag has been dropped out of the List.
+        if self.drag_over.is_some() && !cx.has_active_drag() {
+            self.drag_over = None;
+            self._drag_scroll_task = None;
+        }
+
         let view = cx.view().clone();
         let vertical_scroll_handle = self.vertical_scroll_handle.clone();
-        let items_count = self.delegate.items_count(cx);
-        let sizing_behavior = if self.max_height.is_some() {
-            ListSizingBehavior::Infer
+        let items_count = self.items_count(cx);
+        let pinned_count = self.pinned_count(cx);
+        let columns = self.columns;
+        let pinned_items = (0..pinned_count)
+            .step_by(columns)
+            .map(|start| self.render_row(start, pinned_count, cx))
+            .collect::<Vec<_>>();
+        let size = self.size(cx);
+        if let (Some(size), Some(input)) = (self.size, &self.query_input) {
+            if input.read(cx).size(cx) != size {
+                input.update(cx, |input, cx| input.set_size(size, cx));
+            }
+        }
+        let sizing = self.sizing;
+        let paddings = self.paddings;
+        let loading_opacity = self.loading_opacity.tick(cx);
+        let show_skeleton = self.is_showing_skeleton(cx);
+        let skeleton_row_height = self
+            .vertical_scroll_handle
+            .0
+            .borrow()
+            .last_item_size
+            .map_or(SKELETON_ROW_HEIGHT, |size| size.item.height);
+        // The skeleton rows are the loading state already.
+        let loading_overlay = if self.loading && loading_opacity > 0. && !show_skeleton {
+            self.delegate.render_loading(cx)
         } else {
-            ListSizingBehavior::Auto
+            None
         };
+        let (scroll_padding_top, scroll_padding_bottom) = self.scroll_paddings(pinned_count);
 
         let initial_view = if let Some(input) = &self.query_input {
             if input.read(cx).text().is_empty() {
@@ -390,26 +1787,48 @@ where
             None
         };
 
-        v_flex()
+        let list = v_flex()
             .key_context("List")
             .id("list")
             .track_focus(&self.focus_handle)
-            .size_full()
+            .w_full()
+            .when(sizing.fill(), |this| this.h_full())
             .relative()
             .overflow_hidden()
+            .bg(self
+                .theme
+                .background
+                .unwrap_or(cx.theme().panel_background()))
+            .text_size(cx.theme().font_size_for(size))
+            .when(self.query_input.is_none(), |this| {
+                this.focus_ring(&self.focus_handle, cx)
+            })
             .on_action(cx.listener(Self::on_action_cancel))
             .on_action(cx.listener(Self::on_action_confirm))
+            .on_action(cx.listener(Self::on_action_secondary_confirm))
             .on_action(cx.listener(Self::on_action_select_next))
             .on_action(cx.listener(Self::on_action_select_prev))
+            .on_action(cx.listener(Self::on_action_select_left))
+            .on_action(cx.listener(Self::on_action_select_right))
+            .on_action(cx.listener(Self::on_action_select_first))
+            .on_action(cx.listener(Self::on_action_select_last))
+            .on_action(cx.listener(Self::on_action_select_page_up))
+            .on_action(cx.listener(Self::on_action_select_page_down))
+            .on_action(cx.listener(Self::on_action_tab))
+            .on_action(cx.listener(Self::on_action_tab_prev))
+            .on_action(cx.listener(Self::on_action_focus_query))
             .when_some(self.query_input.clone(), |this, input| {
                 this.child(
-                    div()
-                        .map(|this| match self.size {
-                            Size::Small => this.py_0().px_1p5(),
+                    h_flex()
+                        .gap_with(size)
+                        .map(|this| match size {
+                            Size::XSmall | Size::Small => this.py_0().px_1p5(),
+                            Size::XLarge => this.py_2().px_3(),
                             _ => this.py_1().px_2(),
                         })
-                        .border_b_1()
-                        .border_color(cx.theme().border)
+                        .border_b_w(cx.theme().border_width())
+                        .border_color(self.theme.border.unwrap_or(cx.theme().border))
+                        .on_scroll_wheel(cx.listener(Self::forward_scroll_wheel))
                         .child(input),
                 )
             })
@@ -419,29 +1838,88 @@ where
                 } else {
                     this.child(
                         v_flex()
-                            .flex_grow()
+                            .when(sizing.fill(), |this| this.flex_grow())
                             .relative()
-                            .when_some(self.max_height, |this, h| this.max_h(h))
+                            .when_some(sizing.max_height(), |this, h| this.max_h(h))
                             .overflow_hidden()
+                            .on_drag_move(cx.listener(Self::on_items_drag_move))
+                            .on_drop(cx.listener(Self::on_items_drop))
                             .when(items_count == 0, |this| {
-                                this.child(self.delegate().render_empty(cx))
+                                if show_skeleton {
+                                    this.child(
+                                        SkeletonList::new(self.skeleton_rows, skeleton_row_height)
+                                            .pt(paddings.top)
+                                            .pl(paddings.left)
+                                            .pr(paddings.right),
+                                    )
+                                } else {
+                                    this.child(self.delegate().render_empty(cx))
+                                }
+                            })
+                            .when(pinned_count > 0, |this| {
+                                this.child(
+                                    v_flex()
+                                        .flex_none()
+                                        .pt(paddings.top)
+                                        .pl(paddings.left)
+                                        .pr(paddings.right)
+                                        .border_b_w(cx.theme().border_width())
+                                        .border_color(
+                                            self.theme.border.unwrap_or(cx.theme().border),
+                                        )
+                                        .on_scroll_wheel(cx.listener(Self::forward_scroll_wheel))
+                                        .children(pinned_items),
+                                )
                             })
-                            .when(items_count > 0, |this| {
+                            .when(items_count > pinned_count, |this| {
+                                // The scrollbar only covers the scrolling items.
                                 this.child(
-                                    uniform_list(view, "uniform-list", items_count, {
-                                        move |list, visible_range, cx| {
-                                            visible_range
-                                                .map(|ix| list.render_list_item(ix, cx))
-                                                .collect::<Vec<_>>()
-                                        }
-                                    })
-                                    .flex_grow()
-                                    .with_sizing_behavior(sizing_behavior)
-                                    .track_scroll(vertical_scroll_handle)
-                                    .into_any_element(),
+                                    v_flex()
+                                        .flex_grow()
+                                        .relative()
+                                        .overflow_hidden()
+                                        .child(
+                                            uniform_list(
+                                                view,
+                                                "uniform-list",
+                                                (items_count - pinned_count).div_ceil(columns),
+                                                move |list, visible_range, cx| {
+                                                    visible_range
+                                                        .map(|row| {
+                                                            list.render_row(
+                                                                pinned_count + row * columns,
+                                                                items_count,
+                                                                cx,
+                                                            )
+                                                        })
+                                                        .collect::<Vec<_>>()
+                                                },
+                                            )
+                                            .when(sizing.fill(), |this| this.flex_grow())
+                                            .pt(scroll_padding_top)
+                                            .pb(scroll_padding_bottom)
+                                            .pl(paddings.left)
+                                            .pr(paddings.right)
+                                            .with_sizing_behavior(sizing.sizing_behavior())
+                                            .track_scroll(vertical_scroll_handle)
+                                            .into_any_element(),
+                                        )
+                                        .children(self.render_scrollbar(cx)),
                                 )
                             })
-                            .children(self.render_scrollbar(cx)),
+                            .when_some(loading_overlay, |this, overlay| {
+                                // Without the interactivity it doesn't occlude the items,
+                                // so the scrolling still works.
+                                this.child(
+                                    div()
+                                        .absolute()
+                                        .top_0()
+                                        .left_0()
+                                        .size_full()
+                                        .opacity(loading_opacity)
+                                        .child(overlay),
+                                )
+                            }),
                     )
                 }
             })
@@ -451,6 +1929,838 @@ where
                     this.right_clicked_index = None;
                     cx.notify();
                 }))
+            });
+
+        #[cfg(test)]
+        let list = list.debug_selector(|| "list".into());
+        list
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc, time::Duration};
+
+    use gpui::{
+        AppContext, Modifiers, ParentElement as _, Task, TestAppContext, View, ViewContext,
+        VisualContext as _, VisualTestContext,
+    };
+
+    use super::{
+        drop_candidates, grid_step, ConfirmContext, DropTarget, List, ListDelegate, ListEvent,
+        ListKeymap, ListOptions, ListSizing, SelectionSource,
+    };
+    use crate::{input::TextInput, list::ListItem};
+
+    #[derive(Default)]
+    struct TestDelegate {
+        count: usize,
+        pinned: usize,
+        query: String,
+        searches: usize,
+        selected_index: Option<usize>,
+        confirmed: Vec<ConfirmContext>,
+        cancelled: bool,
+        disabled: Vec<usize>,
+        /// Finish the searches in place.
+        instant: bool,
+    }
+
+    impl ListDelegate for TestDelegate {
+        type Item = ListItem;
+
+        fn items_count(&self, _: &AppContext) -> usize {
+            self.count
+        }
+
+        fn pinned_count(&self, _: &AppContext) -> usize {
+            self.pinned
+        }
+
+        fn render_item(&self, ix: usize, _: &mut ViewContext<List<Self>>) -> Option<Self::Item> {
+            Some(ListItem::new(("item", ix)).child(format!("Item {}", ix)))
+        }
+
+        fn is_disabled(&self, ix: usize, _: &AppContext) -> bool {
+            self.disabled.contains(&ix)
+        }
+
+        /// The shorter query is slower, so the older search is finished after the newer.
+        fn perform_search(&mut self, query: &str, cx: &mut ViewContext<List<Self>>) -> Task<()> {
+            self.searches += 1;
+            let query = query.to_string();
+            if self.instant {
+                self.query = query;
+                return Task::ready(());
+            }
+            let delay = Duration::from_millis(if query.len() > 1 { 50 } else { 200 });
+            cx.spawn(|this, mut cx| async move {
+                cx.background_executor().timer(delay).await;
+                _ = this.update(&mut cx, |this, _| this.delegate_mut().query = query);
+            })
+        }
+
+        fn set_selected_index(&mut self, ix: Option<usize>, _: &mut ViewContext<List<Self>>) {
+            self.selected_index = ix;
+        }
+
+        fn confirm_with(&mut self, confirm: &ConfirmContext, _: &mut ViewContext<List<Self>>) {
+            self.confirmed.push(confirm.clone());
+        }
+
+        fn cancel(&mut self, _: &mut ViewContext<List<Self>>) {
+            self.cancelled = true;
+        }
+    }
+
+    fn build_list(
+        count: usize,
+        cx: &mut TestAppContext,
+    ) -> (View<List<TestDelegate>>, &mut VisualTestContext) {
+        cx.update(|cx| {
+            crate::init(cx);
+        });
+        let (list, cx) = cx.add_window_view(|cx| {
+            List::new(
+                TestDelegate {
+                    count,
+                    ..Default::default()
+                },
+                cx,
+            )
+            .no_query()
+        });
+        list.update(cx, |list, cx| list.focus(cx));
+        (list, cx)
+    }
+
+    fn selected_index(
+        list: &View<List<TestDelegate>>,
+        cx: &mut VisualTestContext,
+    ) -> Option<usize> {
+        list.read_with(cx, |list, _| {
+            assert_eq!(list.selected_index, list.delegate.selected_index);
+            list.selected_index
+        })
+    }
+
+    #[gpui::test]
+    fn test_keyboard_navigation_wraps(cx: &mut TestAppContext) {
+        let (list, cx) = build_list(3, cx);
+
+        cx.simulate_keystrokes("down");
+        assert_eq!(selected_index(&list, cx), Some(0));
+        cx.simulate_keystrokes("down down");
+        assert_eq!(selected_index(&list, cx), Some(2));
+        cx.simulate_keystrokes("down");
+        assert_eq!(selected_index(&list, cx), Some(0));
+        cx.simulate_keystrokes("up");
+        assert_eq!(selected_index(&list, cx), Some(2));
+    }
+
+    #[gpui::test]
+    fn test_init_with_keymap_replaces_the_last(cx: &mut TestAppContext) {
+        let (list, cx) = build_list(5, cx);
+        cx.update(|cx| {
+            super::init_with_keymap(
+                cx,
+                ListKeymap {
+                    select_next: vec!["j"],
+                    ..Default::default()
+                },
+            );
+            super::init_with_keymap(
+                cx,
+                ListKeymap {
+                    select_next: vec!["n"],
+                    ..Default::default()
+                },
+            );
+        });
+
+        // Neither the default nor the keystroke of the first keymap is bound.
+        cx.simulate_keystrokes("down j");
+        assert_eq!(selected_index(&list, cx), None);
+        cx.simulate_keystrokes("n");
+        assert_eq!(selected_index(&list, cx), Some(0));
+    }
+
+    #[gpui::test]
+    fn test_selection_clamped_after_items_shrink(cx: &mut TestAppContext) {
+        let (list, cx) = build_list(5, cx);
+
+        list.update(cx, |list, cx| {
+            list.set_selected_index(Some(4), cx);
+            list.delegate_mut().count = 2;
+            list.items_changed(cx);
+        });
+        assert_eq!(selected_index(&list, cx), Some(1));
+
+        list.update(cx, |list, cx| {
+            list.delegate_mut().count = 0;
+            list.items_changed(cx);
+        });
+        assert_eq!(selected_index(&list, cx), None);
+    }
+
+    #[gpui::test]
+    fn test_loading_overlay_hidden_with_results(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            crate::init(cx);
+        });
+        let (list, cx) = cx.add_window_view(|cx| List::new(TestDelegate::default(), cx));
+
+        // The single letter query is searched in 200ms.
+        list.update(cx, |list, cx| list.set_query("a", cx));
+        list.read_with(cx, |list, _| {
+            assert!(list.loading);
+            assert_eq!(list.loading_opacity.target(), 1.);
+        });
+        cx.executor().advance_clock(Duration::from_millis(200));
+        cx.run_until_parked();
+        list.read_with(cx, |list, _| {
+            assert_eq!(list.delegate.query, "a");
+            assert!(list.loading);
+            assert_eq!(list.loading_opacity.target(), 0.);
+        });
+
+        // The next search in the tail of the loading shows the overlay again.
+        list.update(cx, |list, cx| list.set_query("b", cx));
+        list.read_with(cx, |list, _| assert_eq!(list.loading_opacity.target(), 1.));
+        cx.executor().advance_clock(Duration::from_millis(500));
+        cx.run_until_parked();
+        list.read_with(cx, |list, _| assert!(!list.loading));
+    }
+
+    #[gpui::test]
+    fn test_stale_search_is_dropped(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            crate::init(cx);
+        });
+        let (list, cx) = cx.add_window_view(|cx| List::new(TestDelegate::default(), cx));
+
+        list.update(cx, |list, cx| list.set_query("a", cx));
+        cx.run_until_parked();
+        list.update(cx, |list, cx| list.set_query("ab", cx));
+        cx.executor().advance_clock(Duration::from_millis(500));
+        cx.run_until_parked();
+
+        list.read_with(cx, |list, _| {
+            assert_eq!(list.delegate.query, "ab");
+            assert_eq!(list.last_query.as_deref(), Some("ab"));
+        });
+    }
+
+    #[gpui::test]
+    fn test_confirm_and_cancel(cx: &mut TestAppContext) {
+        let (list, cx) = build_list(0, cx);
+
+        // Nothing to confirm without items.
+        cx.simulate_keystrokes("enter");
+        list.read_with(cx, |list, _| assert!(list.delegate.confirmed.is_empty()));
+
+        list.update(cx, |list, cx| {
+            list.delegate_mut().count = 3;
+            list.items_changed(cx);
+        });
+        cx.simulate_keystrokes("down enter");
+        if cfg!(target_os = "macos") {
+            cx.simulate_keystrokes("cmd-enter");
+        } else {
+            cx.simulate_keystrokes("ctrl-enter");
+        }
+        let confirm = |secondary| ConfirmContext {
+            index: Some(0),
+            query: None,
+            secondary,
+            source: SelectionSource::Keyboard,
+        };
+        list.read_with(cx, |list, _| {
+            assert_eq!(list.delegate.confirmed, vec![confirm(false), confirm(true)])
+        });
+
+        cx.simulate_keystrokes("escape");
+        assert_eq!(selected_index(&list, cx), None);
+        list.read_with(cx, |list, _| assert!(list.delegate.cancelled));
+    }
+
+    #[gpui::test]
+    fn test_click_disabled_item(cx: &mut TestAppContext) {
+        let (list, cx) = build_list(3, cx);
+        list.update(cx, |list, cx| {
+            list.delegate_mut().disabled = vec![1];
+            list.items_changed(cx);
+        });
+
+        let bounds = cx.debug_bounds("list-item-1").unwrap();
+        cx.simulate_click(bounds.center(), Modifiers::none());
+        assert_eq!(selected_index(&list, cx), None);
+        list.read_with(cx, |list, _| assert!(list.delegate.confirmed.is_empty()));
+
+        let bounds = cx.debug_bounds("list-item-2").unwrap();
+        cx.simulate_click(bounds.center(), Modifiers::none());
+        assert_eq!(selected_index(&list, cx), Some(2));
+        list.read_with(cx, |list, _| {
+            let confirmed = list.delegate.confirmed.iter().map(|c| c.index);
+            assert_eq!(confirmed.collect::<Vec<_>>(), vec![Some(2)]);
+        });
+    }
+
+    #[gpui::test]
+    fn test_confirm_clamps_selection(cx: &mut TestAppContext) {
+        let (list, cx) = build_list(5, cx);
+
+        // The count is changed without `items_changed`.
+        list.update(cx, |list, cx| {
+            list.set_selected_index(Some(4), cx);
+            list.delegate.count = 2;
+        });
+        cx.simulate_keystrokes("enter");
+        assert_eq!(selected_index(&list, cx), Some(1));
+        list.read_with(cx, |list, _| {
+            let confirmed = list.delegate.confirmed.iter().map(|c| c.index);
+            assert_eq!(confirmed.collect::<Vec<_>>(), vec![Some(1)]);
+        });
+
+        list.update(cx, |list, _| list.delegate.count = 0);
+        cx.simulate_keystrokes("enter");
+        assert_eq!(selected_index(&list, cx), None);
+        list.read_with(cx, |list, _| assert_eq!(list.delegate.confirmed.len(), 1));
+    }
+
+    #[gpui::test]
+    fn test_set_query_input_replaces_subscription(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            crate::init(cx);
+        });
+        let (list, cx) = cx.add_window_view(|cx| List::new(TestDelegate::default(), cx));
+        let old_input = list.read_with(cx, |list, _| list.query_input.clone().unwrap());
+        let input = cx.new_view(|cx| TextInput::new(cx));
+
+        list.update(cx, |list, cx| {
+            list.set_query_input(input.clone(), cx);
+            list.set_query_input(input.clone(), cx);
+        });
+        input.update(cx, |input, cx| input.set_text("abc", cx));
+        old_input.update(cx, |input, cx| input.set_text("old", cx));
+        cx.run_until_parked();
+
+        list.read_with(cx, |list, _| assert_eq!(list.delegate.searches, 1));
+    }
+
+    #[gpui::test]
+    fn test_controlled_selection(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            crate::init(cx);
+        });
+        let options = ListOptions {
+            query: false,
+            controlled_selection: true,
+            ..Default::default()
+        };
+        let (list, cx) = cx.add_window_view(|cx| {
+            List::with_options(
+                TestDelegate {
+                    count: 5,
+                    ..Default::default()
+                },
+                options,
+                cx,
+            )
+        });
+        list.update(cx, |list, cx| list.focus(cx));
+        let events = Rc::new(RefCell::new(Vec::new()));
+        cx.update(|cx| {
+            let events = events.clone();
+            cx.subscribe(&list, move |_, event: &ListEvent, _| {
+                events.borrow_mut().push(*event)
             })
+            .detach();
+        });
+
+        // The selection is not changed until the parent set it.
+        cx.simulate_keystrokes("down");
+        assert_eq!(selected_index(&list, cx), None);
+        list.update(cx, |list, cx| list.set_selected_index(Some(2), cx));
+        cx.simulate_keystrokes("down enter escape");
+        assert_eq!(selected_index(&list, cx), Some(2));
+
+        assert_eq!(
+            *events.borrow(),
+            vec![
+                ListEvent::SelectRequested(0),
+                ListEvent::SelectRequested(3),
+                ListEvent::ConfirmRequested(2),
+            ]
+        );
+        list.read_with(cx, |list, _| assert!(list.delegate.confirmed.is_empty()));
+    }
+
+    #[gpui::test]
+    fn test_render_budget(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            crate::init(cx);
+        });
+        // Without the scrollbar, it fades out by the animation frames.
+        let options = ListOptions {
+            scrollbar: false,
+            ..Default::default()
+        };
+        let (list, cx) = cx.add_window_view(|cx| {
+            List::with_options(
+                TestDelegate {
+                    count: 100,
+                    instant: true,
+                    ..Default::default()
+                },
+                options,
+                cx,
+            )
+        });
+        list.update(cx, |list, cx| list.focus(cx));
+        cx.run_until_parked();
+        let render_count =
+            |cx: &mut VisualTestContext| list.read_with(cx, |list, _| list.render_count);
+        let baseline = render_count(cx);
+
+        // Redraw once per keystroke, the instant searches don't toggle the loading state.
+        const KEYSTROKES: usize = 20;
+        for _ in 0..KEYSTROKES - 3 {
+            cx.simulate_keystrokes("down");
+        }
+        cx.simulate_input("abc");
+        cx.run_until_parked();
+
+        let renders = render_count(cx) - baseline;
+        assert!(
+            renders <= KEYSTROKES,
+            "{} renders for {} keystrokes",
+            renders,
+            KEYSTROKES
+        );
+        list.read_with(cx, |list, _| {
+            assert_eq!(list.delegate.query, "abc");
+            assert_eq!(list.delegate.searches, 3);
+            assert!(!list.loading);
+        });
+    }
+
+    #[gpui::test]
+    fn test_with_options(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            crate::init(cx);
+        });
+        let options = ListOptions {
+            query: false,
+            scrollbar: false,
+            ..Default::default()
+        };
+        let (list, cx) =
+            cx.add_window_view(|cx| List::with_options(TestDelegate::default(), options, cx));
+        list.read_with(cx, |list, _| {
+            assert!(list.query_input.is_none());
+            assert!(!list.enable_scrollbar);
+        });
+    }
+
+    #[gpui::test]
+    fn test_search_debounce(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            crate::init(cx);
+        });
+        let options = ListOptions {
+            search_debounce: Duration::from_millis(100),
+            ..Default::default()
+        };
+        let (list, cx) =
+            cx.add_window_view(|cx| List::with_options(TestDelegate::default(), options, cx));
+
+        list.update(cx, |list, cx| list.set_query("abc", cx));
+        cx.executor().advance_clock(Duration::from_millis(80));
+        cx.run_until_parked();
+        list.read_with(cx, |list, _| {
+            assert!(list.loading && list.last_query.is_none());
+            assert_eq!(list.loading_opacity.target(), 1.);
+        });
+
+        cx.executor().advance_clock(Duration::from_millis(100));
+        cx.run_until_parked();
+        list.read_with(cx, |list, _| {
+            assert_eq!(list.delegate.query, "abc");
+            assert_eq!(list.last_query.as_deref(), Some("abc"));
+        });
+
+        cx.executor().advance_clock(Duration::from_millis(100));
+        cx.run_until_parked();
+        list.read_with(cx, |list, _| {
+            // The overlay is hidden at once when the loading is finished.
+            assert!(!list.loading);
+            assert_eq!(list.loading_opacity.target(), 0.);
+            assert!(!list.loading_opacity.is_animating());
+        });
+    }
+
+    #[gpui::test]
+    fn test_focus_query_and_escape_back(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            crate::init(cx);
+        });
+        let (list, cx) = cx.add_window_view(|cx| List::new(TestDelegate::default(), cx));
+        let input = list.read_with(cx, |list, _| list.query_input.clone().unwrap());
+
+        list.update(cx, |list, cx| list.focus_handle.focus(cx));
+        cx.simulate_keystrokes("/");
+        cx.update(|cx| assert!(input.focus_handle(cx).is_focused(cx)));
+
+        // Escape from the empty query focuses the items, without cancel.
+        cx.simulate_keystrokes("escape");
+        cx.update(|cx| {
+            let list = list.read(cx);
+            assert!(list.focus_handle.is_focused(cx));
+            assert!(!list.delegate.cancelled);
+        });
+    }
+
+    #[gpui::test]
+    fn test_scroll_to_selected_item(cx: &mut TestAppContext) {
+        let (list, cx) = build_list(100, cx);
+
+        list.update(cx, |list, cx| {
+            list.select_prev(cx);
+            let deferred = list
+                .vertical_scroll_handle
+                .0
+                .borrow()
+                .deferred_scroll_to_item;
+            assert_eq!(deferred.map(|(ix, _)| ix), Some(99));
+        });
+    }
+
+    #[gpui::test]
+    fn test_pinned_items(cx: &mut TestAppContext) {
+        let (list, cx) = build_list(10, cx);
+
+        list.update(cx, |list, cx| {
+            list.delegate_mut().pinned = 2;
+            list.set_selected_index(Some(1), cx);
+
+            // Cross the boundary to the first scrolling item.
+            list.select_next(cx);
+            assert_eq!(list.selected_index(), Some(2));
+            let deferred = list
+                .vertical_scroll_handle
+                .0
+                .borrow()
+                .deferred_scroll_to_item;
+            assert_eq!(deferred.map(|(ix, _)| ix), Some(0));
+
+            list.set_selected_index(Some(0), cx);
+            list.select_prev(cx);
+            assert_eq!(list.selected_index(), Some(9));
+            let deferred = list
+                .vertical_scroll_handle
+                .0
+                .borrow()
+                .deferred_scroll_to_item;
+            assert_eq!(deferred.map(|(ix, _)| ix), Some(7));
+        });
+    }
+
+    #[gpui::test]
+    fn test_home_end_in_body(cx: &mut TestAppContext) {
+        let (list, cx) = build_list(30, cx);
+
+        cx.simulate_keystrokes("end");
+        assert_eq!(selected_index(&list, cx), Some(29));
+        cx.simulate_keystrokes("home");
+        assert_eq!(selected_index(&list, cx), Some(0));
+        cx.simulate_keystrokes("pagedown");
+        assert!(selected_index(&list, cx) > Some(0));
+        cx.simulate_keystrokes("pageup");
+        assert_eq!(selected_index(&list, cx), Some(0));
+    }
+
+    #[gpui::test]
+    fn test_home_end_in_query(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            crate::init(cx);
+        });
+        let (list, cx) = cx.add_window_view(|cx| {
+            List::new(
+                TestDelegate {
+                    count: 30,
+                    ..Default::default()
+                },
+                cx,
+            )
+        });
+        list.update(cx, |list, cx| list.focus(cx));
+
+        // The empty query has no caret to move, so the list is navigated.
+        cx.simulate_keystrokes("end");
+        assert_eq!(selected_index(&list, cx), Some(29));
+
+        // Move the caret of the query.
+        cx.simulate_input("abc");
+        cx.simulate_keystrokes("home");
+        assert_eq!(selected_index(&list, cx), Some(29));
+
+        // The arrows and PageUp/PageDown are always for the list.
+        cx.simulate_keystrokes("up");
+        assert_eq!(selected_index(&list, cx), Some(28));
+        cx.simulate_keystrokes("pageup");
+        assert!(selected_index(&list, cx) < Some(28));
+    }
+
+    #[gpui::test]
+    fn test_ctrl_n_p_in_query(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            crate::init(cx);
+        });
+        let (list, cx) = cx.add_window_view(|cx| {
+            List::new(
+                TestDelegate {
+                    count: 3,
+                    ..Default::default()
+                },
+                cx,
+            )
+        });
+        list.update(cx, |list, cx| list.focus(cx));
+
+        cx.simulate_keystrokes("ctrl-n ctrl-n");
+        assert_eq!(selected_index(&list, cx), Some(1));
+        cx.simulate_keystrokes("ctrl-p");
+        assert_eq!(selected_index(&list, cx), Some(0));
+        // The other keys are still typed into the query.
+        cx.simulate_input("n");
+        assert_eq!(selected_index(&list, cx), Some(0));
+    }
+
+    #[gpui::test]
+    fn test_sizing(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            crate::init(cx);
+        });
+
+        let max_height = gpui::px(200.);
+        for sizing in [
+            ListSizing::Fill,
+            ListSizing::FitContent,
+            ListSizing::MaxHeight(max_height.into()),
+        ] {
+            for count in [0, 2, 1000] {
+                let (list, cx) = cx.add_window_view(|cx| {
+                    List::new(
+                        TestDelegate {
+                            count,
+                            ..Default::default()
+                        },
+                        cx,
+                    )
+                    .no_query()
+                    .sizing(sizing)
+                });
+                cx.run_until_parked();
+
+                let viewport = cx.update(|cx| cx.viewport_size());
+                let bounds = cx.debug_bounds("list").unwrap();
+                let label = format!("{:?} with {} items", sizing, count);
+                match (sizing, count) {
+                    (ListSizing::Fill, _) => {
+                        assert_eq!(bounds.size.height, viewport.height, "{}", label)
+                    }
+                    // The empty state of the default `render_empty` has no height.
+                    (_, 0) => assert_eq!(bounds.size.height, gpui::px(0.), "{}", label),
+                    // No empty region below the last item.
+                    (_, 2) => {
+                        let last = cx.debug_bounds("list-item-1").unwrap();
+                        assert_eq!(bounds.bottom(), last.bottom(), "{}", label);
+                        assert!(bounds.size.height < max_height, "{}", label);
+                    }
+                    (ListSizing::MaxHeight(_), _) => {
+                        assert_eq!(bounds.size.height, max_height, "{}", label)
+                    }
+                    // Fit all the items, they are clipped by the window.
+                    _ => assert!(bounds.size.height > viewport.height, "{}", label),
+                }
+
+                list.update(cx, |list, cx| {
+                    assert_eq!(list.sizing, sizing);
+                    list.on_action_select_last(&super::SelectLast, cx);
+                });
+                assert_eq!(selected_index(&list, cx), count.checked_sub(1));
+            }
+        }
+
+        assert!(matches!(
+            ListSizing::Fill.sizing_behavior(),
+            gpui::ListSizingBehavior::Auto
+        ));
+        assert!(matches!(
+            ListSizing::FitContent.sizing_behavior(),
+            gpui::ListSizingBehavior::Infer
+        ));
+        assert!(ListSizing::MaxHeight(gpui::px(200.).into())
+            .max_height()
+            .is_some());
+    }
+
+    #[gpui::test]
+    fn test_item_gap_and_paddings(cx: &mut TestAppContext) {
+        use gpui::px;
+
+        cx.update(|cx| {
+            crate::init(cx);
+        });
+        let (list, cx) = cx.add_window_view(|cx| {
+            List::new(
+                TestDelegate {
+                    count: 100,
+                    ..Default::default()
+                },
+                cx,
+            )
+            .no_query()
+            .item_gap(px(4.))
+            .px(px(8.))
+            .py(px(6.))
+        });
+        list.update(cx, |list, cx| list.set_selected_index(Some(0), cx));
+        // The scrollbar is sized by the items measured in the first frame.
+        cx.run_until_parked();
+        list.update(cx, |_, cx| cx.notify());
+        cx.run_until_parked();
+
+        let container = cx.debug_bounds("list").unwrap();
+        let item = cx.debug_bounds("list-item-0").unwrap();
+        let next_item = cx.debug_bounds("list-item-1").unwrap();
+        let overlay = cx.debug_bounds("list-item-0-highlight").unwrap();
+        assert_eq!(item.left(), container.left() + px(8.));
+        assert_eq!(item.right(), container.right() - px(8.));
+        assert_eq!(item.top(), container.top() + px(6.));
+        assert_eq!(next_item.top(), item.bottom());
+        // The overlay covers the padded item, not the gap below it.
+        assert_eq!(overlay.left(), item.left());
+        assert_eq!(overlay.right(), item.right());
+        assert_eq!(overlay.top(), item.top());
+        assert_eq!(overlay.bottom(), item.bottom() - px(4.));
+        // The scrollbar is at the edge of the container, out of the paddings.
+        let bar = list
+            .read_with(cx, |list, _| list.scrollbar_state.get().vertical_bar_bounds)
+            .unwrap();
+        assert_eq!(bar.right(), container.right());
+        assert_eq!(bar.top(), container.top());
+
+        list.update(cx, |list, _| {
+            assert_eq!(list.paddings.left, px(8.));
+            assert_eq!(list.paddings.right, px(8.));
+            // The gap of the last item is a part of the bottom padding.
+            assert_eq!(list.scroll_paddings(0), (px(6.), px(2.)));
+            // The top padding is before the pinned items.
+            assert_eq!(list.scroll_paddings(2), (px(0.), px(2.)));
+
+            list.item_gap = px(10.);
+            assert_eq!(list.scroll_paddings(0), (px(6.), px(0.)));
+        });
+    }
+
+    #[gpui::test]
+    fn test_skeleton_rows(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            crate::init(cx);
+        });
+        let (list, cx) =
+            cx.add_window_view(|cx| List::new(TestDelegate::default(), cx).skeleton_rows(5));
+
+        list.update(cx, |list, cx| {
+            assert!(!list.is_showing_skeleton(cx));
+            list.set_loading(true, cx);
+            assert!(list.is_showing_skeleton(cx));
+
+            // Replaced by the items.
+            list.delegate_mut().count = 3;
+            assert!(!list.is_showing_skeleton(cx));
+
+            // Replaced by the empty state.
+            list.delegate_mut().count = 0;
+            list.set_loading(false, cx);
+            assert!(!list.is_showing_skeleton(cx));
+        });
+        cx.run_until_parked();
+    }
+
+    #[test]
+    fn test_grid_step() {
+        // 7 items in 3 columns:
+        // 0 1 2
+        // 3 4 5
+        // 6
+        assert_eq!(grid_step(None, 3, 3, 7), Some(0));
+        assert_eq!(grid_step(None, -3, 3, 7), Some(6));
+        assert_eq!(grid_step(Some(1), 3, 3, 7), Some(4));
+        assert_eq!(grid_step(Some(4), -3, 3, 7), Some(1));
+        assert_eq!(grid_step(Some(1), -3, 3, 7), None);
+        // Down to the shorter last row.
+        assert_eq!(grid_step(Some(5), 3, 3, 7), Some(6));
+        assert_eq!(grid_step(Some(6), 3, 3, 7), None);
+        // Left and right move across the rows.
+        assert_eq!(grid_step(Some(2), 1, 3, 7), Some(3));
+        assert_eq!(grid_step(Some(3), -1, 3, 7), Some(2));
+        assert_eq!(grid_step(Some(6), 1, 3, 7), None);
+        assert_eq!(grid_step(Some(0), -1, 3, 7), None);
+        assert_eq!(grid_step(None, 1, 3, 0), None);
+    }
+
+    #[gpui::test]
+    fn test_grid_navigation(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            crate::init(cx);
+        });
+        let (list, cx) = cx.add_window_view(|cx| {
+            List::new(
+                TestDelegate {
+                    count: 7,
+                    ..Default::default()
+                },
+                cx,
+            )
+            .grid(3)
+        });
+
+        list.update(cx, |list, cx| list.select_next(cx));
+        assert_eq!(selected_index(&list, cx), Some(0));
+        list.update(cx, |list, cx| {
+            list.on_action_select_right(&super::SelectRight, cx)
+        });
+        assert_eq!(selected_index(&list, cx), Some(1));
+        list.update(cx, |list, cx| list.select_next(cx));
+        assert_eq!(selected_index(&list, cx), Some(4));
+        list.update(cx, |list, cx| list.select_next(cx));
+        assert_eq!(selected_index(&list, cx), Some(6));
+        // Keep the selection at the edge.
+        list.update(cx, |list, cx| list.select_next(cx));
+        assert_eq!(selected_index(&list, cx), Some(6));
+        list.update(cx, |list, cx| {
+            list.on_action_select_left(&super::SelectLeft, cx)
+        });
+        assert_eq!(selected_index(&list, cx), Some(5));
+        list.update(cx, |list, cx| list.select_prev(cx));
+        assert_eq!(selected_index(&list, cx), Some(2));
+        list.update(cx, |list, cx| list.select_prev(cx));
+        assert_eq!(selected_index(&list, cx), Some(2));
+    }
+
+    #[test]
+    fn test_drop_candidates() {
+        use DropTarget::*;
+
+        assert_eq!(drop_candidates(1, 0.1, 3), vec![Between(1), OnItem(1)]);
+        assert_eq!(drop_candidates(1, 0.4, 3), vec![OnItem(1), Between(1)]);
+        assert_eq!(drop_candidates(1, 0.6, 3), vec![OnItem(1), Between(2)]);
+        assert_eq!(drop_candidates(2, 0.9, 3), vec![Between(3), OnItem(2)]);
+        // Below the last item, or an empty List.
+        assert_eq!(drop_candidates(5, 0.5, 3), vec![Between(3)]);
+        assert_eq!(drop_candidates(0, 0., 0), vec![Between(0)]);
     }
 }
@@ -1,7 +1,10 @@
-use crate::{h_flex, theme::ActiveTheme, Disableable, Icon, IconName, Selectable, Sizable as _};
+use crate::{
+    checkbox::Checkbox, h_flex, theme::ActiveTheme, tooltip::TooltipExt as _, Disableable, Icon,
+    IconName, Selectable, Sizable as _,
+};
 use gpui::{
     div, prelude::FluentBuilder as _, AnyElement, ClickEvent, Div, ElementId, InteractiveElement,
-    IntoElement, MouseButton, MouseMoveEvent, ParentElement, RenderOnce, Stateful,
+    IntoElement, MouseButton, MouseMoveEvent, ParentElement, RenderOnce, SharedString, Stateful,
     StatefulInteractiveElement as _, Styled, WindowContext,
 };
 use smallvec::SmallVec;
@@ -13,10 +16,15 @@ pub struct ListItem {
     disabled: bool,
     selected: bool,
     confirmed: bool,
+    /// Paint the selected, confirmed and hover backgrounds.
+    background: bool,
     check_icon: Option<Icon>,
+    /// The checked state of the checkbox in the checkable mode.
+    checkable: Option<bool>,
     on_click: Option<Box<dyn Fn(&ClickEvent, &mut WindowContext) + 'static>>,
     on_mouse_enter: Option<Box<dyn Fn(&MouseMoveEvent, &mut WindowContext) + 'static>>,
     suffix: Option<Box<dyn Fn(&mut WindowContext) -> AnyElement + 'static>>,
+    tooltip: Option<SharedString>,
     children: SmallVec<[AnyElement; 2]>,
 }
 
@@ -29,10 +37,13 @@ impl ListItem {
             disabled: false,
             selected: false,
             confirmed: false,
+            background: true,
             on_click: None,
             on_mouse_enter: None,
             check_icon: None,
+            checkable: None,
             suffix: None,
+            tooltip: None,
             children: SmallVec::new(),
         }
     }
@@ -43,6 +54,14 @@ impl ListItem {
         self
     }
 
+    /// Set to the checkable mode, to show a leading checkbox with the `checked` state.
+    ///
+    /// The checkbox is toggled by the click of the item, e.g. in the `confirm` of the delegate.
+    pub fn checkable(mut self, checked: bool) -> Self {
+        self.checkable = Some(checked);
+        self
+    }
+
     /// Set ListItem as the selected item style.
     pub fn selected(mut self, selected: bool) -> Self {
         self.selected = selected;
@@ -55,6 +74,13 @@ impl ListItem {
         self
     }
 
+    /// Don't paint the selected, confirmed and hover backgrounds, e.g. in a List with the
+    /// [`SelectionStyle::Inset`](super::SelectionStyle::Inset) that paints them itself.
+    pub fn no_background(mut self) -> Self {
+        self.background = false;
+        self
+    }
+
     pub fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
         self
@@ -70,6 +96,12 @@ impl ListItem {
         self
     }
 
+    /// Set the tooltip to show when hovering, e.g. the full text of a truncated item.
+    pub fn tooltip(mut self, tooltip: impl Into<SharedString>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
     pub fn on_click(mut self, handler: impl Fn(&ClickEvent, &mut WindowContext) + 'static) -> Self {
         self.on_click = Some(Box::new(handler));
         self
@@ -123,7 +155,9 @@ impl RenderOnce for ListItem {
             .relative()
             .items_center()
             .justify_between()
-            .when(is_active, |this| this.bg(cx.theme().list_active))
+            .when(is_active && self.background, |this| {
+                this.bg(cx.theme().list_active)
+            })
             .when(!self.disabled, |this| {
                 this.when_some(self.on_click, |this, on_click| {
                     this.cursor_pointer()
@@ -135,7 +169,7 @@ impl RenderOnce for ListItem {
                 .when_some(self.on_mouse_enter, |this, on_mouse_enter| {
                     this.on_mouse_move(move |ev, cx| (on_mouse_enter)(ev, cx))
                 })
-                .when(!is_active, |this| {
+                .when(!is_active && self.background, |this| {
                     this.hover(|this| this.bg(cx.theme().list_hover))
                 })
             })
@@ -145,6 +179,13 @@ impl RenderOnce for ListItem {
                     .items_center()
                     .justify_between()
                     .gap_x_1()
+                    .when_some(self.checkable, |this, checked| {
+                        this.child(
+                            Checkbox::new("checkbox")
+                                .checked(checked)
+                                .disabled(self.disabled),
+                        )
+                    })
                     .child(div().w_full().children(self.children))
                     .when_some(self.check_icon, |this, icon| {
                         this.child(
@@ -158,5 +199,9 @@ impl RenderOnce for ListItem {
                     }),
             )
             .when_some(self.suffix, |this, suffix| this.child(suffix(cx)))
+            .map(|this| match self.tooltip {
+                Some(tooltip) => this.tooltip_text(tooltip).into_any_element(),
+                None => this.into_any_element(),
+            })
     }
 }
@@ -1,5 +1,9 @@
+mod enum_list_delegate;
 mod list;
 mod list_item;
+mod simple_list_delegate;
 
+pub use enum_list_delegate::*;
 pub use list::*;
 pub use list_item::*;
+pub use simple_list_delegate::*;
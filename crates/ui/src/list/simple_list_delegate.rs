@@ -0,0 +1,118 @@
+use std::sync::Arc;
+
+use gpui::{AppContext, ParentElement as _, SharedString, Task, ViewContext};
+
+use super::{List, ListDelegate, ListItem};
+
+/// Returns the indexes of the items that contains the `query`, case insensitive.
+fn match_items(items: &[SharedString], query: &str) -> Vec<usize> {
+    let query = query.to_lowercase();
+    items
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| item.to_lowercase().contains(&query))
+        .map(|(ix, _)| ix)
+        .collect()
+}
+
+/// A [`ListDelegate`] of the text items, the search is performed on the background thread.
+///
+/// ```ignore
+/// let list = cx.new_view(|cx| List::new(SimpleListDelegate::new(["Apple", "Banana"]), cx));
+/// ```
+pub struct SimpleListDelegate {
+    items: Arc<[SharedString]>,
+    /// The indexes of the matched items.
+    matches: Vec<usize>,
+    selected_index: Option<usize>,
+    /// The index of the confirmed item in `items`, to keep it after the search.
+    confirmed: Option<usize>,
+}
+
+impl SimpleListDelegate {
+    pub fn new(items: impl IntoIterator<Item = impl Into<SharedString>>) -> Self {
+        let items: Arc<[SharedString]> = items.into_iter().map(Into::into).collect();
+        Self {
+            matches: (0..items.len()).collect(),
+            items,
+            selected_index: None,
+            confirmed: None,
+        }
+    }
+
+    /// Replace the items, all of them are matched until the next search.
+    ///
+    /// Call [`List::items_changed`] if this is not called via [`List::delegate_mut`].
+    pub fn set_items(&mut self, items: impl IntoIterator<Item = impl Into<SharedString>>) {
+        *self = Self::new(items);
+    }
+
+    /// Returns the matched item at the index of the List.
+    pub fn item(&self, ix: usize) -> Option<&SharedString> {
+        self.items.get(*self.matches.get(ix)?)
+    }
+
+    /// Returns the last confirmed item.
+    pub fn confirmed_item(&self) -> Option<&SharedString> {
+        self.items.get(self.confirmed?)
+    }
+}
+
+impl ListDelegate for SimpleListDelegate {
+    type Item = ListItem;
+
+    fn items_count(&self, _: &AppContext) -> usize {
+        self.matches.len()
+    }
+
+    fn render_item(&self, ix: usize, _: &mut ViewContext<List<Self>>) -> Option<Self::Item> {
+        let item = self.item(ix)?;
+
+        Some(
+            ListItem::new(("item", ix))
+                .selected(self.selected_index == Some(ix))
+                .confirmed(self.matches.get(ix) == self.confirmed.as_ref())
+                .child(item.clone()),
+        )
+    }
+
+    fn perform_search(&mut self, query: &str, cx: &mut ViewContext<List<Self>>) -> Task<()> {
+        let items = self.items.clone();
+        let query = query.to_string();
+
+        List::spawn_search(
+            cx,
+            move || match_items(&items, &query),
+            |this, matches, _| this.matches = matches,
+        )
+    }
+
+    fn confirmed_index(&self, _: &AppContext) -> Option<usize> {
+        let confirmed = self.confirmed?;
+        self.matches.iter().position(|&ix| ix == confirmed)
+    }
+
+    fn set_selected_index(&mut self, ix: Option<usize>, _: &mut ViewContext<List<Self>>) {
+        self.selected_index = ix;
+    }
+
+    fn confirm(&mut self, ix: Option<usize>, _: &mut ViewContext<List<Self>>) {
+        self.confirmed = ix.and_then(|ix| self.matches.get(ix).copied());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::SharedString;
+
+    use super::match_items;
+
+    #[test]
+    fn test_match_items() {
+        let items: Vec<SharedString> = vec!["Apple".into(), "Banana".into(), "Pineapple".into()];
+        assert_eq!(match_items(&items, ""), vec![0, 1, 2]);
+        assert_eq!(match_items(&items, "apple"), vec![0, 2]);
+        assert_eq!(match_items(&items, "NAN"), vec![1]);
+        assert_eq!(match_items(&items, "cherry"), Vec::<usize>::new());
+    }
+}
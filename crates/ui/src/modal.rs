@@ -6,19 +6,25 @@ use gpui::{
     InteractiveElement, IntoElement, KeyBinding, MouseButton, ParentElement, Pixels, Point,
     RenderOnce, SharedString, Styled, WindowContext,
 };
+use rust_i18n::t;
 
 use crate::{
-    animation::cubic_bezier,
+    animation::{cubic_bezier, reduced_motion},
     button::{Button, ButtonVariants as _},
+    h_flex,
     theme::ActiveTheme as _,
-    v_flex, ContextModal, IconName, Sizable as _,
+    v_flex, ContextModal, FocusCycle, IconName, Sizable as _,
 };
 
-actions!(modal, [Escape]);
+actions!(modal, [Escape, Tab, TabPrev]);
 
 const CONTEXT: &str = "Modal";
 pub fn init(cx: &mut AppContext) {
-    cx.bind_keys([KeyBinding::new("escape", Escape, Some(CONTEXT))])
+    cx.bind_keys([
+        KeyBinding::new("escape", Escape, Some(CONTEXT)),
+        KeyBinding::new("tab", Tab, Some(CONTEXT)),
+        KeyBinding::new("shift-tab", TabPrev, Some(CONTEXT)),
+    ])
 }
 
 #[derive(IntoElement)]
@@ -35,6 +41,8 @@ pub struct Modal {
     show_close: bool,
     overlay: bool,
     keyboard: bool,
+    dismissable: bool,
+    tab_stops: Vec<FocusHandle>,
 
     /// This will be change when open the modal, the focus handle is create when open the modal.
     pub(crate) focus_handle: FocusHandle,
@@ -77,6 +85,8 @@ impl Modal {
             max_width: None,
             overlay: true,
             keyboard: true,
+            dismissable: true,
+            tab_stops: Vec::new(),
             layer_ix: 0,
             overlay_visible: true,
             on_close: Rc::new(|_, _| {}),
@@ -141,11 +151,50 @@ impl Modal {
         self
     }
 
+    /// Set whether the modal can be dismissed by Escape or clicking the overlay, defaults to `true`.
+    ///
+    /// When `false`, the modal only closes by its own buttons, e.g. a required confirm.
+    pub fn dismissable(mut self, dismissable: bool) -> Self {
+        self.dismissable = dismissable;
+        self
+    }
+
+    /// Set the focus handles to cycle by Tab and Shift-Tab, in the order of the list.
+    ///
+    /// The focus is trapped in the modal, it never moves to the view behind.
+    pub fn tab_stops(mut self, focus_handles: impl IntoIterator<Item = FocusHandle>) -> Self {
+        self.tab_stops = focus_handles.into_iter().collect();
+        self
+    }
+
     pub(crate) fn has_overlay(&self) -> bool {
         self.overlay
     }
 }
 
+/// Move the focus to the next (or previous) of the `tab_stops`, and wrap around at the end.
+///
+/// Without any tab stops, the focus stays on the modal itself.
+fn cycle_focus(
+    tab_stops: &[FocusHandle],
+    modal_focus_handle: &FocusHandle,
+    is_next: bool,
+    cx: &mut WindowContext,
+) {
+    cx.stop_propagation();
+    if tab_stops.is_empty() {
+        modal_focus_handle.focus(cx);
+        return;
+    }
+
+    let cycle = FocusCycle::new(tab_stops.iter().cloned());
+    if is_next {
+        cycle.next(cx);
+    } else {
+        cycle.prev(cx);
+    }
+}
+
 impl ParentElement for Modal {
     fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
         self.content.extend(elements);
@@ -162,6 +211,7 @@ impl RenderOnce for Modal {
     fn render(self, cx: &mut WindowContext) -> impl gpui::IntoElement {
         let layer_ix = self.layer_ix;
         let on_close = self.on_close.clone();
+        let tab_stops = Rc::new(self.tab_stops);
         let window_paddings = crate::window_border::window_paddings(cx);
         let view_size = cx.viewport_size()
             - gpui::size(
@@ -187,19 +237,32 @@ impl RenderOnce for Modal {
                     .when(self.overlay_visible, |this| {
                         this.bg(overlay_color(self.overlay, cx))
                     })
-                    .on_mouse_down(MouseButton::Left, {
-                        let on_close = self.on_close.clone();
-                        move |_, cx| {
-                            on_close(&ClickEvent::default(), cx);
-                            cx.close_modal();
-                        }
+                    .when(self.dismissable, |this| {
+                        this.on_mouse_down(MouseButton::Left, {
+                            let on_close = self.on_close.clone();
+                            move |_, cx| {
+                                on_close(&ClickEvent::default(), cx);
+                                cx.close_modal();
+                            }
+                        })
                     })
                     .child(
                         self.base
                             .id(SharedString::from(format!("modal-{layer_ix}")))
                             .key_context(CONTEXT)
                             .track_focus(&self.focus_handle)
-                            .when(self.keyboard, |this| {
+                            .on_action({
+                                let tab_stops = tab_stops.clone();
+                                let focus_handle = self.focus_handle.clone();
+                                move |_: &Tab, cx| cycle_focus(&tab_stops, &focus_handle, true, cx)
+                            })
+                            .on_action({
+                                let focus_handle = self.focus_handle.clone();
+                                move |_: &TabPrev, cx| {
+                                    cycle_focus(&tab_stops, &focus_handle, false, cx)
+                                }
+                            })
+                            .when(self.keyboard && self.dismissable, |this| {
                                 this.on_action({
                                     let on_close = self.on_close.clone();
                                     move |_: &Escape, cx| {
@@ -245,8 +308,9 @@ impl RenderOnce for Modal {
                             .children(self.footer)
                             .with_animation(
                                 "slide-down",
-                                Animation::new(Duration::from_secs_f64(0.25))
-                                    .with_easing(cubic_bezier(0.32, 0.72, 0., 1.)),
+                                Animation::new(Duration::from_secs_f64(0.25)).with_easing(
+                                    reduced_motion(cubic_bezier(0.32, 0.72, 0., 1.), cx),
+                                ),
                                 move |this, delta| {
                                     let y_offset = px(0.) + delta * px(30.);
                                     this.top(y + y_offset).opacity(delta)
@@ -256,3 +320,126 @@ impl RenderOnce for Modal {
             )
     }
 }
+
+/// A dialog to confirm an action, with the title, message, and the confirm / cancel buttons.
+///
+/// ```ignore
+/// ConfirmDialog::new("Delete the file?")
+///     .message("This action cannot be undone.")
+///     .danger(true)
+///     .on_result(|confirmed, cx| {
+///         if confirmed {
+///             // Delete the file
+///         }
+///     })
+///     .open(cx);
+/// ```
+pub struct ConfirmDialog {
+    title: SharedString,
+    message: Option<SharedString>,
+    confirm_label: Option<SharedString>,
+    cancel_label: Option<SharedString>,
+    danger: bool,
+    on_result: Rc<dyn Fn(bool, &mut WindowContext)>,
+}
+
+impl ConfirmDialog {
+    pub fn new(title: impl Into<SharedString>) -> Self {
+        Self {
+            title: title.into(),
+            message: None,
+            confirm_label: None,
+            cancel_label: None,
+            danger: false,
+            on_result: Rc::new(|_, _| {}),
+        }
+    }
+
+    /// Set the message below the title.
+    pub fn message(mut self, message: impl Into<SharedString>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// Set the label of the confirm button, defaults to `OK`.
+    pub fn confirm_label(mut self, label: impl Into<SharedString>) -> Self {
+        self.confirm_label = Some(label.into());
+        self
+    }
+
+    /// Set the label of the cancel button, defaults to `Cancel`.
+    pub fn cancel_label(mut self, label: impl Into<SharedString>) -> Self {
+        self.cancel_label = Some(label.into());
+        self
+    }
+
+    /// Set to use the danger style for the confirm button, for a destructive action.
+    pub fn danger(mut self, danger: bool) -> Self {
+        self.danger = danger;
+        self
+    }
+
+    /// Set the callback with the result, `true` if confirmed.
+    ///
+    /// The Escape and clicking the overlay are treated as cancel.
+    pub fn on_result(mut self, on_result: impl Fn(bool, &mut WindowContext) + 'static) -> Self {
+        self.on_result = Rc::new(on_result);
+        self
+    }
+
+    /// Open the dialog in the modal layer.
+    pub fn open(self, cx: &mut WindowContext) {
+        let dialog = Rc::new(self);
+
+        cx.open_modal(move |modal, _| {
+            let on_result = dialog.on_result.clone();
+            let confirm_label = dialog
+                .confirm_label
+                .clone()
+                .unwrap_or_else(|| t!("Modal.OK").into());
+            let cancel_label = dialog
+                .cancel_label
+                .clone()
+                .unwrap_or_else(|| t!("Modal.Cancel").into());
+
+            modal
+                .title(dialog.title.clone())
+                .show_close(false)
+                .width(px(420.))
+                .min_h_0()
+                .on_close({
+                    let on_result = on_result.clone();
+                    move |_, cx| on_result(false, cx)
+                })
+                .when_some(dialog.message.clone(), |this, message| this.child(message))
+                .footer(
+                    h_flex()
+                        .justify_end()
+                        .gap_2()
+                        .child(Button::new("cancel").label(cancel_label).on_click({
+                            let on_result = on_result.clone();
+                            move |_, cx| {
+                                // Close first to restore the focus before the callback.
+                                cx.close_modal();
+                                on_result(false, cx);
+                            }
+                        }))
+                        .child(
+                            Button::new("confirm")
+                                .label(confirm_label)
+                                .map(|this| {
+                                    if dialog.danger {
+                                        this.danger()
+                                    } else {
+                                        this.primary()
+                                    }
+                                })
+                                .on_click(move |_, cx| {
+                                    cx.close_modal();
+                                    on_result(true, cx);
+                                }),
+                        ),
+                )
+        });
+    }
+}
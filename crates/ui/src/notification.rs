@@ -1,14 +1,15 @@
 use std::{any::TypeId, collections::VecDeque, sync::Arc, time::Duration};
 
 use gpui::{
-    div, prelude::FluentBuilder, px, Animation, AnimationExt, ClickEvent, DismissEvent, ElementId,
-    EventEmitter, InteractiveElement as _, IntoElement, ParentElement as _, Render, SharedString,
-    StatefulInteractiveElement, Styled, View, ViewContext, VisualContext, WindowContext,
+    div, prelude::FluentBuilder, px, Animation, AnimationExt, ClickEvent, Corner, DismissEvent,
+    ElementId, EventEmitter, InteractiveElement as _, IntoElement, ParentElement as _, Render,
+    SharedString, StatefulInteractiveElement, Styled, Task, View, ViewContext, VisualContext,
+    WindowContext,
 };
 use smol::Timer;
 
 use crate::{
-    animation::cubic_bezier,
+    animation::{cubic_bezier, reduced_motion},
     button::{Button, ButtonVariants as _},
     h_flex,
     theme::ActiveTheme as _,
@@ -28,6 +29,14 @@ pub(crate) enum NotificationId {
     IdAndElementId(TypeId, ElementId),
 }
 
+impl NotificationId {
+    fn type_id(&self) -> TypeId {
+        match self {
+            Self::Id(type_id) | Self::IdAndElementId(type_id, _) => *type_id,
+        }
+    }
+}
+
 impl From<TypeId> for NotificationId {
     fn from(type_id: TypeId) -> Self {
         Self::Id(type_id)
@@ -52,8 +61,13 @@ pub struct Notification {
     message: SharedString,
     icon: Option<Icon>,
     autohide: bool,
+    timeout: Duration,
     on_click: Option<Arc<dyn Fn(&ClickEvent, &mut WindowContext)>>,
+    action: Option<(SharedString, Arc<dyn Fn(&mut WindowContext)>)>,
     closing: bool,
+    /// The autohide timer is paused while hovering.
+    hovered: bool,
+    _autohide_task: Option<Task<()>>,
 }
 
 impl From<String> for Notification {
@@ -102,8 +116,12 @@ impl Notification {
             type_: NotificationType::Info,
             icon: None,
             autohide: true,
+            timeout: Duration::from_secs(5),
             on_click: None,
+            action: None,
             closing: false,
+            hovered: false,
+            _autohide_task: None,
         }
     }
 
@@ -168,6 +186,26 @@ impl Notification {
         self
     }
 
+    /// Set the time to auto hide the notification, default is 5 seconds.
+    ///
+    /// The time is paused while the mouse is hovering the notification.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Add an action button to the notification, e.g. `Undo`.
+    ///
+    /// The notification will be dismissed after the `on_action` is called.
+    pub fn action(
+        mut self,
+        label: impl Into<SharedString>,
+        on_action: impl Fn(&mut WindowContext) + 'static,
+    ) -> Self {
+        self.action = Some((label.into(), Arc::new(on_action)));
+        self
+    }
+
     /// Set the click callback of the notification.
     pub fn on_click(
         mut self,
@@ -177,6 +215,26 @@ impl Notification {
         self
     }
 
+    /// Start the timer to dismiss the notification after the timeout, it pauses while hovering.
+    fn start_autohide(&mut self, cx: &mut ViewContext<Self>) {
+        let timeout = self.timeout;
+        self._autohide_task = Some(cx.spawn(|view, mut cx| async move {
+            let tick = Duration::from_millis(100);
+            let mut remaining = timeout;
+            while !remaining.is_zero() {
+                Timer::after(tick).await;
+                let Ok(hovered) = view.update(&mut cx, |view, _| view.hovered) else {
+                    return;
+                };
+                if !hovered {
+                    remaining = remaining.saturating_sub(tick);
+                }
+            }
+
+            _ = view.update(&mut cx, |view, cx| view.dismiss(&ClickEvent::default(), cx));
+        }));
+    }
+
     fn dismiss(&mut self, _: &ClickEvent, cx: &mut ViewContext<Self>) {
         self.closing = true;
         cx.notify();
@@ -204,15 +262,15 @@ impl Render for Notification {
         let icon = match self.icon.clone() {
             Some(icon) => icon,
             None => match self.type_ {
-                NotificationType::Info => Icon::new(IconName::Info).text_color(crate::blue_500()),
+                NotificationType::Info => Icon::new(IconName::Info).text_color(cx.theme().info),
                 NotificationType::Success => {
-                    Icon::new(IconName::CircleCheck).text_color(crate::green_500())
+                    Icon::new(IconName::CircleCheck).text_color(cx.theme().success)
                 }
                 NotificationType::Warning => {
-                    Icon::new(IconName::TriangleAlert).text_color(crate::yellow_500())
+                    Icon::new(IconName::TriangleAlert).text_color(cx.theme().warning)
                 }
                 NotificationType::Error => {
-                    Icon::new(IconName::CircleX).text_color(crate::red_500())
+                    Icon::new(IconName::CircleX).text_color(cx.theme().danger)
                 }
             },
         };
@@ -231,6 +289,7 @@ impl Render for Notification {
             .py_2()
             .px_4()
             .gap_3()
+            .on_hover(cx.listener(|view, hovered, _| view.hovered = *hovered))
             .child(div().absolute().top_3().left_4().child(icon))
             .child(
                 v_flex()
@@ -240,7 +299,21 @@ impl Render for Notification {
                         this.child(div().text_sm().font_semibold().child(title))
                     })
                     .overflow_hidden()
-                    .child(div().text_sm().child(self.message.clone())),
+                    .child(div().text_sm().child(self.message.clone()))
+                    .when_some(self.action.clone(), |this, (label, on_action)| {
+                        this.child(
+                            h_flex().pt_1().child(
+                                Button::new("action")
+                                    .label(label)
+                                    .small()
+                                    .on_click(cx.listener(move |view, event, cx| {
+                                        cx.stop_propagation();
+                                        view.dismiss(event, cx);
+                                        on_action(cx);
+                                    })),
+                            ),
+                        )
+                    }),
             )
             .when_some(self.on_click.clone(), |this, on_click| {
                 this.cursor_pointer()
@@ -269,7 +342,7 @@ impl Render for Notification {
             .with_animation(
                 ElementId::NamedInteger("slide-down".into(), closing as usize),
                 Animation::new(Duration::from_secs_f64(0.15))
-                    .with_easing(cubic_bezier(0.4, 0., 0.2, 1.)),
+                    .with_easing(reduced_motion(cubic_bezier(0.4, 0., 0.2, 1.), cx)),
                 move |this, delta| {
                     if closing {
                         let x_offset = px(0.) + delta * px(45.);
@@ -287,6 +360,10 @@ impl Render for Notification {
 pub struct NotificationList {
     /// Notifications that will be auto hidden.
     pub(crate) notifications: VecDeque<View<Notification>>,
+    /// Notifications waiting to show, when the visible ones reach the `max_visible`.
+    queue: VecDeque<Notification>,
+    placement: Corner,
+    max_visible: usize,
     expanded: bool,
 }
 
@@ -294,43 +371,84 @@ impl NotificationList {
     pub fn new(_cx: &mut ViewContext<Self>) -> Self {
         Self {
             notifications: VecDeque::new(),
+            queue: VecDeque::new(),
+            placement: Corner::TopRight,
+            max_visible: 10,
             expanded: false,
         }
     }
 
+    /// Set the corner of the window to show the notifications, default is `Corner::TopRight`.
+    pub fn set_placement(&mut self, placement: Corner, cx: &mut ViewContext<Self>) {
+        self.placement = placement;
+        cx.notify();
+    }
+
+    /// Set the max number of the visible notifications, default is 10.
+    ///
+    /// The more notifications will be queued, and shown when the visible ones are dismissed.
+    pub fn set_max_visible(&mut self, max_visible: usize, cx: &mut ViewContext<Self>) {
+        self.max_visible = max_visible.max(1);
+        self.show_queued(cx);
+        cx.notify();
+    }
+
     pub fn push(&mut self, notification: impl Into<Notification>, cx: &mut ViewContext<Self>) {
         let notification = notification.into();
-        let id = notification.id.clone();
-        let autohide = notification.autohide;
 
         // Remove the notification by id, for keep unique.
+        let id = notification.id.clone();
         self.notifications.retain(|note| note.read(cx).id != id);
+        self.queue.retain(|note| note.id != id);
+
+        if self.notifications.len() >= self.max_visible {
+            self.queue.push_back(notification);
+        } else {
+            self.show(notification, cx);
+        }
+        cx.notify();
+    }
+
+    fn show(&mut self, notification: Notification, cx: &mut ViewContext<Self>) {
+        let id = notification.id.clone();
+        let autohide = notification.autohide;
 
         let notification = cx.new_view(|_| notification);
         cx.subscribe(&notification, move |view, _, _: &DismissEvent, cx| {
             view.notifications.retain(|note| id != note.read(cx).id);
+            view.show_queued(cx);
+            cx.notify();
         })
         .detach();
 
-        self.notifications.push_back(notification.clone());
         if autohide {
-            // Sleep for 5 seconds to autohide the notification
-            cx.spawn(|_, mut cx| async move {
-                Timer::after(Duration::from_secs(5)).await;
-
-                if let Err(err) = notification
-                    .update(&mut cx, |note, cx| note.dismiss(&ClickEvent::default(), cx))
-                {
-                    println!("failed to auto hide notification: {:?}", err);
-                }
-            })
-            .detach();
+            notification.update(cx, |note, cx| note.start_autohide(cx));
+        }
+        self.notifications.push_back(notification);
+    }
+
+    fn show_queued(&mut self, cx: &mut ViewContext<Self>) {
+        while self.notifications.len() < self.max_visible {
+            let Some(notification) = self.queue.pop_front() else {
+                break;
+            };
+            self.show(notification, cx);
         }
+    }
+
+    /// Dismiss the notifications with the id type `T`, both of the `id` and `id1`.
+    pub fn remove<T: Sized + 'static>(&mut self, cx: &mut ViewContext<Self>) {
+        let type_id = TypeId::of::<T>();
+        self.notifications
+            .retain(|note| note.read(cx).id.type_id() != type_id);
+        self.queue.retain(|note| note.id.type_id() != type_id);
+        self.show_queued(cx);
         cx.notify();
     }
 
     pub fn clear(&mut self, cx: &mut ViewContext<Self>) {
         self.notifications.clear();
+        self.queue.clear();
         cx.notify();
     }
 
@@ -342,21 +460,37 @@ impl NotificationList {
 impl Render for NotificationList {
     fn render(&mut self, cx: &mut gpui::ViewContext<Self>) -> impl IntoElement {
         let size = cx.viewport_size();
-        let items = self.notifications.iter().rev().take(10).rev().cloned();
+        let items = self.notifications.iter().cloned();
+        let placement = self.placement;
+        let is_left = matches!(placement, Corner::TopLeft | Corner::BottomLeft);
+        let is_bottom = matches!(placement, Corner::BottomLeft | Corner::BottomRight);
 
         div()
             .absolute()
             .flex()
             .top_4()
             .bottom_4()
-            .right_4()
+            .map(|this| {
+                if is_left {
+                    this.left_4()
+                } else {
+                    this.right_4()
+                }
+            })
             .justify_end()
             .child(
                 v_flex()
                     .id("notification-list")
                     .absolute()
                     .relative()
-                    .right_0()
+                    .map(|this| {
+                        if is_left {
+                            this.left_0()
+                        } else {
+                            this.right_0()
+                        }
+                    })
+                    .when(is_bottom, |this| this.justify_end())
                     .h(size.height - px(8.))
                     .on_hover(cx.listener(|view, hovered, cx| {
                         view.expanded = *hovered;
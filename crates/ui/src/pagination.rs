@@ -0,0 +1,496 @@
+use gpui::{
+    actions, div, prelude::FluentBuilder as _, px, AppContext, ElementId, EventEmitter,
+    FocusHandle, FocusableView, InteractiveElement as _, IntoElement, KeyBinding,
+    ParentElement as _, Render, SharedString, Styled as _, Subscription, View, ViewContext,
+    VisualContext as _,
+};
+
+use crate::{
+    button::{Button, ButtonVariants as _},
+    dropdown::{Dropdown, DropdownEvent, DropdownItem},
+    h_flex,
+    theme::ActiveTheme as _,
+    Disableable as _, Icon, IconName, Selectable as _, Sizable, Size, StyledExt as _,
+};
+
+actions!(pagination, [FirstPage, PrevPage, NextPage, LastPage]);
+
+const CONTEXT: &str = "Pagination";
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("left", PrevPage, Some(CONTEXT)),
+        KeyBinding::new("right", NextPage, Some(CONTEXT)),
+        KeyBinding::new("home", FirstPage, Some(CONTEXT)),
+        KeyBinding::new("end", LastPage, Some(CONTEXT)),
+    ]);
+}
+
+pub enum PaginationEvent {
+    /// The page (starts from 1) is changed by the user.
+    PageChanged(usize),
+    /// The page size is changed by the page size dropdown, the page will be reset to 1.
+    PageSizeChanged(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PageItem {
+    Page(usize),
+    Ellipsis,
+}
+
+/// Returns the numbered pages to show around the `current` page, the hidden pages are
+/// replaced by an ellipsis.
+///
+/// The first and the last pages are always shown, with `siblings` pages on each side
+/// of the current page, so the count of the items is constant while paging.
+fn page_items(current: usize, total: usize, siblings: usize) -> Vec<PageItem> {
+    // The first, last, current and 2 ellipses.
+    let max_items = siblings * 2 + 5;
+    if total <= max_items {
+        return (1..=total).map(PageItem::Page).collect();
+    }
+
+    let current = current.clamp(1, total);
+    let left = current.saturating_sub(siblings).max(1);
+    let right = (current + siblings).min(total);
+    // Only show the ellipsis when it hides 2 pages at least.
+    let show_left_ellipsis = left > 3;
+    let show_right_ellipsis = right + 2 < total;
+
+    let mut items = vec![];
+    match (show_left_ellipsis, show_right_ellipsis) {
+        (false, _) => {
+            items.extend((1..=max_items - 2).map(PageItem::Page));
+            items.push(PageItem::Ellipsis);
+            items.push(PageItem::Page(total));
+        }
+        (true, false) => {
+            items.push(PageItem::Page(1));
+            items.push(PageItem::Ellipsis);
+            items.extend((total + 3 - max_items..=total).map(PageItem::Page));
+        }
+        (true, true) => {
+            items.push(PageItem::Page(1));
+            items.push(PageItem::Ellipsis);
+            items.extend((left..=right).map(PageItem::Page));
+            items.push(PageItem::Ellipsis);
+            items.push(PageItem::Page(total));
+        }
+    }
+    items
+}
+
+/// Returns the number of pages for the `total_items`, at least 1 page.
+fn pages_for_items(total_items: usize, page_size: usize) -> usize {
+    total_items.div_ceil(page_size.max(1)).max(1)
+}
+
+struct PageSizeItem {
+    size: usize,
+    title: SharedString,
+}
+
+impl DropdownItem for PageSizeItem {
+    type Value = usize;
+
+    fn title(&self) -> SharedString {
+        self.title.clone()
+    }
+
+    fn value(&self) -> &Self::Value {
+        &self.size
+    }
+}
+
+/// A pagination control with the first/prev/next/last buttons and the numbered pages.
+///
+/// When the total is unknown, only the prev/next buttons are shown,
+/// use `set_has_next` to disable the next button on the last page.
+///
+/// ```ignore
+/// let pagination = cx.new_view(|cx| {
+///     Pagination::new("users", cx)
+///         .total_items(1234, 20)
+///         .page_sizes(vec![20, 50, 100], cx)
+/// });
+/// ```
+pub struct Pagination {
+    id: ElementId,
+    focus_handle: FocusHandle,
+    /// The current page, starts from 1.
+    page: usize,
+    /// The total pages, `None` for unknown.
+    total_pages: Option<usize>,
+    total_items: Option<usize>,
+    page_size: usize,
+    /// Whether has the next page, only for the unknown total.
+    has_next: bool,
+    siblings: usize,
+    hide_on_single_page: bool,
+    disabled: bool,
+    size: Size,
+    page_size_dropdown: Option<View<Dropdown<Vec<PageSizeItem>>>>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl Pagination {
+    pub fn new(id: impl Into<ElementId>, cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            id: id.into(),
+            focus_handle: cx.focus_handle(),
+            page: 1,
+            total_pages: None,
+            total_items: None,
+            page_size: 10,
+            has_next: true,
+            siblings: 1,
+            hide_on_single_page: true,
+            disabled: false,
+            size: Size::default(),
+            page_size_dropdown: None,
+            _subscriptions: vec![],
+        }
+    }
+
+    /// Set the total pages.
+    pub fn total_pages(mut self, total_pages: usize) -> Self {
+        self.total_pages = Some(total_pages.max(1));
+        self.total_items = None;
+        self
+    }
+
+    /// Set the total items and the page size, the total pages are calculated by them.
+    pub fn total_items(mut self, total_items: usize, page_size: usize) -> Self {
+        self.total_items = Some(total_items);
+        self.page_size = page_size.max(1);
+        self.total_pages = Some(pages_for_items(total_items, self.page_size));
+        self
+    }
+
+    /// Set the initial page, starts from 1.
+    pub fn page(mut self, page: usize) -> Self {
+        self.page = page.max(1);
+        self
+    }
+
+    /// Set the number of pages to show on each side of the current page, default: 1
+    pub fn siblings(mut self, siblings: usize) -> Self {
+        self.siblings = siblings;
+        self
+    }
+
+    /// Set to hide the control when there is only one page, default: true
+    pub fn hide_on_single_page(mut self, hide: bool) -> Self {
+        self.hide_on_single_page = hide;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Show a dropdown to change the page size with the `page_sizes`.
+    pub fn page_sizes(mut self, page_sizes: Vec<usize>, cx: &mut ViewContext<Self>) -> Self {
+        let selected_index = page_sizes.iter().position(|size| *size == self.page_size);
+        let items = page_sizes
+            .into_iter()
+            .map(|size| PageSizeItem {
+                size,
+                title: format!("{} / page", size).into(),
+            })
+            .collect::<Vec<_>>();
+        let size = self.size;
+        let dropdown = cx.new_view(|cx| {
+            Dropdown::new("page-size", items, selected_index, cx)
+                .with_size(size)
+                .width(px(120.))
+        });
+        self._subscriptions.push(cx.subscribe(
+            &dropdown,
+            |this, _, event: &DropdownEvent<Vec<PageSizeItem>>, cx| {
+                if let DropdownEvent::Changed(Some(page_size)) = event {
+                    this.change_page_size(*page_size, cx);
+                }
+            },
+        ));
+        self.page_size_dropdown = Some(dropdown);
+        self
+    }
+
+    /// Returns the current page, starts from 1.
+    pub fn current_page(&self) -> usize {
+        self.page
+    }
+
+    /// Returns the total pages, `None` for unknown.
+    pub fn total_page_count(&self) -> Option<usize> {
+        self.total_pages
+    }
+
+    pub fn page_size(&self) -> usize {
+        self.page_size
+    }
+
+    /// Set the current page, this will not emit the [`PaginationEvent::PageChanged`] event.
+    pub fn set_page(&mut self, page: usize, cx: &mut ViewContext<Self>) {
+        self.page = self.clamp_page(page);
+        cx.notify();
+    }
+
+    /// Set the total pages, `None` for unknown.
+    pub fn set_total_pages(&mut self, total_pages: Option<usize>, cx: &mut ViewContext<Self>) {
+        self.total_pages = total_pages.map(|total| total.max(1));
+        self.total_items = None;
+        self.page = self.clamp_page(self.page);
+        cx.notify();
+    }
+
+    /// Set the total items, the total pages are calculated by the page size.
+    pub fn set_total_items(&mut self, total_items: usize, cx: &mut ViewContext<Self>) {
+        self.total_items = Some(total_items);
+        self.total_pages = Some(pages_for_items(total_items, self.page_size));
+        self.page = self.clamp_page(self.page);
+        cx.notify();
+    }
+
+    /// Set whether has the next page, only used when the total is unknown.
+    pub fn set_has_next(&mut self, has_next: bool, cx: &mut ViewContext<Self>) {
+        self.has_next = has_next;
+        cx.notify();
+    }
+
+    fn clamp_page(&self, page: usize) -> usize {
+        match self.total_pages {
+            Some(total) => page.clamp(1, total),
+            None => page.max(1),
+        }
+    }
+
+    fn has_prev_page(&self) -> bool {
+        self.page > 1
+    }
+
+    fn has_next_page(&self) -> bool {
+        match self.total_pages {
+            Some(total) => self.page < total,
+            None => self.has_next,
+        }
+    }
+
+    fn change_page(&mut self, page: usize, cx: &mut ViewContext<Self>) {
+        if self.disabled {
+            return;
+        }
+
+        let page = self.clamp_page(page);
+        if page == self.page {
+            return;
+        }
+
+        self.page = page;
+        cx.emit(PaginationEvent::PageChanged(page));
+        cx.notify();
+    }
+
+    fn change_page_size(&mut self, page_size: usize, cx: &mut ViewContext<Self>) {
+        if page_size == self.page_size {
+            return;
+        }
+
+        self.page_size = page_size.max(1);
+        if let Some(total_items) = self.total_items {
+            self.total_pages = Some(pages_for_items(total_items, self.page_size));
+        }
+        cx.emit(PaginationEvent::PageSizeChanged(self.page_size));
+        if self.page != 1 {
+            self.page = 1;
+            cx.emit(PaginationEvent::PageChanged(1));
+        }
+        cx.notify();
+    }
+
+    fn first_page(&mut self, _: &FirstPage, cx: &mut ViewContext<Self>) {
+        if self.total_pages.is_some() {
+            self.change_page(1, cx);
+        }
+    }
+
+    fn prev_page(&mut self, _: &PrevPage, cx: &mut ViewContext<Self>) {
+        if self.has_prev_page() {
+            self.change_page(self.page - 1, cx);
+        }
+    }
+
+    fn next_page(&mut self, _: &NextPage, cx: &mut ViewContext<Self>) {
+        if self.has_next_page() {
+            self.change_page(self.page + 1, cx);
+        }
+    }
+
+    fn last_page(&mut self, _: &LastPage, cx: &mut ViewContext<Self>) {
+        if let Some(total) = self.total_pages {
+            self.change_page(total, cx);
+        }
+    }
+
+    fn render_nav_button(
+        &self,
+        id: &'static str,
+        icon: IconName,
+        disabled: bool,
+        on_click: impl Fn(&mut Self, &mut ViewContext<Self>) + 'static,
+        cx: &mut ViewContext<Self>,
+    ) -> Button {
+        Button::new(id)
+            .ghost()
+            .icon(icon)
+            .with_size(self.size)
+            .disabled(self.disabled || disabled)
+            .on_click(cx.listener(move |this, _, cx| {
+                this.focus_handle.focus(cx);
+                on_click(this, cx);
+            }))
+    }
+}
+
+impl Sizable for Pagination {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl EventEmitter<PaginationEvent> for Pagination {}
+
+impl FocusableView for Pagination {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for Pagination {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        if self.hide_on_single_page && self.total_pages == Some(1) {
+            return div().id(self.id.clone());
+        }
+
+        let has_prev = self.has_prev_page();
+        let has_next = self.has_next_page();
+
+        h_flex()
+            .id(self.id.clone())
+            .key_context(CONTEXT)
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::first_page))
+            .on_action(cx.listener(Self::prev_page))
+            .on_action(cx.listener(Self::next_page))
+            .on_action(cx.listener(Self::last_page))
+            .gap_1()
+            .p_0p5()
+            .rounded(px(cx.theme().radius))
+            .border_1()
+            .border_color(cx.theme().transparent)
+            .focus_ring(&self.focus_handle, cx)
+            .when(self.total_pages.is_some(), |this| {
+                this.child(self.render_nav_button(
+                    "first",
+                    IconName::ChevronsLeft,
+                    !has_prev,
+                    |this, cx| this.change_page(1, cx),
+                    cx,
+                ))
+            })
+            .child(self.render_nav_button(
+                "prev",
+                IconName::ChevronLeft,
+                !has_prev,
+                |this, cx| this.change_page(this.page - 1, cx),
+                cx,
+            ))
+            .when_some(self.total_pages, |this, total| {
+                this.children(
+                    page_items(self.page, total, self.siblings)
+                        .into_iter()
+                        .enumerate()
+                        .map(|(ix, item)| match item {
+                            PageItem::Page(page) => Button::new(("page", page))
+                                .ghost()
+                                .label(page.to_string())
+                                .with_size(self.size)
+                                .selected(page == self.page)
+                                .disabled(self.disabled)
+                                .on_click(cx.listener(move |this, _, cx| {
+                                    this.focus_handle.focus(cx);
+                                    this.change_page(page, cx);
+                                }))
+                                .into_any_element(),
+                            PageItem::Ellipsis => h_flex()
+                                .id(("ellipsis", ix))
+                                .px_1()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(Icon::new(IconName::Ellipsis).with_size(self.size))
+                                .into_any_element(),
+                        }),
+                )
+            })
+            .child(self.render_nav_button(
+                "next",
+                IconName::ChevronRight,
+                !has_next,
+                |this, cx| this.change_page(this.page + 1, cx),
+                cx,
+            ))
+            .when_some(self.total_pages, |this, total| {
+                this.child(self.render_nav_button(
+                    "last",
+                    IconName::ChevronsRight,
+                    !has_next,
+                    move |this, cx| this.change_page(total, cx),
+                    cx,
+                ))
+            })
+            .when_some(self.page_size_dropdown.clone(), |this, dropdown| {
+                this.child(div().ml_2().child(dropdown))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{page_items, pages_for_items, PageItem};
+
+    fn format_items(items: Vec<PageItem>) -> String {
+        items
+            .iter()
+            .map(|item| match item {
+                PageItem::Page(page) => page.to_string(),
+                PageItem::Ellipsis => "...".to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    #[test]
+    fn test_page_items() {
+        assert_eq!(format_items(page_items(1, 5, 1)), "1 2 3 4 5");
+        assert_eq!(format_items(page_items(1, 10, 1)), "1 2 3 4 5 ... 10");
+        assert_eq!(format_items(page_items(4, 10, 1)), "1 2 3 4 5 ... 10");
+        assert_eq!(format_items(page_items(5, 10, 1)), "1 ... 4 5 6 ... 10");
+        assert_eq!(format_items(page_items(7, 10, 1)), "1 ... 6 7 8 9 10");
+        assert_eq!(format_items(page_items(10, 10, 1)), "1 ... 6 7 8 9 10");
+        assert_eq!(
+            format_items(page_items(5000, 10000, 2)),
+            "1 ... 4998 4999 5000 5001 5002 ... 10000"
+        );
+    }
+
+    #[test]
+    fn test_pages_for_items() {
+        assert_eq!(pages_for_items(0, 10), 1);
+        assert_eq!(pages_for_items(10, 10), 1);
+        assert_eq!(pages_for_items(11, 10), 2);
+        assert_eq!(pages_for_items(5, 0), 5);
+    }
+}
@@ -1,13 +1,16 @@
 use gpui::{
-    actions, anchored, deferred, div, prelude::FluentBuilder as _, px, AnyElement, AppContext,
-    Bounds, Corner, DismissEvent, DispatchPhase, Element, ElementId, EventEmitter, FocusHandle,
-    FocusableView, GlobalElementId, Hitbox, InteractiveElement as _, IntoElement, KeyBinding,
-    LayoutId, ManagedView, MouseButton, MouseDownEvent, ParentElement, Pixels, Point, Render,
-    Style, StyleRefinement, Styled, View, ViewContext, VisualContext, WindowContext,
+    actions, anchored, canvas, deferred, div, point, prelude::FluentBuilder as _, px, AnyElement,
+    AppContext, Bounds, Corner, DismissEvent, DispatchPhase, Element, ElementId, EventEmitter,
+    FocusHandle, FocusableView, GlobalElementId, Hitbox, InteractiveElement as _, IntoElement,
+    KeyBinding, LayoutId, ManagedView, MouseButton, MouseDownEvent, ParentElement, Pixels, Point,
+    Render, Size, Style, StyleRefinement, Styled, View, ViewContext, VisualContext, WindowContext,
+};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
 };
-use std::{cell::RefCell, rc::Rc};
 
-use crate::{Selectable, StyledExt as _};
+use crate::{theme::ActiveTheme as _, Placement, Selectable, StyledExt as _};
 
 const CONTEXT: &str = "Popover";
 
@@ -62,9 +65,135 @@ impl Render for PopoverContent {
     }
 }
 
+/// The alignment of the popover along the side of the trigger.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum PopoverAlign {
+    #[default]
+    Start,
+    Center,
+    End,
+}
+
+/// The gap between the trigger and the popover.
+const GAP: f32 = 6.;
+/// The size of the arrow, the width is double of it.
+const ARROW_SIZE: f32 = 6.;
+
+/// Returns the placement flipped to the opposite side if the popover will overflow the window
+/// and the opposite side has enough room.
+fn resolve_placement(
+    placement: Placement,
+    trigger: Bounds<Pixels>,
+    size: Size<Pixels>,
+    viewport: Size<Pixels>,
+) -> Placement {
+    let gap = px(GAP);
+    let fits_top = trigger.top() - gap - size.height >= px(0.);
+    let fits_bottom = trigger.bottom() + gap + size.height <= viewport.height;
+    let fits_left = trigger.left() - gap - size.width >= px(0.);
+    let fits_right = trigger.right() + gap + size.width <= viewport.width;
+
+    match placement {
+        Placement::Bottom if !fits_bottom && fits_top => Placement::Top,
+        Placement::Top if !fits_top && fits_bottom => Placement::Bottom,
+        Placement::Right if !fits_right && fits_left => Placement::Left,
+        Placement::Left if !fits_left && fits_right => Placement::Right,
+        _ => placement,
+    }
+}
+
+/// Returns the anchor corner and the position of the popover at the side of the trigger.
+fn placement_position(
+    placement: Placement,
+    align: PopoverAlign,
+    trigger: Bounds<Pixels>,
+    size: Size<Pixels>,
+) -> (Corner, Point<Pixels>) {
+    let gap = px(GAP);
+    let center = trigger.center();
+
+    match placement {
+        Placement::Bottom | Placement::Top => {
+            let (y, start, end) = if placement == Placement::Bottom {
+                (trigger.bottom() + gap, Corner::TopLeft, Corner::TopRight)
+            } else {
+                (trigger.top() - gap, Corner::BottomLeft, Corner::BottomRight)
+            };
+
+            match align {
+                PopoverAlign::Start => (start, point(trigger.left(), y)),
+                PopoverAlign::Center => (start, point(center.x - size.width / 2., y)),
+                PopoverAlign::End => (end, point(trigger.right(), y)),
+            }
+        }
+        Placement::Left | Placement::Right => {
+            let (x, start, end) = if placement == Placement::Right {
+                (trigger.right() + gap, Corner::TopLeft, Corner::BottomLeft)
+            } else {
+                (trigger.left() - gap, Corner::TopRight, Corner::BottomRight)
+            };
+
+            match align {
+                PopoverAlign::Start => (start, point(x, trigger.top())),
+                PopoverAlign::Center => (start, point(x, center.y - size.height / 2.)),
+                PopoverAlign::End => (end, point(x, trigger.bottom())),
+            }
+        }
+    }
+}
+
+/// Render the arrow at the edge of the popover facing the trigger,
+/// the `offset` is the position of the trigger center along the edge.
+fn render_arrow(
+    placement: Placement,
+    offset: Pixels,
+    popover_size: Size<Pixels>,
+    cx: &WindowContext,
+) -> impl IntoElement {
+    let size = px(ARROW_SIZE);
+    let length = match placement {
+        Placement::Top | Placement::Bottom => popover_size.width,
+        Placement::Left | Placement::Right => popover_size.height,
+    };
+    // Keep the arrow inside the rounded corners.
+    let offset = (offset - size).min(length - size * 3.).max(size);
+    let color = cx.theme().popover_background();
+
+    canvas(
+        |_, _| {},
+        move |bounds, _, cx| {
+            let (w, h) = (bounds.size.width, bounds.size.height);
+            let points = match placement {
+                // The popover is below the trigger, the arrow points up.
+                Placement::Bottom => [point(px(0.), h), point(w / 2., px(0.)), point(w, h)],
+                Placement::Top => [point(px(0.), px(0.)), point(w / 2., h), point(w, px(0.))],
+                Placement::Right => [point(w, px(0.)), point(px(0.), h / 2.), point(w, h)],
+                Placement::Left => [point(px(0.), px(0.)), point(w, h / 2.), point(px(0.), h)],
+            };
+
+            let mut path = gpui::Path::new(bounds.origin + points[0]);
+            path.line_to(bounds.origin + points[1]);
+            path.line_to(bounds.origin + points[2]);
+            path.line_to(bounds.origin + points[0]);
+            cx.paint_path(path, color);
+        },
+    )
+    .absolute()
+    .map(|this| match placement {
+        Placement::Bottom => this.top(-size).left(offset).w(size * 2.).h(size),
+        Placement::Top => this.bottom(-size).left(offset).w(size * 2.).h(size),
+        Placement::Right => this.left(-size).top(offset).w(size).h(size * 2.),
+        Placement::Left => this.right(-size).top(offset).w(size).h(size * 2.),
+    })
+}
+
 pub struct Popover<M: ManagedView> {
     id: ElementId,
     anchor: Corner,
+    placement: Option<Placement>,
+    align: PopoverAlign,
+    arrow: bool,
+    focus_on_open: bool,
     trigger: Option<Box<dyn FnOnce(bool, &WindowContext) -> AnyElement + 'static>>,
     content: Option<Rc<dyn Fn(&mut WindowContext) -> View<M> + 'static>>,
     /// Style for trigger element.
@@ -83,6 +212,10 @@ where
         Self {
             id: id.into(),
             anchor: Corner::TopLeft,
+            placement: None,
+            align: PopoverAlign::Start,
+            arrow: false,
+            focus_on_open: true,
             trigger: None,
             trigger_style: None,
             content: None,
@@ -96,6 +229,38 @@ where
         self
     }
 
+    /// Set the placement of the popover at the side of the trigger, this will override the `anchor`.
+    ///
+    /// The popover will be flipped to the opposite side if there is no room.
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = Some(placement);
+        self
+    }
+
+    /// Set the alignment along the side of the trigger, default is `PopoverAlign::Start`.
+    ///
+    /// This only works with the `placement`.
+    pub fn align(mut self, align: PopoverAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Set to show an arrow pointing to the trigger, default is `false`.
+    ///
+    /// This only works with the `placement`.
+    pub fn arrow(mut self, arrow: bool) -> Self {
+        self.arrow = arrow;
+        self
+    }
+
+    /// Set whether to focus the content when the popover is opened, default is `true`.
+    ///
+    /// Disable it to keep the focus on the trigger, e.g. for a hint popover.
+    pub fn focus_on_open(mut self, focus_on_open: bool) -> Self {
+        self.focus_on_open = focus_on_open;
+        self
+    }
+
     /// Set the mouse button to trigger the popover, default is `MouseButton::Left`.
     pub fn mouse_button(mut self, mouse_button: MouseButton) -> Self {
         self.mouse_button = mouse_button;
@@ -192,6 +357,8 @@ pub struct PopoverElementState<M> {
     content_view: Rc<RefCell<Option<View<M>>>>,
     /// Trigger bounds for positioning the popover.
     trigger_bounds: Option<Bounds<Pixels>>,
+    /// The bounds of the popover in last frame, for flipping the placement.
+    popover_bounds: Rc<Cell<Bounds<Pixels>>>,
 }
 
 impl<M> Default for PopoverElementState<M> {
@@ -203,6 +370,7 @@ impl<M> Default for PopoverElementState<M> {
             trigger_element: None,
             content_view: Rc::new(RefCell::new(None)),
             trigger_bounds: None,
+            popover_bounds: Rc::new(Cell::new(Bounds::default())),
         }
     }
 }
@@ -249,28 +417,87 @@ impl<M: ManagedView> Element for Popover<M> {
             if let Some(content_view) = element_state.content_view.borrow_mut().as_mut() {
                 is_open = true;
 
+                let popover_size = element_state.popover_bounds.get().size;
                 let mut anchored = anchored()
                     .snap_to_window_with_margin(px(8.))
                     .anchor(view.anchor);
+                let mut placement = None;
                 if let Some(trigger_bounds) = element_state.trigger_bounds {
-                    anchored = anchored.position(view.resolved_corner(trigger_bounds));
+                    anchored = match view.placement {
+                        Some(desired) => {
+                            let resolved = resolve_placement(
+                                desired,
+                                trigger_bounds,
+                                popover_size,
+                                cx.viewport_size(),
+                            );
+                            let (corner, position) = placement_position(
+                                resolved,
+                                view.align,
+                                trigger_bounds,
+                                popover_size,
+                            );
+                            placement = Some((resolved, corner, position));
+                            anchored.anchor(corner).position(position)
+                        }
+                        None => anchored.position(view.resolved_corner(trigger_bounds)),
+                    };
                 }
 
+                // The arrow points to the center of the trigger.
+                let arrow = placement
+                    .filter(|_| view.arrow)
+                    .zip(element_state.trigger_bounds)
+                    .map(|((placement, corner, position), trigger_bounds)| {
+                        let origin =
+                            Bounds::from_corner_and_size(corner, position, popover_size).origin;
+                        let center = trigger_bounds.center();
+                        let offset = match placement {
+                            Placement::Top | Placement::Bottom => center.x - origin.x,
+                            Placement::Left | Placement::Right => center.y - origin.y,
+                        };
+                        (placement, offset)
+                    });
+
                 let mut element = {
                     let content_view_mut = element_state.content_view.clone();
+                    let content_view_escape = content_view.clone();
+                    let popover_bounds = element_state.popover_bounds.clone();
                     let anchor = view.anchor;
                     let no_style = view.no_style;
+                    let has_placement = placement.is_some();
                     deferred(
                         anchored.child(
                             div()
                                 .size_full()
                                 .occlude()
+                                .key_context(CONTEXT)
+                                .on_action(move |_: &Escape, cx| {
+                                    content_view_escape.update(cx, |_, cx| cx.emit(DismissEvent));
+                                })
                                 .when(!no_style, |this| this.popover_style(cx))
-                                .map(|this| match anchor {
+                                .when(!has_placement, |this| match anchor {
                                     Corner::TopLeft | Corner::TopRight => this.top_1p5(),
                                     Corner::BottomLeft | Corner::BottomRight => this.bottom_1p5(),
                                 })
                                 .child(content_view.clone())
+                                .child(
+                                    canvas(
+                                        move |bounds, cx| {
+                                            // Refresh to apply the placement with the new size.
+                                            if popover_bounds.get().size != bounds.size {
+                                                cx.refresh();
+                                            }
+                                            popover_bounds.set(bounds);
+                                        },
+                                        |_, _, _| {},
+                                    )
+                                    .absolute()
+                                    .size_full(),
+                                )
+                                .when_some(arrow, |this, (placement, offset)| {
+                                    this.child(render_arrow(placement, offset, popover_size, cx))
+                                })
                                 .when(!no_style, |this| {
                                     this.on_mouse_down_out(move |_, cx| {
                                         // Update the element_state.content_view to `None`,
@@ -368,6 +595,7 @@ impl<M: ManagedView> Element for Popover<M> {
             let old_content_view = element_state.content_view.clone();
             let hitbox_id = prepaint.hitbox.id;
             let mouse_button = this.mouse_button;
+            let focus_on_open = this.focus_on_open;
             cx.on_mouse_event(move |event: &MouseDownEvent, phase, cx| {
                 if phase == DispatchPhase::Bubble
                     && event.button == mouse_button
@@ -393,7 +621,9 @@ impl<M: ManagedView> Element for Popover<M> {
                     })
                     .detach();
 
-                    cx.focus_view(&new_content_view);
+                    if focus_on_open {
+                        cx.focus_view(&new_content_view);
+                    }
                     *old_content_view.borrow_mut() = Some(new_content_view);
                     cx.refresh();
                 }
@@ -401,3 +631,54 @@ impl<M: ManagedView> Element for Popover<M> {
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use gpui::{point, px, size, Bounds, Corner};
+
+    use super::{placement_position, resolve_placement, PopoverAlign};
+    use crate::Placement;
+
+    #[test]
+    fn test_placement() {
+        let viewport = size(px(800.), px(600.));
+        let popover = size(px(200.), px(100.));
+        let trigger = Bounds::new(point(px(100.), px(100.)), size(px(80.), px(30.)));
+
+        assert_eq!(
+            resolve_placement(Placement::Bottom, trigger, popover, viewport),
+            Placement::Bottom
+        );
+        assert_eq!(
+            placement_position(Placement::Bottom, PopoverAlign::Start, trigger, popover),
+            (Corner::TopLeft, point(px(100.), px(136.)))
+        );
+        assert_eq!(
+            placement_position(Placement::Bottom, PopoverAlign::Center, trigger, popover),
+            (Corner::TopLeft, point(px(40.), px(136.)))
+        );
+        assert_eq!(
+            placement_position(Placement::Top, PopoverAlign::End, trigger, popover),
+            (Corner::BottomRight, point(px(180.), px(94.)))
+        );
+
+        // Flip to the top, when there is no room at the bottom.
+        let trigger = Bounds::new(point(px(100.), px(520.)), size(px(80.), px(30.)));
+        assert_eq!(
+            resolve_placement(Placement::Bottom, trigger, popover, viewport),
+            Placement::Top
+        );
+        // No room at both sides, keep the placement.
+        let tall = size(px(200.), px(580.));
+        assert_eq!(
+            resolve_placement(Placement::Bottom, trigger, tall, viewport),
+            Placement::Bottom
+        );
+        // Flip to the left.
+        let trigger = Bounds::new(point(px(700.), px(100.)), size(px(80.), px(30.)));
+        assert_eq!(
+            resolve_placement(Placement::Right, trigger, popover, viewport),
+            Placement::Left
+        );
+    }
+}
@@ -15,11 +15,21 @@ use gpui::{
 use crate::scroll::{Scrollbar, ScrollbarState};
 use crate::StyledExt;
 use crate::{
-    button::Button, h_flex, list::ListItem, popover::Popover, theme::ActiveTheme, v_flex, Icon,
-    IconName, Selectable, Sizable as _,
+    button::Button, divider::Divider, h_flex, list::ListItem, popover::Popover, theme::ActiveTheme,
+    v_flex, Icon, IconName, Selectable, Sizable as _,
 };
 
-actions!(menu, [Confirm, Dismiss, SelectNext, SelectPrev]);
+actions!(
+    menu,
+    [
+        Confirm,
+        Dismiss,
+        SelectNext,
+        SelectPrev,
+        SelectLeft,
+        SelectRight
+    ]
+);
 
 pub fn init(cx: &mut AppContext) {
     let context = Some("PopupMenu");
@@ -28,6 +38,8 @@ pub fn init(cx: &mut AppContext) {
         KeyBinding::new("escape", Dismiss, context),
         KeyBinding::new("up", SelectPrev, context),
         KeyBinding::new("down", SelectNext, context),
+        KeyBinding::new("left", SelectLeft, context),
+        KeyBinding::new("right", SelectRight, context),
     ]);
 }
 
@@ -66,6 +78,7 @@ enum PopupMenuItem {
         label: SharedString,
         action: Option<Box<dyn Action>>,
         handler: Rc<dyn Fn(&mut WindowContext)>,
+        disabled: bool,
     },
     ElementItem {
         render: Box<dyn Fn(&mut WindowContext) -> AnyElement + 'static>,
@@ -80,7 +93,10 @@ enum PopupMenuItem {
 
 impl PopupMenuItem {
     fn is_clickable(&self) -> bool {
-        !matches!(self, PopupMenuItem::Separator)
+        !matches!(
+            self,
+            PopupMenuItem::Separator | PopupMenuItem::Item { disabled: true, .. }
+        )
     }
 
     fn is_separator(&self) -> bool {
@@ -119,9 +135,8 @@ impl PopupMenu {
     ) -> View<Self> {
         cx.new_view(|cx| {
             let focus_handle = cx.focus_handle();
-            let _on_blur_subscription = cx.on_blur(&focus_handle, |this: &mut PopupMenu, cx| {
-                this.dismiss(&Dismiss, cx)
-            });
+            let _on_blur_subscription =
+                cx.on_blur(&focus_handle, |this: &mut PopupMenu, cx| this.on_blur(cx));
 
             let menu = Self {
                 focus_handle,
@@ -184,6 +199,7 @@ impl PopupMenu {
             label: label.into(),
             action: None,
             handler: Rc::new(move |cx| cx.open_url(&href)),
+            disabled: false,
         });
         self
     }
@@ -201,6 +217,7 @@ impl PopupMenu {
             label: label.into(),
             action: None,
             handler: Rc::new(move |cx| cx.open_url(&href)),
+            disabled: false,
         });
         self
     }
@@ -232,6 +249,41 @@ impl PopupMenu {
         self
     }
 
+    /// Add Menu Item with disabled state, the disabled item can't be clicked or selected by keyboard.
+    pub fn menu_with_disabled(
+        mut self,
+        label: impl Into<SharedString>,
+        disabled: bool,
+        action: Box<dyn Action>,
+    ) -> Self {
+        self.add_menu_item(label, None, action);
+        if let Some(PopupMenuItem::Item { disabled: d, .. }) = self.menu_items.last_mut() {
+            *d = disabled;
+        }
+        self
+    }
+
+    /// Add Menu Item with a click handler instead of an action.
+    pub fn menu_with_handler(
+        mut self,
+        label: impl Into<SharedString>,
+        icon: Option<Icon>,
+        handler: impl Fn(&mut WindowContext) + 'static,
+    ) -> Self {
+        if icon.is_some() {
+            self.has_icon = true;
+        }
+
+        self.menu_items.push(PopupMenuItem::Item {
+            icon,
+            label: label.into(),
+            action: None,
+            handler: Rc::new(handler),
+            disabled: false,
+        });
+        self
+    }
+
     /// Add Menu Item with custom element render.
     pub fn menu_with_element<F, E>(mut self, builder: F, action: Box<dyn Action>) -> Self
     where
@@ -286,6 +338,7 @@ impl PopupMenu {
             label: label.into(),
             action: Some(action.boxed_clone()),
             handler: self.wrap_handler(action),
+            disabled: false,
         });
         self
     }
@@ -379,6 +432,7 @@ impl PopupMenu {
                         handler(cx);
                         self.dismiss(&Dismiss, cx)
                     }
+                    Some(PopupMenuItem::Submenu { .. }) => self.select_right(&SelectRight, cx),
                     _ => {}
                 }
             }
@@ -387,37 +441,86 @@ impl PopupMenu {
     }
 
     fn select_next(&mut self, _: &SelectNext, cx: &mut ViewContext<Self>) {
-        let count = self.clickable_menu_items().count();
-        if count > 0 {
-            let last_ix = count.saturating_sub(1);
-            let ix = self
-                .selected_index
-                .map(|index| if index == last_ix { 0 } else { index + 1 })
-                .unwrap_or(0);
-
-            self.selected_index = Some(ix);
-            cx.notify();
+        let ixs = self
+            .clickable_menu_items()
+            .map(|(ix, _)| ix)
+            .collect::<Vec<_>>();
+        if ixs.is_empty() {
+            return;
         }
+
+        let pos = self
+            .selected_index
+            .and_then(|selected_ix| ixs.iter().position(|ix| *ix == selected_ix));
+        let next = match pos {
+            Some(pos) => (pos + 1) % ixs.len(),
+            None => 0,
+        };
+        self.selected_index = Some(ixs[next]);
+        cx.notify();
     }
 
     fn select_prev(&mut self, _: &SelectPrev, cx: &mut ViewContext<Self>) {
-        let count = self.clickable_menu_items().count();
-        if count > 0 {
-            let last_ix = count.saturating_sub(1);
-
-            let ix = self
-                .selected_index
-                .map(|index| {
-                    if index == last_ix {
-                        0
-                    } else {
-                        index.saturating_sub(1)
-                    }
-                })
-                .unwrap_or(last_ix);
-            self.selected_index = Some(ix);
+        let ixs = self
+            .clickable_menu_items()
+            .map(|(ix, _)| ix)
+            .collect::<Vec<_>>();
+        if ixs.is_empty() {
+            return;
+        }
+
+        let pos = self
+            .selected_index
+            .and_then(|selected_ix| ixs.iter().position(|ix| *ix == selected_ix));
+        let prev = match pos {
+            Some(pos) => (pos + ixs.len() - 1) % ixs.len(),
+            None => ixs.len() - 1,
+        };
+        self.selected_index = Some(ixs[prev]);
+        cx.notify();
+    }
+
+    /// Open the selected submenu and move the focus into it.
+    fn select_right(&mut self, _: &SelectRight, cx: &mut ViewContext<Self>) {
+        let Some(ix) = self.selected_index else {
+            return;
+        };
+        let Some(PopupMenuItem::Submenu { menu, .. }) = self.menu_items.get(ix) else {
+            return;
+        };
+
+        let menu = menu.clone();
+        self.hovered_menu_ix = Some(ix);
+        menu.update(cx, |menu, cx| {
+            menu.selected_index = menu.clickable_menu_items().next().map(|(ix, _)| ix);
+            menu.focus_handle.focus(cx);
+            cx.notify();
+        });
+        cx.notify();
+    }
+
+    /// Close this submenu and move the focus back to the parent menu.
+    fn select_left(&mut self, _: &SelectLeft, cx: &mut ViewContext<Self>) {
+        let Some(parent_menu) = self.parent_menu.clone().and_then(|menu| menu.upgrade()) else {
+            return;
+        };
+
+        parent_menu.update(cx, |menu, cx| {
+            menu.hovered_menu_ix = None;
+            menu.focus_handle.focus(cx);
             cx.notify();
+        });
+    }
+
+    fn on_blur(&mut self, cx: &mut ViewContext<Self>) {
+        // Keep the menus open when the focus is moved back to the parent menu by the Left key.
+        if let Some(parent_menu) = self.parent_menu.clone().and_then(|menu| menu.upgrade()) {
+            if parent_menu.read(cx).focus_handle.is_focused(cx) {
+                return;
+            }
         }
+
+        self.dismiss(&Dismiss, cx)
     }
 
     fn dismiss(&mut self, _: &Dismiss, cx: &mut ViewContext<Self>) {
@@ -511,6 +614,8 @@ impl Render for PopupMenu {
             .track_focus(&self.focus_handle)
             .on_action(cx.listener(Self::select_next))
             .on_action(cx.listener(Self::select_prev))
+            .on_action(cx.listener(Self::select_left))
+            .on_action(cx.listener(Self::select_right))
             .on_action(cx.listener(Self::confirm))
             .on_action(cx.listener(Self::dismiss))
             .on_mouse_down_out(cx.listener(|this, _, cx| this.dismiss(&Dismiss, cx)))
@@ -564,15 +669,14 @@ impl Render for PopupMenu {
                                         match item {
                                             PopupMenuItem::Separator => {
                                                 this.h_auto().p_0().disabled(true).child(
-                                                    div()
-                                                        .rounded_none()
-                                                        .h(px(1.))
+                                                    Divider::horizontal()
                                                         .mx_neg_1()
                                                         .my_0p5()
-                                                        .bg(cx.theme().muted),
+                                                        .color(cx.theme().muted),
                                                 )
                                             }
                                             PopupMenuItem::ElementItem { render, .. } => this
+                                                .selected(self.selected_index == Some(ix))
                                                 .on_click(cx.listener(move |this, _, cx| {
                                                     this.on_click(ix, cx)
                                                 }))
@@ -590,6 +694,7 @@ impl Render for PopupMenu {
                                                 icon,
                                                 label,
                                                 action,
+                                                disabled,
                                                 ..
                                             } => {
                                                 let action = action
@@ -597,34 +702,42 @@ impl Render for PopupMenu {
                                                     .map(|action| action.boxed_clone());
                                                 let key = Self::render_keybinding(action, cx);
 
-                                                this.on_click(cx.listener(move |this, _, cx| {
-                                                    this.on_click(ix, cx)
-                                                }))
-                                                .child(
-                                                    h_flex()
-                                                        .h(ITEM_HEIGHT)
-                                                        .items_center()
-                                                        .gap_x_1p5()
-                                                        .children(Self::render_icon(
-                                                            has_icon,
-                                                            icon.clone(),
-                                                            cx,
+                                                this.selected(self.selected_index == Some(ix))
+                                                    .disabled(*disabled)
+                                                    .when(!*disabled, |this| {
+                                                        this.on_click(cx.listener(
+                                                            move |this, _, cx| {
+                                                                this.on_click(ix, cx)
+                                                            },
                                                         ))
-                                                        .child(
-                                                            h_flex()
-                                                                .flex_1()
-                                                                .gap_2()
-                                                                .items_center()
-                                                                .justify_between()
-                                                                .child(label.clone())
-                                                                .children(key),
-                                                        ),
-                                                )
+                                                    })
+                                                    .child(
+                                                        h_flex()
+                                                            .h(ITEM_HEIGHT)
+                                                            .items_center()
+                                                            .gap_x_1p5()
+                                                            .children(Self::render_icon(
+                                                                has_icon,
+                                                                icon.clone(),
+                                                                cx,
+                                                            ))
+                                                            .child(
+                                                                h_flex()
+                                                                    .flex_1()
+                                                                    .gap_2()
+                                                                    .items_center()
+                                                                    .justify_between()
+                                                                    .child(label.clone())
+                                                                    .children(key),
+                                                            ),
+                                                    )
                                             }
                                             PopupMenuItem::Submenu { icon, label, menu } => this
-                                                .when(self.hovered_menu_ix == Some(ix), |this| {
-                                                    this.selected(true)
-                                                })
+                                                .when(
+                                                    self.hovered_menu_ix == Some(ix)
+                                                        || self.selected_index == Some(ix),
+                                                    |this| this.selected(true),
+                                                )
                                                 .child(
                                                     h_flex()
                                                         .items_start()
@@ -1,9 +1,64 @@
 //! The prelude of this crate. When building UI in Zed you almost always want to import this.
+//!
+//! This exports the commonly used components, and the extension traits to resolve their methods, e.g.:
+//!
+//! ```ignore
+//! use ui::prelude::*;
+//!
+//! let list = cx.new_view(|cx| List::new(SimpleListDelegate::new(["Apple", "Banana"]), cx).small());
+//! ```
 
 pub use gpui::prelude::*;
 #[allow(unused_imports)]
 pub use gpui::{
-    div, px, relative, rems, AbsoluteLength, DefiniteLength, Div, Element, ElementId,
-    InteractiveElement, ParentElement, Pixels, Rems, RenderOnce, SharedString, Styled, ViewContext,
-    WindowContext,
+    div, px, relative, rems, AbsoluteLength, AppContext, DefiniteLength, Div, Element, ElementId,
+    FocusHandle, FocusableView, Hsla, InteractiveElement, Length, ParentElement, Pixels, Rems,
+    RenderOnce, SharedString, Styled, Task, View, ViewContext, WindowContext,
 };
+
+#[allow(unused_imports)]
+pub use crate::{
+    button::{Button, ButtonVariants},
+    h_flex,
+    input::{InputEvent, TextInput},
+    list::{List, ListDelegate, ListItem, SimpleListDelegate},
+    theme::{ActiveTheme, Colorize, ReadTheme},
+    v_flex, ContextModal, Disableable, Icon, IconName, Selectable, Sizable, Size, StyleSized,
+    StyledExt,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Delegate(Vec<SharedString>);
+
+    impl ListDelegate for Delegate {
+        type Item = ListItem;
+
+        fn items_count(&self, _: &AppContext) -> usize {
+            self.0.len()
+        }
+
+        fn render_item(&self, ix: usize, _: &mut ViewContext<List<Self>>) -> Option<Self::Item> {
+            Some(ListItem::new(ix).child(self.0.get(ix)?.clone()))
+        }
+
+        fn set_selected_index(&mut self, _: Option<usize>, _: &mut ViewContext<List<Self>>) {}
+    }
+
+    #[gpui::test]
+    fn test_prelude_list(cx: &mut gpui::TestAppContext) {
+        cx.update(|cx| {
+            crate::init(cx);
+        });
+        let (list, cx) = cx.add_window_view(|cx| {
+            List::new(Delegate(vec!["Apple".into(), "Banana".into()]), cx)
+                .max_h(px(200.))
+                .small()
+        });
+
+        list.update(cx, |list, cx| list.select_next(cx));
+        list.read_with(cx, |list, _| assert_eq!(list.selected_index(), Some(0)));
+    }
+}
@@ -1,56 +1,394 @@
-use crate::theme::ActiveTheme;
+use std::{f32::consts::PI, time::Duration};
+
+use crate::{theme::ActiveTheme, Sizable, Size};
 use gpui::{
-    div, prelude::FluentBuilder, px, relative, IntoElement, ParentElement, RenderOnce, Styled,
-    WindowContext,
+    bounce, canvas, div, ease_in_out, point, prelude::FluentBuilder, px, relative, Animation,
+    AnimationExt as _, Bounds, ElementId, Hsla, IntoElement, ParentElement, Path, Pixels, Point,
+    Render, RenderOnce, Styled, ViewContext, WindowContext,
 };
 
+/// Clamp the value into `0.0..=1.0`, the `NaN` is treated as `0.0`.
+fn clamp_value(value: f32) -> f32 {
+    if value.is_nan() {
+        return 0.;
+    }
+
+    value.clamp(0., 1.)
+}
+
 /// A Progress bar element.
+///
+/// The `value` is in `0.0..=1.0`, or use `indeterminate` when the progress is unknown.
 #[derive(IntoElement)]
 pub struct Progress {
-    value: f32,
-    height: f32,
+    id: ElementId,
+    /// None means the indeterminate mode.
+    value: Option<f32>,
+    size: Size,
+    show_label: bool,
 }
 
 impl Progress {
     pub fn new() -> Self {
         Progress {
-            value: Default::default(),
-            height: 8.,
+            id: "progress".into(),
+            value: Some(0.),
+            size: Size::Medium,
+            show_label: false,
         }
     }
 
+    /// Set the element id, this is required to have more than one
+    /// indeterminate progress in the same view, that the animations can run independently.
+    pub fn id(mut self, id: impl Into<ElementId>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    /// Set the value of the progress in `0.0..=1.0`.
     pub fn value(mut self, value: f32) -> Self {
-        self.value = value;
+        self.value = Some(clamp_value(value));
+        self
+    }
+
+    /// Set to the indeterminate mode, to show a sliding segment.
+    pub fn indeterminate(mut self) -> Self {
+        self.value = None;
+        self
+    }
+
+    /// Set to show the percentage label at the right of the bar, default: false
+    pub fn show_label(mut self, show_label: bool) -> Self {
+        self.show_label = show_label;
+        self
+    }
+}
+
+impl Sizable for Progress {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
         self
     }
 }
 
 impl RenderOnce for Progress {
     fn render(self, cx: &mut WindowContext) -> impl IntoElement {
-        let rounded = px(self.height / 2.);
-        let relative_w = relative(match self.value {
-            v if v < 0. => 0.,
-            v if v > 100. => 1.,
-            v => v / 100.,
-        });
+        let height = match self.size {
+            Size::Size(size) => size,
+            Size::XSmall => px(4.),
+            Size::Small => px(6.),
+            Size::Medium => px(8.),
+            Size::Large => px(12.),
+            Size::XLarge => px(16.),
+        };
+        let rounded = height / 2.;
+        let color = cx.theme().progress_bar;
+        let reduce_motion = cx.theme().reduce_motion();
 
-        div()
+        let track = div()
             .relative()
-            .h(px(self.height))
+            .flex_1()
+            .h(height)
             .rounded(rounded)
-            .bg(cx.theme().progress_bar.opacity(0.2))
-            .child(
+            .overflow_hidden()
+            .bg(color.opacity(0.2));
+
+        let track = match self.value {
+            Some(value) => track.child(
                 div()
                     .absolute()
                     .top_0()
                     .left_0()
                     .h_full()
-                    .w(relative_w)
-                    .bg(cx.theme().progress_bar)
-                    .map(|this| match self.value {
-                        v if v >= 100. => this.rounded(rounded),
+                    .w(relative(value))
+                    .bg(color)
+                    .map(|this| match value {
+                        v if v >= 1. => this.rounded(rounded),
                         _ => this.rounded_l(rounded),
                     }),
+            ),
+            // Pulse the full bar instead of the sliding, when the reduced motion is enabled.
+            None if reduce_motion => track.child(
+                div().size_full().bg(color).with_animation(
+                    self.id.clone(),
+                    Animation::new(Duration::from_secs(2))
+                        .repeat()
+                        .with_easing(bounce(ease_in_out)),
+                    |this, delta| this.opacity(1.0 - delta * 0.6),
+                ),
+            ),
+            None => track.child(
+                div()
+                    .absolute()
+                    .top_0()
+                    .h_full()
+                    .w(relative(0.3))
+                    .rounded(rounded)
+                    .bg(color)
+                    .with_animation(
+                        self.id.clone(),
+                        Animation::new(Duration::from_secs_f32(1.5))
+                            .repeat()
+                            .with_easing(ease_in_out),
+                        |this, delta| this.left(relative(delta * 1.3 - 0.3)),
+                    ),
+            ),
+        };
+
+        div()
+            .flex()
+            .items_center()
+            .gap_2()
+            .child(track)
+            .when(self.show_label, |this| {
+                this.child(
+                    div()
+                        .flex_none()
+                        .text_xs()
+                        .text_color(cx.theme().muted_foreground)
+                        .children(
+                            self.value
+                                .map(|value| format!("{}%", (value * 100.).round() as u32)),
+                        ),
+                )
+            })
+    }
+}
+
+/// A view to keep the state of the [`Progress`], for the live updates without
+/// rebuilding the progress in the parent view.
+pub struct ProgressBar {
+    value: Option<f32>,
+    size: Size,
+    show_label: bool,
+}
+
+impl ProgressBar {
+    pub fn new(_: &mut ViewContext<Self>) -> Self {
+        Self {
+            value: Some(0.),
+            size: Size::Medium,
+            show_label: false,
+        }
+    }
+
+    /// Set to show the percentage label, default: false
+    pub fn show_label(mut self, show_label: bool) -> Self {
+        self.show_label = show_label;
+        self
+    }
+
+    /// Return the value in `0.0..=1.0`, or `None` in the indeterminate mode.
+    pub fn value(&self) -> Option<f32> {
+        self.value
+    }
+
+    /// Update the value in `0.0..=1.0`.
+    pub fn set_value(&mut self, value: f32, cx: &mut ViewContext<Self>) {
+        let value = clamp_value(value);
+        if self.value != Some(value) {
+            self.value = Some(value);
+            cx.notify();
+        }
+    }
+
+    /// Switch to the indeterminate mode, until the next `set_value`.
+    pub fn set_indeterminate(&mut self, cx: &mut ViewContext<Self>) {
+        self.value = None;
+        cx.notify();
+    }
+}
+
+impl Sizable for ProgressBar {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl Render for ProgressBar {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let progress = Progress::new()
+            .id(("progress-bar", cx.entity_id().as_u64() as usize))
+            .with_size(self.size)
+            .show_label(self.show_label);
+
+        match self.value {
+            Some(value) => progress.value(value),
+            None => progress.indeterminate(),
+        }
+    }
+}
+
+/// Returns the points of a ring segment from the `start` angle to the `end` angle,
+/// the angles are in radians clockwise from the top.
+fn ring_points(
+    bounds: Bounds<Pixels>,
+    thickness: Pixels,
+    start: f32,
+    end: f32,
+) -> Vec<Point<Pixels>> {
+    const SEGMENTS: usize = 48;
+
+    let center = bounds.center();
+    let outer = bounds.size.width.min(bounds.size.height) / 2.;
+    let inner = (outer - thickness).max(px(0.));
+    // Enough segments for a smooth arc, and at least 1 for a tiny value.
+    let steps = ((SEGMENTS as f32 * ((end - start) / (2. * PI))).ceil() as usize).max(1);
+    let point_at = |radius: Pixels, angle: f32| {
+        point(
+            center.x + radius * angle.sin(),
+            center.y - radius * angle.cos(),
+        )
+    };
+
+    let angles = (0..=steps).map(|i| start + (end - start) * i as f32 / steps as f32);
+    angles
+        .clone()
+        .map(|angle| point_at(outer, angle))
+        .chain(angles.rev().map(|angle| point_at(inner, angle)))
+        .collect()
+}
+
+fn paint_ring(
+    bounds: Bounds<Pixels>,
+    thickness: Pixels,
+    start: f32,
+    end: f32,
+    color: Hsla,
+    cx: &mut WindowContext,
+) {
+    let points = ring_points(bounds, thickness, start, end);
+    let Some((first, rest)) = points.split_first() else {
+        return;
+    };
+
+    let mut path = Path::new(*first);
+    for point in rest {
+        path.line_to(*point);
+    }
+    path.line_to(*first);
+    cx.paint_path(path, color);
+}
+
+/// A circular progress, for the small places like the loading state of a List or TextInput.
+#[derive(IntoElement)]
+pub struct ProgressCircle {
+    id: ElementId,
+    /// None means the indeterminate mode.
+    value: Option<f32>,
+    size: Size,
+    color: Option<Hsla>,
+}
+
+impl ProgressCircle {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            value: None,
+            size: Size::Medium,
+            color: None,
+        }
+    }
+
+    /// Set the value of the progress in `0.0..=1.0`, default is the indeterminate mode.
+    pub fn value(mut self, value: f32) -> Self {
+        self.value = Some(clamp_value(value));
+        self
+    }
+
+    /// Set the color of the progress, default is the `progress_bar` of the theme.
+    pub fn color(mut self, color: Hsla) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+impl Sizable for ProgressCircle {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl RenderOnce for ProgressCircle {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        // The same sizes as the Icon, to replace the loading icon.
+        let size = match self.size {
+            Size::Size(size) => size,
+            Size::XSmall => px(12.),
+            Size::Small => px(14.),
+            Size::Medium => px(16.),
+            Size::Large => px(24.),
+            Size::XLarge => px(32.),
+        };
+        let thickness = (size / 8.).max(px(1.5));
+        let color = self.color.unwrap_or(cx.theme().progress_bar);
+        let track_color = color.opacity(0.2);
+        let reduce_motion = cx.theme().reduce_motion();
+
+        let base = div().flex_none().size(size);
+        let ring = move |start: f32, end: f32| {
+            canvas(
+                |_, _| {},
+                move |bounds, _, cx| {
+                    paint_ring(bounds, thickness, 0., 2. * PI, track_color, cx);
+                    if end > start {
+                        paint_ring(bounds, thickness, start, end, color, cx);
+                    }
+                },
             )
+            .size_full()
+        };
+
+        match self.value {
+            Some(value) => base.child(ring(0., value * 2. * PI)).into_any_element(),
+            None if reduce_motion => base
+                .child(ring(0., 2. * PI))
+                .with_animation(
+                    self.id,
+                    Animation::new(Duration::from_secs(2))
+                        .repeat()
+                        .with_easing(bounce(ease_in_out)),
+                    |this, delta| this.opacity(1.0 - delta * 0.6),
+                )
+                .into_any_element(),
+            None => base
+                .with_animation(
+                    self.id,
+                    Animation::new(Duration::from_secs_f32(0.8)).repeat(),
+                    move |this, delta| {
+                        let start = delta * 2. * PI;
+                        this.child(ring(start, start + PI / 2.))
+                    },
+                )
+                .into_any_element(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{point, px, size, Bounds};
+
+    use super::{clamp_value, ring_points};
+
+    #[test]
+    fn test_clamp_value() {
+        assert_eq!(clamp_value(0.5), 0.5);
+        assert_eq!(clamp_value(-1.), 0.);
+        assert_eq!(clamp_value(1.5), 1.);
+        assert_eq!(clamp_value(f32::NAN), 0.);
+    }
+
+    #[test]
+    fn test_ring_points() {
+        let bounds = Bounds::new(point(px(0.), px(0.)), size(px(20.), px(20.)));
+        let points = ring_points(bounds, px(2.), 0., std::f32::consts::PI);
+        // 24 steps of the half circle, both of the outer and inner arcs.
+        assert_eq!(points.len(), 50);
+        // Starts at the top of the outer arc, and ends at the top of the inner arc.
+        assert_eq!(points[0], point(px(10.), px(0.)));
+        assert_eq!(points[49], point(px(10.), px(2.)));
     }
 }
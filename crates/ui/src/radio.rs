@@ -1,18 +1,40 @@
-use crate::{h_flex, theme::ActiveTheme, IconName};
+use crate::{
+    h_flex,
+    root::is_keyboard_modality,
+    selection::step_option,
+    theme::{box_shadow, ActiveTheme},
+};
 use gpui::{
-    div, prelude::FluentBuilder, relative, svg, ElementId, InteractiveElement, IntoElement,
-    ParentElement, RenderOnce, SharedString, StatefulInteractiveElement, Styled, WindowContext,
+    actions, div, prelude::FluentBuilder, px, relative, AppContext, Axis, ElementId, EventEmitter,
+    FocusHandle, FocusableView, InteractiveElement, IntoElement, KeyBinding, ParentElement, Render,
+    RenderOnce, SharedString, StatefulInteractiveElement, Styled, ViewContext, WindowContext,
 };
+use smallvec::smallvec;
+
+actions!(radio, [SelectPrev, SelectNext]);
+
+const CONTEXT: &str = "RadioGroup";
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("up", SelectPrev, Some(CONTEXT)),
+        KeyBinding::new("left", SelectPrev, Some(CONTEXT)),
+        KeyBinding::new("down", SelectNext, Some(CONTEXT)),
+        KeyBinding::new("right", SelectNext, Some(CONTEXT)),
+    ]);
+}
 
 /// A Radio element.
 ///
-/// This is not included the Radio group implementation, you can manage the group by yourself.
+/// Use [`RadioGroup`] for a set of mutually exclusive options with the keyboard navigation.
 #[derive(IntoElement)]
 pub struct Radio {
     id: ElementId,
     label: Option<SharedString>,
     checked: bool,
     disabled: bool,
+    /// Show the focus ring on the indicator, used by the [`RadioGroup`].
+    focus_ring: bool,
     on_click: Option<Box<dyn Fn(&bool, &mut WindowContext) + 'static>>,
 }
 
@@ -23,6 +45,7 @@ impl Radio {
             label: None,
             checked: false,
             disabled: false,
+            focus_ring: false,
             on_click: None,
         }
     }
@@ -55,6 +78,7 @@ impl RenderOnce for Radio {
         } else {
             cx.theme().primary
         };
+        let ring = cx.theme().ring;
 
         h_flex()
             .id(self.id)
@@ -63,29 +87,20 @@ impl RenderOnce for Radio {
             .items_center()
             .line_height(relative(1.))
             .child(
-                div()
-                    .relative()
+                h_flex()
                     .size_4()
                     .flex_shrink_0()
+                    .justify_center()
                     .rounded_full()
                     .border_1()
                     .border_color(color)
-                    .when(self.checked, |this| this.bg(color))
-                    .child(
-                        svg()
-                            .absolute()
-                            .top_px()
-                            .left_px()
-                            .size_3()
-                            .text_color(color)
-                            .when(self.checked, |this| {
-                                this.text_color(cx.theme().primary_foreground)
-                            })
-                            .map(|this| match self.checked {
-                                true => this.path(IconName::Check.path()),
-                                false => this,
-                            }),
-                    ),
+                    .when(self.focus_ring, |this| {
+                        let shadow = box_shadow(0., 0., 0., px(2.), ring.opacity(0.2));
+                        this.border_color(ring).shadow(smallvec![shadow])
+                    })
+                    .when(self.checked, |this| {
+                        this.child(div().size_2().rounded_full().bg(color))
+                    }),
             )
             .when_some(self.label, |this, label| {
                 this.child(
@@ -97,13 +112,195 @@ impl RenderOnce for Radio {
                         .child(label),
                 )
             })
+            .when(self.disabled, |this| {
+                this.cursor_not_allowed()
+                    .text_color(cx.theme().muted_foreground)
+            })
             .when_some(
                 self.on_click.filter(|_| !self.disabled),
                 |this, on_click| {
-                    this.on_click(move |_event, cx| {
+                    this.cursor_pointer().on_click(move |_event, cx| {
                         on_click(&!self.checked, cx);
                     })
                 },
             )
     }
 }
+
+pub enum RadioGroupEvent<V> {
+    /// The selected value is changed by the user.
+    Changed(V),
+}
+
+struct RadioOption<V> {
+    value: V,
+    label: SharedString,
+    disabled: bool,
+}
+
+/// A group of mutually exclusive [`Radio`] options.
+///
+/// The group is a single tab stop, the arrow keys move the selection to the
+/// prev or next enabled option, and the focus ring follows the selection.
+///
+/// ```ignore
+/// let group = cx.new_view(|cx| {
+///     RadioGroup::new("size", cx)
+///         .option(Size::Small, "Small")
+///         .option(Size::Medium, "Medium")
+///         .disabled_option(Size::Large, "Large")
+///         .selected(Size::Medium)
+/// });
+/// ```
+pub struct RadioGroup<V: PartialEq + Clone + 'static> {
+    id: ElementId,
+    focus_handle: FocusHandle,
+    options: Vec<RadioOption<V>>,
+    selected: Option<V>,
+    axis: Axis,
+    disabled: bool,
+}
+
+impl<V: PartialEq + Clone + 'static> RadioGroup<V> {
+    pub fn new(id: impl Into<ElementId>, cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            id: id.into(),
+            focus_handle: cx.focus_handle(),
+            options: Vec::new(),
+            selected: None,
+            axis: Axis::Vertical,
+            disabled: false,
+        }
+    }
+
+    /// Add an option with the `value` and the `label`.
+    pub fn option(mut self, value: V, label: impl Into<SharedString>) -> Self {
+        self.options.push(RadioOption {
+            value,
+            label: label.into(),
+            disabled: false,
+        });
+        self
+    }
+
+    /// Add a disabled option, it can't be selected by click or keyboard.
+    pub fn disabled_option(mut self, value: V, label: impl Into<SharedString>) -> Self {
+        self.options.push(RadioOption {
+            value,
+            label: label.into(),
+            disabled: true,
+        });
+        self
+    }
+
+    /// Set the layout direction of the options, default: `Axis::Vertical`
+    pub fn axis(mut self, axis: Axis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    /// Set the initial selected value.
+    pub fn selected(mut self, value: V) -> Self {
+        self.selected = Some(value);
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn selected_value(&self) -> Option<&V> {
+        self.selected.as_ref()
+    }
+
+    /// Set the selected value, this will not emit the [`RadioGroupEvent::Changed`] event.
+    pub fn set_selected_value(&mut self, value: Option<V>, cx: &mut ViewContext<Self>) {
+        self.selected = value;
+        cx.notify();
+    }
+
+    fn selected_ix(&self) -> Option<usize> {
+        let selected = self.selected.as_ref()?;
+        self.options
+            .iter()
+            .position(|option| &option.value == selected)
+    }
+
+    fn select(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        if self.selected_ix() == Some(ix) {
+            return;
+        }
+
+        let value = self.options[ix].value.clone();
+        self.selected = Some(value.clone());
+        cx.emit(RadioGroupEvent::Changed(value));
+        cx.notify();
+    }
+
+    fn step(&mut self, forward: bool, cx: &mut ViewContext<Self>) {
+        if self.disabled {
+            return;
+        }
+
+        let disabled = self
+            .options
+            .iter()
+            .map(|option| option.disabled)
+            .collect::<Vec<_>>();
+        if let Some(ix) = step_option(&disabled, self.selected_ix(), forward) {
+            self.select(ix, cx);
+        }
+    }
+
+    fn select_prev(&mut self, _: &SelectPrev, cx: &mut ViewContext<Self>) {
+        self.step(false, cx);
+    }
+
+    fn select_next(&mut self, _: &SelectNext, cx: &mut ViewContext<Self>) {
+        self.step(true, cx);
+    }
+}
+
+impl<V: PartialEq + Clone + 'static> EventEmitter<RadioGroupEvent<V>> for RadioGroup<V> {}
+
+impl<V: PartialEq + Clone + 'static> FocusableView for RadioGroup<V> {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl<V: PartialEq + Clone + 'static> Render for RadioGroup<V> {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let focused = self.focus_handle.is_focused(cx)
+            && (!cx.theme().focus_ring_keyboard_only || is_keyboard_modality(cx));
+        let selected_ix = self.selected_ix();
+        // Show the focus ring on the first enabled option, if nothing is selected.
+        let focus_ix =
+            selected_ix.or_else(|| self.options.iter().position(|option| !option.disabled));
+
+        div()
+            .id(self.id.clone())
+            .key_context(CONTEXT)
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::select_prev))
+            .on_action(cx.listener(Self::select_next))
+            .flex()
+            .map(|this| match self.axis {
+                Axis::Vertical => this.flex_col().gap_2(),
+                Axis::Horizontal => this.flex_row().flex_wrap().gap_4(),
+            })
+            .children(self.options.iter().enumerate().map(|(ix, option)| {
+                let mut radio = Radio::new(("option", ix))
+                    .label(option.label.clone())
+                    .checked(selected_ix == Some(ix))
+                    .disabled(self.disabled || option.disabled)
+                    .on_click(cx.listener(move |this, _, cx| {
+                        this.focus_handle.focus(cx);
+                        this.select(ix, cx);
+                    }));
+                radio.focus_ring = focused && focus_ix == Some(ix);
+                radio
+            }))
+    }
+}
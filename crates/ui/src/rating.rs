@@ -0,0 +1,351 @@
+use std::{cell::Cell, rc::Rc};
+
+use gpui::{
+    div, prelude::FluentBuilder as _, px, relative, AnyElement, Element, ElementId, FocusHandle,
+    GlobalElementId, Hsla, InteractiveElement as _, IntoElement, LayoutId, ParentElement as _,
+    Pixels, StatefulInteractiveElement as _, Styled as _, WindowContext,
+};
+
+use crate::{
+    h_flex, theme::ActiveTheme as _, Disableable, Icon, IconName, Sizable, Size, StyledExt as _,
+};
+
+/// A rating input with stars, it can also be used to display an average rating by `readonly`.
+///
+/// The rating is controlled, the parent owns the `value` and updates it in `on_change`.
+pub struct Rating {
+    id: ElementId,
+    value: f32,
+    count: usize,
+    allow_half: bool,
+    clearable: bool,
+    readonly: bool,
+    disabled: bool,
+    color: Option<Hsla>,
+    size: Size,
+    focus_handle: Option<FocusHandle>,
+    on_change: Option<Rc<dyn Fn(&f32, &mut WindowContext)>>,
+}
+
+impl Rating {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            value: 0.,
+            count: 5,
+            allow_half: false,
+            clearable: true,
+            readonly: false,
+            disabled: false,
+            color: None,
+            size: Size::default(),
+            focus_handle: None,
+            on_change: None,
+        }
+    }
+
+    /// Set the value of the rating, the fractional value is shown as a partially filled star.
+    pub fn value(mut self, value: f32) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Set the number of the stars, default: 5
+    pub fn count(mut self, count: usize) -> Self {
+        self.count = count;
+        self
+    }
+
+    /// Set to allow selecting half a star, default: false
+    pub fn allow_half(mut self, allow_half: bool) -> Self {
+        self.allow_half = allow_half;
+        self
+    }
+
+    /// Set to clear the rating when the current value is clicked again, default: true
+    pub fn clearable(mut self, clearable: bool) -> Self {
+        self.clearable = clearable;
+        self
+    }
+
+    /// Set to only display the value, e.g. an average rating, default: false
+    pub fn readonly(mut self, readonly: bool) -> Self {
+        self.readonly = readonly;
+        self
+    }
+
+    /// Set the color of the filled stars, default: the `warning` color of the theme.
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Set the focus handle, to adjust the value by Left and Right when it is focused.
+    pub fn focusable(mut self, focus_handle: &FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle.clone());
+        self
+    }
+
+    /// Set the handler when the value is changed by the user, the argument is the new value.
+    pub fn on_change(mut self, handler: impl Fn(&f32, &mut WindowContext) + 'static) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+}
+
+impl Sizable for Rating {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl Disableable for Rating {
+    fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl IntoElement for Rating {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+#[derive(Default)]
+pub struct RatingState {
+    /// The value under the mouse, to preview it before click.
+    hovered_value: Rc<Cell<Option<f32>>>,
+}
+
+/// Returns the fill ratio of the star at `ix`, in `0.0..=1.0`.
+fn star_fill(ix: usize, value: f32) -> f32 {
+    (value - ix as f32).clamp(0., 1.)
+}
+
+/// Returns the value after a keyboard step, in `0.0..=count`.
+fn step_value(value: f32, forward: bool, allow_half: bool, count: usize) -> f32 {
+    let step = if allow_half { 0.5 } else { 1. };
+    // Snap a fractional value to the step first, e.g. 3.3 steps to 3.5 or 3.0.
+    let value = if forward {
+        (value / step).floor() * step + step
+    } else {
+        (value / step).ceil() * step - step
+    };
+    value.clamp(0., count as f32)
+}
+
+/// Returns the new value when the `clicked` value is clicked.
+fn click_value(value: f32, clicked: f32, clearable: bool) -> f32 {
+    if clearable && value == clicked {
+        0.
+    } else {
+        clicked
+    }
+}
+
+fn star_size(size: Size) -> Pixels {
+    match size {
+        Size::XSmall => px(12.),
+        Size::Small => px(16.),
+        Size::Large => px(24.),
+        Size::XLarge => px(32.),
+        _ => px(20.),
+    }
+}
+
+impl Element for Rating {
+    type RequestLayoutState = AnyElement;
+
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        Some(self.id.clone())
+    }
+
+    fn request_layout(
+        &mut self,
+        global_id: Option<&GlobalElementId>,
+        cx: &mut WindowContext,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        cx.with_element_state::<RatingState, _>(global_id.unwrap(), move |state, cx| {
+            let state = state.unwrap_or_default();
+
+            let interactive = !self.readonly && !self.disabled;
+            let value = self.value;
+            let display_value = if interactive {
+                state.hovered_value.get().unwrap_or(value)
+            } else {
+                value
+            };
+            if !interactive {
+                state.hovered_value.set(None);
+            }
+
+            let color = self.color.unwrap_or(cx.theme().warning);
+            let muted = cx.theme().muted_foreground.opacity(0.4);
+            let icon_size = star_size(self.size);
+            let count = self.count;
+            let allow_half = self.allow_half;
+            let clearable = self.clearable;
+            let on_change = self.on_change.clone().filter(|_| interactive);
+
+            let mut element = h_flex()
+                .id(self.id.clone())
+                .gap_0p5()
+                .rounded(px(cx.theme().radius))
+                .disabled_style(self.disabled, cx)
+                .when_some(
+                    self.focus_handle.as_ref().filter(|_| interactive),
+                    |this, focus_handle| {
+                        this.track_focus(focus_handle).focus_ring(focus_handle, cx)
+                    },
+                )
+                .when_some(
+                    on_change.clone().filter(|_| self.focus_handle.is_some()),
+                    |this, on_change| {
+                        this.on_key_down(move |event, cx| {
+                            let forward = match event.keystroke.key.as_str() {
+                                "left" => false,
+                                "right" => true,
+                                _ => return,
+                            };
+                            cx.stop_propagation();
+                            let new_value = step_value(value, forward, allow_half, count);
+                            if new_value != value {
+                                on_change(&new_value, cx);
+                            }
+                        })
+                    },
+                )
+                .children((0..count).map(|ix| {
+                    let fill = star_fill(ix, display_value);
+                    let halves: &[f32] = if allow_half { &[0.5, 1.] } else { &[1.] };
+
+                    div()
+                        .relative()
+                        .size(icon_size)
+                        .flex_shrink_0()
+                        .child(
+                            Icon::new(IconName::Star)
+                                .with_px(icon_size)
+                                .text_color(muted),
+                        )
+                        .when(fill > 0., |this| {
+                            this.child(
+                                div()
+                                    .absolute()
+                                    .top_0()
+                                    .left_0()
+                                    .h_full()
+                                    .w(relative(fill))
+                                    .overflow_hidden()
+                                    .child(
+                                        Icon::new(IconName::Star)
+                                            .with_px(icon_size)
+                                            .flex_shrink_0()
+                                            .text_color(color),
+                                    ),
+                            )
+                        })
+                        .when_some(on_change.clone(), |this, on_change| {
+                            // The hit areas of the halves, or of the whole star.
+                            this.cursor_pointer()
+                                .children(halves.iter().enumerate().map(|(half_ix, offset)| {
+                                    let star_value = ix as f32 + offset;
+                                    let hovered_value = state.hovered_value.clone();
+                                    let on_change = on_change.clone();
+
+                                    div()
+                                        .id(("star", ix * 2 + half_ix))
+                                        .absolute()
+                                        .top_0()
+                                        .h_full()
+                                        .map(|this| {
+                                            if halves.len() == 1 {
+                                                this.left_0().w_full()
+                                            } else if half_ix == 0 {
+                                                this.left_0().w_1_2()
+                                            } else {
+                                                this.right_0().w_1_2()
+                                            }
+                                        })
+                                        .on_hover(move |hovered, cx| {
+                                            if *hovered {
+                                                hovered_value.set(Some(star_value));
+                                            } else if hovered_value.get() == Some(star_value) {
+                                                // Only clear if not moved to another star already.
+                                                hovered_value.set(None);
+                                            }
+                                            cx.refresh();
+                                        })
+                                        .on_click(move |_, cx| {
+                                            let new_value =
+                                                click_value(value, star_value, clearable);
+                                            on_change(&new_value, cx);
+                                        })
+                                }))
+                        })
+                }))
+                .into_any_element();
+
+            ((element.request_layout(cx), element), state)
+        })
+    }
+
+    fn prepaint(
+        &mut self,
+        _: Option<&gpui::GlobalElementId>,
+        _: gpui::Bounds<gpui::Pixels>,
+        element: &mut Self::RequestLayoutState,
+        cx: &mut WindowContext,
+    ) {
+        element.prepaint(cx);
+    }
+
+    fn paint(
+        &mut self,
+        _: Option<&gpui::GlobalElementId>,
+        _: gpui::Bounds<gpui::Pixels>,
+        element: &mut Self::RequestLayoutState,
+        _: &mut Self::PrepaintState,
+        cx: &mut WindowContext,
+    ) {
+        element.paint(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{click_value, star_fill, step_value};
+
+    #[test]
+    fn test_star_fill() {
+        assert_eq!(star_fill(0, 3.5), 1.);
+        assert_eq!(star_fill(3, 3.5), 0.5);
+        assert_eq!(star_fill(4, 3.5), 0.);
+        assert!((star_fill(2, 2.3) - 0.3).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_step_value() {
+        assert_eq!(step_value(3., true, false, 5), 4.);
+        assert_eq!(step_value(3., false, false, 5), 2.);
+        assert_eq!(step_value(5., true, false, 5), 5.);
+        assert_eq!(step_value(0., false, false, 5), 0.);
+        assert_eq!(step_value(3., true, true, 5), 3.5);
+        assert_eq!(step_value(3.3, true, true, 5), 3.5);
+        assert_eq!(step_value(3.3, false, true, 5), 3.);
+        assert_eq!(step_value(3.3, false, false, 5), 3.);
+    }
+
+    #[test]
+    fn test_click_value() {
+        assert_eq!(click_value(3., 4., true), 4.);
+        assert_eq!(click_value(3., 3., true), 0.);
+        assert_eq!(click_value(3., 3., false), 3.);
+    }
+}
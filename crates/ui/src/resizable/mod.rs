@@ -1,9 +1,15 @@
-use gpui::{Axis, ViewContext};
+use gpui::{AppContext, Axis, ViewContext};
 
 mod panel;
 mod resize_handle;
+mod split_view;
 pub use panel::*;
 pub(crate) use resize_handle::*;
+pub use split_view::{SplitPane, SplitView, SplitViewEvent};
+
+pub fn init(cx: &mut AppContext) {
+    split_view::init(cx);
+}
 
 pub fn h_resizable(cx: &mut ViewContext<ResizablePanelGroup>) -> ResizablePanelGroup {
     ResizablePanelGroup::new(cx).axis(Axis::Horizontal)
@@ -0,0 +1,442 @@
+use std::rc::Rc;
+
+use gpui::{
+    actions, canvas, div, prelude::FluentBuilder as _, px, relative, AnyElement, AnyView,
+    AppContext, Axis, Bounds, DragMoveEvent, EntityId, EventEmitter, FocusHandle, FocusableView,
+    InteractiveElement, IntoElement, KeyBinding, MouseButton, MouseUpEvent, ParentElement as _,
+    Pixels, Render, StatefulInteractiveElement as _, Styled, ViewContext, VisualContext as _,
+    WindowContext,
+};
+
+use crate::{theme::ActiveTheme as _, AxisExt as _, InteractiveElementExt as _};
+
+use super::{HANDLE_PADDING, HANDLE_SIZE};
+
+actions!(split_view, [ResizeBackward, ResizeForward]);
+
+const CONTEXT: &str = "SplitView";
+/// The size to move the divider by the keyboard.
+const KEYBOARD_STEP: Pixels = px(10.);
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("left", ResizeBackward, Some(CONTEXT)),
+        KeyBinding::new("up", ResizeBackward, Some(CONTEXT)),
+        KeyBinding::new("right", ResizeForward, Some(CONTEXT)),
+        KeyBinding::new("down", ResizeForward, Some(CONTEXT)),
+    ]);
+}
+
+#[derive(Clone, Render)]
+pub struct DragDivider(EntityId);
+
+pub enum SplitViewEvent {
+    /// The ratio is changed by the user, emitted when the drag is finished,
+    /// by the keyboard or reset by double click the divider.
+    Resized(f32),
+}
+
+/// A pane of the [`SplitView`].
+pub struct SplitPane {
+    content_builder: Option<Rc<dyn Fn(&mut WindowContext) -> AnyElement>>,
+    content_view: Option<AnyView>,
+    min_size: Pixels,
+    max_size: Option<Pixels>,
+}
+
+impl SplitPane {
+    pub fn new() -> Self {
+        Self {
+            content_builder: None,
+            content_view: None,
+            min_size: px(0.),
+            max_size: None,
+        }
+    }
+
+    pub fn content<F>(mut self, content: F) -> Self
+    where
+        F: Fn(&mut WindowContext) -> AnyElement + 'static,
+    {
+        self.content_builder = Some(Rc::new(content));
+        self
+    }
+
+    /// Set the content view, use a `View<SplitView>` for the nested splits.
+    pub fn content_view(mut self, content: AnyView) -> Self {
+        self.content_view = Some(content);
+        self
+    }
+
+    /// Set the minimum size of the pane, default: 0px
+    pub fn min_size(mut self, size: Pixels) -> Self {
+        self.min_size = size;
+        self
+    }
+
+    /// Set the maximum size of the pane, default: None
+    pub fn max_size(mut self, size: Pixels) -> Self {
+        self.max_size = Some(size);
+        self
+    }
+
+    fn render_content(&self, cx: &mut WindowContext) -> impl IntoElement {
+        div()
+            .size_full()
+            .when_some(self.content_builder.clone(), |this, c| this.child(c(cx)))
+            .when_some(self.content_view.clone(), |this, c| this.child(c))
+    }
+}
+
+impl Default for SplitPane {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Clamp the `ratio` of the first pane to fit the min/max size of both panes in the `total` size.
+///
+/// The min sizes take precedence if the panes can't fit in the `total` size.
+fn clamp_ratio(
+    ratio: f32,
+    total: f32,
+    first: (f32, Option<f32>),
+    second: (f32, Option<f32>),
+) -> f32 {
+    let ratio = ratio.clamp(0., 1.);
+    if total <= 0. {
+        return ratio;
+    }
+
+    let (first_min, first_max) = first;
+    let (second_min, second_max) = second;
+    let lower = first_min.max(second_max.map_or(0., |max| total - max));
+    let upper = first_max.unwrap_or(total).min(total - second_min);
+
+    let size = if lower > upper {
+        lower.min(total)
+    } else {
+        (ratio * total).clamp(lower, upper)
+    };
+    size / total
+}
+
+/// A view to split the space into two resizable panes, horizontally or vertically.
+///
+/// The size of the panes is stored as the ratio of the first pane, so it can be
+/// persisted by [`SplitView::ratio`] and restored by [`SplitView::set_ratio`].
+///
+/// The divider can be dragged, or moved by the arrow keys when it is focused,
+/// double click on it to reset to the default ratio.
+///
+/// The pane sizes are derived from the container size only, so the drag will
+/// not be affected by the layout of the children, e.g. the virtualized lists.
+pub struct SplitView {
+    focus_handle: FocusHandle,
+    axis: Axis,
+    first: SplitPane,
+    second: SplitPane,
+    ratio: f32,
+    default_ratio: f32,
+    dragging: bool,
+    /// The bounds of the container, updated on every paint.
+    bounds: Bounds<Pixels>,
+}
+
+impl SplitView {
+    pub fn new(axis: Axis, cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            axis,
+            first: SplitPane::new(),
+            second: SplitPane::new(),
+            ratio: 0.5,
+            default_ratio: 0.5,
+            dragging: false,
+            bounds: Bounds::default(),
+        }
+    }
+
+    /// Create a split view with the panes side by side.
+    pub fn horizontal(cx: &mut ViewContext<Self>) -> Self {
+        Self::new(Axis::Horizontal, cx)
+    }
+
+    /// Create a split view with the panes one above the other.
+    pub fn vertical(cx: &mut ViewContext<Self>) -> Self {
+        Self::new(Axis::Vertical, cx)
+    }
+
+    /// Set the first (left or top) pane.
+    pub fn first(mut self, pane: SplitPane) -> Self {
+        self.first = pane;
+        self
+    }
+
+    /// Set the second (right or bottom) pane.
+    pub fn second(mut self, pane: SplitPane) -> Self {
+        self.second = pane;
+        self
+    }
+
+    /// Set the default ratio of the first pane, range of 0.0..1.0, default: 0.5
+    ///
+    /// This is also the initial ratio, and the ratio to reset to by double click the divider.
+    pub fn default_ratio(mut self, ratio: f32) -> Self {
+        self.default_ratio = ratio.clamp(0., 1.);
+        self.ratio = self.default_ratio;
+        self
+    }
+
+    /// Returns the ratio of the first pane, range of 0.0..1.0
+    pub fn ratio(&self) -> f32 {
+        self.ratio
+    }
+
+    /// Set the ratio of the first pane, this will not emit the [`SplitViewEvent::Resized`] event.
+    pub fn set_ratio(&mut self, ratio: f32, cx: &mut ViewContext<Self>) {
+        self.ratio = ratio.clamp(0., 1.);
+        cx.notify();
+    }
+
+    fn total_size(&self) -> Pixels {
+        self.bounds.size.along(self.axis) - HANDLE_SIZE
+    }
+
+    /// Returns the ratio fitted to the min/max size of the panes.
+    fn clamped_ratio(&self, ratio: f32) -> f32 {
+        clamp_ratio(
+            ratio,
+            self.total_size().0,
+            (self.first.min_size.0, self.first.max_size.map(|s| s.0)),
+            (self.second.min_size.0, self.second.max_size.map(|s| s.0)),
+        )
+    }
+
+    fn update_ratio(&mut self, ratio: f32, cx: &mut ViewContext<Self>) -> bool {
+        let ratio = self.clamped_ratio(ratio);
+        if ratio == self.ratio {
+            return false;
+        }
+
+        self.ratio = ratio;
+        cx.notify();
+        true
+    }
+
+    fn resize_by(&mut self, delta: Pixels, cx: &mut ViewContext<Self>) {
+        let total_size = self.total_size();
+        if total_size <= px(0.) {
+            return;
+        }
+
+        let ratio = self.clamped_ratio(self.ratio) + delta / total_size;
+        if self.update_ratio(ratio, cx) {
+            cx.emit(SplitViewEvent::Resized(self.ratio));
+        }
+    }
+
+    fn resize_backward(&mut self, _: &ResizeBackward, cx: &mut ViewContext<Self>) {
+        self.resize_by(-KEYBOARD_STEP, cx);
+    }
+
+    fn resize_forward(&mut self, _: &ResizeForward, cx: &mut ViewContext<Self>) {
+        self.resize_by(KEYBOARD_STEP, cx);
+    }
+
+    fn reset_ratio(&mut self, cx: &mut ViewContext<Self>) {
+        if self.update_ratio(self.default_ratio, cx) {
+            cx.emit(SplitViewEvent::Resized(self.ratio));
+        }
+    }
+
+    fn on_drag_move(&mut self, event: &DragMoveEvent<DragDivider>, cx: &mut ViewContext<Self>) {
+        if event.drag(cx).0 != cx.entity_id() {
+            return;
+        }
+
+        let total_size = self.total_size();
+        if total_size <= px(0.) {
+            return;
+        }
+
+        self.dragging = true;
+        let offset = event.event.position.along(self.axis) - self.bounds.origin.along(self.axis);
+        self.update_ratio(offset / total_size, cx);
+    }
+
+    fn on_mouse_up(&mut self, _: &MouseUpEvent, cx: &mut ViewContext<Self>) {
+        if !self.dragging {
+            return;
+        }
+
+        self.dragging = false;
+        cx.emit(SplitViewEvent::Resized(self.ratio));
+        cx.notify();
+    }
+
+    fn render_divider(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let entity_id = cx.entity_id();
+        let axis = self.axis;
+        let focused = self.focus_handle.is_focused(cx);
+        let color = if self.dragging || focused {
+            cx.theme().drag_border
+        } else {
+            cx.theme().border
+        };
+        let hover_color = cx.theme().drag_border;
+
+        div()
+            .id("divider")
+            .key_context(CONTEXT)
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::resize_backward))
+            .on_action(cx.listener(Self::resize_forward))
+            .relative()
+            .flex_shrink_0()
+            .bg(color)
+            .map(|this| match axis {
+                Axis::Horizontal => this.h_full().w(HANDLE_SIZE),
+                Axis::Vertical => this.w_full().h(HANDLE_SIZE),
+            })
+            .child(
+                // A larger hit area around the divider.
+                div()
+                    .id("handle")
+                    .occlude()
+                    .absolute()
+                    .map(|this| match axis {
+                        Axis::Horizontal => this
+                            .cursor_col_resize()
+                            .top_0()
+                            .left(-HANDLE_PADDING)
+                            .h_full()
+                            .w(HANDLE_SIZE + HANDLE_PADDING * 2.),
+                        Axis::Vertical => this
+                            .cursor_row_resize()
+                            .top(-HANDLE_PADDING)
+                            .left_0()
+                            .w_full()
+                            .h(HANDLE_SIZE + HANDLE_PADDING * 2.),
+                    })
+                    .child(
+                        div()
+                            .absolute()
+                            .map(|this| match axis {
+                                Axis::Horizontal => this
+                                    .top_0()
+                                    .left(HANDLE_PADDING - px(1.))
+                                    .h_full()
+                                    .w(px(3.)),
+                                Axis::Vertical => this
+                                    .left_0()
+                                    .top(HANDLE_PADDING - px(1.))
+                                    .w_full()
+                                    .h(px(3.)),
+                            })
+                            .when(self.dragging, |this| this.bg(color)),
+                    )
+                    .hover(|this| this.bg(hover_color.opacity(0.3)))
+                    .on_mouse_down(
+                        MouseButton::Left,
+                        cx.listener(|this, _, cx| this.focus_handle.focus(cx)),
+                    )
+                    .on_drag(DragDivider(entity_id), |drag, _, cx| {
+                        cx.stop_propagation();
+                        cx.new_view(|_| drag.clone())
+                    })
+                    .on_double_click(cx.listener(|this, _, cx| this.reset_ratio(cx))),
+            )
+    }
+}
+
+impl EventEmitter<SplitViewEvent> for SplitView {}
+
+impl FocusableView for SplitView {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for SplitView {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let view = cx.view().clone();
+        let axis = self.axis;
+        let ratio = self.clamped_ratio(self.ratio);
+
+        div()
+            .id("split-view")
+            .relative()
+            .flex()
+            .when(axis.is_vertical(), |this| this.flex_col())
+            .size_full()
+            .overflow_hidden()
+            .on_drag_move(cx.listener(Self::on_drag_move))
+            .on_mouse_up(MouseButton::Left, cx.listener(Self::on_mouse_up))
+            .on_mouse_up_out(MouseButton::Left, cx.listener(Self::on_mouse_up))
+            .child(
+                div()
+                    .flex_shrink_0()
+                    .overflow_hidden()
+                    .map(|this| match axis {
+                        Axis::Horizontal => this.h_full().w(self.total_size() * ratio),
+                        Axis::Vertical => this.w_full().h(self.total_size() * ratio),
+                    })
+                    // Use the ratio before the first paint, the container size is unknown.
+                    .when(self.bounds.size.along(axis).is_zero(), |this| match axis {
+                        Axis::Horizontal => this.w(relative(ratio)),
+                        Axis::Vertical => this.h(relative(ratio)),
+                    })
+                    .child(self.first.render_content(cx)),
+            )
+            .child(self.render_divider(cx))
+            .child(
+                div()
+                    .flex_1()
+                    .overflow_hidden()
+                    .map(|this| match axis {
+                        Axis::Horizontal => this.h_full().min_w_0(),
+                        Axis::Vertical => this.w_full().min_h_0(),
+                    })
+                    .child(self.second.render_content(cx)),
+            )
+            .child(
+                canvas(
+                    move |bounds, cx| {
+                        view.update(cx, |r, cx| {
+                            // Only the container size is measured, to avoid the layout
+                            // feedback loop from the content of the panes.
+                            if r.bounds.size != bounds.size {
+                                cx.notify();
+                            }
+                            r.bounds = bounds;
+                        })
+                    },
+                    |_, _, _| {},
+                )
+                .absolute()
+                .size_full(),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::clamp_ratio;
+
+    #[test]
+    fn test_clamp_ratio() {
+        assert_eq!(clamp_ratio(0.5, 1000., (0., None), (0., None)), 0.5);
+        assert_eq!(clamp_ratio(1.5, 1000., (0., None), (0., None)), 1.);
+        // The first pane min and max size.
+        assert_eq!(clamp_ratio(0.1, 1000., (200., None), (0., None)), 0.2);
+        assert_eq!(clamp_ratio(0.9, 1000., (0., Some(600.)), (0., None)), 0.6);
+        // The second pane min and max size.
+        assert_eq!(clamp_ratio(0.9, 1000., (0., None), (300., None)), 0.7);
+        assert_eq!(clamp_ratio(0.1, 1000., (0., None), (0., Some(500.))), 0.5);
+        // The min sizes can't fit, the first pane takes precedence.
+        assert_eq!(clamp_ratio(0.5, 1000., (600., None), (600., None)), 0.6);
+        // Unknown size before the first paint.
+        assert_eq!(clamp_ratio(0.3, 0., (600., None), (0., None)), 0.3);
+    }
+}
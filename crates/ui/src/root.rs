@@ -1,13 +1,13 @@
 use crate::{
-    drawer::Drawer,
+    drawer::{Drawer, DrawerResizeState},
     modal::Modal,
     notification::{Notification, NotificationList},
     theme::ActiveTheme,
     window_border, Placement,
 };
 use gpui::{
-    canvas, div, prelude::FluentBuilder as _, AnyView, DefiniteLength, FocusHandle,
-    InteractiveElement, IntoElement, ParentElement as _, Render, Styled, View, ViewContext,
+    canvas, div, prelude::FluentBuilder as _, AnyView, AppContext, DefiniteLength, FocusHandle,
+    Global, InteractiveElement, IntoElement, ParentElement as _, Render, Styled, View, ViewContext,
     VisualContext as _, WindowContext,
 };
 use std::{
@@ -15,6 +15,26 @@ use std::{
     rc::Rc,
 };
 
+/// Tracks whether the last user interaction was from the keyboard.
+///
+/// This is updated by the [`Root`] view, and used to decide to show the focus ring or not.
+struct KeyboardModality(bool);
+
+impl Global for KeyboardModality {}
+
+/// Returns true if the last user interaction was from the keyboard (or not yet known).
+pub(crate) fn is_keyboard_modality(cx: &AppContext) -> bool {
+    cx.try_global::<KeyboardModality>()
+        .map(|modality| modality.0)
+        .unwrap_or(true)
+}
+
+fn set_keyboard_modality(keyboard: bool, cx: &mut WindowContext) {
+    if is_keyboard_modality(cx) != keyboard {
+        cx.set_global(KeyboardModality(keyboard));
+    }
+}
+
 /// Extension trait for [`WindowContext`] and [`ViewContext`] to add drawer functionality.
 pub trait ContextModal: Sized {
     /// Opens a Drawer at right placement.
@@ -50,6 +70,8 @@ pub trait ContextModal: Sized {
     /// Pushes a notification to the notification list.
     fn push_notification(&mut self, note: impl Into<Notification>);
     fn clear_notifications(&mut self);
+    /// Dismisses the notifications with the id type `T`, e.g. to remove a progress notification.
+    fn remove_notification<T: Sized + 'static>(&mut self);
     /// Returns number of notifications.
     fn notifications(&self) -> Rc<Vec<View<Notification>>>;
 }
@@ -72,11 +94,16 @@ impl ContextModal for WindowContext<'_> {
             }
 
             let focus_handle = cx.focus_handle();
-            focus_handle.focus(cx);
+            // Build the drawer once to focus the `initial_focus`, e.g. the query input of a List.
+            match build(Drawer::new(cx), cx).initial_focus {
+                Some(initial_focus) => initial_focus.focus(cx),
+                None => focus_handle.focus(cx),
+            }
 
             root.active_drawer = Some(ActiveDrawer {
                 focus_handle,
                 placement,
+                resize_state: Rc::new(DrawerResizeState::default()),
                 builder: Rc::new(build),
             });
             cx.notify();
@@ -100,17 +127,15 @@ impl ContextModal for WindowContext<'_> {
         F: Fn(Modal, &mut WindowContext) -> Modal + 'static,
     {
         Root::update(self, move |root, cx| {
-            // Only save focus handle if there are no active modals.
-            // This is used to restore focus when all modals are closed.
-            if root.active_modals.len() == 0 {
-                root.previous_focus_handle = cx.focused();
-            }
+            // Save the focused element of each modal, to restore it when the modal is closed.
+            let previous_focus_handle = cx.focused();
 
             let focus_handle = cx.focus_handle();
             focus_handle.focus(cx);
 
             root.active_modals.push(ActiveModal {
                 focus_handle,
+                previous_focus_handle,
                 builder: Rc::new(build),
             });
             cx.notify();
@@ -123,14 +148,16 @@ impl ContextModal for WindowContext<'_> {
 
     fn close_modal(&mut self) {
         Root::update(self, move |root, cx| {
-            root.active_modals.pop();
-
-            if let Some(top_modal) = root.active_modals.last() {
+            let Some(modal) = root.active_modals.pop() else {
+                return;
+            };
+
+            if let Some(handle) = modal.previous_focus_handle {
+                // Restore focus to the element focused before the modal opened.
+                cx.focus(&handle);
+            } else if let Some(top_modal) = root.active_modals.last() {
                 // Focus the next modal.
                 top_modal.focus_handle.focus(cx);
-            } else {
-                // Restore focus if there are no more modals.
-                root.focus_back(cx);
             }
             cx.notify();
         })
@@ -138,8 +165,10 @@ impl ContextModal for WindowContext<'_> {
 
     fn close_all_modals(&mut self) {
         Root::update(self, |root, cx| {
-            root.active_modals.clear();
-            root.focus_back(cx);
+            let first_modal = root.active_modals.drain(..).next();
+            if let Some(handle) = first_modal.and_then(|modal| modal.previous_focus_handle) {
+                cx.focus(&handle);
+            }
             cx.notify();
         })
     }
@@ -159,6 +188,14 @@ impl ContextModal for WindowContext<'_> {
         })
     }
 
+    fn remove_notification<T: Sized + 'static>(&mut self) {
+        Root::update(self, move |root, cx| {
+            root.notification
+                .update(cx, |view, cx| view.remove::<T>(cx));
+            cx.notify();
+        })
+    }
+
     fn notifications(&self) -> Rc<Vec<View<Notification>>> {
         Rc::new(Root::read(&self).notification.read(&self).notifications())
     }
@@ -215,6 +252,10 @@ impl<V> ContextModal for ViewContext<'_, V> {
         self.deref_mut().clear_notifications()
     }
 
+    fn remove_notification<T: Sized + 'static>(&mut self) {
+        self.deref_mut().remove_notification::<T>()
+    }
+
     fn notifications(&self) -> Rc<Vec<View<Notification>>> {
         self.deref().notifications()
     }
@@ -225,7 +266,7 @@ impl<V> ContextModal for ViewContext<'_, V> {
 /// It is used to manage the Drawer, Modal, and Notification.
 pub struct Root {
     /// Used to store the focus handle of the previous view.
-    /// When the Modal, Drawer closes, we will focus back to the previous view.
+    /// When the Drawer closes, we will focus back to the previous view.
     previous_focus_handle: Option<FocusHandle>,
     active_drawer: Option<ActiveDrawer>,
     active_modals: Vec<ActiveModal>,
@@ -238,12 +279,15 @@ pub struct Root {
 struct ActiveDrawer {
     focus_handle: FocusHandle,
     placement: Placement,
+    resize_state: Rc<DrawerResizeState>,
     builder: Rc<dyn Fn(Drawer, &mut WindowContext) -> Drawer + 'static>,
 }
 
 #[derive(Clone)]
 struct ActiveModal {
     focus_handle: FocusHandle,
+    /// The focused element before the modal opened.
+    previous_focus_handle: Option<FocusHandle>,
     builder: Rc<dyn Fn(Modal, &mut WindowContext) -> Modal + 'static>,
 }
 
@@ -333,8 +377,9 @@ impl Root {
             drawer = (active_drawer.builder)(drawer, cx);
             drawer.focus_handle = active_drawer.focus_handle.clone();
             drawer.placement = active_drawer.placement;
+            drawer.resize_state = active_drawer.resize_state.clone();
 
-            let drawer_size = drawer.size;
+            let drawer_size = drawer.current_size();
 
             return Some(
                 div().relative().child(drawer).child(
@@ -407,8 +452,10 @@ impl Render for Root {
                 .id("root")
                 .relative()
                 .size_full()
-                .font_family(".SystemUIFont")
-                .bg(cx.theme().background)
+                .capture_key_down(|_, cx| set_keyboard_modality(true, cx))
+                .capture_any_mouse_down(|_, cx| set_keyboard_modality(false, cx))
+                .font_family(cx.theme().font_family.clone())
+                .bg(cx.theme().panel_background())
                 .text_color(cx.theme().foreground)
                 .child(self.view.clone()),
         )
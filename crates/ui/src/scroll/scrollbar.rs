@@ -1,33 +1,140 @@
-use std::{cell::Cell, rc::Rc, time::Instant};
+use std::{any::TypeId, cell::Cell, rc::Rc, time::Duration};
 
-use crate::theme::ActiveTheme;
+use crate::{
+    animation::{Animated, Easing},
+    theme::ActiveTheme,
+};
 use gpui::{
-    fill, point, px, relative, AppContext, Bounds, ContentMask, CursorStyle, Edges, Element,
-    EntityId, Hitbox, Hsla, IntoElement, MouseDownEvent, MouseMoveEvent, MouseUpEvent, PaintQuad,
-    Pixels, Point, Position, ScrollHandle, ScrollWheelEvent, Style, UniformListScrollHandle,
+    actions, fill, point, px, relative, Action, AppContext, Bounds, ContentMask, CursorStyle,
+    DispatchPhase, Edges, Element, EntityId, FocusHandle, Hitbox, Hsla, IntoElement, KeyBinding,
+    KeyContext, MouseDownEvent, MouseMoveEvent, MouseUpEvent, PaintQuad, Pixels, Point, Position,
+    ScrollHandle, ScrollWheelEvent, Style, UniformListScrollHandle, WindowContext,
 };
 use serde::{Deserialize, Serialize};
 
+actions!(
+    scrollbar,
+    [
+        ScrollUp,
+        ScrollDown,
+        ScrollLeft,
+        ScrollRight,
+        ScrollPageUp,
+        ScrollPageDown,
+        ScrollToStart,
+        ScrollToEnd
+    ]
+);
+
+const CONTEXT: &str = "Scrollbar";
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("up", ScrollUp, Some(CONTEXT)),
+        KeyBinding::new("down", ScrollDown, Some(CONTEXT)),
+        KeyBinding::new("left", ScrollLeft, Some(CONTEXT)),
+        KeyBinding::new("right", ScrollRight, Some(CONTEXT)),
+        KeyBinding::new("pageup", ScrollPageUp, Some(CONTEXT)),
+        KeyBinding::new("pagedown", ScrollPageDown, Some(CONTEXT)),
+        KeyBinding::new("home", ScrollToStart, Some(CONTEXT)),
+        KeyBinding::new("end", ScrollToEnd, Some(CONTEXT)),
+    ]);
+}
+
 /// Scrollbar show mode.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Hash, Default)]
 pub enum ScrollbarShow {
     #[default]
     Scrolling,
     Hover,
+    /// Always show the scrollbar, used by the high contrast themes.
+    Always,
 }
 
 impl ScrollbarShow {
     fn is_hover(&self) -> bool {
         matches!(self, Self::Hover)
     }
+
+    fn is_always(&self) -> bool {
+        matches!(self, Self::Always)
+    }
 }
 
 const BORDER_WIDTH: Pixels = px(0.);
 const MIN_THUMB_SIZE: f32 = 80.;
 const THUMB_RADIUS: Pixels = Pixels(3.0);
 const THUMB_INSET: Pixels = Pixels(4.);
-const FADE_OUT_DURATION: f32 = 3.0;
-const FADE_OUT_DELAY: f32 = 2.0;
+const FADE_OUT_DURATION: Duration = Duration::from_secs(1);
+const FADE_OUT_DELAY: Duration = Duration::from_secs(2);
+/// The distance away from the scrollbar to snap back the dragging thumb, see [`Scrollbar::snap_back`].
+const SNAP_BACK_DISTANCE: Pixels = px(150.);
+/// The distance to scroll by the arrow keys, when the scrollbar is focused.
+const KEY_SCROLL_STEP: Pixels = px(40.);
+
+/// How to scroll by the keyboard, when the scrollbar is focused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum KeyScroll {
+    Backward,
+    Forward,
+    PageBackward,
+    PageForward,
+    Start,
+    End,
+}
+
+/// Returns the new offset (negative when scrolled) of an axis, when the thumb is dragged by `delta` from the `start_offset`.
+///
+/// The `track_size` is the length the thumb can move in, plus the thumb size.
+fn drag_scroll_offset(
+    start_offset: Pixels,
+    delta: Pixels,
+    scroll_size: Pixels,
+    container_size: Pixels,
+    track_size: Pixels,
+    thumb_size: Pixels,
+) -> Pixels {
+    let max_offset = (scroll_size - container_size).max(px(0.));
+    let movable = track_size - thumb_size;
+    if movable <= px(0.) {
+        return start_offset;
+    }
+
+    (start_offset - max_offset * (delta / movable)).clamp(-max_offset, px(0.))
+}
+
+/// Returns the distance of the `position` out of the `start..end` range, 0 if it is in the range.
+fn distance_out_of(position: Pixels, start: Pixels, end: Pixels) -> Pixels {
+    if position < start {
+        start - position
+    } else if position > end {
+        position - end
+    } else {
+        px(0.)
+    }
+}
+
+/// Returns the new offset (negative when scrolled) of an axis, for the keyboard scroll.
+fn key_scroll_offset(
+    scroll: KeyScroll,
+    offset: Pixels,
+    scroll_size: Pixels,
+    container_size: Pixels,
+) -> Pixels {
+    let max_offset = (scroll_size - container_size).max(px(0.));
+    // Keep a line of the previous page visible.
+    let page = (container_size - KEY_SCROLL_STEP).max(KEY_SCROLL_STEP);
+
+    let offset = match scroll {
+        KeyScroll::Backward => offset + KEY_SCROLL_STEP,
+        KeyScroll::Forward => offset - KEY_SCROLL_STEP,
+        KeyScroll::PageBackward => offset + page,
+        KeyScroll::PageForward => offset - page,
+        KeyScroll::Start => px(0.),
+        KeyScroll::End => -max_offset,
+    };
+    offset.clamp(-max_offset, px(0.))
+}
 
 pub trait ScrollHandleOffsetable {
     fn offset(&self) -> Point<Pixels>;
@@ -66,9 +173,16 @@ pub struct ScrollbarState {
     hovered_axis: Option<ScrollbarAxis>,
     hovered_on_thumb: Option<ScrollbarAxis>,
     dragged_axis: Option<ScrollbarAxis>,
-    drag_pos: Point<Pixels>,
+    /// The mouse position when the thumb is grabbed.
+    drag_start: Point<Pixels>,
+    /// The scroll offset when the thumb is grabbed.
+    drag_start_offset: Point<Pixels>,
     last_scroll_offset: Point<Pixels>,
-    last_scroll_time: Option<Instant>,
+    /// The opacity of the idle thumb, it fades out after scrolling.
+    thumb_opacity: Animated<f32>,
+    /// The bounds of the vertical bar in the last prepaint, to check the layout in the tests.
+    #[cfg(test)]
+    pub(crate) vertical_bar_bounds: Option<Bounds<Pixels>>,
 }
 
 impl Default for ScrollbarState {
@@ -77,9 +191,12 @@ impl Default for ScrollbarState {
             hovered_axis: None,
             hovered_on_thumb: None,
             dragged_axis: None,
-            drag_pos: point(px(0.), px(0.)),
+            drag_start: point(px(0.), px(0.)),
+            drag_start_offset: point(px(0.), px(0.)),
             last_scroll_offset: point(px(0.), px(0.)),
-            last_scroll_time: None,
+            thumb_opacity: Animated::new(0.),
+            #[cfg(test)]
+            vertical_bar_bounds: None,
         }
     }
 }
@@ -89,14 +206,15 @@ impl ScrollbarState {
         Self::default()
     }
 
-    fn with_drag_pos(&self, axis: ScrollbarAxis, pos: Point<Pixels>) -> Self {
+    fn with_drag_start(
+        &self,
+        axis: ScrollbarAxis,
+        position: Point<Pixels>,
+        offset: Point<Pixels>,
+    ) -> Self {
         let mut state = *self;
-        if axis.is_vertical() {
-            state.drag_pos.y = pos.y;
-        } else {
-            state.drag_pos.x = pos.x;
-        }
-
+        state.drag_start = position;
+        state.drag_start_offset = offset;
         state.dragged_axis = Some(axis);
         state
     }
@@ -111,7 +229,7 @@ impl ScrollbarState {
         let mut state = *self;
         state.hovered_axis = axis;
         if self.is_scrollbar_visible() {
-            state.last_scroll_time = Some(Instant::now());
+            state.thumb_opacity = fade_out();
         }
         state
     }
@@ -122,30 +240,69 @@ impl ScrollbarState {
         state
     }
 
-    fn with_last_scroll(
-        &self,
-        last_scroll_offset: Point<Pixels>,
-        last_scroll_time: Option<Instant>,
-    ) -> Self {
+    /// Set the last scroll offset, and show the thumb then fade it out.
+    fn with_last_scroll(&self, last_scroll_offset: Point<Pixels>) -> Self {
         let mut state = *self;
         state.last_scroll_offset = last_scroll_offset;
-        state.last_scroll_time = last_scroll_time;
+        state.thumb_opacity = fade_out();
         state
     }
 
-    fn with_last_scroll_time(&self, t: Option<Instant>) -> Self {
+    /// Restart the fade out of the thumb, to keep it visible.
+    fn with_fade_out_restarted(&self) -> Self {
         let mut state = *self;
-        state.last_scroll_time = t;
+        state.thumb_opacity = fade_out();
         state
     }
 
     fn is_scrollbar_visible(&self) -> bool {
-        if let Some(last_time) = self.last_scroll_time {
-            let elapsed = Instant::now().duration_since(last_time).as_secs_f32();
-            elapsed < FADE_OUT_DURATION
-        } else {
-            false
-        }
+        self.thumb_opacity.is_animating()
+    }
+
+    /// Returns true if the scrollbar is visible by the `scrollbar_show` of the theme,
+    /// e.g. to only put a [`Scrollbar::focusable`] in the tab order when it can be seen.
+    pub fn is_visible(&self, cx: &AppContext) -> bool {
+        let show = cx.theme().scrollbar_show;
+        show.is_always()
+            || (show.is_hover() && self.hovered_axis.is_some())
+            || self.is_scrollbar_visible()
+    }
+}
+
+/// Returns the thumb opacity that is fully visible for the delay, and then fades out.
+fn fade_out() -> Animated<f32> {
+    let mut opacity = Animated::new(1.)
+        .delay(FADE_OUT_DELAY)
+        .duration(FADE_OUT_DURATION)
+        .easing(Easing::EaseIn);
+    opacity.animate_to(0.);
+    opacity
+}
+
+/// Override the theme colors for a Scrollbar.
+///
+/// The `None` fields will fall back to the global theme, so they will follow the theme changes.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ScrollbarTheme {
+    /// The track background, default: `scrollbar`.
+    pub track: Option<Hsla>,
+    /// The thumb color, default: `scrollbar_thumb`.
+    pub thumb: Option<Hsla>,
+    /// The thumb color when hovered or dragging, default: `scrollbar_thumb_hover`.
+    pub thumb_hover: Option<Hsla>,
+}
+
+impl ScrollbarTheme {
+    fn track(&self, cx: &AppContext) -> Hsla {
+        self.track.unwrap_or(cx.theme().scrollbar)
+    }
+
+    fn thumb(&self, cx: &AppContext) -> Hsla {
+        self.thumb.unwrap_or(cx.theme().scrollbar_thumb)
+    }
+
+    fn thumb_hover(&self, cx: &AppContext) -> Hsla {
+        self.thumb_hover.unwrap_or(cx.theme().scrollbar_thumb_hover)
     }
 }
 
@@ -198,6 +355,9 @@ pub struct Scrollbar {
     scroll_handle: Rc<Box<dyn ScrollHandleOffsetable>>,
     scroll_size: gpui::Size<Pixels>,
     state: Rc<Cell<ScrollbarState>>,
+    theme: ScrollbarTheme,
+    focus_handle: Option<FocusHandle>,
+    snap_back: bool,
 }
 
 impl Scrollbar {
@@ -215,6 +375,9 @@ impl Scrollbar {
             scroll_size,
             width: px(12.),
             scroll_handle: Rc::new(Box::new(scroll_handle)),
+            theme: ScrollbarTheme::default(),
+            focus_handle: None,
+            snap_back: false,
         }
     }
 
@@ -294,43 +457,66 @@ impl Scrollbar {
         self
     }
 
-    fn style_for_active(cx: &AppContext) -> (Hsla, Hsla, Hsla, Pixels, Pixels) {
+    /// Make the scrollbar focusable with the `focus_handle`, to scroll by the arrows, PageUp/PageDown and Home/End.
+    ///
+    /// The scrollbar is kept visible while it is focused, and the parent decides the tab order of it,
+    /// see [`ScrollbarState::is_visible`].
+    pub fn focusable(mut self, focus_handle: &FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle.clone());
+        self
+    }
+
+    /// Set true to restore the scroll offset while the dragging mouse is far away from the scrollbar,
+    /// it is scrolled again when the mouse comes back, default: false
+    pub fn snap_back(mut self, snap_back: bool) -> Self {
+        self.snap_back = snap_back;
+        self
+    }
+
+    /// Override the theme colors of this scrollbar, see [`ScrollbarTheme`].
+    pub fn theme_override(mut self, theme: ScrollbarTheme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    fn style_for_active(&self, cx: &AppContext) -> (Hsla, Hsla, Hsla, Pixels, Pixels) {
         (
-            cx.theme().scrollbar_thumb_hover,
-            cx.theme().scrollbar,
+            self.theme.thumb_hover(cx),
+            self.theme.track(cx),
             cx.theme().border,
             THUMB_INSET - px(1.),
             THUMB_RADIUS,
         )
     }
 
-    fn style_for_hovered_thumb(cx: &AppContext) -> (Hsla, Hsla, Hsla, Pixels, Pixels) {
+    fn style_for_hovered_thumb(&self, cx: &AppContext) -> (Hsla, Hsla, Hsla, Pixels, Pixels) {
         (
-            cx.theme().scrollbar_thumb_hover,
-            cx.theme().scrollbar,
+            self.theme.thumb_hover(cx),
+            self.theme.track(cx),
             cx.theme().border,
             THUMB_INSET - px(1.),
             THUMB_RADIUS,
         )
     }
 
-    fn style_for_hovered_bar(cx: &AppContext) -> (Hsla, Hsla, Hsla, Pixels, Pixels) {
-        let (inset, radius) = if cx.theme().scrollbar_show.is_hover() {
+    fn style_for_hovered_bar(&self, cx: &AppContext) -> (Hsla, Hsla, Hsla, Pixels, Pixels) {
+        let scrollbar_show = cx.theme().scrollbar_show;
+        let (inset, radius) = if scrollbar_show.is_hover() || scrollbar_show.is_always() {
             (THUMB_INSET, THUMB_RADIUS - px(1.))
         } else {
             (THUMB_INSET - px(1.), THUMB_RADIUS)
         };
 
         (
-            cx.theme().scrollbar_thumb,
-            cx.theme().scrollbar,
+            self.theme.thumb(cx),
+            self.theme.track(cx),
             gpui::transparent_black(),
             inset,
             radius,
         )
     }
 
-    fn style_for_idle(_: &AppContext) -> (Hsla, Hsla, Hsla, Pixels, Pixels) {
+    fn style_for_idle(&self, _: &AppContext) -> (Hsla, Hsla, Hsla, Pixels, Pixels) {
         (
             gpui::transparent_black(),
             gpui::transparent_black(),
@@ -409,6 +595,10 @@ impl Element for Scrollbar {
         let mut states = vec![];
 
         let mut has_both = self.axis.is_both();
+        let is_focused = self
+            .focus_handle
+            .as_ref()
+            .map_or(false, |handle| handle.is_focused(cx));
 
         for axis in self.axis.all().into_iter() {
             let is_vertical = axis.is_vertical();
@@ -472,43 +662,42 @@ impl Element for Scrollbar {
             };
 
             let state = self.state.clone();
+            #[cfg(test)]
+            if is_vertical {
+                state.set(ScrollbarState {
+                    vertical_bar_bounds: Some(bounds),
+                    ..state.get()
+                });
+            }
             let is_hover_to_show = cx.theme().scrollbar_show.is_hover();
+            let is_always_to_show = cx.theme().scrollbar_show.is_always();
             let is_hovered_on_bar = state.get().hovered_axis == Some(axis);
             let is_hovered_on_thumb = state.get().hovered_on_thumb == Some(axis);
 
             let (thumb_bg, bar_bg, bar_border, inset, radius) =
-                if state.get().dragged_axis == Some(axis) {
-                    Self::style_for_active(cx)
-                } else if is_hover_to_show && is_hovered_on_bar {
+                if state.get().dragged_axis == Some(axis) || is_focused {
+                    self.style_for_active(cx)
+                } else if (is_hover_to_show && is_hovered_on_bar) || is_always_to_show {
                     if is_hovered_on_thumb {
-                        Self::style_for_hovered_thumb(cx)
+                        self.style_for_hovered_thumb(cx)
                     } else {
-                        Self::style_for_hovered_bar(cx)
+                        self.style_for_hovered_bar(cx)
                     }
                 } else {
-                    let mut idle_state = Self::style_for_idle(cx);
+                    let mut idle_state = self.style_for_idle(cx);
                     // Delay 2s to fade out the scrollbar thumb (in 1s)
-                    if let Some(last_time) = state.get().last_scroll_time {
-                        let elapsed = Instant::now().duration_since(last_time).as_secs_f32();
-                        if elapsed < FADE_OUT_DURATION {
-                            if is_hovered_on_bar {
-                                state.set(state.get().with_last_scroll_time(Some(Instant::now())));
-                                idle_state = if is_hovered_on_thumb {
-                                    Self::style_for_hovered_thumb(cx)
-                                } else {
-                                    Self::style_for_hovered_bar(cx)
-                                };
+                    let thumb_opacity = state.get().thumb_opacity;
+                    if thumb_opacity.is_animating() {
+                        if is_hovered_on_bar {
+                            state.set(state.get().with_fade_out_restarted());
+                            idle_state = if is_hovered_on_thumb {
+                                self.style_for_hovered_thumb(cx)
                             } else {
-                                if elapsed < FADE_OUT_DELAY {
-                                    idle_state.0 = cx.theme().scrollbar_thumb;
-                                } else {
-                                    // opacity = 1 - (x - 2)^10
-                                    let opacity = 1.0 - (elapsed - FADE_OUT_DELAY).powi(10);
-                                    idle_state.0 = cx.theme().scrollbar_thumb.opacity(opacity);
-                                };
-
-                                cx.request_animation_frame();
-                            }
+                                self.style_for_hovered_bar(cx)
+                            };
+                        } else {
+                            let opacity = thumb_opacity.tick(cx);
+                            idle_state.0 = self.theme.thumb(cx).opacity(opacity);
                         }
                     }
 
@@ -550,6 +739,9 @@ impl Element for Scrollbar {
                 )
             };
 
+            // Follow the theme radius, but keep the thumb no more rounded than a pill.
+            let radius = radius.min(cx.theme().radius());
+
             let bar_hitbox = cx.with_content_mask(Some(ContentMask { bounds }), |cx| {
                 cx.insert_hitbox(bounds, false)
             });
@@ -585,6 +777,15 @@ impl Element for Scrollbar {
         let hitbox_bounds = prepaint.hitbox.bounds;
         let is_visible = self.state.get().is_scrollbar_visible();
         let is_hover_to_show = cx.theme().scrollbar_show.is_hover();
+        let is_always_to_show = cx.theme().scrollbar_show.is_always();
+        let is_focused = self
+            .focus_handle
+            .as_ref()
+            .map_or(false, |handle| handle.is_focused(cx));
+        if is_focused {
+            // Keep the thumb visible after it is unfocused, like the end of scrolling.
+            self.state.set(self.state.get().with_fade_out_restarted());
+        }
 
         for state in prepaint.states.iter() {
             let axis = state.axis;
@@ -625,6 +826,15 @@ impl Element for Scrollbar {
                 });
 
                 cx.paint_quad(fill(state.thumb_fill_bounds, state.thumb_bg).corner_radii(radius));
+                if is_focused {
+                    cx.paint_quad(PaintQuad {
+                        bounds: state.thumb_fill_bounds,
+                        corner_radii: radius.into(),
+                        background: gpui::transparent_black().into(),
+                        border_widths: Edges::all(px(1.)),
+                        border_color: cx.theme().ring,
+                    });
+                }
             });
 
             cx.on_mouse_event({
@@ -635,11 +845,7 @@ impl Element for Scrollbar {
                 move |event: &ScrollWheelEvent, phase, cx| {
                     if phase.bubble() && hitbox_bounds.contains(&event.position) {
                         if scroll_handle.offset() != state.get().last_scroll_offset {
-                            state.set(
-                                state
-                                    .get()
-                                    .with_last_scroll(scroll_handle.offset(), Some(Instant::now())),
-                            );
+                            state.set(state.get().with_last_scroll(scroll_handle.offset()));
                             cx.notify(Some(view_id));
                         }
                     }
@@ -648,7 +854,7 @@ impl Element for Scrollbar {
 
             let safe_range = (-scroll_area_size + container_size)..px(0.);
 
-            if is_hover_to_show || is_visible {
+            if is_hover_to_show || is_always_to_show || is_visible {
                 cx.on_mouse_event({
                     let state = self.state.clone();
                     let view_id = self.view_id;
@@ -659,10 +865,12 @@ impl Element for Scrollbar {
                             cx.stop_propagation();
 
                             if thumb_bounds.contains(&event.position) {
-                                // click on the thumb bar, set the drag position
-                                let pos = event.position - thumb_bounds.origin;
-
-                                state.set(state.get().with_drag_pos(axis, pos));
+                                // click on the thumb bar, the thumb follows the mouse moved from here.
+                                state.set(state.get().with_drag_start(
+                                    axis,
+                                    event.position,
+                                    scroll_handle.offset(),
+                                ));
 
                                 cx.notify(Some(view_id));
                             } else {
@@ -701,8 +909,9 @@ impl Element for Scrollbar {
                 let scroll_handle = self.scroll_handle.clone();
                 let state = self.state.clone();
                 let view_id = self.view_id;
+                let snap_back = self.snap_back;
 
-                move |event: &MouseMoveEvent, _, cx| {
+                move |event: &MouseMoveEvent, phase, cx| {
                     // Update hovered state for scrollbar
                     if bounds.contains(&event.position) {
                         if state.get().hovered_axis != Some(axis) {
@@ -731,37 +940,53 @@ impl Element for Scrollbar {
                         }
                     }
 
-                    // Move thumb position on dragging
-                    if state.get().dragged_axis == Some(axis) && event.dragging() {
-                        // drag_pos is the position of the mouse down event
-                        // We need to keep the thumb bar still at the origin down position
-                        let drag_pos = state.get().drag_pos;
-
-                        let percentage = (if is_vertical {
-                            (event.position.y - drag_pos.y - bounds.origin.y)
-                                / (bounds.size.height - thumb_size)
+                    // Move thumb position on dragging, in the capture phase to follow the mouse anywhere,
+                    // and the elements under the mouse are not hovered.
+                    if phase.capture() && state.get().dragged_axis == Some(axis) && event.dragging()
+                    {
+                        cx.stop_propagation();
+                        let start = state.get().drag_start;
+                        let start_offset = state.get().drag_start_offset;
+
+                        let (delta, distance, start_offset, track_size) = if is_vertical {
+                            (
+                                event.position.y - start.y,
+                                distance_out_of(event.position.x, bounds.left(), bounds.right()),
+                                start_offset.y,
+                                bounds.size.height,
+                            )
                         } else {
-                            (event.position.x - drag_pos.x - bounds.origin.x)
-                                / (bounds.size.width - thumb_size - margin_end)
-                        })
-                        .clamp(0., 1.);
-
-                        let offset = if is_vertical {
-                            point(
-                                scroll_handle.offset().x,
-                                (-(scroll_area_size - container_size) * percentage)
-                                    .clamp(safe_range.start, safe_range.end),
+                            (
+                                event.position.x - start.x,
+                                distance_out_of(event.position.y, bounds.top(), bounds.bottom()),
+                                start_offset.x,
+                                bounds.size.width - margin_end,
                             )
+                        };
+
+                        let axis_offset = if snap_back && distance > SNAP_BACK_DISTANCE {
+                            start_offset
                         } else {
-                            point(
-                                (-(scroll_area_size - container_size) * percentage)
-                                    .clamp(safe_range.start, safe_range.end),
-                                scroll_handle.offset().y,
+                            drag_scroll_offset(
+                                start_offset,
+                                delta,
+                                scroll_area_size,
+                                container_size,
+                                track_size,
+                                thumb_size,
                             )
                         };
 
-                        scroll_handle.set_offset(offset);
-                        cx.notify(Some(view_id));
+                        let mut offset = scroll_handle.offset();
+                        if is_vertical {
+                            offset.y = axis_offset;
+                        } else {
+                            offset.x = axis_offset;
+                        }
+                        if offset != scroll_handle.offset() {
+                            scroll_handle.set_offset(offset);
+                            cx.notify(Some(view_id));
+                        }
                     }
                 }
             });
@@ -771,12 +996,135 @@ impl Element for Scrollbar {
                 let state = self.state.clone();
 
                 move |_event: &MouseUpEvent, phase, cx| {
-                    if phase.bubble() {
+                    // The drag ends anywhere, even if the mouse up is handled by the other elements.
+                    if phase.capture() && state.get().dragged_axis.is_some() {
                         state.set(state.get().with_unset_drag_pos());
                         cx.notify(Some(view_id));
                     }
                 }
             });
         }
+
+        self.paint_key_listeners(prepaint, cx);
+    }
+}
+
+impl Scrollbar {
+    /// Scroll by the keyboard when focused, the vertical axis is the primary one to PageUp/PageDown and Home/End.
+    fn paint_key_listeners(&self, prepaint: &PrepaintState, cx: &mut WindowContext) {
+        let Some(focus_handle) = self.focus_handle.clone() else {
+            return;
+        };
+        if prepaint.states.is_empty() {
+            return;
+        }
+
+        let axes = prepaint
+            .states
+            .iter()
+            .map(|state| (state.axis, state.scroll_size, state.container_size))
+            .collect::<Vec<_>>();
+        let state = self.state.clone();
+        let view_id = self.view_id;
+        let scroll_handle = self.scroll_handle.clone();
+        let scroll = Rc::new(
+            move |axis: Option<ScrollbarAxis>, scroll: KeyScroll, cx: &mut WindowContext| {
+                let Some(&(axis, scroll_size, container_size)) = axes
+                    .iter()
+                    .find(|(a, _, _)| axis.map_or(true, |axis| *a == axis))
+                else {
+                    return;
+                };
+
+                let mut offset = scroll_handle.offset();
+                if axis.is_vertical() {
+                    offset.y = key_scroll_offset(scroll, offset.y, scroll_size, container_size);
+                } else {
+                    offset.x = key_scroll_offset(scroll, offset.x, scroll_size, container_size);
+                }
+                if offset != scroll_handle.offset() {
+                    scroll_handle.set_offset(offset);
+                    state.set(state.get().with_last_scroll(offset));
+                    cx.notify(Some(view_id));
+                }
+            },
+        );
+
+        let mut context = KeyContext::default();
+        context.add(CONTEXT);
+        cx.with_key_dispatch(Some(context), Some(focus_handle), |_, cx| {
+            let vertical = Some(ScrollbarAxis::Vertical);
+            let horizontal = Some(ScrollbarAxis::Horizontal);
+            on_key_scroll::<ScrollUp>(vertical, KeyScroll::Backward, &scroll, cx);
+            on_key_scroll::<ScrollDown>(vertical, KeyScroll::Forward, &scroll, cx);
+            on_key_scroll::<ScrollLeft>(horizontal, KeyScroll::Backward, &scroll, cx);
+            on_key_scroll::<ScrollRight>(horizontal, KeyScroll::Forward, &scroll, cx);
+            on_key_scroll::<ScrollPageUp>(None, KeyScroll::PageBackward, &scroll, cx);
+            on_key_scroll::<ScrollPageDown>(None, KeyScroll::PageForward, &scroll, cx);
+            on_key_scroll::<ScrollToStart>(None, KeyScroll::Start, &scroll, cx);
+            on_key_scroll::<ScrollToEnd>(None, KeyScroll::End, &scroll, cx);
+        });
+    }
+}
+
+fn on_key_scroll<A: Action>(
+    axis: Option<ScrollbarAxis>,
+    scroll: KeyScroll,
+    handler: &Rc<impl Fn(Option<ScrollbarAxis>, KeyScroll, &mut WindowContext) + 'static>,
+    cx: &mut WindowContext,
+) {
+    let handler = handler.clone();
+    cx.on_action(TypeId::of::<A>(), move |_, phase, cx| {
+        if phase == DispatchPhase::Bubble {
+            handler(axis, scroll, cx);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::px;
+
+    use super::{distance_out_of, drag_scroll_offset, key_scroll_offset, KeyScroll};
+
+    #[test]
+    fn test_drag_scroll_offset() {
+        // 800px to scroll in the 200px container, the 100px thumb moves in the 200px track.
+        let offset = |start_offset, delta| {
+            drag_scroll_offset(start_offset, delta, px(1000.), px(200.), px(200.), px(100.))
+        };
+
+        assert_eq!(offset(px(0.), px(50.)), px(-400.));
+        assert_eq!(offset(px(-400.), px(-50.)), px(0.));
+        // Clamped far away from the track.
+        assert_eq!(offset(px(-400.), px(1000.)), px(-800.));
+        assert_eq!(offset(px(-400.), px(-1000.)), px(0.));
+        // The thumb can't move.
+        assert_eq!(
+            drag_scroll_offset(px(-10.), px(50.), px(1000.), px(200.), px(80.), px(80.)),
+            px(-10.)
+        );
+
+        assert_eq!(distance_out_of(px(5.), px(10.), px(20.)), px(5.));
+        assert_eq!(distance_out_of(px(15.), px(10.), px(20.)), px(0.));
+        assert_eq!(distance_out_of(px(30.), px(10.), px(20.)), px(10.));
+    }
+
+    #[test]
+    fn test_key_scroll_offset() {
+        let (scroll_size, container_size) = (px(1000.), px(200.));
+        let offset =
+            |scroll, offset| key_scroll_offset(scroll, offset, scroll_size, container_size);
+
+        assert_eq!(offset(KeyScroll::Forward, px(0.)), px(-40.));
+        assert_eq!(offset(KeyScroll::Backward, px(-40.)), px(0.));
+        // Clamped at the edges.
+        assert_eq!(offset(KeyScroll::Backward, px(0.)), px(0.));
+        assert_eq!(offset(KeyScroll::Forward, px(-790.)), px(-800.));
+        // A page keeps a line of the previous page.
+        assert_eq!(offset(KeyScroll::PageForward, px(0.)), px(-160.));
+        assert_eq!(offset(KeyScroll::PageBackward, px(-100.)), px(0.));
+        assert_eq!(offset(KeyScroll::End, px(0.)), px(-800.));
+        assert_eq!(offset(KeyScroll::Start, px(-500.)), px(0.));
     }
 }
@@ -0,0 +1,343 @@
+use std::time::Duration;
+
+use gpui::{
+    actions, canvas, div, prelude::FluentBuilder as _, px, Animation, AnimationExt as _,
+    AppContext, Bounds, ElementId, EventEmitter, FocusHandle, FocusableView, InteractiveElement,
+    IntoElement, KeyBinding, ParentElement as _, Pixels, Render, SharedString,
+    StatefulInteractiveElement as _, Styled, ViewContext,
+};
+
+use crate::{
+    animation::{cubic_bezier, reduced_motion},
+    h_flex,
+    selection::step_option,
+    theme::ActiveTheme as _,
+    Icon, IconName, Sizable, Size, StyledExt as _,
+};
+
+actions!(segmented_control, [SelectPrev, SelectNext]);
+
+const CONTEXT: &str = "SegmentedControl";
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("left", SelectPrev, Some(CONTEXT)),
+        KeyBinding::new("right", SelectNext, Some(CONTEXT)),
+    ]);
+}
+
+pub enum SegmentedControlEvent {
+    /// The selected segment is changed by the user, with the index of the segment.
+    Changed(usize),
+}
+
+/// A segment of the [`SegmentedControl`], with a label, an icon or both.
+pub struct Segment {
+    label: Option<SharedString>,
+    icon: Option<IconName>,
+    disabled: bool,
+}
+
+impl Segment {
+    pub fn new() -> Self {
+        Self {
+            label: None,
+            icon: None,
+            disabled: false,
+        }
+    }
+
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn icon(mut self, icon: IconName) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl Default for Segment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<&'static str> for Segment {
+    fn from(label: &'static str) -> Self {
+        Self::new().label(label)
+    }
+}
+
+impl From<SharedString> for Segment {
+    fn from(label: SharedString) -> Self {
+        Self::new().label(label)
+    }
+}
+
+impl From<IconName> for Segment {
+    fn from(icon: IconName) -> Self {
+        Self::new().icon(icon)
+    }
+}
+
+/// A compact control to select one of a few options, the options are shown as connected buttons.
+///
+/// ```ignore
+/// let control = cx.new_view(|cx| {
+///     SegmentedControl::new("view-mode", cx)
+///         .segment(Segment::new().icon(IconName::LayoutDashboard).label("Grid"))
+///         .segment(Segment::new().icon(IconName::Menu).label("List"))
+/// });
+/// ```
+pub struct SegmentedControl {
+    id: ElementId,
+    focus_handle: FocusHandle,
+    segments: Vec<Segment>,
+    selected_ix: usize,
+    /// The previous selected index, to animate the highlight from it.
+    prev_ix: Option<usize>,
+    size: Size,
+    disabled: bool,
+    /// The bounds of the segments, relative to the control.
+    segment_bounds: Vec<Bounds<Pixels>>,
+    bounds: Bounds<Pixels>,
+}
+
+impl SegmentedControl {
+    pub fn new(id: impl Into<ElementId>, cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            id: id.into(),
+            focus_handle: cx.focus_handle(),
+            segments: Vec::new(),
+            selected_ix: 0,
+            prev_ix: None,
+            size: Size::default(),
+            disabled: false,
+            segment_bounds: Vec::new(),
+            bounds: Bounds::default(),
+        }
+    }
+
+    /// Add a segment, it can be a text label, an icon or a [`Segment`] with both.
+    pub fn segment(mut self, segment: impl Into<Segment>) -> Self {
+        self.segments.push(segment.into());
+        self
+    }
+
+    /// Set the initial selected index, default: 0
+    pub fn selected(mut self, ix: usize) -> Self {
+        self.selected_ix = ix;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn selected_index(&self) -> usize {
+        self.selected_ix
+    }
+
+    /// Set the selected index, this will not emit the [`SegmentedControlEvent::Changed`] event.
+    pub fn set_selected_index(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        if ix == self.selected_ix {
+            return;
+        }
+
+        self.prev_ix = Some(self.selected_ix);
+        self.selected_ix = ix;
+        cx.notify();
+    }
+
+    fn select(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        if self.disabled || ix == self.selected_ix {
+            return;
+        }
+        if self
+            .segments
+            .get(ix)
+            .map_or(true, |segment| segment.disabled)
+        {
+            return;
+        }
+
+        self.set_selected_index(ix, cx);
+        cx.emit(SegmentedControlEvent::Changed(ix));
+    }
+
+    fn step(&mut self, forward: bool, cx: &mut ViewContext<Self>) {
+        let disabled = self
+            .segments
+            .iter()
+            .map(|segment| segment.disabled)
+            .collect::<Vec<_>>();
+        if let Some(ix) = step_option(&disabled, Some(self.selected_ix), forward) {
+            self.select(ix, cx);
+        }
+    }
+
+    fn select_prev(&mut self, _: &SelectPrev, cx: &mut ViewContext<Self>) {
+        self.step(false, cx);
+    }
+
+    fn select_next(&mut self, _: &SelectNext, cx: &mut ViewContext<Self>) {
+        self.step(true, cx);
+    }
+
+    fn update_segment_bounds(
+        &mut self,
+        ix: usize,
+        bounds: Bounds<Pixels>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let bounds = Bounds {
+            origin: bounds.origin - self.bounds.origin,
+            size: bounds.size,
+        };
+        if self.segment_bounds.len() <= ix {
+            self.segment_bounds.resize(ix + 1, Bounds::default());
+        }
+        if self.segment_bounds[ix] != bounds {
+            self.segment_bounds[ix] = bounds;
+            cx.notify();
+        }
+    }
+
+    /// Render the highlight of the selected segment, slide from the previous selected segment.
+    fn render_highlight(&self, cx: &mut ViewContext<Self>) -> Option<impl IntoElement> {
+        let to = *self.segment_bounds.get(self.selected_ix)?;
+        if to.size.width.is_zero() {
+            return None;
+        }
+        let from = self
+            .prev_ix
+            .and_then(|ix| self.segment_bounds.get(ix).copied())
+            .unwrap_or(to);
+
+        Some(
+            div()
+                .absolute()
+                .top(to.origin.y)
+                .h(to.size.height)
+                .rounded(px(cx.theme().radius))
+                .bg(cx.theme().accent)
+                .when(cx.theme().shadow, |this| this.shadow_sm())
+                .with_animation(
+                    ElementId::NamedInteger("slide".into(), self.selected_ix),
+                    Animation::new(Duration::from_secs_f64(0.15))
+                        .with_easing(reduced_motion(cubic_bezier(0.4, 0., 0.2, 1.), cx)),
+                    move |this, delta| {
+                        let left = from.origin.x + (to.origin.x - from.origin.x) * delta;
+                        let width = from.size.width + (to.size.width - from.size.width) * delta;
+                        this.left(left).w(width)
+                    },
+                ),
+        )
+    }
+}
+
+impl Sizable for SegmentedControl {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl EventEmitter<SegmentedControlEvent> for SegmentedControl {}
+
+impl FocusableView for SegmentedControl {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for SegmentedControl {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let view = cx.view().clone();
+        let highlight = self.render_highlight(cx);
+        let has_highlight = highlight.is_some();
+
+        h_flex()
+            .id(self.id.clone())
+            .key_context(CONTEXT)
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::select_prev))
+            .on_action(cx.listener(Self::select_next))
+            .relative()
+            .p_0p5()
+            .gap_0p5()
+            .rounded(px(cx.theme().radius))
+            .border_1()
+            .border_color(cx.theme().border)
+            .bg(cx.theme().secondary)
+            .focus_ring(&self.focus_handle, cx)
+            .disabled_style(self.disabled, cx)
+            .child({
+                let view = view.clone();
+                canvas(
+                    move |bounds, cx| view.update(cx, |r, _| r.bounds = bounds),
+                    |_, _, _| {},
+                )
+                .absolute()
+                .size_full()
+            })
+            .children(highlight)
+            .children(self.segments.iter().enumerate().map(|(ix, segment)| {
+                let selected = ix == self.selected_ix;
+                let disabled = self.disabled || segment.disabled;
+                let view = view.clone();
+
+                h_flex()
+                    .id(("segment", ix))
+                    .relative()
+                    .justify_center()
+                    .gap_1()
+                    .rounded(px(cx.theme().radius))
+                    .whitespace_nowrap()
+                    .map(|this| match self.size {
+                        Size::XSmall => this.h_5().px_1p5().text_xs(),
+                        Size::Small => this.h_6().px_2().text_xs(),
+                        Size::Large => this.h_10().px_4().text_base(),
+                        Size::XLarge => this.h_12().px_5().text_lg(),
+                        _ => this.h_8().px_3().text_sm(),
+                    })
+                    .text_color(cx.theme().muted_foreground)
+                    .when(selected, |this| {
+                        this.text_color(cx.theme().accent_foreground)
+                            // Fill the segment before the highlight is measured.
+                            .when(!has_highlight, |this| this.bg(cx.theme().accent))
+                    })
+                    .when(segment.disabled, |this| this.opacity(0.5))
+                    .when(!disabled && !selected, |this| {
+                        this.cursor_pointer()
+                            .hover(|this| this.text_color(cx.theme().foreground))
+                            .on_click(cx.listener(move |this, _, cx| {
+                                this.focus_handle.focus(cx);
+                                this.select(ix, cx);
+                            }))
+                    })
+                    .when_some(segment.icon.clone(), |this, icon| {
+                        this.child(Icon::new(icon).with_size(self.size))
+                    })
+                    .when_some(segment.label.clone(), |this, label| this.child(label))
+                    .child(
+                        canvas(
+                            move |bounds, cx| {
+                                view.update(cx, |r, cx| r.update_segment_bounds(ix, bounds, cx))
+                            },
+                            |_, _, _| {},
+                        )
+                        .absolute()
+                        .size_full(),
+                    )
+            }))
+    }
+}
@@ -0,0 +1,106 @@
+//! The keyboard selection helpers of the components with the disabled options,
+//! e.g. the List, RadioGroup, SegmentedControl and Toolbar.
+//!
+//! The options are probed with `is_disabled` from the current index, so the cost is the distance
+//! to the result, not the number of the options.
+
+/// Returns the next enabled option from the `current`, wrapping around the ends.
+///
+/// Start from the first (or the last one, when backward) if nothing is selected.
+pub(crate) fn step_enabled(
+    len: usize,
+    current: Option<usize>,
+    forward: bool,
+    is_disabled: impl Fn(usize) -> bool,
+) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+
+    let current = current
+        .unwrap_or(if forward { len - 1 } else { 0 })
+        .min(len - 1);
+    (1..=len)
+        .map(|step| {
+            if forward {
+                (current + step) % len
+            } else {
+                (current + len - step) % len
+            }
+        })
+        .find(|ix| !is_disabled(*ix))
+}
+
+/// Same as [`step_enabled`] with the disabled states of the options.
+pub(crate) fn step_option(
+    disabled: &[bool],
+    current: Option<usize>,
+    forward: bool,
+) -> Option<usize> {
+    step_enabled(disabled.len(), current, forward, |ix| disabled[ix])
+}
+
+/// Returns the enabled option nearest to the `target`, prefer the `forward` direction, without wrapping.
+pub(crate) fn nearest_enabled(
+    len: usize,
+    target: usize,
+    forward: bool,
+    is_disabled: impl Fn(usize) -> bool,
+) -> Option<usize> {
+    let target = target.min(len.checked_sub(1)?);
+    let mut after = (target..len).filter(|ix| !is_disabled(*ix));
+    let mut before = (0..=target).rev().filter(|ix| !is_disabled(*ix));
+    if forward {
+        after.next().or_else(|| before.next())
+    } else {
+        before.next().or_else(|| after.next())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::{nearest_enabled, step_enabled, step_option};
+
+    #[test]
+    fn test_step_option() {
+        let disabled = [false, true, false];
+        assert_eq!(step_option(&disabled, Some(0), true), Some(2));
+        assert_eq!(step_option(&disabled, Some(2), true), Some(0));
+        assert_eq!(step_option(&disabled, Some(0), false), Some(2));
+        assert_eq!(step_option(&disabled, None, true), Some(0));
+        assert_eq!(step_option(&disabled, None, false), Some(2));
+        assert_eq!(step_option(&[true, true], None, true), None);
+        assert_eq!(step_option(&[], None, true), None);
+    }
+
+    #[test]
+    fn test_nearest_enabled() {
+        let disabled = [true, false, false, true];
+        let is_disabled = |ix: usize| disabled[ix];
+        assert_eq!(nearest_enabled(4, 0, true, is_disabled), Some(1));
+        assert_eq!(nearest_enabled(4, 3, false, is_disabled), Some(2));
+        assert_eq!(nearest_enabled(4, 3, true, is_disabled), Some(2));
+        assert_eq!(nearest_enabled(4, 100, false, is_disabled), Some(2));
+        assert_eq!(nearest_enabled(1, 0, true, |_| true), None);
+        assert_eq!(nearest_enabled(0, 0, true, |_| false), None);
+    }
+
+    #[test]
+    fn test_probe_from_the_current() {
+        let probes = Cell::new(0);
+        let is_disabled = |ix: usize| {
+            probes.set(probes.get() + 1);
+            ix % 2 == 1
+        };
+
+        assert_eq!(step_enabled(100_000, Some(50), true, is_disabled), Some(52));
+        assert_eq!(probes.replace(0), 2);
+        assert_eq!(
+            nearest_enabled(100_000, 99_999, false, is_disabled),
+            Some(99_998)
+        );
+        assert_eq!(probes.replace(0), 2);
+    }
+}
@@ -1,10 +1,38 @@
-use crate::theme::ActiveTheme;
+use crate::theme::{ActiveTheme, Colorize as _};
 use gpui::{
-    bounce, div, ease_in_out, Animation, AnimationExt, Div, IntoElement, ParentElement as _,
-    RenderOnce, Styled,
+    bounce, div, ease_in_out, px, relative, Animation, AnimationExt, Div, Hsla, IntoElement,
+    Length, ParentElement as _, Pixels, RenderOnce, Styled,
 };
 use std::time::Duration;
 
+/// Returns the highlight color of the shimmer, derived from the skeleton color.
+///
+/// The default skeleton colors are translucent (black in light, white in dark),
+/// so increase the alpha to adapt both modes, otherwise adjust the lightness.
+fn shimmer_color(base: Hsla, is_dark: bool) -> Hsla {
+    if base.a < 0.5 {
+        Hsla {
+            a: (base.a * 2.).min(1.),
+            ..base
+        }
+    } else if is_dark {
+        base.lighten(0.3)
+    } else {
+        base.darken(0.1)
+    }
+}
+
+/// Linear interpolate the color from `from` to `to` by `t` in `0.0..=1.0`.
+fn mix(from: Hsla, to: Hsla, t: f32) -> Hsla {
+    let lerp = |a: f32, b: f32| a + (b - a) * t;
+    Hsla {
+        h: lerp(from.h, to.h),
+        s: lerp(from.s, to.s),
+        l: lerp(from.l, to.l),
+        a: lerp(from.a, to.a),
+    }
+}
+
 #[derive(IntoElement)]
 pub struct Skeleton {
     base: Div,
@@ -16,6 +44,21 @@ impl Skeleton {
             base: div().w_full().h_4().rounded_md(),
         }
     }
+
+    /// A line of text placeholder with the `width`.
+    pub fn line(width: impl Into<Length>) -> Self {
+        Self::new().w(width)
+    }
+
+    /// A circle placeholder, e.g. for an avatar.
+    pub fn circle(size: Pixels) -> Self {
+        Self::new().size(size).rounded_full()
+    }
+
+    /// A rectangle placeholder, e.g. for an image.
+    pub fn rect(width: impl Into<Length>, height: impl Into<Length>) -> Self {
+        Self::new().w(width).h(height)
+    }
 }
 
 impl Styled for Skeleton {
@@ -26,17 +69,82 @@ impl Styled for Skeleton {
 
 impl RenderOnce for Skeleton {
     fn render(self, cx: &mut gpui::WindowContext) -> impl IntoElement {
+        let color = cx.theme().skeleton;
+        let base = self.base.bg(color);
+        if cx.theme().reduce_motion() {
+            return div().child(base);
+        }
+
+        let highlight = shimmer_color(color, cx.theme().mode.is_dark());
         div().child(
-            self.base.bg(cx.theme().skeleton).with_animation(
+            base.with_animation(
                 "skeleton",
                 Animation::new(Duration::from_secs(2))
                     .repeat()
                     .with_easing(bounce(ease_in_out)),
-                move |this, delta| {
-                    let v = 1.0 - delta * 0.5;
-                    this.opacity(v)
-                },
+                move |this, delta| this.bg(mix(color, highlight, delta)),
             ),
         )
     }
 }
+
+/// A list of skeleton rows, for the loading placeholder of a list.
+#[derive(IntoElement)]
+pub struct SkeletonList {
+    base: Div,
+    rows: usize,
+    row_height: Pixels,
+}
+
+impl SkeletonList {
+    pub fn new(rows: usize, row_height: Pixels) -> Self {
+        Self {
+            base: div().w_full(),
+            rows,
+            row_height,
+        }
+    }
+}
+
+impl Styled for SkeletonList {
+    fn style(&mut self) -> &mut gpui::StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for SkeletonList {
+    fn render(self, _: &mut gpui::WindowContext) -> impl IntoElement {
+        // Vary the widths of the lines, to look like the real content.
+        const WIDTHS: [f32; 4] = [0.9, 0.7, 0.8, 0.55];
+        let line_height = (self.row_height * 0.5).min(px(16.));
+
+        self.base.children((0..self.rows).map(|ix| {
+            div()
+                .flex()
+                .items_center()
+                .h(self.row_height)
+                .px_2()
+                .child(Skeleton::line(relative(WIDTHS[ix % WIDTHS.len()])).h(line_height))
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::hsla;
+
+    use super::{mix, shimmer_color};
+
+    #[test]
+    fn test_shimmer_color() {
+        let translucent = hsla(0., 0., 1., 0.1);
+        assert_eq!(shimmer_color(translucent, true), hsla(0., 0., 1., 0.2));
+        assert_eq!(shimmer_color(translucent, false), hsla(0., 0., 1., 0.2));
+
+        let opaque = hsla(0., 0., 0.5, 1.);
+        assert!(shimmer_color(opaque, true).l > opaque.l);
+        assert!(shimmer_color(opaque, false).l < opaque.l);
+
+        assert_eq!(mix(translucent, hsla(0., 0., 1., 0.3), 0.5).a, 0.2);
+    }
+}
@@ -1,42 +1,88 @@
-use crate::{theme::ActiveTheme, tooltip::Tooltip};
+use crate::{theme::ActiveTheme, StyledExt as _};
 use gpui::{
-    canvas, div, prelude::FluentBuilder as _, px, relative, Axis, Bounds, DragMoveEvent, EntityId,
-    EventEmitter, InteractiveElement, IntoElement, MouseButton, MouseDownEvent, ParentElement as _,
-    Pixels, Point, Render, StatefulInteractiveElement as _, Styled, ViewContext,
-    VisualContext as _,
+    actions, canvas, div, prelude::FluentBuilder as _, px, relative, AppContext, Axis, Bounds,
+    DragMoveEvent, EntityId, EventEmitter, FocusHandle, FocusableView, InteractiveElement,
+    IntoElement, KeyBinding, MouseButton, MouseDownEvent, MouseUpEvent, ParentElement as _, Pixels,
+    Point, Render, StatefulInteractiveElement as _, Styled, ViewContext, VisualContext as _,
 };
 
+actions!(
+    slider,
+    [Decrease, Increase, PageDecrease, PageIncrease, ToMin, ToMax]
+);
+
+const CONTEXT: &str = "Slider";
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("left", Decrease, Some(CONTEXT)),
+        KeyBinding::new("down", Decrease, Some(CONTEXT)),
+        KeyBinding::new("right", Increase, Some(CONTEXT)),
+        KeyBinding::new("up", Increase, Some(CONTEXT)),
+        KeyBinding::new("pagedown", PageDecrease, Some(CONTEXT)),
+        KeyBinding::new("pageup", PageIncrease, Some(CONTEXT)),
+        KeyBinding::new("home", ToMin, Some(CONTEXT)),
+        KeyBinding::new("end", ToMax, Some(CONTEXT)),
+    ]);
+}
+
 #[derive(Clone, Render)]
 pub struct DragThumb(EntityId);
 
 pub enum SliderEvent {
+    /// The value is changed by dragging, clicking or the keyboard.
     Change(f32),
+    /// The value is committed, when the mouse is released or by the keyboard.
+    ///
+    /// Use this to wait for the final value for the expensive updates.
+    Commit(f32),
+}
+
+/// Snap the `value` to the nearest step from the `min`, and clamp it in the range.
+fn snap_value(value: f32, min: f32, max: f32, step: f32) -> f32 {
+    let value = if step > 0. {
+        min + ((value - min) / step).round() * step
+    } else {
+        value
+    };
+    value.clamp(min, max)
 }
 
 /// A Slider element.
+///
+/// When focused, the value can be adjusted by the arrow keys by a step,
+/// PageUp/PageDown by a tenth of the range, and Home/End to the min/max.
 pub struct Slider {
+    focus_handle: FocusHandle,
     axis: Axis,
     min: f32,
     max: f32,
     step: f32,
     value: f32,
+    disabled: bool,
+    show_tooltip: bool,
+    dragging: bool,
     bounds: Bounds<Pixels>,
 }
 
 impl Slider {
-    fn new(axis: Axis) -> Self {
+    fn new(axis: Axis, cx: &mut ViewContext<Self>) -> Self {
         Self {
+            focus_handle: cx.focus_handle(),
             axis,
             min: 0.0,
             max: 100.0,
             step: 1.0,
             value: 0.0,
+            disabled: false,
+            show_tooltip: true,
+            dragging: false,
             bounds: Bounds::default(),
         }
     }
 
-    pub fn horizontal() -> Self {
-        Self::new(Axis::Horizontal)
+    pub fn horizontal(cx: &mut ViewContext<Self>) -> Self {
+        Self::new(Axis::Horizontal, cx)
     }
 
     /// Set the minimum value of the slider, default: 0.0
@@ -63,24 +109,48 @@ impl Slider {
         self
     }
 
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set to show the value in a tooltip above the thumb while dragging, default: true
+    pub fn show_tooltip(mut self, show_tooltip: bool) -> Self {
+        self.show_tooltip = show_tooltip;
+        self
+    }
+
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
     /// Set the value of the slider.
     pub fn set_value(&mut self, value: f32, cx: &mut gpui::ViewContext<Self>) {
         self.value = value;
         cx.notify();
     }
 
+    pub fn set_disabled(&mut self, disabled: bool, cx: &mut ViewContext<Self>) {
+        self.disabled = disabled;
+        cx.notify();
+    }
+
     /// Return percentage value of the slider, range of 0.0..1.0
     fn relative_value(&self) -> f32 {
-        let step = self.step;
-        let value = self.value;
-        let min = self.min;
-        let max = self.max;
+        let value = snap_value(self.value, self.min, self.max, self.step);
+        ((value - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+    }
 
-        let relative_value = (value - min) / (max - min);
-        let relative_step = step / (max - min);
+    /// Snap and update the value, and emit the change event if it is changed.
+    fn update_value(&mut self, value: f32, cx: &mut ViewContext<Self>) {
+        let value = snap_value(value, self.min, self.max, self.step);
+        if value == self.value {
+            return;
+        }
 
-        let relative_value = (relative_value / relative_step).round() * relative_step;
-        relative_value.clamp(0.0, 1.0)
+        self.value = value;
+        cx.emit(SliderEvent::Change(self.value));
+        cx.notify();
     }
 
     /// Update value by mouse position
@@ -90,12 +160,10 @@ impl Slider {
         cx: &mut gpui::ViewContext<Self>,
     ) {
         let bounds = self.bounds;
-        let axis = self.axis;
         let min = self.min;
         let max = self.max;
-        let step = self.step;
 
-        let value = match axis {
+        let value = match self.axis {
             Axis::Horizontal => {
                 let relative = (position.x - bounds.left()) / bounds.size.width;
                 min + (max - min) * relative
@@ -106,35 +174,75 @@ impl Slider {
             }
         };
 
-        let value = (value / step).round() * step;
+        self.update_value(value, cx);
+    }
 
-        self.value = value.clamp(self.min, self.max);
-        cx.emit(SliderEvent::Change(self.value));
-        cx.notify();
+    /// Adjust the value by the keyboard, and commit it immediately.
+    fn adjust_value(&mut self, value: f32, cx: &mut ViewContext<Self>) {
+        if self.disabled {
+            return;
+        }
+
+        let prev_value = self.value;
+        self.update_value(value, cx);
+        if self.value != prev_value {
+            cx.emit(SliderEvent::Commit(self.value));
+        }
+    }
+
+    /// The step of PageUp/PageDown, a tenth of the range but not less than a step.
+    fn page_step(&self) -> f32 {
+        ((self.max - self.min) / 10.).max(self.step)
+    }
+
+    fn decrease(&mut self, _: &Decrease, cx: &mut ViewContext<Self>) {
+        self.adjust_value(self.value - self.step, cx);
+    }
+
+    fn increase(&mut self, _: &Increase, cx: &mut ViewContext<Self>) {
+        self.adjust_value(self.value + self.step, cx);
+    }
+
+    fn page_decrease(&mut self, _: &PageDecrease, cx: &mut ViewContext<Self>) {
+        self.adjust_value(self.value - self.page_step(), cx);
+    }
+
+    fn page_increase(&mut self, _: &PageIncrease, cx: &mut ViewContext<Self>) {
+        self.adjust_value(self.value + self.page_step(), cx);
+    }
+
+    fn to_min(&mut self, _: &ToMin, cx: &mut ViewContext<Self>) {
+        self.adjust_value(self.min, cx);
+    }
+
+    fn to_max(&mut self, _: &ToMax, cx: &mut ViewContext<Self>) {
+        self.adjust_value(self.max, cx);
     }
 
     fn render_thumb(&self, cx: &mut ViewContext<Self>) -> impl gpui::IntoElement {
-        let value = self.value;
         let entity_id = cx.entity_id();
 
         div()
             .id("slider-thumb")
-            .on_drag(DragThumb(entity_id), |drag, _, cx| {
-                cx.stop_propagation();
-                cx.new_view(|_| drag.clone())
-            })
-            .on_drag_move(cx.listener(
-                move |view, e: &DragMoveEvent<DragThumb>, cx| match e.drag(cx) {
-                    DragThumb(id) => {
-                        if *id != entity_id {
-                            return;
-                        }
+            .when(!self.disabled, |this| {
+                this.on_drag(DragThumb(entity_id), |drag, _, cx| {
+                    cx.stop_propagation();
+                    cx.new_view(|_| drag.clone())
+                })
+                .on_drag_move(cx.listener(
+                    move |view, e: &DragMoveEvent<DragThumb>, cx| match e.drag(cx) {
+                        DragThumb(id) => {
+                            if *id != entity_id {
+                                return;
+                            }
 
-                        // set value by mouse position
-                        view.update_value_by_position(e.event.position, cx)
-                    }
-                },
-            ))
+                            // set value by mouse position
+                            view.dragging = true;
+                            view.update_value_by_position(e.event.position, cx)
+                        }
+                    },
+                ))
+            })
             .absolute()
             .top(px(-5.))
             .left(relative(self.relative_value()))
@@ -145,22 +253,81 @@ impl Slider {
             .border_color(cx.theme().slider_bar.opacity(0.9))
             .when(cx.theme().shadow, |this| this.shadow_md())
             .bg(cx.theme().slider_thumb)
-            .tooltip(move |cx| Tooltip::new(format!("{}", value), cx))
+            .focus_ring(&self.focus_handle, cx)
+            .when(self.show_tooltip && self.dragging, |this| {
+                this.child(
+                    div()
+                        .absolute()
+                        .bottom(relative(1.))
+                        .left(relative(0.5))
+                        .mb_2()
+                        .child(
+                            div()
+                                .ml(-px(16.))
+                                .min_w(px(32.))
+                                .flex()
+                                .justify_center()
+                                .bg(cx.theme().popover_background())
+                                .text_color(cx.theme().popover_foreground)
+                                .border_1()
+                                .border_color(cx.theme().border)
+                                .shadow_md()
+                                .rounded(px(cx.theme().radius))
+                                .py_0p5()
+                                .px_2()
+                                .text_sm()
+                                .child(format!("{}", self.value)),
+                        ),
+                )
+            })
     }
 
     fn on_mouse_down(&mut self, event: &MouseDownEvent, cx: &mut gpui::ViewContext<Self>) {
+        if self.disabled {
+            return;
+        }
+
+        self.focus_handle.focus(cx);
+        self.dragging = true;
         self.update_value_by_position(event.position, cx);
     }
+
+    fn on_mouse_up(&mut self, _: &MouseUpEvent, cx: &mut ViewContext<Self>) {
+        if !self.dragging {
+            return;
+        }
+
+        self.dragging = false;
+        cx.emit(SliderEvent::Commit(self.value));
+        cx.notify();
+    }
 }
 
 impl EventEmitter<SliderEvent> for Slider {}
 
+impl FocusableView for Slider {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
 impl Render for Slider {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
         div()
             .id("slider")
+            .key_context(CONTEXT)
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::decrease))
+            .on_action(cx.listener(Self::increase))
+            .on_action(cx.listener(Self::page_decrease))
+            .on_action(cx.listener(Self::page_increase))
+            .on_action(cx.listener(Self::to_min))
+            .on_action(cx.listener(Self::to_max))
             .on_mouse_down(MouseButton::Left, cx.listener(Self::on_mouse_down))
+            .on_mouse_up(MouseButton::Left, cx.listener(Self::on_mouse_up))
+            .on_mouse_up_out(MouseButton::Left, cx.listener(Self::on_mouse_up))
             .h_5()
+            .disabled_style(self.disabled, cx)
             .child(
                 div()
                     .id("slider-bar")
@@ -169,7 +336,9 @@ impl Render for Slider {
                     .my_1p5()
                     .h_1p5()
                     .bg(cx.theme().slider_bar.opacity(0.2))
-                    .active(|this| this.bg(cx.theme().slider_bar.opacity(0.4)))
+                    .when(!self.disabled, |this| {
+                        this.active(|this| this.bg(cx.theme().slider_bar.opacity(0.4)))
+                    })
                     .rounded(px(3.))
                     .child(
                         div()
@@ -194,3 +363,18 @@ impl Render for Slider {
             )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::snap_value;
+
+    #[test]
+    fn test_snap_value() {
+        assert_eq!(snap_value(14., 0., 100., 5.), 15.);
+        assert_eq!(snap_value(-3., 0., 100., 5.), 0.);
+        assert_eq!(snap_value(120., 0., 100., 5.), 100.);
+        // The steps start from the min.
+        assert_eq!(snap_value(4., 1., 10., 2.), 5.);
+        assert_eq!(snap_value(4.2, 0., 10., 0.), 4.2);
+    }
+}
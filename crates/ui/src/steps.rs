@@ -0,0 +1,245 @@
+use std::rc::Rc;
+
+use gpui::{
+    div, prelude::FluentBuilder as _, px, relative, AnyElement, Axis, ElementId, Hsla,
+    InteractiveElement as _, IntoElement, ParentElement as _, RenderOnce, SharedString,
+    StatefulInteractiveElement as _, Styled, WindowContext,
+};
+
+use crate::{h_flex, theme::ActiveTheme as _, v_flex, Icon, IconName, StyledExt as _};
+
+/// The status of a step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepStatus {
+    Complete,
+    Current,
+    Upcoming,
+    Error,
+}
+
+/// Returns the status of the step at `ix` by the `current` step.
+fn step_status(ix: usize, current: usize, error: bool) -> StepStatus {
+    if error {
+        StepStatus::Error
+    } else if ix < current {
+        StepStatus::Complete
+    } else if ix == current {
+        StepStatus::Current
+    } else {
+        StepStatus::Upcoming
+    }
+}
+
+/// A step of the [`Steps`].
+pub struct Step {
+    label: SharedString,
+    description: Option<SharedString>,
+    error: bool,
+}
+
+impl Step {
+    pub fn new(label: impl Into<SharedString>) -> Self {
+        Self {
+            label: label.into(),
+            description: None,
+            error: false,
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set to show the step in the error status.
+    pub fn error(mut self, error: bool) -> Self {
+        self.error = error;
+        self
+    }
+}
+
+/// A Steps element to show the progress of a multi-step flow.
+///
+/// This is a controlled element, the `current` step should be updated by the `on_click` handler,
+/// only the completed steps can be clicked to jump back.
+#[derive(IntoElement)]
+pub struct Steps {
+    id: ElementId,
+    steps: Vec<Step>,
+    current: usize,
+    axis: Axis,
+    on_click: Option<Rc<dyn Fn(&usize, &mut WindowContext)>>,
+}
+
+impl Steps {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            steps: Vec::new(),
+            current: 0,
+            axis: Axis::Horizontal,
+            on_click: None,
+        }
+    }
+
+    /// Add a step.
+    pub fn step(mut self, step: Step) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// Set the index of the current step, default: 0
+    ///
+    /// The steps before the current one are completed, set it to the steps count to complete all.
+    pub fn current_step(mut self, ix: usize) -> Self {
+        self.current = ix;
+        self
+    }
+
+    /// Set the direction of the steps, default: `Axis::Horizontal`
+    pub fn axis(mut self, axis: Axis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    /// Set the handler when a completed step is clicked, the argument is the index of the step.
+    pub fn on_click(mut self, handler: impl Fn(&usize, &mut WindowContext) + 'static) -> Self {
+        self.on_click = Some(Rc::new(handler));
+        self
+    }
+}
+
+fn render_indicator(ix: usize, status: StepStatus, cx: &WindowContext) -> AnyElement {
+    let theme = cx.theme();
+    let (bg, fg, border) = match status {
+        StepStatus::Complete => (theme.primary, theme.primary_foreground, theme.primary),
+        StepStatus::Current => (theme.background, theme.primary, theme.primary),
+        StepStatus::Upcoming => (theme.background, theme.muted_foreground, theme.border),
+        StepStatus::Error => (theme.danger, theme.danger_foreground, theme.danger),
+    };
+
+    h_flex()
+        .size_7()
+        .flex_shrink_0()
+        .justify_center()
+        .rounded_full()
+        .border_2()
+        .border_color(border)
+        .bg(bg)
+        .text_color(fg)
+        .text_xs()
+        .font_semibold()
+        .line_height(relative(1.))
+        .map(|this| match status {
+            StepStatus::Complete => this.child(Icon::new(IconName::Check).size_3p5()),
+            StepStatus::Error => this.child(Icon::new(IconName::Close).size_3p5()),
+            _ => this.child(format!("{}", ix + 1)),
+        })
+        .into_any_element()
+}
+
+fn render_connector(axis: Axis, color: Hsla) -> impl IntoElement {
+    div().flex_1().bg(color).map(|this| match axis {
+        Axis::Horizontal => this.h(px(2.)).min_w_4(),
+        Axis::Vertical => this.w(px(2.)).min_h_4(),
+    })
+}
+
+impl RenderOnce for Steps {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let axis = self.axis;
+        let steps_count = self.steps.len();
+
+        div()
+            .id(self.id)
+            .flex()
+            .when(axis == Axis::Vertical, |this| this.flex_col())
+            .w_full()
+            .children(self.steps.into_iter().enumerate().map(|(ix, step)| {
+                let status = step_status(ix, self.current, step.error);
+                let is_last = ix + 1 == steps_count;
+                let line_color = if status == StepStatus::Complete {
+                    cx.theme().primary
+                } else {
+                    cx.theme().border
+                };
+                let on_click = self
+                    .on_click
+                    .clone()
+                    .filter(|_| status == StepStatus::Complete);
+
+                let label = v_flex()
+                    .gap_0p5()
+                    .child(
+                        div()
+                            .text_sm()
+                            .font_semibold()
+                            .text_color(match status {
+                                StepStatus::Upcoming => cx.theme().muted_foreground,
+                                StepStatus::Error => cx.theme().danger,
+                                _ => cx.theme().foreground,
+                            })
+                            .child(step.label),
+                    )
+                    .when_some(step.description, |this, description| {
+                        this.child(
+                            div()
+                                .text_xs()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(description),
+                        )
+                    });
+
+                let item = match axis {
+                    Axis::Horizontal => v_flex()
+                        .id(("step", ix))
+                        .gap_2()
+                        .when(!is_last, |this| this.flex_1())
+                        .child(
+                            h_flex()
+                                .gap_2()
+                                .child(render_indicator(ix, status, cx))
+                                .when(!is_last, |this| {
+                                    this.child(render_connector(axis, line_color)).mr_2()
+                                }),
+                        )
+                        .child(label.pr_2()),
+                    Axis::Vertical => h_flex()
+                        .id(("step", ix))
+                        .items_start()
+                        .gap_3()
+                        .when(!is_last, |this| this.flex_1())
+                        .child(
+                            v_flex()
+                                .h_full()
+                                .items_center()
+                                .gap_1()
+                                .child(render_indicator(ix, status, cx))
+                                .when(!is_last, |this| {
+                                    this.child(render_connector(axis, line_color)).mb_1()
+                                }),
+                        )
+                        .child(label.pt_1().pb_4()),
+                };
+
+                item.when_some(on_click, |this, on_click| {
+                    this.cursor_pointer()
+                        .on_click(move |_, cx| on_click(&ix, cx))
+                })
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{step_status, StepStatus};
+
+    #[test]
+    fn test_step_status() {
+        assert_eq!(step_status(0, 1, false), StepStatus::Complete);
+        assert_eq!(step_status(1, 1, false), StepStatus::Current);
+        assert_eq!(step_status(2, 1, false), StepStatus::Upcoming);
+        assert_eq!(step_status(0, 1, true), StepStatus::Error);
+        assert_eq!(step_status(2, 3, false), StepStatus::Complete);
+    }
+}
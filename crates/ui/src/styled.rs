@@ -1,14 +1,17 @@
 use std::fmt::{self, Display, Formatter};
 
 use crate::{
+    divider::Divider,
+    root::is_keyboard_modality,
     scroll::{Scrollable, ScrollbarAxis},
-    theme::ActiveTheme,
+    theme::{box_shadow, ActiveTheme, Colorize as _},
 };
 use gpui::{
-    div, px, Axis, Div, Edges, Element, ElementId, EntityId, FocusHandle, Pixels, Styled,
-    WindowContext,
+    div, px, AbsoluteLength, Axis, Div, Edges, Element, ElementId, EntityId, FocusHandle, Hsla,
+    IntoElement, ParentElement as _, Pixels, Styled, WindowContext,
 };
 use serde::{Deserialize, Serialize};
+use smallvec::smallvec;
 
 /// Returns a `Div` as horizontal flex layout.
 pub fn h_flex() -> Div {
@@ -20,6 +23,19 @@ pub fn v_flex() -> Div {
     div().v_flex()
 }
 
+/// Returns a `Div` as horizontal flex layout, with a vertical [`Divider`] between the `children`.
+pub fn h_flex_separated<E: IntoElement>(children: impl IntoIterator<Item = E>) -> Div {
+    let mut children = children.into_iter().peekable();
+    let mut base = h_flex().gap_2();
+    while let Some(child) = children.next() {
+        base = base.child(child);
+        if children.peek().is_some() {
+            base = base.child(Divider::vertical().h_4());
+        }
+    }
+    base
+}
+
 macro_rules! font_weight {
     ($fn:ident, $const:ident) => {
         /// [docs](https://tailwindcss.com/docs/font-weight)
@@ -41,6 +57,25 @@ pub trait StyledExt: Styled + Sized {
         self.flex().flex_col()
     }
 
+    /// Center the children in both axes.
+    fn center(self) -> Self {
+        self.items_center().justify_center()
+    }
+
+    /// Apply the disabled style (muted text, [`Theme::disabled_opacity`] and not-allowed cursor),
+    /// when `disabled` is true.
+    ///
+    /// [`Theme::disabled_opacity`]: crate::theme::Theme::disabled_opacity
+    fn disabled_style(self, disabled: bool, cx: &WindowContext) -> Self {
+        if disabled {
+            self.text_color(cx.theme().muted_foreground)
+                .opacity(cx.theme().disabled_opacity)
+                .cursor_not_allowed()
+        } else {
+            self
+        }
+    }
+
     /// Render a border with a width of 1px, color red
     fn debug_red(self) -> Self {
         if cfg!(debug_assertions) {
@@ -99,11 +134,49 @@ pub trait StyledExt: Styled + Sized {
         }
     }
 
+    /// Set the border width of all edges, e.g.: `border_w(cx.theme().border_width())`.
+    fn border_w(mut self, width: impl Into<AbsoluteLength>) -> Self {
+        let width = width.into();
+        let widths = &mut self.style().border_widths;
+        widths.top = Some(width);
+        widths.right = Some(width);
+        widths.bottom = Some(width);
+        widths.left = Some(width);
+        self
+    }
+
+    /// Set the border width of the bottom edge.
+    fn border_b_w(mut self, width: impl Into<AbsoluteLength>) -> Self {
+        self.style().border_widths.bottom = Some(width.into());
+        self
+    }
+
     /// Render a border with a width of 1px, color ring color
     fn outline(self, cx: &WindowContext) -> Self {
         self.border_color(cx.theme().ring)
     }
 
+    /// Render the focus ring with the `ring` color, when the `focus_handle` is focused.
+    ///
+    /// If the `focus_ring_keyboard_only` of the theme is enabled,
+    /// the ring only visible when the focus was moved by the keyboard.
+    fn focus_ring(self, focus_handle: &FocusHandle, cx: &WindowContext) -> Self {
+        self.focus_ring_color(focus_handle, cx.theme().ring, cx)
+    }
+
+    /// Same as [`StyledExt::focus_ring`], but with a custom ring color.
+    fn focus_ring_color(self, focus_handle: &FocusHandle, color: Hsla, cx: &WindowContext) -> Self {
+        if !focus_handle.is_focused(cx) {
+            return self;
+        }
+        if cx.theme().focus_ring_keyboard_only && !is_keyboard_modality(cx) {
+            return self;
+        }
+
+        let ring = box_shadow(0., 0., 0., px(2.), color.opacity(0.2));
+        self.border_color(color).shadow(smallvec![ring])
+    }
+
     /// Wraps the element in a ScrollView.
     ///
     /// Current this is only have a vertical scrollbar.
@@ -126,7 +199,7 @@ pub trait StyledExt: Styled + Sized {
 
     /// Set as Popover style
     fn popover_style(self, cx: &mut WindowContext) -> Self {
-        self.bg(cx.theme().popover)
+        self.bg(cx.theme().popover_background())
             .border_1()
             .border_color(cx.theme().border)
             .shadow_lg()
@@ -139,22 +212,28 @@ impl<E: Styled> StyledExt for E {}
 /// A size for elements.
 #[derive(Clone, Default, Copy, PartialEq, Eq, Debug, Deserialize, Serialize)]
 pub enum Size {
+    /// A custom size, e.g. `px(30.)`.
+    ///
+    /// The height-driven components (Input, Button, Table row) use it as the exact height.
     Size(Pixels),
     XSmall,
     Small,
     #[default]
     Medium,
     Large,
+    XLarge,
 }
 
 impl Size {
     /// Returns the height for table row.
     pub fn table_row_height(&self) -> Pixels {
         match self {
+            Size::Size(size) => *size,
             Size::XSmall => px(26.),
             Size::Small => px(30.),
             Size::Large => px(40.),
-            _ => px(32.),
+            Size::XLarge => px(48.),
+            Size::Medium => px(32.),
         }
     }
     /// Returns the padding for a table cell.
@@ -178,6 +257,12 @@ impl Size {
                 left: px(12.),
                 right: px(12.),
             },
+            Size::XLarge => Edges {
+                top: px(12.),
+                bottom: px(12.),
+                left: px(16.),
+                right: px(16.),
+            },
             _ => Edges {
                 top: px(4.),
                 bottom: px(4.),
@@ -194,6 +279,31 @@ impl From<Pixels> for Size {
     }
 }
 
+/// The density of the components, see [`crate::theme::Theme::density`].
+///
+/// The components without an explicit size use the size of the density.
+#[derive(Clone, Default, Copy, PartialEq, Eq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Density {
+    Compact,
+    #[default]
+    Comfortable,
+    Spacious,
+}
+
+impl Density {
+    /// Returns the default size of the components in the density.
+    ///
+    /// Keep this as the only mapping from the density, so the components are consistent.
+    pub fn size(&self) -> Size {
+        match self {
+            Self::Compact => Size::Small,
+            Self::Comfortable => Size::Medium,
+            Self::Spacious => Size::Large,
+        }
+    }
+}
+
 /// A trait for defining element that can be selected.
 pub trait Selectable: Sized {
     fn element_id(&self) -> &ElementId;
@@ -208,7 +318,15 @@ pub trait Disableable {
 }
 
 /// A trait for setting the size of an element.
-/// Size::Medium is use by default.
+/// Size::Medium is used by default, some components follow the [`crate::Density`] of the theme.
+///
+/// The containers can propagate their size to the children by this trait, e.g.:
+///
+/// ```ignore
+/// fn sized<E: Sizable>(child: E, size: Size) -> E {
+///     child.with_size(size)
+/// }
+/// ```
 pub trait Sizable: Sized {
     /// Set the ui::Size of this element.
     ///
@@ -230,6 +348,11 @@ pub trait Sizable: Sized {
     fn large(self) -> Self {
         self.with_size(Size::Large)
     }
+
+    /// Set to Size::XLarge
+    fn xlarge(self) -> Self {
+        self.with_size(Size::XLarge)
+    }
 }
 
 #[allow(unused)]
@@ -246,6 +369,8 @@ pub trait StyleSized<T: Styled> {
     fn list_py(self, size: Size) -> Self;
     /// Apply size with the given `Size`.
     fn size_with(self, size: Size) -> Self;
+    /// Apply the gap between the children with the given `Size`.
+    fn gap_with(self, size: Size) -> Self;
     /// Apply the table cell size (Font size, padding) with the given `Size`.
     fn table_cell_size(self, size: Size) -> Self;
 }
@@ -257,6 +382,7 @@ impl<T: Styled> StyleSized<T> for T {
             Size::Small => self.text_sm(),
             Size::Medium => self.text_base(),
             Size::Large => self.text_lg(),
+            Size::XLarge => self.text_xl(),
             Size::Size(size) => self.text_size(size),
         }
     }
@@ -267,6 +393,7 @@ impl<T: Styled> StyleSized<T> for T {
 
     fn input_pl(self, size: Size) -> Self {
         match size {
+            Size::XLarge => self.pl_6(),
             Size::Large => self.pl_5(),
             Size::Medium => self.pl_3(),
             _ => self.pl_2(),
@@ -275,6 +402,7 @@ impl<T: Styled> StyleSized<T> for T {
 
     fn input_pr(self, size: Size) -> Self {
         match size {
+            Size::XLarge => self.pr_6(),
             Size::Large => self.pr_5(),
             Size::Medium => self.pr_3(),
             _ => self.pr_2(),
@@ -283,6 +411,7 @@ impl<T: Styled> StyleSized<T> for T {
 
     fn input_px(self, size: Size) -> Self {
         match size {
+            Size::XLarge => self.px_6(),
             Size::Large => self.px_5(),
             Size::Medium => self.px_3(),
             _ => self.px_2(),
@@ -291,6 +420,7 @@ impl<T: Styled> StyleSized<T> for T {
 
     fn input_py(self, size: Size) -> Self {
         match size {
+            Size::XLarge => self.py_6(),
             Size::Large => self.py_5(),
             Size::Medium => self.py_2(),
             _ => self.py_1(),
@@ -299,9 +429,12 @@ impl<T: Styled> StyleSized<T> for T {
 
     fn input_h(self, size: Size) -> Self {
         match size {
+            Size::XLarge => self.h_12(),
             Size::Large => self.h_11(),
             Size::Medium => self.h_8(),
-            _ => self.h(px(26.)),
+            Size::Small => self.h(px(26.)),
+            Size::XSmall => self.h_5(),
+            Size::Size(size) => self.h(size),
         }
         .input_text_size(size)
     }
@@ -313,12 +446,14 @@ impl<T: Styled> StyleSized<T> for T {
     fn list_px(self, size: Size) -> Self {
         match size {
             Size::Small => self.px_2(),
+            Size::XLarge => self.px_4(),
             _ => self.px_3(),
         }
     }
 
     fn list_py(self, size: Size) -> Self {
         match size {
+            Size::XLarge => self.py_2p5(),
             Size::Large => self.py_2(),
             Size::Medium => self.py_1(),
             Size::Small => self.py_0p5(),
@@ -328,6 +463,7 @@ impl<T: Styled> StyleSized<T> for T {
 
     fn size_with(self, size: Size) -> Self {
         match size {
+            Size::XLarge => self.size_12(),
             Size::Large => self.size_11(),
             Size::Medium => self.size_8(),
             Size::Small => self.size_5(),
@@ -336,6 +472,17 @@ impl<T: Styled> StyleSized<T> for T {
         }
     }
 
+    fn gap_with(self, size: Size) -> Self {
+        match size {
+            Size::XSmall => self.gap_1(),
+            Size::Small => self.gap_1p5(),
+            Size::Medium => self.gap_2(),
+            Size::Large => self.gap_3(),
+            Size::XLarge => self.gap_4(),
+            Size::Size(size) => self.gap(size),
+        }
+    }
+
     fn table_cell_size(self, size: Size) -> Self {
         let padding = size.table_cell_padding();
         match size {
@@ -40,6 +40,12 @@ impl From<&[u8]> for SvgSource {
     }
 }
 
+impl<const N: usize> From<&[u8; N]> for SvgSource {
+    fn from(data: &[u8; N]) -> Self {
+        Self::Data(data.as_slice().into())
+    }
+}
+
 impl From<Arc<[u8]>> for SvgSource {
     fn from(data: Arc<[u8]>) -> Self {
         Self::Data(data)
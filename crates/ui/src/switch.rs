@@ -1,17 +1,27 @@
-use crate::{h_flex, theme::ActiveTheme, Disableable, Side, Sizable, Size};
+use crate::{h_flex, theme::ActiveTheme, Disableable, Side, Sizable, Size, StyledExt as _};
 use gpui::{
     div, prelude::FluentBuilder as _, px, Animation, AnimationExt as _, AnyElement, Element,
-    ElementId, GlobalElementId, InteractiveElement, IntoElement, LayoutId, ParentElement as _,
-    SharedString, Styled as _, WindowContext,
+    ElementId, FocusHandle, GlobalElementId, InteractiveElement, IntoElement, LayoutId,
+    ParentElement as _, Pixels, SharedString, Styled as _, WindowContext,
+};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    time::Duration,
 };
-use std::{cell::RefCell, rc::Rc, time::Duration};
 
+/// A on/off switch, the thumb slides between the positions when it is toggled.
+///
+/// The switch is controlled when `checked` is set, the parent owns the state and updates it
+/// in `on_change`. Otherwise the switch keeps the state by itself, start with `default_checked`.
 pub struct Switch {
     id: ElementId,
-    checked: bool,
+    checked: Option<bool>,
+    default_checked: bool,
     disabled: bool,
     label: Option<SharedString>,
     label_side: Side,
+    focus_handle: Option<FocusHandle>,
     on_click: Option<Rc<dyn Fn(&bool, &mut WindowContext)>>,
     size: Size,
 }
@@ -21,17 +31,27 @@ impl Switch {
         let id: ElementId = id.into();
         Self {
             id: id.clone(),
-            checked: false,
+            checked: None,
+            default_checked: false,
             disabled: false,
             label: None,
+            focus_handle: None,
             on_click: None,
             label_side: Side::Right,
             size: Size::Medium,
         }
     }
 
+    /// Set the checked state to make the switch controlled,
+    /// the toggle only reports the new state by `on_change`.
     pub fn checked(mut self, checked: bool) -> Self {
-        self.checked = checked;
+        self.checked = Some(checked);
+        self
+    }
+
+    /// Set the initial checked state of the uncontrolled switch, default: false
+    pub fn default_checked(mut self, checked: bool) -> Self {
+        self.default_checked = checked;
         self
     }
 
@@ -48,6 +68,20 @@ impl Switch {
         self
     }
 
+    /// The same as `on_click`, the argument is the new checked state.
+    pub fn on_change<F>(self, handler: F) -> Self
+    where
+        F: Fn(&bool, &mut WindowContext) + 'static,
+    {
+        self.on_click(handler)
+    }
+
+    /// Set the focus handle, to toggle by Space or Enter when it is focused, and show the focus ring.
+    pub fn focusable(mut self, focus_handle: &FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle.clone());
+        self
+    }
+
     pub fn label_side(mut self, label_side: Side) -> Self {
         self.label_side = label_side;
         self
@@ -79,6 +113,19 @@ impl IntoElement for Switch {
 #[derive(Default)]
 pub struct SwitchState {
     prev_checked: Rc<RefCell<Option<bool>>>,
+    /// The checked state of the uncontrolled switch, `None` before the first toggle.
+    checked: Rc<Cell<Option<bool>>>,
+}
+
+/// Returns the `(width, height)` of the track, and the size of the thumb.
+fn switch_size(size: Size) -> (Pixels, Pixels, Pixels) {
+    match size {
+        Size::XSmall => (px(24.), px(14.), px(10.)),
+        Size::Small => (px(28.), px(16.), px(12.)),
+        Size::Large => (px(44.), px(24.), px(20.)),
+        Size::XLarge => (px(52.), px(28.), px(24.)),
+        _ => (px(36.), px(20.), px(16.)),
+    }
 }
 
 impl Element for Switch {
@@ -99,10 +146,13 @@ impl Element for Switch {
             let state = state.unwrap_or_default();
 
             let theme = cx.theme();
-            let checked = self.checked;
+            let controlled = self.checked.is_some();
+            let checked = self
+                .checked
+                .unwrap_or_else(|| state.checked.get().unwrap_or(self.default_checked));
             let on_click = self.on_click.clone();
 
-            let (bg, toggle_bg) = match self.checked {
+            let (bg, toggle_bg) = match checked {
                 true => (theme.primary, theme.background),
                 false => (theme.input, theme.background),
             };
@@ -112,16 +162,24 @@ impl Element for Switch {
                 false => (bg, toggle_bg),
             };
 
-            let (bg_width, bg_height) = match self.size {
-                Size::XSmall | Size::Small => (px(28.), px(16.)),
-                _ => (px(36.), px(20.)),
-            };
-            let bar_width = match self.size {
-                Size::XSmall | Size::Small => px(12.),
-                _ => px(16.),
-            };
+            let (bg_width, bg_height, bar_width) = switch_size(self.size);
             let inset = px(2.);
 
+            let toggle = Rc::new({
+                let prev_checked = state.prev_checked.clone();
+                let uncontrolled_checked = state.checked.clone();
+                move |cx: &mut WindowContext| {
+                    *prev_checked.borrow_mut() = Some(checked);
+                    if !controlled {
+                        uncontrolled_checked.set(Some(!checked));
+                        cx.refresh();
+                    }
+                    if let Some(on_click) = on_click.as_ref() {
+                        on_click(&!checked, cx);
+                    }
+                }
+            });
+
             let mut element = h_flex()
                 .id(self.id.clone())
                 .items_center()
@@ -140,6 +198,9 @@ impl Element for Switch {
                         .border_color(theme.transparent)
                         .bg(bg)
                         .when(!self.disabled, |this| this.cursor_pointer())
+                        .when_some(self.focus_handle.as_ref(), |this, focus_handle| {
+                            this.track_focus(focus_handle).focus_ring(focus_handle, cx)
+                        })
                         .child(
                             // Switch Toggle
                             div()
@@ -149,6 +210,7 @@ impl Element for Switch {
                                 .map(|this| {
                                     let prev_checked = state.prev_checked.clone();
                                     if !self.disabled
+                                        && !cx.theme().reduce_motion()
                                         && prev_checked
                                             .borrow()
                                             .map_or(false, |prev| prev != checked)
@@ -187,24 +249,30 @@ impl Element for Switch {
                 )
                 .when_some(self.label.clone(), |this, label| {
                     this.child(div().child(label).map(|this| match self.size {
-                        Size::XSmall | Size::Small => this.text_sm(),
+                        Size::XSmall => this.text_xs(),
+                        Size::Small => this.text_sm(),
+                        Size::Large => this.text_lg(),
+                        Size::XLarge => this.text_xl(),
                         _ => this.text_base(),
                     }))
                 })
-                .when_some(
-                    on_click
-                        .as_ref()
-                        .map(|c| c.clone())
-                        .filter(|_| !self.disabled),
-                    |this, on_click| {
-                        let prev_checked = state.prev_checked.clone();
-                        this.on_mouse_down(gpui::MouseButton::Left, move |_, cx| {
+                .when(!self.disabled, |this| {
+                    this.on_mouse_down(gpui::MouseButton::Left, {
+                        let toggle = toggle.clone();
+                        move |_, cx| {
                             cx.stop_propagation();
-                            *prev_checked.borrow_mut() = Some(checked);
-                            on_click(&!checked, cx);
+                            toggle(cx);
+                        }
+                    })
+                    .when(self.focus_handle.is_some(), |this| {
+                        this.on_key_down(move |event, cx| {
+                            if event.keystroke.key == "space" || event.keystroke.key == "enter" {
+                                cx.stop_propagation();
+                                toggle(cx);
+                            }
                         })
-                    },
-                )
+                    })
+                })
                 .into_any_element();
 
             ((element.request_layout(cx), element), state)
@@ -0,0 +1,412 @@
+use std::rc::Rc;
+
+use gpui::{
+    actions, canvas, div, prelude::FluentBuilder as _, px, AnyElement, AnyView, AppContext,
+    ElementId, EventEmitter, FocusHandle, FocusableView, InteractiveElement, IntoElement,
+    KeyBinding, ParentElement as _, Pixels, Render, ScrollHandle, SharedString,
+    StatefulInteractiveElement as _, Styled, ViewContext, WindowContext,
+};
+
+use crate::{
+    button::{Button, ButtonVariants as _},
+    h_flex,
+    selection::step_enabled,
+    theme::ActiveTheme,
+    v_flex, IconName, Sizable as _,
+};
+
+actions!(tabs, [SelectPrev, SelectNext, SelectFirst, SelectLast]);
+
+const CONTEXT: &str = "Tabs";
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("left", SelectPrev, Some(CONTEXT)),
+        KeyBinding::new("right", SelectNext, Some(CONTEXT)),
+        KeyBinding::new("home", SelectFirst, Some(CONTEXT)),
+        KeyBinding::new("end", SelectLast, Some(CONTEXT)),
+    ]);
+}
+
+pub enum TabEvent {
+    /// A tab is selected by click or keyboard, in the controlled mode the parent must
+    /// call `set_selected_index` to apply it.
+    Selected(usize),
+    /// The close button of a tab is clicked.
+    Closed(usize),
+}
+
+/// The style of the active tab.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TabVariant {
+    /// An underline below the active tab.
+    #[default]
+    Underline,
+    /// A filled pill background of the active tab.
+    Pill,
+}
+
+enum TabContent {
+    None,
+    View(AnyView),
+    Render(Rc<dyn Fn(&mut WindowContext) -> AnyElement>),
+}
+
+/// A tab of the [`Tabs`], with the content to show when it is selected.
+pub struct TabItem {
+    label: SharedString,
+    content: TabContent,
+    closable: bool,
+    disabled: bool,
+}
+
+impl TabItem {
+    pub fn new(label: impl Into<SharedString>) -> Self {
+        Self {
+            label: label.into(),
+            content: TabContent::None,
+            closable: false,
+            disabled: false,
+        }
+    }
+
+    /// Set a view as the content of the tab.
+    pub fn view(mut self, view: impl Into<AnyView>) -> Self {
+        self.content = TabContent::View(view.into());
+        self
+    }
+
+    /// Set a render closure as the content of the tab, it is called only when the tab is selected.
+    pub fn render<E, F>(mut self, builder: F) -> Self
+    where
+        E: IntoElement,
+        F: Fn(&mut WindowContext) -> E + 'static,
+    {
+        self.content = TabContent::Render(Rc::new(move |cx| builder(cx).into_any_element()));
+        self
+    }
+
+    /// Set to show a close button, that emits [`TabEvent::Closed`], default: false
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    /// Set disabled state to the tab, it can't be selected by click or keyboard.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// A tabs view with a strip of tab buttons and the content of the selected tab.
+///
+/// By default the tabs are uncontrolled, the selection changes by click or keyboard.
+/// Use `controlled` to own the `selected_index` in the parent, by handling the [`TabEvent`].
+///
+/// ```ignore
+/// let tabs = cx.new_view(|cx| {
+///     Tabs::new("settings", cx)
+///         .tab(TabItem::new("General").view(general))
+///         .tab(TabItem::new("About").render(|_| "Version 1.0"))
+/// });
+/// ```
+pub struct Tabs {
+    id: ElementId,
+    focus_handle: FocusHandle,
+    items: Vec<TabItem>,
+    selected_index: usize,
+    controlled: bool,
+    variant: TabVariant,
+    scroll_handle: ScrollHandle,
+    /// The tabs don't fit the strip, to show the chevron buttons.
+    overflowing: bool,
+}
+
+impl Tabs {
+    pub fn new(id: impl Into<ElementId>, cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            id: id.into(),
+            focus_handle: cx.focus_handle(),
+            items: Vec::new(),
+            selected_index: 0,
+            controlled: false,
+            variant: TabVariant::default(),
+            scroll_handle: ScrollHandle::new(),
+            overflowing: false,
+        }
+    }
+
+    /// Add a tab.
+    pub fn tab(mut self, item: TabItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// Set the style of the active tab, default: [`TabVariant::Underline`]
+    pub fn variant(mut self, variant: TabVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Set to the controlled mode, the selection and closing are only emitted as the [`TabEvent`],
+    /// the parent should apply them by `set_selected_index` and `remove_tab`, default: false
+    pub fn controlled(mut self, controlled: bool) -> Self {
+        self.controlled = controlled;
+        self
+    }
+
+    pub fn selected_index(&self) -> usize {
+        self.selected_index
+    }
+
+    pub fn set_selected_index(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        let ix = ix.min(self.items.len().saturating_sub(1));
+        if self.selected_index != ix {
+            self.selected_index = ix;
+            self.scroll_handle.scroll_to_item(ix);
+            cx.notify();
+        }
+    }
+
+    pub fn add_tab(&mut self, item: TabItem, cx: &mut ViewContext<Self>) {
+        self.items.push(item);
+        cx.notify();
+    }
+
+    pub fn remove_tab(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        if ix >= self.items.len() {
+            return;
+        }
+
+        self.items.remove(ix);
+        if ix < self.selected_index || self.selected_index >= self.items.len() {
+            self.selected_index = self.selected_index.saturating_sub(1);
+        }
+        cx.notify();
+    }
+
+    fn select(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        if self.items.get(ix).map_or(true, |item| item.disabled) {
+            return;
+        }
+
+        if !self.controlled {
+            self.set_selected_index(ix, cx);
+        }
+        cx.emit(TabEvent::Selected(ix));
+    }
+
+    fn close(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        if !self.controlled {
+            self.remove_tab(ix, cx);
+        }
+        cx.emit(TabEvent::Closed(ix));
+    }
+
+    /// Returns the next enabled tab from the selected one, wraps around the ends.
+    fn step(&self, forward: bool) -> Option<usize> {
+        step_enabled(self.items.len(), Some(self.selected_index), forward, |ix| {
+            self.items[ix].disabled
+        })
+    }
+
+    fn select_prev(&mut self, _: &SelectPrev, cx: &mut ViewContext<Self>) {
+        if let Some(ix) = self.step(false) {
+            self.select(ix, cx);
+        }
+    }
+
+    fn select_next(&mut self, _: &SelectNext, cx: &mut ViewContext<Self>) {
+        if let Some(ix) = self.step(true) {
+            self.select(ix, cx);
+        }
+    }
+
+    fn select_first(&mut self, _: &SelectFirst, cx: &mut ViewContext<Self>) {
+        if let Some(ix) = self.items.iter().position(|item| !item.disabled) {
+            self.select(ix, cx);
+        }
+    }
+
+    fn select_last(&mut self, _: &SelectLast, cx: &mut ViewContext<Self>) {
+        if let Some(ix) = self.items.iter().rposition(|item| !item.disabled) {
+            self.select(ix, cx);
+        }
+    }
+
+    /// Returns the width of all the tabs, from the last layout.
+    fn content_width(&self) -> Pixels {
+        let count = self.items.len();
+        match (
+            self.scroll_handle.bounds_for_item(0),
+            self.scroll_handle.bounds_for_item(count.saturating_sub(1)),
+        ) {
+            (Some(first), Some(last)) => last.right() - first.left(),
+            _ => px(0.),
+        }
+    }
+
+    fn update_overflow(&mut self, cx: &mut ViewContext<Self>) {
+        let overflowing = self.content_width() > self.scroll_handle.bounds().size.width;
+        if self.overflowing != overflowing {
+            self.overflowing = overflowing;
+            cx.notify();
+        }
+    }
+
+    /// Scroll the strip by half of the visible width, in the direction.
+    fn scroll_tabs(&mut self, forward: bool, cx: &mut ViewContext<Self>) {
+        let viewport = self.scroll_handle.bounds().size.width;
+        let max_offset = (self.content_width() - viewport).max(px(0.));
+        let delta = if forward {
+            -viewport / 2.
+        } else {
+            viewport / 2.
+        };
+
+        let mut offset = self.scroll_handle.offset();
+        offset.x = (offset.x + delta).max(-max_offset).min(px(0.));
+        self.scroll_handle.set_offset(offset);
+        cx.notify();
+    }
+
+    fn render_tab(&self, ix: usize, item: &TabItem, cx: &mut ViewContext<Self>) -> AnyElement {
+        let selected = ix == self.selected_index;
+        let text_color = if selected {
+            cx.theme().tab_active_foreground
+        } else {
+            cx.theme().muted_foreground
+        };
+
+        h_flex()
+            .id(("tab", ix))
+            .flex_shrink_0()
+            .gap_1()
+            .h(px(30.))
+            .px_3()
+            .text_sm()
+            .whitespace_nowrap()
+            .text_color(text_color)
+            .map(|this| match self.variant {
+                TabVariant::Underline => this.border_b_2().border_color(if selected {
+                    cx.theme().primary
+                } else {
+                    cx.theme().transparent
+                }),
+                TabVariant::Pill => this
+                    .rounded(px(cx.theme().radius))
+                    .when(selected, |this| this.bg(cx.theme().tab_active).shadow_sm()),
+            })
+            .when(item.disabled, |this| this.opacity(0.5))
+            .when(!item.disabled, |this| {
+                this.cursor_pointer()
+                    .hover(|this| this.text_color(cx.theme().tab_active_foreground))
+                    .on_click(cx.listener(move |this, _, cx| {
+                        this.focus_handle.focus(cx);
+                        this.select(ix, cx);
+                    }))
+            })
+            .child(item.label.clone())
+            .when(item.closable, |this| {
+                this.child(
+                    Button::new(("close", ix))
+                        .icon(IconName::Close)
+                        .ghost()
+                        .xsmall()
+                        .on_click(cx.listener(move |this, _, cx| this.close(ix, cx))),
+                )
+            })
+            .into_any_element()
+    }
+
+    fn render_chevron(&self, forward: bool, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let (id, icon) = if forward {
+            ("scroll-next", IconName::ChevronRight)
+        } else {
+            ("scroll-prev", IconName::ChevronLeft)
+        };
+
+        Button::new(id)
+            .icon(icon)
+            .ghost()
+            .xsmall()
+            .on_click(cx.listener(move |this, _, cx| this.scroll_tabs(forward, cx)))
+    }
+}
+
+impl EventEmitter<TabEvent> for Tabs {}
+
+impl FocusableView for Tabs {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for Tabs {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let view = cx.view().clone();
+        let tabs = self
+            .items
+            .iter()
+            .enumerate()
+            .map(|(ix, item)| self.render_tab(ix, item, cx))
+            .collect::<Vec<_>>();
+        let content = match self
+            .items
+            .get(self.selected_index)
+            .map(|item| &item.content)
+        {
+            Some(TabContent::View(content)) => Some(content.clone().into_any_element()),
+            Some(TabContent::Render(builder)) => Some(builder(cx)),
+            _ => None,
+        };
+
+        v_flex()
+            .id(self.id.clone())
+            .size_full()
+            .child(
+                h_flex()
+                    .key_context(CONTEXT)
+                    .track_focus(&self.focus_handle)
+                    .on_action(cx.listener(Self::select_prev))
+                    .on_action(cx.listener(Self::select_next))
+                    .on_action(cx.listener(Self::select_first))
+                    .on_action(cx.listener(Self::select_last))
+                    .relative()
+                    .flex_none()
+                    .gap_1()
+                    .map(|this| match self.variant {
+                        TabVariant::Underline => this.border_b_1().border_color(cx.theme().border),
+                        TabVariant::Pill => this
+                            .p_0p5()
+                            .rounded(px(cx.theme().radius))
+                            .bg(cx.theme().tab_bar),
+                    })
+                    .when(self.overflowing, |this| {
+                        this.child(self.render_chevron(false, cx))
+                    })
+                    .child(
+                        h_flex()
+                            .id("tabs")
+                            .flex_1()
+                            .gap_1()
+                            .overflow_x_scroll()
+                            .track_scroll(&self.scroll_handle)
+                            .children(tabs),
+                    )
+                    .when(self.overflowing, |this| {
+                        this.child(self.render_chevron(true, cx))
+                    })
+                    .child(
+                        canvas(
+                            move |_, cx| view.update(cx, |this, cx| this.update_overflow(cx)),
+                            |_, _, _| {},
+                        )
+                        .absolute()
+                        .size_full(),
+                    ),
+            )
+            .child(div().flex_1().children(content))
+    }
+}
@@ -18,6 +18,7 @@ use gpui::{
     StatefulInteractiveElement as _, Styled, UniformListScrollHandle, ViewContext,
     VisualContext as _, WindowContext,
 };
+use serde::{Deserialize, Serialize};
 
 actions!(
     table,
@@ -109,6 +110,16 @@ pub enum TableEvent {
     SelectCol(usize),
     ColWidthsChanged(Vec<Pixels>),
     MoveCol(usize, usize),
+    /// The sort of the column is changed by clicking the head, the `bool` is true for ascending.
+    ///
+    /// The delegate should reorder the data in the [`TableDelegate::perform_sort`].
+    SortChanged(usize, bool),
+}
+
+/// The layout of the table columns, to persist the column widths and restore them.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TableLayout {
+    pub col_widths: Vec<Pixels>,
 }
 
 #[derive(Clone, Copy, Default)]
@@ -180,6 +191,11 @@ pub trait TableDelegate: Sized + 'static {
         px(100.)
     }
 
+    /// Returns the min width of the column at the given index, when resizing. Default: 10px
+    fn col_min_width(&self, col_ix: usize, cx: &AppContext) -> Pixels {
+        px(10.)
+    }
+
     /// Return the sort state of the column at the given index.
     ///
     /// This is only called when the table initializes.
@@ -349,6 +365,26 @@ where
         self.prepare_col_groups(cx);
     }
 
+    /// Returns the current layout of the columns, to persist it.
+    pub fn layout(&self) -> TableLayout {
+        TableLayout {
+            col_widths: self.col_groups.iter().map(|group| group.width).collect(),
+        }
+    }
+
+    /// Restore the layout of the columns, the extra widths are ignored if the columns are changed.
+    pub fn set_layout(&mut self, layout: &TableLayout, cx: &mut ViewContext<Self>) {
+        for (ix, (group, width)) in self
+            .col_groups
+            .iter_mut()
+            .zip(layout.col_widths.iter())
+            .enumerate()
+        {
+            group.width = (*width).max(self.delegate.col_min_width(ix, cx));
+        }
+        cx.notify();
+    }
+
     fn prepare_col_groups(&mut self, cx: &mut ViewContext<Self>) {
         self.col_groups = (0..self.delegate.cols_count(cx))
             .map(|col_ix| ColGroup {
@@ -513,19 +549,16 @@ where
     /// The `ix`` is the index of the col to resize,
     /// and the `size` is the new size for the col.
     fn resize_cols(&mut self, ix: usize, size: Pixels, cx: &mut ViewContext<Self>) {
-        const MIN_WIDTH: Pixels = px(10.0);
         const MAX_WIDTH: Pixels = px(1200.0);
 
         if !self.delegate.can_resize_col(ix, cx) {
             return;
         }
+        let min_width = self.delegate.col_min_width(ix, cx);
         let size = size.floor();
 
         let old_width = self.col_groups[ix].width;
-        let new_width = size;
-        if new_width < MIN_WIDTH {
-            return;
-        }
+        let new_width = size.max(min_width);
         let changed_width = new_width - old_width;
         // If change size is less than 1px, do nothing.
         if changed_width > px(-1.0) && changed_width < px(1.0) {
@@ -565,6 +598,11 @@ where
         }
 
         self.delegate_mut().perform_sort(col_ix, sort, cx);
+        match sort {
+            ColSort::Ascending => cx.emit(TableEvent::SortChanged(col_ix, true)),
+            ColSort::Descending => cx.emit(TableEvent::SortChanged(col_ix, false)),
+            ColSort::Default => {}
+        }
 
         cx.notify();
     }
@@ -0,0 +1,147 @@
+use std::rc::Rc;
+
+use gpui::{
+    div, prelude::FluentBuilder as _, relative, ClickEvent, ElementId, Hsla,
+    InteractiveElement as _, IntoElement, ParentElement as _, RenderOnce, SharedString,
+    StatefulInteractiveElement as _, Styled, WindowContext,
+};
+
+use crate::{
+    badge::{is_long_label, BadgeVariant, LABEL_MAX_WIDTH},
+    h_flex,
+    tooltip::Tooltip,
+    Disableable, Icon, IconName, Sizable, Size,
+};
+
+/// A Tag element to show a label, e.g. the tags of an item in the list.
+///
+/// Set `on_remove` to show a close icon to remove the tag.
+#[derive(IntoElement)]
+pub struct Tag {
+    id: ElementId,
+    label: SharedString,
+    icon: Option<IconName>,
+    variant: BadgeVariant,
+    size: Size,
+    disabled: bool,
+    on_remove: Option<Rc<dyn Fn(&ClickEvent, &mut WindowContext)>>,
+}
+
+impl Tag {
+    pub fn new(id: impl Into<ElementId>, label: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            icon: None,
+            variant: BadgeVariant::Secondary,
+            size: Size::default(),
+            disabled: false,
+            on_remove: None,
+        }
+    }
+
+    /// Set the color variant of the tag, default: `BadgeVariant::Secondary`
+    pub fn with_variant(mut self, variant: BadgeVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Set a tinted color of the tag, see [`BadgeVariant::tinted`].
+    pub fn color(self, color: Hsla) -> Self {
+        self.with_variant(BadgeVariant::tinted(color))
+    }
+
+    /// Set the icon to show before the label.
+    pub fn icon(mut self, icon: IconName) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Show a close icon, and call the `handler` when it is clicked.
+    pub fn on_remove(
+        mut self,
+        handler: impl Fn(&ClickEvent, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.on_remove = Some(Rc::new(handler));
+        self
+    }
+}
+
+impl Disableable for Tag {
+    fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl Sizable for Tag {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl RenderOnce for Tag {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let fg = self.variant.fg(cx);
+        let label = self.label.clone();
+
+        h_flex()
+            .id(self.id)
+            .flex_shrink_0()
+            .gap_1()
+            .rounded_md()
+            .border_1()
+            .border_color(self.variant.border(cx))
+            .bg(self.variant.bg(cx))
+            .text_color(fg)
+            .line_height(relative(1.3))
+            .map(|this| match self.size {
+                Size::XSmall => this.text_xs().px_1().py_0(),
+                Size::Small => this.text_xs().px_1p5().py_0(),
+                Size::Large => this.text_base().px_3().py_1(),
+                Size::XLarge => this.text_lg().px_4().py_1p5(),
+                _ => this.text_sm().px_2().py_0p5(),
+            })
+            .when(self.disabled, |this| this.opacity(0.5))
+            .when_some(self.icon, |this, icon| {
+                this.child(Icon::new(icon).with_size(self.size).flex_shrink_0())
+            })
+            .child(
+                div()
+                    .max_w(LABEL_MAX_WIDTH)
+                    .overflow_hidden()
+                    .text_ellipsis()
+                    .whitespace_nowrap()
+                    .child(self.label),
+            )
+            .when(is_long_label(&label), |this| {
+                this.tooltip(move |cx| Tooltip::new(label.clone(), cx))
+            })
+            .when_some(self.on_remove, |this, on_remove| {
+                this.child(
+                    div()
+                        .id("remove")
+                        .flex_shrink_0()
+                        .rounded_sm()
+                        .opacity(0.6)
+                        .when(!self.disabled, |this| {
+                            this.cursor_pointer()
+                                .hover(|this| this.opacity(1.))
+                                .on_click(move |event, cx| {
+                                    cx.stop_propagation();
+                                    on_remove(event, cx);
+                                })
+                        })
+                        .child(Icon::new(IconName::Close).text_color(fg).map(
+                            |this| match self.size {
+                                Size::XSmall | Size::Small => this.size_3(),
+                                Size::Large => this.size_4(),
+                                Size::XLarge => this.size_5(),
+                                _ => this.size_3p5(),
+                            },
+                        )),
+                )
+            })
+    }
+}
@@ -0,0 +1,24 @@
+use gpui::{px, Font, Hsla, Pixels, SharedString, TextRun, WindowContext};
+
+/// Measure the width of the `text` with the `font` and the `font_size`, without wrapping.
+///
+/// Returns the width of the widest line for the multi-line text, e.g. to decide if a label should be truncated.
+pub fn measure_text(text: &str, font: &Font, font_size: Pixels, cx: &WindowContext) -> Pixels {
+    text.lines()
+        .map(|line| {
+            let run = TextRun {
+                len: line.len(),
+                font: font.clone(),
+                color: Hsla::default(),
+                background_color: None,
+                underline: None,
+                strikethrough: None,
+            };
+
+            cx.text_system()
+                .shape_line(SharedString::from(line.to_string()), font_size, &[run])
+                .map(|line| line.width)
+                .unwrap_or_default()
+        })
+        .fold(px(0.), |width, line_width| width.max(line_width))
+}
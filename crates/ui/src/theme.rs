@@ -1,16 +1,33 @@
-use std::ops::{Deref, DerefMut};
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    ops::{Deref, DerefMut},
+    sync::{Arc, OnceLock},
+};
+#[cfg(debug_assertions)]
+use std::{path::PathBuf, time::Duration};
 
+use anyhow::{anyhow, Result};
 use gpui::{
-    hsla, point, AppContext, BoxShadow, Global, Hsla, ModelContext, Pixels, SharedString,
-    ViewContext, WindowAppearance, WindowContext,
+    hsla, point, px, AppContext, AsyncAppContext, AsyncWindowContext, BoxShadow, Global, Hsla,
+    ModelContext, Pixels, Rems, SharedString, ViewContext, WindowAppearance,
+    WindowBackgroundAppearance, WindowContext,
 };
+use serde::{de::Error as _, Deserialize, Deserializer};
 
-use crate::scroll::ScrollbarShow;
+use crate::{colors::ColorExt as _, scroll::ScrollbarShow, Density, Size};
 
 pub fn init(cx: &mut AppContext) {
-    Theme::sync_system_appearance(cx)
+    cx.set_global(ThemeRegistry::default());
+    Theme::sync_system_appearance(cx);
+    // Keep the tests hermetic, they don't read the settings of the machine.
+    #[cfg(not(test))]
+    Theme::sync_system_reduce_motion(cx);
 }
 
+/// The reduced motion of the system accessibility settings, it is read in the background by [`init`].
+static SYSTEM_REDUCE_MOTION: OnceLock<bool> = OnceLock::new();
+
 pub trait ActiveTheme {
     fn theme(&self) -> &Theme;
 }
@@ -39,6 +56,31 @@ impl ActiveTheme for WindowContext<'_> {
     }
 }
 
+/// Read the theme in the async contexts, they can't borrow the global directly.
+///
+/// ```ignore
+/// cx.spawn(|this, mut cx| async move {
+///     let color = cx.read_theme(|theme| theme.primary)?;
+///     // ...
+/// })
+/// ```
+pub trait ReadTheme {
+    /// Read the global theme with `f`, returns an error if the app (or the window) has been closed.
+    fn read_theme<R>(&mut self, f: impl FnOnce(&Theme) -> R) -> Result<R>;
+}
+
+impl ReadTheme for AsyncAppContext {
+    fn read_theme<R>(&mut self, f: impl FnOnce(&Theme) -> R) -> Result<R> {
+        self.update(|cx| f(cx.theme()))
+    }
+}
+
+impl ReadTheme for AsyncWindowContext {
+    fn read_theme<R>(&mut self, f: impl FnOnce(&Theme) -> R) -> Result<R> {
+        self.update(|cx| f(cx.theme()))
+    }
+}
+
 /// Make a [gpui::Hsla] color.
 ///
 /// - h: 0..360.0
@@ -69,6 +111,81 @@ pub fn box_shadow(
         color,
     }
 }
+/// Generate a 12 steps color scale from a base color, like the [Radix Colors](https://www.radix-ui.com/colors/docs/palette-composition/understanding-the-scale).
+///
+/// The base color will be used as the step 9, and the scale is inverted in dark mode.
+///
+/// - 1, 2: App backgrounds.
+/// - 3, 4, 5: Component backgrounds (normal, hover, active).
+/// - 6, 7, 8: Borders (subtle, normal, strong / focus ring).
+/// - 9, 10: Solid backgrounds (normal, hover).
+/// - 11, 12: Texts (low contrast, high contrast).
+pub fn color_scale(base: Hsla, mode: ThemeMode) -> [Hsla; 12] {
+    const LIGHT: [f32; 8] = [0.99, 0.975, 0.945, 0.91, 0.87, 0.82, 0.75, 0.65];
+    const DARK: [f32; 8] = [0.08, 0.1, 0.14, 0.18, 0.22, 0.27, 0.33, 0.42];
+
+    let (steps, text_l) = if mode.is_dark() {
+        (DARK, [0.75, 0.93])
+    } else {
+        (LIGHT, [0.4, 0.16])
+    };
+
+    let mut scale = [base; 12];
+    for (ix, l) in steps.iter().enumerate() {
+        // Keep the backgrounds less saturated than the solid color.
+        let s = base.s * (0.5 + ix as f32 * 0.0625);
+        scale[ix] = Hsla { s, l: *l, ..base };
+    }
+    scale[8] = base;
+    scale[9] = if mode.is_dark() {
+        base.lighten(0.1)
+    } else {
+        base.darken(0.1)
+    };
+    scale[10] = Hsla {
+        l: text_l[0],
+        ..base
+    };
+    scale[11] = Hsla {
+        s: base.s * 0.5,
+        l: text_l[1],
+        ..base
+    };
+    scale
+}
+
+/// Returns the WCAG contrast ratio between two colors, in range of 1.0 ..= 21.0.
+pub fn contrast_ratio(a: Hsla, b: Hsla) -> f32 {
+    fn luminance(color: Hsla) -> f32 {
+        fn channel(c: f32) -> f32 {
+            if c <= 0.03928 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        }
+
+        let rgb = color.to_rgb();
+        0.2126 * channel(rgb.r) + 0.7152 * channel(rgb.g) + 0.0722 * channel(rgb.b)
+    }
+
+    let (l1, l2) = (luminance(a), luminance(b));
+    let (lighter, darker) = if l1 > l2 { (l1, l2) } else { (l2, l1) };
+    (lighter + 0.05) / (darker + 0.05)
+}
+
+/// Returns a white or black foreground color that has the better contrast on the `background`.
+pub fn readable_foreground(background: Hsla) -> Hsla {
+    let white = hsl(0.0, 0.0, 100.0);
+    let black = hsl(0.0, 0.0, 9.0);
+
+    if contrast_ratio(white, background) >= contrast_ratio(black, background) {
+        white
+    } else {
+        black
+    }
+}
+
 pub trait Colorize {
     fn opacity(&self, opacity: f32) -> Hsla;
     fn divide(&self, divisor: f32) -> Hsla;
@@ -146,82 +263,182 @@ impl Colorize for Hsla {
         }
     }
 }
-#[derive(Debug, Clone, Copy, Default)]
-pub struct ThemeColor {
-    pub accent: Hsla,
-    pub accent_foreground: Hsla,
-    pub accordion: Hsla,
-    pub accordion_active: Hsla,
-    pub accordion_hover: Hsla,
-    pub background: Hsla,
-    pub border: Hsla,
-    pub window_border: Hsla,
-    pub card: Hsla,
-    pub card_foreground: Hsla,
-    pub destructive: Hsla,
-    pub destructive_active: Hsla,
-    pub destructive_foreground: Hsla,
-    pub destructive_hover: Hsla,
-    pub drag_border: Hsla,
-    pub drop_target: Hsla,
-    pub foreground: Hsla,
-    pub input: Hsla,
-    pub link: Hsla,
-    pub link_active: Hsla,
-    pub link_hover: Hsla,
-    pub list: Hsla,
-    pub list_active: Hsla,
-    pub list_active_border: Hsla,
-    pub list_even: Hsla,
-    pub list_head: Hsla,
-    pub list_hover: Hsla,
-    pub muted: Hsla,
-    pub muted_foreground: Hsla,
-    pub panel: Hsla,
-    pub popover: Hsla,
-    pub popover_foreground: Hsla,
-    pub primary: Hsla,
-    pub primary_active: Hsla,
-    pub primary_foreground: Hsla,
-    pub primary_hover: Hsla,
-    pub progress_bar: Hsla,
-    pub ring: Hsla,
-    pub scrollbar: Hsla,
-    pub scrollbar_thumb: Hsla,
-    pub scrollbar_thumb_hover: Hsla,
-    pub secondary: Hsla,
-    pub secondary_active: Hsla,
-    pub secondary_foreground: Hsla,
-    pub secondary_hover: Hsla,
-    pub selection: Hsla,
-    pub skeleton: Hsla,
-    pub slider_bar: Hsla,
-    pub slider_thumb: Hsla,
-    pub tab: Hsla,
-    pub tab_active: Hsla,
-    pub tab_active_foreground: Hsla,
-    pub tab_bar: Hsla,
-    pub tab_foreground: Hsla,
-    pub table: Hsla,
-    pub table_active: Hsla,
-    pub table_active_border: Hsla,
-    pub table_even: Hsla,
-    pub table_head: Hsla,
-    pub table_head_foreground: Hsla,
-    pub table_hover: Hsla,
-    pub table_row_border: Hsla,
-    pub title_bar: Hsla,
-    pub title_bar_border: Hsla,
-    pub sidebar: Hsla,
-    pub sidebar_accent: Hsla,
-    pub sidebar_accent_foreground: Hsla,
-    pub sidebar_border: Hsla,
-    pub sidebar_foreground: Hsla,
-    pub sidebar_primary: Hsla,
-    pub sidebar_primary_foreground: Hsla,
+
+/// Declare the [`ThemeColor`] tokens.
+///
+/// This generates the [`ThemeColor`] struct and a [`ThemeColorConfig`] with the same fields
+/// as optional values for loading from JSON, so a new token only needs to be added once here.
+macro_rules! theme_colors {
+    ($($name:ident),+ $(,)?) => {
+        #[derive(Debug, Clone, Copy, Default)]
+        pub struct ThemeColor {
+            $(pub $name: Hsla,)+
+        }
+
+        /// The color tokens of a [`ThemeConfig`], all fields are optional.
+        ///
+        /// The values are hex strings, e.g.: `"#FF0000"` or `"#FF000080"`.
+        #[derive(Debug, Clone, Default, Deserialize)]
+        #[serde(default)]
+        pub struct ThemeColorConfig {
+            $(
+                #[serde(deserialize_with = "deserialize_color")]
+                pub $name: Option<Hsla>,
+            )+
+        }
+
+        impl ThemeColor {
+            /// Override the colors with the values that are set in the config.
+            pub fn apply_config(&mut self, config: &ThemeColorConfig) {
+                $(
+                    if let Some(color) = config.$name {
+                        self.$name = color;
+                    }
+                )+
+                self.apply_config_fallbacks(config);
+            }
+        }
+    };
+}
+
+theme_colors!(
+    accent,
+    accent_foreground,
+    accordion,
+    accordion_active,
+    accordion_hover,
+    background,
+    border,
+    window_border,
+    card,
+    card_foreground,
+    caret,
+    danger,
+    danger_foreground,
+    destructive,
+    destructive_active,
+    destructive_foreground,
+    destructive_hover,
+    drag_border,
+    drop_target,
+    foreground,
+    info,
+    info_foreground,
+    input,
+    link,
+    link_active,
+    link_hover,
+    list,
+    list_active,
+    list_active_border,
+    list_context_border,
+    list_even,
+    list_head,
+    list_hover,
+    muted,
+    muted_foreground,
+    panel,
+    popover,
+    popover_foreground,
+    primary,
+    primary_active,
+    primary_foreground,
+    primary_hover,
+    progress_bar,
+    ring,
+    scrollbar,
+    scrollbar_thumb,
+    scrollbar_thumb_hover,
+    secondary,
+    secondary_active,
+    secondary_foreground,
+    secondary_hover,
+    selection,
+    selection_unfocused,
+    skeleton,
+    slider_bar,
+    slider_thumb,
+    success,
+    success_foreground,
+    tab,
+    tab_active,
+    tab_active_foreground,
+    tab_bar,
+    tab_foreground,
+    table,
+    table_active,
+    table_active_border,
+    table_even,
+    table_head,
+    table_head_foreground,
+    table_hover,
+    table_row_border,
+    title_bar,
+    title_bar_border,
+    warning,
+    warning_foreground,
+    sidebar,
+    sidebar_accent,
+    sidebar_accent_foreground,
+    sidebar_border,
+    sidebar_foreground,
+    sidebar_primary,
+    sidebar_primary_foreground,
+);
+
+fn deserialize_color<'de, D>(deserializer: D) -> Result<Option<Hsla>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let Some(hex) = Option::<String>::deserialize(deserializer)? else {
+        return Ok(None);
+    };
+
+    Hsla::parse_hex_string(&hex)
+        .map(Some)
+        .map_err(|err| D::Error::custom(format!("invalid color `{}`: {}", hex, err)))
 }
 
 impl ThemeColor {
+    /// Derive the tokens that are split from an older token, if only the older one is set in the config.
+    fn apply_config_fallbacks(&mut self, config: &ThemeColorConfig) {
+        if config.list_context_border.is_none() {
+            if let Some(color) = config.list_active_border {
+                self.list_context_border = color;
+            }
+        }
+    }
+
+    /// Apply the accent color to the brand related tokens, the variants are derived from [`color_scale`].
+    ///
+    /// This will change the primary, selection, focus ring and active list / table colors.
+    pub fn apply_accent(&mut self, accent: Hsla, mode: ThemeMode) {
+        let scale = color_scale(accent, mode);
+
+        self.primary = scale[8];
+        self.primary_hover = scale[9];
+        self.primary_active = if mode.is_dark() {
+            scale[9].lighten(0.1)
+        } else {
+            scale[9].darken(0.1)
+        };
+        self.primary_foreground = readable_foreground(scale[8]);
+        self.ring = scale[7];
+        self.selection = scale[4];
+        self.caret = scale[8];
+        self.drag_border = scale[8];
+        self.link = scale[10];
+        self.link_hover = scale[9];
+        self.link_active = scale[11];
+        self.list_active = scale[8].opacity(0.15);
+        self.list_active_border = scale[7];
+        self.list_context_border = scale[5];
+        self.table_active = scale[8].opacity(0.15);
+        self.table_active_border = scale[7];
+        self.progress_bar = scale[8];
+        self.slider_bar = scale[8];
+    }
+
     pub fn light() -> Self {
         Self {
             accent: hsl(240.0, 5.0, 96.0),
@@ -234,6 +451,9 @@ impl ThemeColor {
             window_border: hsl(240.0, 5.9, 78.0),
             card: hsl(0.0, 0.0, 100.0),
             card_foreground: hsl(240.0, 10.0, 3.9),
+            caret: hsl(217.0, 91.0, 60.0),
+            danger: hsl(0.0, 84.2, 60.2),
+            danger_foreground: hsl(0.0, 0.0, 98.0),
             destructive: hsl(0.0, 84.2, 60.2),
             destructive_active: hsl(0.0, 84.2, 47.0),
             destructive_foreground: hsl(0.0, 0.0, 98.0),
@@ -241,6 +461,8 @@ impl ThemeColor {
             drag_border: crate::blue_500(),
             drop_target: hsl(235.0, 30., 44.0).opacity(0.25),
             foreground: hsl(240.0, 10., 3.9),
+            info: hsl(204.0, 94.0, 48.0),
+            info_foreground: hsl(0.0, 0.0, 100.0),
             input: hsl(240.0, 5.9, 90.0),
             link: hsl(221.0, 83.0, 53.0),
             link_active: hsl(221.0, 83.0, 53.0).darken(0.2),
@@ -248,6 +470,7 @@ impl ThemeColor {
             list: hsl(0.0, 0.0, 100.),
             list_active: hsl(211.0, 97.0, 85.0).opacity(0.2),
             list_active_border: hsl(211.0, 97.0, 85.0),
+            list_context_border: hsl(240.0, 5.0, 75.0),
             list_even: hsl(240.0, 5.0, 96.0),
             list_head: hsl(0.0, 0.0, 100.),
             list_hover: hsl(240.0, 4.8, 95.0),
@@ -270,9 +493,12 @@ impl ThemeColor {
             secondary_foreground: hsl(240.0, 59.0, 10.),
             secondary_hover: hsl(240.0, 5.9, 98.),
             selection: hsl(211.0, 97.0, 85.0),
+            selection_unfocused: hsl(240.0, 5.0, 88.0),
             skeleton: hsl(223.0, 5.9, 10.0).opacity(0.1),
             slider_bar: hsl(223.0, 5.9, 10.0),
             slider_thumb: hsl(0.0, 0.0, 100.0),
+            success: hsl(142.0, 71.0, 38.0),
+            success_foreground: hsl(0.0, 0.0, 100.0),
             tab: gpui::transparent_black(),
             tab_active: hsl(0.0, 0.0, 100.0),
             tab_active_foreground: hsl(240.0, 10., 3.9),
@@ -288,6 +514,8 @@ impl ThemeColor {
             table_row_border: hsl(240.0, 7.7, 94.5),
             title_bar: hsl(0.0, 0.0, 100.),
             title_bar_border: hsl(240.0, 5.9, 90.0),
+            warning: hsl(38.0, 92.0, 50.0),
+            warning_foreground: hsl(26.0, 83.0, 14.0),
             sidebar: hsl(0.0, 0.0, 98.0),
             sidebar_accent: hsl(240.0, 4.8, 92.),
             sidebar_accent_foreground: hsl(240.0, 5.9, 10.0),
@@ -310,6 +538,9 @@ impl ThemeColor {
             window_border: hsl(240.0, 3.7, 28.0),
             card: hsl(0.0, 0.0, 8.0),
             card_foreground: hsl(0.0, 0.0, 78.0),
+            caret: hsl(217.0, 91.0, 60.0),
+            danger: hsl(0.0, 72.0, 51.0),
+            danger_foreground: hsl(0.0, 0.0, 98.0),
             destructive: hsl(0.0, 62.8, 30.6),
             destructive_active: hsl(0.0, 62.8, 20.6),
             destructive_foreground: hsl(0.0, 0.0, 78.0),
@@ -317,6 +548,8 @@ impl ThemeColor {
             drag_border: crate::blue_500(),
             drop_target: hsl(235.0, 30., 44.0).opacity(0.1),
             foreground: hsl(0., 0., 78.),
+            info: hsl(204.0, 80.0, 55.0),
+            info_foreground: hsl(0.0, 0.0, 8.0),
             input: hsl(240.0, 3.7, 15.9),
             link: hsl(221.0, 83.0, 53.0),
             link_active: hsl(221.0, 83.0, 53.0).darken(0.2),
@@ -324,6 +557,7 @@ impl ThemeColor {
             list: hsl(0.0, 0.0, 8.0),
             list_active: hsl(240.0, 3.7, 15.0).opacity(0.2),
             list_active_border: hsl(240.0, 5.9, 35.5),
+            list_context_border: hsl(240.0, 5.0, 55.0),
             list_even: hsl(240.0, 3.7, 10.0),
             list_head: hsl(0.0, 0.0, 8.0),
             list_hover: hsl(240.0, 3.7, 15.9),
@@ -346,9 +580,12 @@ impl ThemeColor {
             secondary_foreground: hsl(0.0, 0.0, 78.0),
             secondary_hover: hsl(240.0, 0., 15.),
             selection: hsl(211.0, 97.0, 22.0),
+            selection_unfocused: hsl(240.0, 3.7, 20.0),
             skeleton: hsla(223.0, 0.0, 98.0, 0.1),
             slider_bar: hsl(223.0, 0.0, 98.0),
             slider_thumb: hsl(0.0, 0.0, 8.0),
+            success: hsl(142.0, 69.0, 45.0),
+            success_foreground: hsl(0.0, 0.0, 8.0),
             tab: gpui::transparent_black(),
             tab_active: hsl(0.0, 0.0, 8.0),
             tab_active_foreground: hsl(0., 0., 78.),
@@ -364,6 +601,8 @@ impl ThemeColor {
             table_row_border: hsl(240.0, 3.7, 16.9).opacity(0.5),
             title_bar: hsl(0., 0., 9.7),
             title_bar_border: hsl(240.0, 3.7, 15.9),
+            warning: hsl(43.0, 96.0, 56.0),
+            warning_foreground: hsl(26.0, 83.0, 14.0),
             sidebar: hsl(240.0, 0.0, 10.0),
             sidebar_accent: hsl(240.0, 3.7, 15.9),
             sidebar_accent_foreground: hsl(240.0, 4.8, 95.9),
@@ -381,12 +620,55 @@ pub struct Theme {
 
     pub mode: ThemeMode,
     pub font_family: SharedString,
+    /// The monospace font for the code, e.g. the [`crate::clipboard::Snippet`].
+    pub mono_font_family: SharedString,
+    /// The base font size, this is also used as the rem size, default: 16.0
     pub font_size: f32,
+    /// The font size for the `Size::Small` components, default: 14.0
+    pub font_size_sm: f32,
+    /// The font size for the `Size::Large` components, default: 18.0
+    pub font_size_lg: f32,
+    /// The line height in rems, default: 1.25
+    pub line_height: f32,
+    /// The border radius of the components, default: 4.0
     pub radius: f32,
+    /// The large border radius for containers such as Popover and Modal, default: 8.0
+    pub radius_lg: f32,
+    /// The border width of the components, default: 1.0
+    pub border_width: f32,
+    /// The density of the components without an explicit size, default: Comfortable
+    pub density: Density,
     pub shadow: bool,
+    /// Disable the animations, default: the system accessibility setting if detectable, otherwise false
+    pub reduce_motion: bool,
+    /// The `reduce_motion` is not set by the config, so it follows the system setting once it is read.
+    follow_system_reduce_motion: bool,
+    /// The opacity of the panel backgrounds, less than 1.0 to make them translucent, default: 1.0
+    pub opacity: f32,
+    /// The opacity of the disabled components, default: 0.5
+    pub disabled_opacity: f32,
+    /// Blur the content behind the window when the panel backgrounds are translucent, default: false
+    pub blur: bool,
+    /// Whether the theme is a high contrast theme, see [`Theme::is_high_contrast`].
+    pub high_contrast: bool,
+    /// Only show the focus ring when the focus was moved by the keyboard, default: false
+    pub focus_ring_keyboard_only: bool,
     pub transparent: Hsla,
     /// Show the scrollbar mode, default: Scrolling
     pub scrollbar_show: ScrollbarShow,
+    /// The unknown sections of the [`ThemeConfig`], for the extensions to parse.
+    pub extra: Arc<HashMap<String, serde_json::Value>>,
+    extensions: HashMap<TypeId, Arc<dyn Any + Send + Sync>>,
+    extension_factories: HashMap<TypeId, fn(ThemeMode) -> Arc<dyn Any + Send + Sync>>,
+}
+
+/// A custom theme extension that has different values for the light and dark mode.
+///
+/// Register it by [`Theme::register_extension`], then the value will be regenerated
+/// when the theme mode changes.
+pub trait ThemeExtension: Any + Send + Sync + Sized {
+    /// Returns the extension values for the theme mode.
+    fn for_mode(mode: ThemeMode) -> Self;
 }
 
 impl Deref for Theme {
@@ -411,11 +693,139 @@ impl Theme {
         cx.global::<Theme>()
     }
 
+    /// Returns the global theme reference, or None if [`init`] has not been called.
+    pub fn try_global(cx: &AppContext) -> Option<&Theme> {
+        cx.try_global::<Theme>()
+    }
+
     /// Returns the global theme mutable reference
     pub fn global_mut(cx: &mut AppContext) -> &mut Theme {
         cx.global_mut::<Theme>()
     }
 
+    /// Returns the extension value of type `T`, if it has been set.
+    pub fn extension<T: Any>(&self) -> Option<&T> {
+        self.extensions
+            .get(&TypeId::of::<T>())
+            .and_then(|value| value.downcast_ref::<T>())
+    }
+
+    /// Set an extension value, the value will be kept when the theme changes.
+    ///
+    /// If the value should be different in light and dark mode,
+    /// use [`Theme::register_extension`] instead.
+    pub fn set_extension<T: Any + Send + Sync>(&mut self, value: T) {
+        self.extensions.insert(TypeId::of::<T>(), Arc::new(value));
+    }
+
+    /// Register a [`ThemeExtension`] to the global theme, the value will be created for
+    /// the current mode, and recreated when the theme mode changes.
+    pub fn register_extension<T: ThemeExtension>(cx: &mut AppContext) {
+        let theme = Self::global_mut(cx);
+        theme
+            .extension_factories
+            .insert(TypeId::of::<T>(), |mode| Arc::new(T::for_mode(mode)));
+        let mode = theme.mode;
+        theme.set_extension(T::for_mode(mode));
+        cx.refresh();
+    }
+
+    /// Keep the extensions of the previous theme, the registered [`ThemeExtension`]s
+    /// are recreated for the current mode.
+    fn inherit_extensions(&mut self, prev: &Theme) {
+        for (type_id, value) in prev.extensions.iter() {
+            self.extensions.entry(*type_id).or_insert(value.clone());
+        }
+        for (type_id, factory) in prev.extension_factories.iter() {
+            self.extension_factories.insert(*type_id, *factory);
+            self.extensions.insert(*type_id, factory(self.mode));
+        }
+    }
+
+    /// Set the theme as the global theme, keep the extensions of the previous one.
+    fn set_as_global(mut self, cx: &mut AppContext) {
+        if let Some(prev) = Self::try_global(cx) {
+            self.inherit_extensions(prev);
+        }
+
+        cx.set_global(self);
+        cx.refresh();
+    }
+
+    /// Returns the `radius` token as Pixels.
+    #[inline]
+    pub fn radius(&self) -> Pixels {
+        px(self.radius)
+    }
+
+    /// Returns the `radius_lg` token as Pixels.
+    #[inline]
+    pub fn radius_lg(&self) -> Pixels {
+        px(self.radius_lg)
+    }
+
+    /// Returns the `border_width` token as Pixels.
+    #[inline]
+    pub fn border_width(&self) -> Pixels {
+        px(self.border_width)
+    }
+
+    /// Returns the `background` color with the `opacity` token applied.
+    #[inline]
+    pub fn panel_background(&self) -> Hsla {
+        self.background.opacity(self.opacity)
+    }
+
+    /// Returns the `popover` color with the `opacity` token applied.
+    #[inline]
+    pub fn popover_background(&self) -> Hsla {
+        self.popover.opacity(self.opacity)
+    }
+
+    /// Returns the [`WindowBackgroundAppearance`] that matches the `opacity` and `blur` tokens,
+    /// pass it to the `WindowOptions` to make the translucent panels show the content behind.
+    pub fn window_background_appearance(&self) -> WindowBackgroundAppearance {
+        if self.opacity >= 1.0 {
+            WindowBackgroundAppearance::Opaque
+        } else if self.blur {
+            WindowBackgroundAppearance::Blurred
+        } else {
+            WindowBackgroundAppearance::Transparent
+        }
+    }
+
+    /// Returns the font size for the given component [`Size`].
+    ///
+    /// - XSmall: `font_size_sm - 2`
+    /// - Small: `font_size_sm`
+    /// - Medium: `font_size`
+    /// - Large: `font_size_lg`
+    /// - XLarge: `font_size_lg + 2`
+    pub fn font_size_for(&self, size: Size) -> Pixels {
+        match size {
+            Size::XSmall => px(self.font_size_sm - 2.),
+            Size::Small => px(self.font_size_sm),
+            Size::Large => px(self.font_size_lg),
+            Size::XLarge => px(self.font_size_lg + 2.),
+            Size::Medium | Size::Size(_) => px(self.font_size),
+        }
+    }
+
+    /// Returns the `line_height` token as Rems.
+    #[inline]
+    pub fn line_height(&self) -> Rems {
+        Rems(self.line_height)
+    }
+
+    /// Return the theme with the brand colors derived from the `accent` color.
+    ///
+    /// See also [`ThemeColor::apply_accent`].
+    pub fn with_accent(mut self, accent: Hsla) -> Self {
+        let mode = self.mode;
+        self.colors.apply_accent(accent, mode);
+        self
+    }
+
     /// Apply a mask color to the theme.
     pub fn apply_color(&mut self, mask_color: Hsla) {
         self.title_bar = self.title_bar.apply(mask_color);
@@ -465,6 +875,7 @@ impl Theme {
         self.list_head = self.list_head.apply(mask_color);
         self.list_active = self.list_active.apply(mask_color);
         self.list_active_border = self.list_active_border.apply(mask_color);
+        self.list_context_border = self.list_context_border.apply(mask_color);
         self.list_hover = self.list_hover.apply(mask_color);
         self.table = self.table.apply(mask_color);
         self.table_even = self.table_even.apply(mask_color);
@@ -504,6 +915,27 @@ impl Theme {
         }
     }
 
+    /// Read the reduced motion of the system in the background, then apply it to the global theme,
+    /// unless the `reduce_motion` is set by the config.
+    #[cfg(not(test))]
+    fn sync_system_reduce_motion(cx: &mut AppContext) {
+        let read = cx
+            .background_executor()
+            .spawn(async { read_system_reduce_motion() });
+        cx.spawn(|mut cx| async move {
+            let reduce_motion = read.await;
+            _ = SYSTEM_REDUCE_MOTION.set(reduce_motion);
+            _ = cx.update(|cx| {
+                let theme = Theme::global_mut(cx);
+                if theme.follow_system_reduce_motion && theme.reduce_motion != reduce_motion {
+                    theme.reduce_motion = reduce_motion;
+                    cx.refresh();
+                }
+            });
+        })
+        .detach();
+    }
+
     pub fn change(mode: ThemeMode, cx: &mut AppContext) {
         let colors = match mode {
             ThemeMode::Light => ThemeColor::light(),
@@ -512,10 +944,334 @@ impl Theme {
 
         let mut theme = Theme::from(colors);
         theme.mode = mode;
+        // Keep the density and the reduced motion changed at runtime or by the config.
+        if let Some(prev) = cx.try_global::<Theme>() {
+            theme.density = prev.density;
+            theme.reduce_motion = prev.reduce_motion;
+            theme.follow_system_reduce_motion = prev.follow_system_reduce_motion;
+        }
+        theme.set_as_global(cx);
+    }
 
-        cx.set_global(theme);
+    /// Change the density of the global theme, all the windows will be re-rendered.
+    pub fn change_density(density: Density, cx: &mut AppContext) {
+        Self::global_mut(cx).density = density;
         cx.refresh();
     }
+
+    /// Apply a [`ThemeConfig`] as the global theme.
+    ///
+    /// The tokens missing in the config will use the default colors of the config `mode`.
+    pub fn apply_config(config: &ThemeConfig, cx: &mut AppContext) {
+        Theme::from_config(config).set_as_global(cx);
+    }
+
+    /// Watch a JSON theme file, and apply it as the global theme when the file changes.
+    ///
+    /// This is only available in debug builds, for live previewing a theme in development.
+    /// The errors are logged to stderr, and the last valid theme will be kept.
+    ///
+    /// The watching is stopped when the returned task is dropped.
+    #[cfg(debug_assertions)]
+    pub fn watch(path: impl Into<PathBuf>, cx: &mut AppContext) -> gpui::Task<()> {
+        const POLL_INTERVAL: Duration = Duration::from_millis(500);
+        // Editors may write the file multiple times in one save.
+        const DEBOUNCE: Duration = Duration::from_millis(100);
+
+        let path = path.into();
+        let modified_at = |path: &PathBuf| std::fs::metadata(path).and_then(|m| m.modified()).ok();
+
+        cx.spawn(|cx| async move {
+            let mut last_modified = None;
+            loop {
+                let modified = modified_at(&path);
+                if modified.is_some() && modified != last_modified {
+                    smol::Timer::after(DEBOUNCE).await;
+                    last_modified = modified_at(&path);
+
+                    let config = std::fs::read_to_string(&path)
+                        .map_err(anyhow::Error::from)
+                        .and_then(|json| ThemeConfig::from_json(&json));
+                    match config {
+                        Ok(config) => {
+                            if cx.update(|cx| Self::apply_config(&config, cx)).is_err() {
+                                break;
+                            }
+                        }
+                        Err(err) => {
+                            eprintln!("failed to load theme {}: {:?}", path.display(), err)
+                        }
+                    }
+                }
+
+                smol::Timer::after(POLL_INTERVAL).await;
+            }
+        })
+    }
+
+    /// Change the global theme to a theme registered in the [`ThemeRegistry`] by name.
+    pub fn select(name: &str, cx: &mut AppContext) -> Result<()> {
+        let Some(config) = ThemeRegistry::global(cx).get(name).cloned() else {
+            return Err(anyhow!("theme `{}` is not registered", name));
+        };
+
+        Self::apply_config(&config, cx);
+        Ok(())
+    }
+
+    /// Build a theme from a [`ThemeConfig`].
+    pub fn from_config(config: &ThemeConfig) -> Theme {
+        let mut colors = match config.mode {
+            ThemeMode::Light => ThemeColor::light(),
+            ThemeMode::Dark => ThemeColor::dark(),
+        };
+        colors.apply_config(&config.colors);
+
+        let mut theme = Theme::from(colors);
+        theme.mode = config.mode;
+        theme.high_contrast = config.high_contrast;
+        theme.extra = Arc::new(config.extra.clone());
+        if config.high_contrast {
+            theme.scrollbar_show = ScrollbarShow::Always;
+        }
+        if let Some(radius) = config.radius {
+            theme.radius = radius;
+        }
+        if let Some(radius_lg) = config.radius_lg {
+            theme.radius_lg = radius_lg;
+        }
+        if let Some(border_width) = config.border_width {
+            theme.border_width = border_width;
+        }
+        if let Some(density) = config.density {
+            theme.density = density;
+        }
+        if let Some(font_family) = config.font_family.clone() {
+            theme.font_family = font_family.into();
+        }
+        if let Some(mono_font_family) = config.mono_font_family.clone() {
+            theme.mono_font_family = mono_font_family.into();
+        }
+        if let Some(font_size) = config.font_size {
+            theme.font_size = font_size;
+        }
+        if let Some(font_size_sm) = config.font_size_sm {
+            theme.font_size_sm = font_size_sm;
+        }
+        if let Some(font_size_lg) = config.font_size_lg {
+            theme.font_size_lg = font_size_lg;
+        }
+        if let Some(line_height) = config.line_height {
+            theme.line_height = line_height;
+        }
+        if let Some(focus_ring_keyboard_only) = config.focus_ring_keyboard_only {
+            theme.focus_ring_keyboard_only = focus_ring_keyboard_only;
+        }
+        if let Some(opacity) = config.opacity {
+            theme.opacity = opacity.clamp(0.0, 1.0);
+        }
+        if let Some(disabled_opacity) = config.disabled_opacity {
+            theme.disabled_opacity = disabled_opacity.clamp(0.0, 1.0);
+        }
+        if let Some(blur) = config.blur {
+            theme.blur = blur;
+        }
+        if let Some(reduce_motion) = config.reduce_motion {
+            theme.reduce_motion = reduce_motion;
+            theme.follow_system_reduce_motion = false;
+        }
+
+        theme
+    }
+
+    /// Returns true if the animations should be disabled.
+    ///
+    /// The components should show the end state immediately instead of animating.
+    #[inline]
+    pub fn reduce_motion(&self) -> bool {
+        self.reduce_motion
+    }
+
+    /// Returns the size of the components without an explicit size, by the `density`.
+    #[inline]
+    pub fn default_size(&self) -> Size {
+        self.density.size()
+    }
+
+    /// Returns true if the theme is a high contrast theme.
+    ///
+    /// Components can use this to make structural adjustments, e.g. always show the scrollbar.
+    #[inline]
+    pub fn is_high_contrast(&self) -> bool {
+        self.high_contrast
+    }
+}
+
+/// A theme that can be loaded from JSON.
+///
+/// ```json
+/// {
+///     "name": "My Theme",
+///     "mode": "dark",
+///     "radius": 2.0,
+///     "colors": {
+///         "background": "#1E1E1E",
+///         "danger": "#F14C4C"
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ThemeConfig {
+    pub name: String,
+    pub mode: ThemeMode,
+    pub high_contrast: bool,
+    pub radius: Option<f32>,
+    pub radius_lg: Option<f32>,
+    pub border_width: Option<f32>,
+    pub density: Option<Density>,
+    pub font_family: Option<String>,
+    pub mono_font_family: Option<String>,
+    pub font_size: Option<f32>,
+    pub font_size_sm: Option<f32>,
+    pub font_size_lg: Option<f32>,
+    pub line_height: Option<f32>,
+    pub focus_ring_keyboard_only: Option<bool>,
+    pub opacity: Option<f32>,
+    pub disabled_opacity: Option<f32>,
+    pub blur: Option<bool>,
+    pub reduce_motion: Option<bool>,
+    pub colors: ThemeColorConfig,
+    /// The unknown sections, e.g. the domain colors of the app, see [`Theme::extra`].
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl ThemeConfig {
+    /// Parse a theme config from a JSON string.
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// The built-in high contrast light theme, the colors are meet the WCAG AA contrast.
+    pub fn light_high_contrast() -> Self {
+        let accent = hsl(221.0, 100.0, 35.0);
+
+        Self {
+            name: "light-high-contrast".into(),
+            mode: ThemeMode::Light,
+            high_contrast: true,
+            border_width: Some(2.0),
+            colors: ThemeColorConfig {
+                background: Some(hsl(0.0, 0.0, 100.0)),
+                foreground: Some(hsl(0.0, 0.0, 0.0)),
+                muted_foreground: Some(hsl(240.0, 6.0, 25.0)),
+                border: Some(hsl(240.0, 6.0, 35.0)),
+                input: Some(hsl(240.0, 6.0, 35.0)),
+                ring: Some(accent),
+                selection: Some(hsl(221.0, 100.0, 80.0)),
+                link: Some(accent),
+                list_active: Some(accent.opacity(0.2)),
+                list_active_border: Some(accent),
+                list_context_border: Some(hsl(240.0, 6.0, 35.0)),
+                table_active: Some(accent.opacity(0.2)),
+                table_active_border: Some(accent),
+                scrollbar_thumb: Some(hsl(240.0, 6.0, 30.0)),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    /// The built-in high contrast dark theme, the colors are meet the WCAG AA contrast.
+    pub fn dark_high_contrast() -> Self {
+        let accent = hsl(210.0, 100.0, 66.0);
+
+        Self {
+            name: "dark-high-contrast".into(),
+            mode: ThemeMode::Dark,
+            high_contrast: true,
+            border_width: Some(2.0),
+            colors: ThemeColorConfig {
+                background: Some(hsl(0.0, 0.0, 0.0)),
+                foreground: Some(hsl(0.0, 0.0, 100.0)),
+                muted_foreground: Some(hsl(240.0, 5.0, 78.0)),
+                border: Some(hsl(240.0, 5.0, 65.0)),
+                input: Some(hsl(240.0, 5.0, 65.0)),
+                ring: Some(accent),
+                selection: Some(hsl(210.0, 100.0, 35.0)),
+                link: Some(accent),
+                list_active: Some(accent.opacity(0.3)),
+                list_active_border: Some(accent),
+                list_context_border: Some(hsl(240.0, 5.0, 65.0)),
+                table_active: Some(accent.opacity(0.3)),
+                table_active_border: Some(accent),
+                scrollbar_thumb: Some(hsl(240.0, 5.0, 75.0)),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+}
+
+/// The registry of the named themes that can be selected at runtime by [`Theme::select`].
+///
+/// The `light`, `dark`, `light-high-contrast` and `dark-high-contrast` themes are built-in.
+#[derive(Debug, Clone)]
+pub struct ThemeRegistry {
+    themes: Vec<ThemeConfig>,
+}
+
+impl Global for ThemeRegistry {}
+
+impl Default for ThemeRegistry {
+    fn default() -> Self {
+        Self {
+            themes: vec![
+                ThemeConfig {
+                    name: "light".into(),
+                    mode: ThemeMode::Light,
+                    ..Default::default()
+                },
+                ThemeConfig {
+                    name: "dark".into(),
+                    mode: ThemeMode::Dark,
+                    ..Default::default()
+                },
+                ThemeConfig::light_high_contrast(),
+                ThemeConfig::dark_high_contrast(),
+            ],
+        }
+    }
+}
+
+impl ThemeRegistry {
+    pub fn global(cx: &AppContext) -> &Self {
+        cx.global::<Self>()
+    }
+
+    pub fn global_mut(cx: &mut AppContext) -> &mut Self {
+        cx.default_global::<Self>()
+    }
+
+    /// Register a theme, the theme with the same name will be replaced.
+    pub fn register(&mut self, config: ThemeConfig) {
+        if let Some(theme) = self.themes.iter_mut().find(|t| t.name == config.name) {
+            *theme = config;
+        } else {
+            self.themes.push(config);
+        }
+    }
+
+    /// Returns the theme config by name.
+    pub fn get(&self, name: &str) -> Option<&ThemeConfig> {
+        self.themes.iter().find(|t| t.name == name)
+    }
+
+    /// Returns all the registered themes.
+    pub fn themes(&self) -> &[ThemeConfig] {
+        &self.themes
+    }
 }
 
 impl From<ThemeColor> for Theme {
@@ -524,6 +1280,9 @@ impl From<ThemeColor> for Theme {
             mode: ThemeMode::default(),
             transparent: Hsla::transparent_black(),
             font_size: 16.0,
+            font_size_sm: 14.0,
+            font_size_lg: 18.0,
+            line_height: 1.25,
             font_family: if cfg!(target_os = "macos") {
                 ".SystemUIFont".into()
             } else if cfg!(target_os = "windows") {
@@ -531,15 +1290,70 @@ impl From<ThemeColor> for Theme {
             } else {
                 "FreeMono".into()
             },
+            mono_font_family: if cfg!(target_os = "macos") {
+                "Menlo".into()
+            } else if cfg!(target_os = "windows") {
+                "Consolas".into()
+            } else {
+                "DejaVu Sans Mono".into()
+            },
             radius: 4.0,
+            radius_lg: 8.0,
+            border_width: 1.0,
+            density: Density::default(),
             shadow: true,
+            reduce_motion: SYSTEM_REDUCE_MOTION.get().copied().unwrap_or(false),
+            follow_system_reduce_motion: true,
+            opacity: 1.0,
+            disabled_opacity: 0.5,
+            blur: false,
+            high_contrast: false,
+            focus_ring_keyboard_only: false,
             scrollbar_show: ScrollbarShow::default(),
+            extra: Arc::default(),
+            extensions: HashMap::new(),
+            extension_factories: HashMap::new(),
             colors,
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd, Eq)]
+/// Returns true if the reduced motion is enabled in the system accessibility settings.
+///
+/// - macOS: `defaults read com.apple.universalaccess reduceMotion`
+/// - Linux: `gsettings get org.gnome.desktop.interface enable-animations`
+///
+/// This runs a subprocess, so don't call it on the main thread.
+#[cfg(not(test))]
+fn read_system_reduce_motion() -> bool {
+    let read = |program: &str, args: &[&str]| {
+        std::process::Command::new(program)
+            .args(args)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    if cfg!(target_os = "macos") {
+        read(
+            "defaults",
+            &["read", "com.apple.universalaccess", "reduceMotion"],
+        )
+        .map_or(false, |value| value == "1")
+    } else if cfg!(target_os = "linux") {
+        read(
+            "gsettings",
+            &["get", "org.gnome.desktop.interface", "enable-animations"],
+        )
+        .map_or(false, |value| value == "false")
+    } else {
+        false
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum ThemeMode {
     Light,
     #[default]
@@ -575,4 +1389,204 @@ mod tests {
         let color = color.darken(0.5);
         assert_eq!(color.l, 0.24);
     }
+
+    #[gpui::test]
+    fn test_theme_global(cx: &mut gpui::TestAppContext) {
+        use super::{ActiveTheme as _, ReadTheme as _, Theme};
+
+        cx.update(|cx| {
+            assert!(Theme::try_global(cx).is_none());
+            super::init(cx);
+            assert!(Theme::try_global(cx).is_some());
+        });
+
+        let primary = cx.to_async().read_theme(|theme| theme.primary).unwrap();
+        cx.update(|cx| assert_eq!(primary, cx.theme().primary));
+    }
+
+    #[gpui::test]
+    fn test_change_keeps_reduce_motion(cx: &mut gpui::TestAppContext) {
+        use super::{Theme, ThemeMode};
+
+        cx.update(|cx| {
+            super::init(cx);
+            Theme::global_mut(cx).reduce_motion = true;
+            Theme::global_mut(cx).follow_system_reduce_motion = false;
+
+            Theme::change(ThemeMode::Light, cx);
+            Theme::change(ThemeMode::Dark, cx);
+            assert!(Theme::global(cx).reduce_motion);
+            assert!(!Theme::global(cx).follow_system_reduce_motion);
+        });
+    }
+
+    #[test]
+    fn test_theme_config_from_json() {
+        use super::{Theme, ThemeColor, ThemeConfig, ThemeMode};
+
+        let config = ThemeConfig::from_json(
+            r##"{
+                "name": "Test",
+                "mode": "light",
+                "radius": 2.0,
+                "font_family": "Inter",
+                "font_size_sm": 13.0,
+                "disabled_opacity": 0.4,
+                "colors": {
+                    "danger": "#FF0000",
+                    "success_foreground": "#00FF0080"
+                }
+            }"##,
+        )
+        .unwrap();
+        assert_eq!(config.name, "Test");
+        assert_eq!(config.mode, ThemeMode::Light);
+        assert_eq!(config.radius, Some(2.0));
+        assert_eq!(config.border_width, None);
+        assert_eq!(config.font_family.as_deref(), Some("Inter"));
+        assert_eq!(config.font_size_sm, Some(13.0));
+        assert_eq!(config.line_height, None);
+        assert_eq!(config.disabled_opacity, Some(0.4));
+        assert_eq!(Theme::from_config(&config).disabled_opacity, 0.4);
+
+        let mut colors = ThemeColor::light();
+        colors.apply_config(&config.colors);
+        assert_eq!(colors.danger, gpui::rgb(0xff0000).into());
+        assert_eq!(colors.success_foreground, gpui::rgba(0x00ff0080).into());
+        assert_eq!(colors.warning, ThemeColor::light().warning);
+
+        assert!(ThemeConfig::from_json(r#"{ "colors": { "info": "blue" } }"#).is_err());
+
+        // The context border follows the active border in the older themes.
+        let config =
+            ThemeConfig::from_json(r##"{ "colors": { "list_active_border": "#ff0000" } }"##)
+                .unwrap();
+        let mut colors = ThemeColor::light();
+        colors.apply_config(&config.colors);
+        assert_eq!(colors.list_context_border, gpui::rgb(0xff0000).into());
+
+        let config = ThemeConfig::from_json(
+            r##"{ "colors": { "list_active_border": "#ff0000", "list_context_border": "#00ff00" } }"##,
+        )
+        .unwrap();
+        let mut colors = ThemeColor::light();
+        colors.apply_config(&config.colors);
+        assert_eq!(colors.list_context_border, gpui::rgb(0x00ff00).into());
+        assert_ne!(
+            ThemeColor::light().list_context_border,
+            ThemeColor::light().list_active_border
+        );
+    }
+
+    #[test]
+    fn test_color_scale() {
+        use super::{color_scale, contrast_ratio, readable_foreground, ThemeMode};
+
+        let base = super::hsl(262.0, 83.0, 58.0);
+
+        let scale = color_scale(base, ThemeMode::Light);
+        assert_eq!(scale[8], base);
+        for ix in 1..8 {
+            assert!(scale[ix].l < scale[ix - 1].l);
+        }
+        assert!(contrast_ratio(scale[11], scale[0]) >= 7.0);
+
+        let scale = color_scale(base, ThemeMode::Dark);
+        assert_eq!(scale[8], base);
+        for ix in 1..8 {
+            assert!(scale[ix].l > scale[ix - 1].l);
+        }
+        assert!(contrast_ratio(scale[11], scale[0]) >= 7.0);
+
+        let fg = readable_foreground(super::hsl(60.0, 100.0, 50.0));
+        assert!(fg.l < 0.5);
+        let fg = readable_foreground(super::hsl(240.0, 100.0, 30.0));
+        assert_eq!(fg.l, 1.0);
+    }
+
+    #[test]
+    fn test_high_contrast_themes() {
+        use super::{contrast_ratio, Theme, ThemeRegistry};
+
+        let registry = ThemeRegistry::default();
+        let names = registry
+            .themes()
+            .iter()
+            .map(|t| t.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec!["light", "dark", "light-high-contrast", "dark-high-contrast"]
+        );
+
+        for name in ["light-high-contrast", "dark-high-contrast"] {
+            let theme = Theme::from_config(registry.get(name).unwrap());
+            assert!(theme.is_high_contrast());
+            assert_eq!(theme.border_width, 2.0);
+            assert!(contrast_ratio(theme.foreground, theme.background) >= 4.5);
+            assert!(contrast_ratio(theme.muted_foreground, theme.background) >= 4.5);
+            assert!(contrast_ratio(theme.list_active_border, theme.background) >= 3.0);
+            assert!(contrast_ratio(theme.list_context_border, theme.background) >= 3.0);
+        }
+
+        assert!(!Theme::from_config(registry.get("light").unwrap()).is_high_contrast());
+    }
+
+    #[test]
+    fn test_theme_extensions() {
+        use super::{Theme, ThemeColor, ThemeConfig, ThemeExtension, ThemeMode};
+
+        #[derive(Debug, PartialEq)]
+        struct DiffColors(&'static str);
+        impl ThemeExtension for DiffColors {
+            fn for_mode(mode: ThemeMode) -> Self {
+                match mode {
+                    ThemeMode::Light => DiffColors("light"),
+                    ThemeMode::Dark => DiffColors("dark"),
+                }
+            }
+        }
+        struct Brand(u32);
+
+        let mut light = Theme::from(ThemeColor::light());
+        light.mode = ThemeMode::Light;
+        light.set_extension(Brand(1));
+        light.set_extension(DiffColors::for_mode(ThemeMode::Light));
+        light
+            .extension_factories
+            .insert(std::any::TypeId::of::<DiffColors>(), |mode| {
+                std::sync::Arc::new(DiffColors::for_mode(mode))
+            });
+        assert!(light.extension::<String>().is_none());
+
+        let mut dark = Theme::from(ThemeColor::dark());
+        dark.mode = ThemeMode::Dark;
+        dark.inherit_extensions(&light);
+        assert_eq!(dark.extension::<Brand>().map(|b| b.0), Some(1));
+        assert_eq!(dark.extension::<DiffColors>(), Some(&DiffColors("dark")));
+
+        let config = ThemeConfig::from_json(r##"{ "diff": { "added": "#00FF00" } }"##).unwrap();
+        let theme = Theme::from_config(&config);
+        assert_eq!(theme.extra["diff"]["added"], "#00FF00");
+    }
+
+    #[test]
+    fn test_selection_contrast() {
+        use super::{contrast_ratio, ThemeColor, ThemeConfig};
+
+        for colors in [ThemeColor::light(), ThemeColor::dark()] {
+            assert!(contrast_ratio(colors.foreground, colors.selection) >= 4.5);
+            assert!(contrast_ratio(colors.foreground, colors.selection_unfocused) >= 4.5);
+        }
+
+        let config = ThemeConfig::from_json(
+            r##"{ "colors": { "selection_unfocused": "#CCCCCC", "caret": "#FF0000" } }"##,
+        )
+        .unwrap();
+        assert_eq!(
+            config.colors.selection_unfocused,
+            Some(gpui::rgb(0xcccccc).into())
+        );
+        assert_eq!(config.colors.caret, Some(gpui::rgb(0xff0000).into()));
+    }
 }
@@ -1,9 +1,10 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, collections::HashMap};
 
-use chrono::{Datelike, Local, NaiveDate};
+use chrono::{Datelike, Days, Local, Months, NaiveDate, Weekday};
 use gpui::{
-    prelude::FluentBuilder as _, px, relative, ClickEvent, ElementId, EventEmitter, FocusHandle,
-    InteractiveElement, IntoElement, ParentElement, Render, SharedString,
+    actions, div, prelude::FluentBuilder as _, px, relative, AppContext, ClickEvent, ElementId,
+    EventEmitter, FocusHandle, FocusableView, Hsla, InteractiveElement, IntoElement, KeyBinding,
+    MouseButton, MouseDownEvent, MouseMoveEvent, MouseUpEvent, ParentElement, Render, SharedString,
     StatefulInteractiveElement, Styled, ViewContext,
 };
 use rust_i18n::t;
@@ -15,11 +16,40 @@ use crate::{
     v_flex, Disableable as _, IconName, Selectable, Sizable, Size,
 };
 
-use super::utils::days_in_month;
+use super::utils::{clamp_date, days_in_month};
+
+actions!(
+    calendar,
+    [
+        SelectPrevDay,
+        SelectNextDay,
+        SelectPrevWeek,
+        SelectNextWeek,
+        SelectPrevMonth,
+        SelectNextMonth,
+        Confirm
+    ]
+);
+
+const CONTEXT: &str = "Calendar";
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("left", SelectPrevDay, Some(CONTEXT)),
+        KeyBinding::new("right", SelectNextDay, Some(CONTEXT)),
+        KeyBinding::new("up", SelectPrevWeek, Some(CONTEXT)),
+        KeyBinding::new("down", SelectNextWeek, Some(CONTEXT)),
+        KeyBinding::new("pageup", SelectPrevMonth, Some(CONTEXT)),
+        KeyBinding::new("pagedown", SelectNextMonth, Some(CONTEXT)),
+        KeyBinding::new("enter", Confirm, Some(CONTEXT)),
+    ]);
+}
 
 pub enum CalendarEvent {
     /// The user selected a date.
     Selected(Date),
+    /// The user navigated to another month, with the year and month of the first month.
+    MonthChanged(i32, u32),
 }
 
 /// The date of the calendar.
@@ -63,7 +93,7 @@ impl Date {
         }
     }
 
-    fn is_single(&self) -> bool {
+    pub fn is_single(&self) -> bool {
         matches!(self, Self::Single(_))
     }
 
@@ -128,6 +158,23 @@ impl Date {
     }
 }
 
+/// Returns the range ordered by the dates `a` and `b`.
+fn ordered_range(a: NaiveDate, b: NaiveDate) -> Date {
+    Date::Range(Some(a.min(b)), Some(a.max(b)))
+}
+
+/// Returns the range after pressing the `day`, and whether to start dragging from it.
+///
+/// Shift-click extends the range from its start, otherwise the first click starts a new
+/// range and the second click completes it.
+fn range_on_press(date: Date, day: NaiveDate, shift: bool) -> (Date, bool) {
+    match (date.start(), date.end()) {
+        (Some(start), _) if shift => (ordered_range(start, day), false),
+        (Some(start), None) if day >= start => (Date::Range(Some(start), Some(day)), false),
+        _ => (Date::Range(Some(day), None), true),
+    }
+}
+
 #[derive(Debug, PartialEq, Eq)]
 enum ViewMode {
     Day,
@@ -149,10 +196,20 @@ impl ViewMode {
     }
 }
 
+/// A calendar to select a date or a range of dates.
+///
+/// When focused, the arrow keys move the cursor day, PageUp/PageDown move by a month,
+/// and Enter selects the cursor day.
+///
+/// In range mode, the range can also be selected by dragging over the days, or extended by shift-click.
 pub struct Calendar {
     focus_handle: FocusHandle,
     size: Size,
     date: Date,
+    /// The day to select by the keyboard.
+    cursor: NaiveDate,
+    min_date: Option<NaiveDate>,
+    max_date: Option<NaiveDate>,
     view_mode: ViewMode,
     current_year: i32,
     current_month: u8,
@@ -160,6 +217,11 @@ pub struct Calendar {
     year_page: i32,
     /// Number of the months view to show.
     number_of_months: usize,
+    week_start: Weekday,
+    /// The colors of the dots to show under the days.
+    marked_dates: HashMap<NaiveDate, Vec<Hsla>>,
+    /// The day where the range dragging started.
+    drag_anchor: Option<NaiveDate>,
     today: NaiveDate,
 }
 
@@ -171,11 +233,17 @@ impl Calendar {
             size: Size::default(),
             view_mode: ViewMode::Day,
             date: Date::Single(None),
+            cursor: today,
+            min_date: None,
+            max_date: None,
             current_month: today.month() as u8,
             current_year: today.year(),
             years: vec![],
             year_page: 0,
             number_of_months: 1,
+            week_start: Weekday::Sun,
+            marked_dates: HashMap::new(),
+            drag_anchor: None,
             today,
         }
         .year_range((today.year() - 50, today.year() + 50))
@@ -187,21 +255,32 @@ impl Calendar {
     pub fn set_date(&mut self, date: impl Into<Date>, cx: &mut ViewContext<Self>) {
         self.date = date.into();
 
-        match self.date {
-            Date::Single(Some(date)) => {
-                self.current_month = date.month() as u8;
-                self.current_year = date.year();
-            }
-            Date::Range(Some(start), _) => {
-                self.current_month = start.month() as u8;
-                self.current_year = start.year();
-            }
-            _ => {}
+        if let Some(date) = self.date.start() {
+            self.current_month = date.month() as u8;
+            self.current_year = date.year();
+            self.cursor = date;
         }
 
         cx.notify()
     }
 
+    /// Set the minimum date can be selected, the days before it are disabled.
+    pub fn set_min_date(&mut self, date: Option<NaiveDate>, cx: &mut ViewContext<Self>) {
+        self.min_date = date;
+        cx.notify();
+    }
+
+    /// Set the maximum date can be selected, the days after it are disabled.
+    pub fn set_max_date(&mut self, date: Option<NaiveDate>, cx: &mut ViewContext<Self>) {
+        self.max_date = date;
+        cx.notify();
+    }
+
+    fn is_disabled_date(&self, date: &NaiveDate) -> bool {
+        self.min_date.map_or(false, |min| *date < min)
+            || self.max_date.map_or(false, |max| *date > max)
+    }
+
     /// Get the date of the calendar.
     pub fn date(&self) -> Date {
         self.date
@@ -223,6 +302,38 @@ impl Calendar {
         cx.notify();
     }
 
+    /// Set the first day of the week, default is Sunday.
+    pub fn week_start(mut self, week_start: Weekday) -> Self {
+        self.week_start = week_start;
+        self
+    }
+
+    pub fn set_week_start(&mut self, week_start: Weekday, cx: &mut ViewContext<Self>) {
+        self.week_start = week_start;
+        cx.notify();
+    }
+
+    /// Set the dates to mark with the colored dots, e.g. the days with the events.
+    ///
+    /// A date can be marked multiple times with different colors, at most 3 dots are shown.
+    pub fn marked_dates(mut self, dates: Vec<(NaiveDate, Hsla)>) -> Self {
+        self.marked_dates = Self::group_marked_dates(dates);
+        self
+    }
+
+    pub fn set_marked_dates(&mut self, dates: Vec<(NaiveDate, Hsla)>, cx: &mut ViewContext<Self>) {
+        self.marked_dates = Self::group_marked_dates(dates);
+        cx.notify();
+    }
+
+    fn group_marked_dates(dates: Vec<(NaiveDate, Hsla)>) -> HashMap<NaiveDate, Vec<Hsla>> {
+        let mut marked_dates = HashMap::<NaiveDate, Vec<Hsla>>::new();
+        for (date, color) in dates {
+            marked_dates.entry(date).or_default().push(color);
+        }
+        marked_dates
+    }
+
     /// Set the year range of the calendar, default is 50 years before and after the current year.
     ///
     /// Each year page contains 20 years, so the range will be divided into chunks of 20 years is better.
@@ -256,15 +367,150 @@ impl Calendar {
         (year, month as u32)
     }
 
-    /// Returns the days of the month in a 2D vector to render on calendar.
-    fn days(&self) -> Vec<Vec<NaiveDate>> {
+    /// Returns the weeks of each month to render on calendar.
+    fn days(&self) -> Vec<Vec<Vec<NaiveDate>>> {
         (0..self.number_of_months)
-            .flat_map(|offset| {
-                days_in_month(self.current_year, self.current_month as u32 + offset as u32)
+            .map(|offset| {
+                let (year, month) = self.offset_year_month(offset);
+                days_in_month(year, month, self.week_start)
             })
             .collect()
     }
 
+    /// Move the cursor to the `date`, and scroll the months to show it.
+    fn move_cursor(&mut self, date: Option<NaiveDate>, cx: &mut ViewContext<Self>) {
+        let Some(date) = date else {
+            return;
+        };
+
+        let date = clamp_date(date, self.min_date, self.max_date);
+        let first = self.first_month();
+        let target = date.year() * 12 + date.month0() as i32;
+        let first = if target < first {
+            target
+        } else if target >= first + self.number_of_months as i32 {
+            target + 1 - self.number_of_months.max(1) as i32
+        } else {
+            first
+        };
+
+        self.cursor = date;
+        self.view_mode = ViewMode::Day;
+        self.set_first_month(first, cx);
+    }
+
+    /// Returns the index (`year * 12 + month0`) of the first month to show.
+    fn first_month(&self) -> i32 {
+        self.current_year * 12 + self.current_month as i32 - 1
+    }
+
+    /// Show the month of the `index` (`year * 12 + month0`) as the first month.
+    fn set_first_month(&mut self, index: i32, cx: &mut ViewContext<Self>) {
+        let year = index.div_euclid(12);
+        let month = (index.rem_euclid(12) + 1) as u8;
+        if (year, month) != (self.current_year, self.current_month) {
+            self.current_year = year;
+            self.current_month = month;
+            cx.emit(CalendarEvent::MonthChanged(year, month as u32));
+        }
+        cx.notify();
+    }
+
+    fn select_prev_day(&mut self, _: &SelectPrevDay, cx: &mut ViewContext<Self>) {
+        self.move_cursor(self.cursor.checked_sub_days(Days::new(1)), cx);
+    }
+
+    fn select_next_day(&mut self, _: &SelectNextDay, cx: &mut ViewContext<Self>) {
+        self.move_cursor(self.cursor.checked_add_days(Days::new(1)), cx);
+    }
+
+    fn select_prev_week(&mut self, _: &SelectPrevWeek, cx: &mut ViewContext<Self>) {
+        self.move_cursor(self.cursor.checked_sub_days(Days::new(7)), cx);
+    }
+
+    fn select_next_week(&mut self, _: &SelectNextWeek, cx: &mut ViewContext<Self>) {
+        self.move_cursor(self.cursor.checked_add_days(Days::new(7)), cx);
+    }
+
+    fn select_prev_month(&mut self, _: &SelectPrevMonth, cx: &mut ViewContext<Self>) {
+        self.move_cursor(self.cursor.checked_sub_months(Months::new(1)), cx);
+    }
+
+    fn select_next_month(&mut self, _: &SelectNextMonth, cx: &mut ViewContext<Self>) {
+        self.move_cursor(self.cursor.checked_add_months(Months::new(1)), cx);
+    }
+
+    fn confirm(&mut self, _: &Confirm, cx: &mut ViewContext<Self>) {
+        if self.view_mode.is_day() && !self.is_disabled_date(&self.cursor) {
+            self.select_day(self.cursor, cx);
+        }
+    }
+
+    /// Select the `date` as the single date, or the start/end of the range.
+    fn select_day(&mut self, date: NaiveDate, cx: &mut ViewContext<Self>) {
+        if self.date.is_single() {
+            self.set_date(date, cx);
+            cx.emit(CalendarEvent::Selected(self.date()));
+        } else {
+            let start = self.date.start();
+            let end = self.date.end();
+
+            if start.is_none() && end.is_none() {
+                self.set_date(Date::Range(Some(date), None), cx);
+            } else if start.is_some() && end.is_none() {
+                if date < start.unwrap() {
+                    self.set_date(Date::Range(Some(date), None), cx);
+                } else {
+                    self.set_date(Date::Range(Some(start.unwrap()), Some(date)), cx);
+                }
+            } else {
+                self.set_date(Date::Range(Some(date), None), cx);
+            }
+
+            if self.date.is_complete() {
+                cx.emit(CalendarEvent::Selected(self.date()));
+            }
+        }
+        self.cursor = date;
+    }
+
+    /// Start to select the range by pressing the `day`, in range mode.
+    ///
+    /// The view is not scrolled to the start of the range, to keep the day under the mouse.
+    fn press_day(&mut self, day: NaiveDate, shift: bool, cx: &mut ViewContext<Self>) {
+        let (date, drag) = range_on_press(self.date, day, shift);
+        self.date = date;
+        self.cursor = day;
+        self.drag_anchor = drag.then_some(day);
+        if !drag && self.date.is_complete() {
+            cx.emit(CalendarEvent::Selected(self.date()));
+        }
+        cx.notify();
+    }
+
+    fn drag_over_day(&mut self, day: NaiveDate, cx: &mut ViewContext<Self>) {
+        let Some(anchor) = self.drag_anchor else {
+            return;
+        };
+
+        let date = if day == anchor {
+            Date::Range(Some(anchor), None)
+        } else {
+            ordered_range(anchor, day)
+        };
+        if self.date != date {
+            self.date = date;
+            self.cursor = day;
+            cx.notify();
+        }
+    }
+
+    fn on_mouse_up(&mut self, _: &MouseUpEvent, cx: &mut ViewContext<Self>) {
+        if self.drag_anchor.take().is_some() && self.date.is_complete() {
+            cx.emit(CalendarEvent::Selected(self.date()));
+        }
+    }
+
     fn has_prev_year_page(&self) -> bool {
         self.year_page > 0
     }
@@ -292,31 +538,11 @@ impl Calendar {
     }
 
     fn prev_month(&mut self, _: &ClickEvent, cx: &mut ViewContext<Self>) {
-        self.current_month = if self.current_month == 1 {
-            12
-        } else {
-            self.current_month - 1
-        };
-        self.current_year = if self.current_month == 12 {
-            self.current_year - 1
-        } else {
-            self.current_year
-        };
-        cx.notify()
+        self.set_first_month(self.first_month() - 1, cx);
     }
 
     fn next_month(&mut self, _: &ClickEvent, cx: &mut ViewContext<Self>) {
-        self.current_month = if self.current_month == 12 {
-            1
-        } else {
-            self.current_month + 1
-        };
-        self.current_year = if self.current_month == 1 {
-            self.current_year + 1
-        } else {
-            self.current_year
-        };
-        cx.notify()
+        self.set_first_month(self.first_month() + 1, cx);
     }
 
     fn month_name(&self, offset_month: usize) -> SharedString {
@@ -347,7 +573,7 @@ impl Calendar {
         h_flex()
             .map(|this| match self.size {
                 Size::Small => this.size_7().rounded_sm(),
-                Size::Large => this.size_10().rounded_md(),
+                Size::Large | Size::XLarge => this.size_10().rounded_md(),
                 _ => this.size_9().rounded_md(),
             })
             .justify_center()
@@ -363,18 +589,19 @@ impl Calendar {
         active: bool,
         secondary_active: bool,
         muted: bool,
+        disabled: bool,
         cx: &mut ViewContext<Self>,
-    ) -> impl IntoElement + Styled + StatefulInteractiveElement {
+    ) -> impl IntoElement + Styled + ParentElement + StatefulInteractiveElement {
         h_flex()
             .id(id.into())
             .map(|this| match self.size {
                 Size::Small => this.size_7().rounded_md(),
-                Size::Large => this.size_10().rounded_lg(),
+                Size::Large | Size::XLarge => this.size_10().rounded_lg(),
                 _ => this.size_9().rounded_lg(),
             })
             .justify_center()
-            .cursor_pointer()
-            .when(muted, |this| {
+            .when(!disabled, |this| this.cursor_pointer())
+            .when(muted || disabled, |this| {
                 this.text_color(cx.theme().muted_foreground.opacity(0.3))
             })
             .when(secondary_active, |this| {
@@ -385,15 +612,17 @@ impl Calendar {
                 })
                 .text_color(cx.theme().accent_foreground)
             })
-            .when(!active, |this| {
+            .when(!active && !disabled, |this| {
                 this.hover(|this| {
                     this.bg(cx.theme().accent)
                         .text_color(cx.theme().accent_foreground)
                 })
             })
             .when(active, |this| {
-                this.bg(cx.theme().primary)
-                    .text_color(cx.theme().primary_foreground)
+                this.bg(cx.theme().list_active)
+                    .border_1()
+                    .border_color(cx.theme().list_active_border)
+                    .text_color(cx.theme().foreground)
             })
             .child(label.into())
     }
@@ -413,6 +642,9 @@ impl Calendar {
 
         let date = *d;
         let is_today = *d == self.today;
+        let is_disabled = self.is_disabled_date(d);
+        let is_cursor = *d == self.cursor && is_current_month && self.focus_handle.is_focused(cx);
+        let marks = self.marked_dates.get(d).cloned().unwrap_or_default();
 
         self.item_button(
             ix,
@@ -420,36 +652,53 @@ impl Calendar {
             is_active,
             is_in_range,
             !is_current_month,
+            is_disabled,
             cx,
         )
         .when(is_today && !is_active, |this| {
             this.border_1().border_color(cx.theme().border)
         }) // Add border for today
-        .on_click(cx.listener(move |view, _: &ClickEvent, cx| {
-            if view.date.is_single() {
-                view.set_date(date, cx);
-                cx.emit(CalendarEvent::Selected(view.date()));
+        .when(is_cursor, |this| {
+            this.border_1().border_color(cx.theme().ring)
+        })
+        .when(!marks.is_empty(), |this| {
+            this.relative().child(
+                h_flex()
+                    .absolute()
+                    .bottom(px(3.))
+                    .left_0()
+                    .right_0()
+                    .justify_center()
+                    .gap(px(2.))
+                    .children(
+                        marks
+                            .into_iter()
+                            .take(3)
+                            .map(|color| div().size(px(4.)).rounded_full().bg(color)),
+                    ),
+            )
+        })
+        .when(!is_disabled, |this| {
+            if self.date.is_single() {
+                this.on_click(cx.listener(move |view, _: &ClickEvent, cx| {
+                    view.select_day(date, cx);
+                }))
             } else {
-                let start = view.date.start();
-                let end = view.date.end();
-
-                if start.is_none() && end.is_none() {
-                    view.set_date(Date::Range(Some(date), None), cx);
-                } else if start.is_some() && end.is_none() {
-                    if date < start.unwrap() {
-                        view.set_date(Date::Range(Some(date), None), cx);
-                    } else {
-                        view.set_date(Date::Range(Some(start.unwrap()), Some(date)), cx);
-                    }
-                } else {
-                    view.set_date(Date::Range(Some(date), None), cx);
-                }
-
-                if view.date.is_complete() {
-                    cx.emit(CalendarEvent::Selected(view.date()));
-                }
+                this.on_mouse_down(
+                    MouseButton::Left,
+                    cx.listener(move |view, event: &MouseDownEvent, cx| {
+                        view.press_day(date, event.modifiers.shift, cx);
+                    }),
+                )
+                .on_mouse_move(cx.listener(
+                    move |view, event: &MouseMoveEvent, cx| {
+                        if event.pressed_button == Some(MouseButton::Left) {
+                            view.drag_over_day(date, cx);
+                        }
+                    },
+                ))
             }
-        }))
+        })
     }
 
     fn set_view_mode(&mut self, mode: ViewMode, cx: &mut ViewContext<Self>) {
@@ -493,7 +742,7 @@ impl Calendar {
             .items_center()
             .child(
                 Button::new("prev")
-                    .icon(IconName::ArrowLeft)
+                    .icon(IconName::ChevronLeft)
                     .ghost()
                     .disabled(disabled)
                     .with_size(icon_size)
@@ -547,21 +796,22 @@ impl Calendar {
             .when(multiple_months, |this| {
                 this.child(h_flex().flex_1().justify_around().children(
                     (0..self.number_of_months).map(|n| {
+                        let (year, _) = self.offset_year_month(n);
                         h_flex()
                             .justify_center()
                             .map(|this| match self.size {
                                 Size::Small => this.gap_2(),
-                                Size::Large => this.gap_4(),
+                                Size::Large | Size::XLarge => this.gap_4(),
                                 _ => this.gap_3(),
                             })
                             .child(self.month_name(n))
-                            .child(current_year.to_string())
+                            .child(year.to_string())
                     }),
                 ))
             })
             .child(
                 Button::new("next")
-                    .icon(IconName::ArrowRight)
+                    .icon(IconName::ChevronRight)
                     .ghost()
                     .disabled(disabled)
                     .with_size(icon_size)
@@ -585,24 +835,30 @@ impl Calendar {
             t!("Calendar.week.5"),
             t!("Calendar.week.6"),
         ];
+        let week_start = self.week_start.num_days_from_sunday() as usize;
 
         h_flex()
             .map(|this| match self.size {
                 Size::Small => this.gap_3().text_sm(),
-                Size::Large => this.gap_5().text_base(),
+                Size::Large | Size::XLarge => this.gap_5().text_base(),
                 _ => this.gap_4().text_sm(),
             })
             .justify_between()
             .children(
                 self.days()
-                    .chunks(5)
+                    .into_iter()
                     .enumerate()
                     .map(|(offset_month, days)| {
                         v_flex()
                             .gap_0p5()
-                            .child(h_flex().gap_0p5().justify_between().children(
-                                weeks.iter().map(|week| self.render_week(week.clone(), cx)),
-                            ))
+                            .child(
+                                h_flex()
+                                    .gap_0p5()
+                                    .justify_between()
+                                    .children((0..7).map(|n| {
+                                        self.render_week(weeks[(week_start + n) % 7].clone(), cx)
+                                    })),
+                            )
                             .children(days.iter().map(|week| {
                                 h_flex().gap_0p5().justify_between().children(
                                     week.iter()
@@ -623,7 +879,7 @@ impl Calendar {
             .gap_y_3()
             .map(|this| match self.size {
                 Size::Small => this.mt_2().gap_y_2().w(px(208.)),
-                Size::Large => this.mt_4().gap_y_4().w(px(292.)),
+                Size::Large | Size::XLarge => this.mt_4().gap_y_4().w(px(292.)),
                 _ => this.mt_3().gap_y_3().w(px(264.)),
             })
             .justify_between()
@@ -635,13 +891,12 @@ impl Calendar {
                     .map(|(ix, month)| {
                         let active = (ix + 1) as u8 == self.current_month;
 
-                        self.item_button(ix, month.to_string(), active, false, false, cx)
+                        self.item_button(ix, month.to_string(), active, false, false, false, cx)
                             .w(relative(0.3))
                             .text_sm()
                             .on_click(cx.listener(move |view, _, cx| {
-                                view.current_month = (ix + 1) as u8;
+                                view.set_first_month(view.current_year * 12 + ix as i32, cx);
                                 view.set_view_mode(ViewMode::Day, cx);
-                                cx.notify();
                             }))
                     })
                     .collect::<Vec<_>>(),
@@ -656,7 +911,7 @@ impl Calendar {
             .gap_0p5()
             .map(|this| match self.size {
                 Size::Small => this.mt_2().gap_y_2().w(px(208.)),
-                Size::Large => this.mt_4().gap_y_4().w(px(292.)),
+                Size::Large | Size::XLarge => this.mt_4().gap_y_4().w(px(292.)),
                 _ => this.mt_3().gap_y_3().w(px(264.)),
             })
             .justify_between()
@@ -669,12 +924,11 @@ impl Calendar {
                         let year = *year;
                         let active = year == self.current_year;
 
-                        self.item_button(ix, year.to_string(), active, false, false, cx)
+                        self.item_button(ix, year.to_string(), active, false, false, false, cx)
                             .w(relative(0.2))
                             .on_click(cx.listener(move |view, _, cx| {
-                                view.current_year = year;
+                                view.set_first_month(year * 12 + view.current_month as i32 - 1, cx);
                                 view.set_view_mode(ViewMode::Day, cx);
-                                cx.notify();
                             }))
                     })
                     .collect::<Vec<_>>(),
@@ -689,11 +943,26 @@ impl Sizable for Calendar {
     }
 }
 impl EventEmitter<CalendarEvent> for Calendar {}
+impl FocusableView for Calendar {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
 
 impl Render for Calendar {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl gpui::IntoElement {
         v_flex()
+            .key_context(CONTEXT)
             .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::select_prev_day))
+            .on_action(cx.listener(Self::select_next_day))
+            .on_action(cx.listener(Self::select_prev_week))
+            .on_action(cx.listener(Self::select_next_week))
+            .on_action(cx.listener(Self::select_prev_month))
+            .on_action(cx.listener(Self::select_next_month))
+            .on_action(cx.listener(Self::confirm))
+            .on_mouse_up(MouseButton::Left, cx.listener(Self::on_mouse_up))
+            .on_mouse_up_out(MouseButton::Left, cx.listener(Self::on_mouse_up))
             .gap_0p5()
             .child(self.render_header(cx))
             .child(
@@ -715,7 +984,7 @@ impl Render for Calendar {
 mod tests {
     use chrono::NaiveDate;
 
-    use super::Date;
+    use super::{range_on_press, Date};
 
     #[test]
     fn test_date_to_string() {
@@ -740,4 +1009,42 @@ mod tests {
         let date = Date::Range(None, None);
         assert_eq!(date.to_string(), "nil");
     }
+
+    #[test]
+    fn test_range_on_press() {
+        let date = |d| NaiveDate::from_ymd_opt(2024, 8, d).unwrap();
+
+        // Start a new range, and drag from it.
+        assert_eq!(
+            range_on_press(Date::Range(None, None), date(10), false),
+            (Date::Range(Some(date(10)), None), true)
+        );
+        // Complete the range.
+        assert_eq!(
+            range_on_press(Date::Range(Some(date(10)), None), date(15), false),
+            (Date::Range(Some(date(10)), Some(date(15))), false)
+        );
+        // Restart the range before the start.
+        assert_eq!(
+            range_on_press(Date::Range(Some(date(10)), None), date(5), false),
+            (Date::Range(Some(date(5)), None), true)
+        );
+        assert_eq!(
+            range_on_press(Date::Range(Some(date(10)), Some(date(15))), date(20), false),
+            (Date::Range(Some(date(20)), None), true)
+        );
+        // Shift-click extends the range from the start.
+        assert_eq!(
+            range_on_press(Date::Range(Some(date(10)), Some(date(15))), date(20), true),
+            (Date::Range(Some(date(10)), Some(date(20))), false)
+        );
+        assert_eq!(
+            range_on_press(Date::Range(Some(date(10)), Some(date(15))), date(3), true),
+            (Date::Range(Some(date(3)), Some(date(10))), false)
+        );
+        assert_eq!(
+            range_on_press(Date::Range(None, None), date(3), true),
+            (Date::Range(Some(date(3)), None), true)
+        );
+    }
 }
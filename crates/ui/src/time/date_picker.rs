@@ -1,22 +1,27 @@
-use chrono::NaiveDate;
+use std::{cell::RefCell, rc::Rc};
+
+use chrono::{NaiveDate, Weekday};
 use gpui::{
-    anchored, deferred, div, prelude::FluentBuilder as _, px, AppContext, ElementId, EventEmitter,
-    FocusHandle, FocusableView, InteractiveElement as _, KeyBinding, Length, MouseButton,
-    ParentElement as _, Render, SharedString, StatefulInteractiveElement as _, Styled, View,
-    ViewContext, VisualContext as _,
+    anchored, deferred, div, prelude::FluentBuilder as _, px, AppContext, ClickEvent, ElementId,
+    EventEmitter, FocusHandle, FocusableView, InteractiveElement as _, KeyBinding, Length,
+    MouseButton, ParentElement as _, Render, SharedString, StatefulInteractiveElement as _, Styled,
+    Subscription, View, ViewContext, VisualContext as _,
 };
 use rust_i18n::t;
 
 use crate::{
-    button::{Button, ButtonVariants as _},
+    button::{Button, ButtonVariants as _, IconButton},
     dropdown::Escape,
     h_flex,
-    input::ClearButton,
+    input::{ClearButton, InputEvent, TextInput},
     theme::ActiveTheme,
     v_flex, Icon, IconName, Sizable, Size, StyleSized as _, StyledExt as _,
 };
 
-use super::calendar::{Calendar, CalendarEvent, Date};
+use super::{
+    calendar::{Calendar, CalendarEvent, Date},
+    utils::{date_mask, match_mask},
+};
 
 pub fn init(cx: &mut AppContext) {
     let context = Some("DatePicker");
@@ -56,19 +61,33 @@ impl DateRangePreset {
         }
     }
 }
+/// A date picker with a calendar popup.
+///
+/// In single mode, the date can also be typed in the input with the `date_format`,
+/// a valid date in the `min_date..=max_date` will be synced to the calendar.
 pub struct DatePicker {
     id: ElementId,
     focus_handle: FocusHandle,
     date: Date,
+    min_date: Option<NaiveDate>,
+    max_date: Option<NaiveDate>,
     cleanable: bool,
     placeholder: Option<SharedString>,
     open: bool,
     size: Size,
     width: Length,
     date_format: SharedString,
+    /// The input mask of the `date_format`, shared with the input validation.
+    date_mask: Rc<RefCell<Option<String>>>,
+    /// The input to type the date in single mode.
+    input: View<TextInput>,
+    /// The size applied to the `input`, to sync the input size on render.
+    input_size: Option<Size>,
     calendar: View<Calendar>,
     number_of_months: usize,
+    week_start: Weekday,
     presets: Option<Vec<DateRangePreset>>,
+    _subscriptions: Vec<Subscription>,
 }
 
 impl DatePicker {
@@ -96,33 +115,103 @@ impl DatePicker {
         let calendar = cx.new_view(Calendar::new);
         calendar.update(cx, |view, cx| view.set_date(date, cx));
 
-        cx.subscribe(&calendar, |this, _, ev: &CalendarEvent, cx| match ev {
-            CalendarEvent::Selected(date) => {
-                this.update_date(*date, true, cx);
-                this.focus_handle.focus(cx);
-            }
-        })
-        .detach();
+        let date_format: SharedString = "%Y/%m/%d".into();
+        let date_mask = Rc::new(RefCell::new(date_mask(&date_format)));
+        let picker = cx.view().downgrade();
+        let input = cx.new_view(|cx| {
+            let date_mask = date_mask.clone();
+            TextInput::new(cx)
+                .validate(move |text| {
+                    date_mask
+                        .borrow()
+                        .as_ref()
+                        .map_or(true, |mask| match_mask(mask, text))
+                })
+                .suffix(move |cx| {
+                    let show_clean = picker.upgrade().map_or(false, |picker| {
+                        let picker = picker.read(cx);
+                        picker.cleanable && picker.date.is_some()
+                    });
+
+                    h_flex()
+                        .gap_1()
+                        .when(show_clean, |this| {
+                            let picker = picker.clone();
+                            this.child(ClearButton::new(cx).on_click(move |event, cx| {
+                                _ = picker.update(cx, |picker, cx| picker.clean(event, cx));
+                            }))
+                        })
+                        .child(
+                            IconButton::new("calendar", IconName::Calendar)
+                                .xsmall()
+                                .on_click({
+                                    let picker = picker.clone();
+                                    move |event, cx| {
+                                        _ = picker.update(cx, |picker, cx| {
+                                            picker.toggle_calendar(event, cx)
+                                        });
+                                    }
+                                }),
+                        )
+                })
+        });
+
+        let _subscriptions = vec![
+            cx.subscribe(&calendar, |this, _, ev: &CalendarEvent, cx| match ev {
+                CalendarEvent::Selected(date) => {
+                    this.update_date(*date, true, cx);
+                    this.focus_handle.focus(cx);
+                }
+                CalendarEvent::MonthChanged(..) => {}
+            }),
+            cx.subscribe(&input, |this, _, ev: &InputEvent, cx| match ev {
+                InputEvent::Change(text) => this.on_input_change(text, cx),
+                InputEvent::PressEnter | InputEvent::Blur => this.sync_input_text(cx),
+                _ => {}
+            }),
+        ];
 
         Self {
             id: id.into(),
             focus_handle: cx.focus_handle(),
             date,
+            min_date: None,
+            max_date: None,
             calendar,
             open: false,
             size: Size::default(),
             width: Length::Auto,
-            date_format: "%Y/%m/%d".into(),
+            date_format,
+            date_mask,
+            input,
+            input_size: None,
             cleanable: false,
             number_of_months: 1,
+            week_start: Weekday::Sun,
             placeholder: None,
             presets: None,
+            _subscriptions,
         }
     }
 
     /// Set the date format of the date picker to display in Input, default: "%Y/%m/%d".
+    ///
+    /// The input is masked by the format when it only contains the numeric `%Y`, `%y`, `%m` and `%d`.
     pub fn date_format(mut self, format: impl Into<SharedString>) -> Self {
         self.date_format = format.into();
+        *self.date_mask.borrow_mut() = date_mask(&self.date_format);
+        self
+    }
+
+    /// Set the minimum date can be selected.
+    pub fn min_date(mut self, date: NaiveDate) -> Self {
+        self.min_date = Some(date);
+        self
+    }
+
+    /// Set the maximum date can be selected.
+    pub fn max_date(mut self, date: NaiveDate) -> Self {
+        self.max_date = Some(date);
         self
     }
 
@@ -150,6 +239,12 @@ impl DatePicker {
         self
     }
 
+    /// Set the first day of the week in the calendar, default is Sunday.
+    pub fn week_start(mut self, week_start: Weekday) -> Self {
+        self.week_start = week_start;
+        self
+    }
+
     /// Set preset ranges for the date picker.
     pub fn presets(mut self, presets: Vec<DateRangePreset>) -> Self {
         self.presets = Some(presets);
@@ -172,12 +267,62 @@ impl DatePicker {
             view.set_date(date, cx);
         });
         self.open = false;
+        self.sync_input_text(cx);
         if emit {
             cx.emit(DatePickerEvent::Change(date));
         }
         cx.notify();
     }
 
+    /// Update the date by the typed text, only a complete and valid date will be applied.
+    fn on_input_change(&mut self, text: &str, cx: &mut ViewContext<Self>) {
+        if !self.date.is_single() {
+            return;
+        }
+
+        let date = if text.is_empty() {
+            None
+        } else {
+            if let Some(mask) = self.date_mask.borrow().as_ref() {
+                if text.chars().count() != mask.chars().count() {
+                    return;
+                }
+            }
+            let Ok(date) = NaiveDate::parse_from_str(text, &self.date_format) else {
+                return;
+            };
+            if self.min_date.map_or(false, |min| date < min)
+                || self.max_date.map_or(false, |max| date > max)
+            {
+                return;
+            }
+            Some(date)
+        };
+
+        let date = Date::Single(date);
+        if self.date == date {
+            return;
+        }
+
+        // Keep the calendar open to show the typed date.
+        self.date = date;
+        self.calendar.update(cx, |view, cx| view.set_date(date, cx));
+        cx.emit(DatePickerEvent::Change(date));
+        cx.notify();
+    }
+
+    /// Reset the input text to the formatted date, e.g. after typing an invalid date.
+    fn sync_input_text(&mut self, cx: &mut ViewContext<Self>) {
+        if !self.date.is_single() {
+            return;
+        }
+
+        let text = self.date.format(&self.date_format).unwrap_or_default();
+        if self.input.read(cx).text() != text {
+            self.input.update(cx, |input, cx| input.set_text(text, cx));
+        }
+    }
+
     /// Set size of the date picker.
     pub fn set_size(&mut self, size: Size, cx: &mut ViewContext<Self>) {
         self.size = size;
@@ -190,7 +335,7 @@ impl DatePicker {
         cx.notify();
     }
 
-    fn clean(&mut self, _: &gpui::ClickEvent, cx: &mut ViewContext<Self>) {
+    fn clean(&mut self, _: &ClickEvent, cx: &mut ViewContext<Self>) {
         match self.date {
             Date::Single(_) => {
                 self.update_date(Date::Single(None), true, cx);
@@ -201,8 +346,12 @@ impl DatePicker {
         }
     }
 
-    fn toggle_calendar(&mut self, _: &gpui::ClickEvent, cx: &mut ViewContext<Self>) {
+    fn toggle_calendar(&mut self, _: &ClickEvent, cx: &mut ViewContext<Self>) {
         self.open = !self.open;
+        if self.open {
+            // Focus the calendar for the keyboard navigation.
+            self.calendar.focus_handle(cx).focus(cx);
+        }
         cx.notify();
     }
 
@@ -247,8 +396,24 @@ impl Render for DatePicker {
         self.calendar.update(cx, |view, cx| {
             view.set_size(self.size, cx);
             view.set_number_of_months(self.number_of_months, cx);
+            view.set_week_start(self.week_start, cx);
+            view.set_min_date(self.min_date, cx);
+            view.set_max_date(self.max_date, cx);
         });
 
+        let is_single = self.date.is_single();
+        if is_single {
+            let size = self.size;
+            let update_size = self.input_size != Some(size);
+            self.input_size = Some(size);
+            self.input.update(cx, |input, cx| {
+                input.set_placeholder(placeholder.clone());
+                if update_size {
+                    input.set_size(size, cx);
+                }
+            });
+        }
+
         div()
             .id(self.id.clone())
             .key_context("DatePicker")
@@ -261,45 +426,50 @@ impl Render for DatePicker {
                 Length::Auto => this.w_full(),
             })
             .input_text_size(self.size)
-            .child(
-                div()
-                    .id("date-picker-input")
-                    .relative()
-                    .flex()
-                    .items_center()
-                    .justify_between()
-                    .bg(cx.theme().background)
-                    .border_1()
-                    .border_color(cx.theme().input)
-                    .rounded(px(cx.theme().radius))
-                    .when(cx.theme().shadow, |this| this.shadow_sm())
-                    .cursor_pointer()
-                    .overflow_hidden()
-                    .input_text_size(self.size)
-                    .when(is_focused, |this| this.outline(cx))
-                    .input_size(self.size)
-                    .when(!self.open, |this| {
-                        this.on_click(cx.listener(Self::toggle_calendar))
-                    })
-                    .child(
-                        h_flex()
-                            .w_full()
-                            .items_center()
-                            .justify_between()
-                            .gap_1()
-                            .child(div().w_full().overflow_hidden().child(display_title))
-                            .when(show_clean, |this| {
-                                this.child(ClearButton::new(cx).on_click(cx.listener(Self::clean)))
-                            })
-                            .when(!show_clean, |this| {
-                                this.child(
-                                    Icon::new(IconName::Calendar)
-                                        .xsmall()
-                                        .text_color(cx.theme().muted_foreground),
-                                )
-                            }),
-                    ),
-            )
+            .when(is_single, |this| this.child(self.input.clone()))
+            .when(!is_single, |this| {
+                this.child(
+                    div()
+                        .id("date-picker-input")
+                        .relative()
+                        .flex()
+                        .items_center()
+                        .justify_between()
+                        .bg(cx.theme().background)
+                        .border_1()
+                        .border_color(cx.theme().input)
+                        .rounded(px(cx.theme().radius))
+                        .when(cx.theme().shadow, |this| this.shadow_sm())
+                        .cursor_pointer()
+                        .overflow_hidden()
+                        .input_text_size(self.size)
+                        .when(is_focused, |this| this.outline(cx))
+                        .input_size(self.size)
+                        .when(!self.open, |this| {
+                            this.on_click(cx.listener(Self::toggle_calendar))
+                        })
+                        .child(
+                            h_flex()
+                                .w_full()
+                                .items_center()
+                                .justify_between()
+                                .gap_1()
+                                .child(div().w_full().overflow_hidden().child(display_title))
+                                .when(show_clean, |this| {
+                                    this.child(
+                                        ClearButton::new(cx).on_click(cx.listener(Self::clean)),
+                                    )
+                                })
+                                .when(!show_clean, |this| {
+                                    this.child(
+                                        Icon::new(IconName::Calendar)
+                                            .xsmall()
+                                            .text_color(cx.theme().muted_foreground),
+                                    )
+                                }),
+                        ),
+                )
+            })
             .when(self.open, |this| {
                 this.child(
                     deferred(
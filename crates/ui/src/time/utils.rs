@@ -1,4 +1,4 @@
-use chrono::{Datelike, Duration, NaiveDate};
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
 
 trait NaiveDateExt {
     fn days_in_month(&self) -> i32;
@@ -28,7 +28,8 @@ impl NaiveDateExt for chrono::NaiveDate {
     }
 }
 
-pub(crate) fn days_in_month(year: i32, month: u32) -> Vec<Vec<NaiveDate>> {
+/// Returns the weeks of the month to show in the calendar, each week starts from the `week_start`.
+pub(crate) fn days_in_month(year: i32, month: u32, week_start: Weekday) -> Vec<Vec<NaiveDate>> {
     let mut year = year;
     let mut month = month;
     if month > 12 {
@@ -42,7 +43,8 @@ pub(crate) fn days_in_month(year: i32, month: u32) -> Vec<Vec<NaiveDate>> {
 
     let date = NaiveDate::from_ymd_opt(year, month, 1).unwrap();
     let num_days = date.days_in_month();
-    let start_weekday = date.weekday().num_days_from_sunday();
+    let start_weekday =
+        (date.weekday().num_days_from_sunday() + 7 - week_start.num_days_from_sunday()) as i32 % 7;
 
     // Get the days in the month, 2023-02 will returns
     // "29|30|31| 1| 2| 3| 4",
@@ -50,46 +52,70 @@ pub(crate) fn days_in_month(year: i32, month: u32) -> Vec<Vec<NaiveDate>> {
     // "12|13|14|15|16|17|18",
     // "19|20|21|22|23|24|25",
     // "26|27|28| 1| 2| 3| 4",
-    let mut days = vec![];
-    for n in 0..5 {
-        let mut week_days = vec![];
-        for weekday in 0..7 {
-            let (mut y, mut m) = (year, month);
-
-            // If the day is less than the start weekday, we need to go back to the previous month.
-            if n == 0 && weekday < start_weekday {
-                m = if m == 1 { 12 } else { m - 1 };
-                y = if m == 1 { year - 1 } else { y };
-            }
-
-            // If start_weekday is 3, and n is 0 and weekday is 3, then day is 1.
-            // If start_weekday is 3, and n is 1 and weekday is 4, then day is 9.
-            let day = n * 7 + weekday as i32 - start_weekday as i32;
+    //
+    // The number of weeks is 4 to 6, to fit all the days with the weekday alignment.
+    let num_weeks = (start_weekday + num_days + 6) / 7;
+    (0..num_weeks)
+        .map(|n| {
+            (0..7)
+                .map(|weekday| {
+                    // If start_weekday is 3, and n is 0 and weekday is 3, then day is 0 (the 1st).
+                    // The days before or after the month are in the previous or next month.
+                    let day = n * 7 + weekday - start_weekday;
+                    date + Duration::days(day as i64)
+                })
+                .collect()
+        })
+        .collect()
+}
 
-            // If the day is greater than the number of days in the month, we need to go to the next month.
-            if day > num_days {
-                m = if m == 12 { 1 } else { m + 1 };
-                y = if m == 1 { year + 1 } else { y };
-            }
+/// Clamp the `date` in the optional `min` and `max` dates.
+pub(crate) fn clamp_date(
+    date: NaiveDate,
+    min: Option<NaiveDate>,
+    max: Option<NaiveDate>,
+) -> NaiveDate {
+    let date = min.map_or(date, |min| date.max(min));
+    max.map_or(date, |max| date.min(max))
+}
 
-            #[allow(clippy::expect_fun_call)]
-            let date = date
-                .checked_add_signed(Duration::days(day as i64))
-                .expect(&format!("invalid date {}-{} days {}", y, m, day));
-            week_days.push(date);
+/// Returns the input mask of the date `format`, the `9` is for a digit.
+///
+/// Only the numeric `%Y`, `%y`, `%m` and `%d` are supported, returns `None` for others.
+pub(crate) fn date_mask(format: &str) -> Option<String> {
+    let mut mask = String::new();
+    let mut chars = format.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            mask.push(c);
+            continue;
         }
 
-        days.push(week_days);
+        match chars.next()? {
+            'Y' => mask.push_str("9999"),
+            'y' | 'm' | 'd' => mask.push_str("99"),
+            '%' => mask.push('%'),
+            _ => return None,
+        }
     }
 
-    days
+    Some(mask)
+}
+
+/// Returns true if the `text` is a (partial) input of the `mask`.
+pub(crate) fn match_mask(mask: &str, text: &str) -> bool {
+    text.chars().count() <= mask.chars().count()
+        && text.chars().zip(mask.chars()).all(|(c, m)| match m {
+            '9' => c.is_ascii_digit(),
+            _ => c == m,
+        })
 }
 
 #[cfg(test)]
 mod tests {
-    use chrono::{Datelike, NaiveDate};
+    use chrono::{Datelike, NaiveDate, Weekday};
 
-    use super::{days_in_month, NaiveDateExt};
+    use super::{clamp_date, date_mask, days_in_month, match_mask, NaiveDateExt};
 
     #[test]
     fn test_days_in_month() {
@@ -115,7 +141,12 @@ mod tests {
     fn test_days() {
         #[track_caller]
         fn assert_case(date: NaiveDate, expected: Vec<&str>) {
-            let out = days_in_month(date.year(), date.month())
+            assert_case_with_week_start(date, Weekday::Sun, expected);
+        }
+
+        #[track_caller]
+        fn assert_case_with_week_start(date: NaiveDate, week_start: Weekday, expected: Vec<&str>) {
+            let out = days_in_month(date.year(), date.month(), week_start)
                 .iter()
                 .map(|week| {
                     week.iter()
@@ -177,5 +208,71 @@ mod tests {
                 "26|27|28|3-1|3-2|3-3|3-4",
             ],
         );
+        assert_case(
+            NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+            vec![
+                "2-25|2-26|2-27|2-28|2-29| 1| 2",
+                " 3| 4| 5| 6| 7| 8| 9",
+                "10|11|12|13|14|15|16",
+                "17|18|19|20|21|22|23",
+                "24|25|26|27|28|29|30",
+                "31|4-1|4-2|4-3|4-4|4-5|4-6",
+            ],
+        );
+        assert_case(
+            NaiveDate::from_ymd_opt(2026, 2, 1).unwrap(),
+            vec![
+                " 1| 2| 3| 4| 5| 6| 7",
+                " 8| 9|10|11|12|13|14",
+                "15|16|17|18|19|20|21",
+                "22|23|24|25|26|27|28",
+            ],
+        );
+
+        assert_case_with_week_start(
+            NaiveDate::from_ymd_opt(2024, 8, 1).unwrap(),
+            Weekday::Mon,
+            vec![
+                "7-29|7-30|7-31| 1| 2| 3| 4",
+                " 5| 6| 7| 8| 9|10|11",
+                "12|13|14|15|16|17|18",
+                "19|20|21|22|23|24|25",
+                "26|27|28|29|30|31|9-1",
+            ],
+        );
+        assert_case_with_week_start(
+            NaiveDate::from_ymd_opt(2026, 2, 1).unwrap(),
+            Weekday::Mon,
+            vec![
+                "1-26|1-27|1-28|1-29|1-30|1-31| 1",
+                " 2| 3| 4| 5| 6| 7| 8",
+                " 9|10|11|12|13|14|15",
+                "16|17|18|19|20|21|22",
+                "23|24|25|26|27|28|3-1",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_clamp_date() {
+        let date = |d| NaiveDate::from_ymd_opt(2024, 8, d).unwrap();
+        assert_eq!(clamp_date(date(10), None, None), date(10));
+        assert_eq!(clamp_date(date(1), Some(date(5)), None), date(5));
+        assert_eq!(
+            clamp_date(date(20), Some(date(5)), Some(date(15))),
+            date(15)
+        );
+    }
+
+    #[test]
+    fn test_date_mask() {
+        assert_eq!(date_mask("%Y/%m/%d").as_deref(), Some("9999/99/99"));
+        assert_eq!(date_mask("%d.%m.%y").as_deref(), Some("99.99.99"));
+        assert_eq!(date_mask("%b %d, %Y"), None);
+
+        assert!(match_mask("9999/99/99", "2024/0"));
+        assert!(match_mask("9999/99/99", "2024/08/03"));
+        assert!(!match_mask("9999/99/99", "2024-08"));
+        assert!(!match_mask("9999/99/99", "2024/08/031"));
     }
 }
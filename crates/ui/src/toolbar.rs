@@ -0,0 +1,399 @@
+use std::rc::Rc;
+
+use gpui::{
+    actions, canvas, div, prelude::FluentBuilder as _, px, AppContext, ClickEvent, ElementId,
+    FocusHandle, FocusableView, InteractiveElement as _, IntoElement, KeyBinding,
+    ParentElement as _, Pixels, Render, SharedString, Styled, ViewContext, WindowContext,
+};
+
+use crate::{
+    button::{Button, ButtonVariants as _},
+    divider::Divider,
+    h_flex,
+    popup_menu::PopupMenuExt as _,
+    selection::step_option,
+    theme::ActiveTheme as _,
+    Disableable as _, Icon, IconName, Selectable as _, Sizable, Size, StyledExt as _,
+};
+
+actions!(toolbar, [FocusPrev, FocusNext, Activate]);
+
+const CONTEXT: &str = "Toolbar";
+/// The horizontal padding of the toolbar.
+const PADDING: Pixels = px(4.);
+/// The gap between the entries.
+const GAP: Pixels = px(2.);
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("left", FocusPrev, Some(CONTEXT)),
+        KeyBinding::new("right", FocusNext, Some(CONTEXT)),
+        KeyBinding::new("enter", Activate, Some(CONTEXT)),
+        KeyBinding::new("space", Activate, Some(CONTEXT)),
+    ]);
+}
+
+type ClickHandler = Rc<dyn Fn(&ClickEvent, &mut WindowContext)>;
+
+/// An item of the [`Toolbar`], the `label` is shown in the overflow menu and the tooltip.
+pub struct ToolbarItem {
+    id: SharedString,
+    icon: Option<IconName>,
+    label: SharedString,
+    disabled: bool,
+    selected: bool,
+    on_click: Option<ClickHandler>,
+}
+
+impl ToolbarItem {
+    pub fn new(id: impl Into<SharedString>, label: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            icon: None,
+            label: label.into(),
+            disabled: false,
+            selected: false,
+            on_click: None,
+        }
+    }
+
+    /// Set the icon, the item shows only the icon with the label as the tooltip.
+    pub fn icon(mut self, icon: IconName) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn selected(mut self, selected: bool) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    pub fn on_click(mut self, handler: impl Fn(&ClickEvent, &mut WindowContext) + 'static) -> Self {
+        self.on_click = Some(Rc::new(handler));
+        self
+    }
+}
+
+enum ToolbarEntry {
+    Item(ToolbarItem),
+    Separator,
+    /// A flexible space to push the following items to the end.
+    Spacer,
+}
+
+impl ToolbarEntry {
+    fn is_focusable(&self) -> bool {
+        matches!(self, Self::Item(item) if !item.disabled)
+    }
+}
+
+/// Returns the number of the leading entries that fit in the `available` width,
+/// reserving the `more` width for the overflow button if not all of them fit.
+fn visible_count(widths: &[f32], available: f32, gap: f32, more: f32) -> usize {
+    let total = widths.iter().sum::<f32>() + gap * widths.len().saturating_sub(1) as f32;
+    if available <= 0. || total <= available {
+        return widths.len();
+    }
+
+    let mut width = more;
+    for (ix, w) in widths.iter().enumerate() {
+        width += w + gap;
+        if width > available {
+            return ix;
+        }
+    }
+    widths.len()
+}
+
+/// A horizontal strip of the buttons, with separators and spacers.
+///
+/// The toolbar is a single tab stop, Left and Right move the focus between the items.
+/// The items that don't fit are collapsed into a trailing "…" menu.
+pub struct Toolbar {
+    id: ElementId,
+    focus_handle: FocusHandle,
+    entries: Vec<ToolbarEntry>,
+    size: Size,
+    /// The index of the entry with the roving focus.
+    focused_ix: Option<usize>,
+    /// The measured widths of the entries, the spacers are zero.
+    widths: Vec<Pixels>,
+    width: Pixels,
+}
+
+impl Toolbar {
+    pub fn new(id: impl Into<ElementId>, cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            id: id.into(),
+            focus_handle: cx.focus_handle(),
+            entries: Vec::new(),
+            size: Size::default(),
+            focused_ix: None,
+            widths: Vec::new(),
+            width: px(0.),
+        }
+    }
+
+    pub fn item(mut self, item: ToolbarItem) -> Self {
+        self.entries.push(ToolbarEntry::Item(item));
+        self
+    }
+
+    pub fn separator(mut self) -> Self {
+        self.entries.push(ToolbarEntry::Separator);
+        self
+    }
+
+    /// Add a flexible space, the following items are placed at the end.
+    pub fn spacer(mut self) -> Self {
+        self.entries.push(ToolbarEntry::Spacer);
+        self
+    }
+
+    fn visible_count(&self) -> usize {
+        if self.widths.len() != self.entries.len() {
+            return self.entries.len();
+        }
+
+        let widths = self.widths.iter().map(|w| w.0).collect::<Vec<_>>();
+        let more = match self.size {
+            Size::XSmall => 20.,
+            Size::Small => 24.,
+            Size::Large => 40.,
+            Size::XLarge => 48.,
+            _ => 32.,
+        };
+        visible_count(&widths, (self.width - PADDING * 2).0, GAP.0, more)
+    }
+
+    fn move_focus(&mut self, forward: bool, cx: &mut ViewContext<Self>) {
+        let disabled = self.entries[..self.visible_count()]
+            .iter()
+            .map(|entry| !entry.is_focusable())
+            .collect::<Vec<_>>();
+        if let Some(ix) = step_option(&disabled, self.focused_ix, forward) {
+            self.focused_ix = Some(ix);
+            cx.notify();
+        }
+    }
+
+    fn focus_prev(&mut self, _: &FocusPrev, cx: &mut ViewContext<Self>) {
+        self.move_focus(false, cx);
+    }
+
+    fn focus_next(&mut self, _: &FocusNext, cx: &mut ViewContext<Self>) {
+        self.move_focus(true, cx);
+    }
+
+    fn activate(&mut self, _: &Activate, cx: &mut ViewContext<Self>) {
+        let Some(ToolbarEntry::Item(item)) = self.focused_ix.and_then(|ix| self.entries.get(ix))
+        else {
+            return;
+        };
+        if item.disabled {
+            return;
+        }
+        if let Some(on_click) = item.on_click.clone() {
+            on_click(&ClickEvent::default(), cx);
+        }
+    }
+
+    fn update_width(&mut self, ix: Option<usize>, width: Pixels, cx: &mut ViewContext<Self>) {
+        let changed = match ix {
+            Some(ix) => {
+                if self.widths.len() != self.entries.len() {
+                    self.widths.resize(self.entries.len(), px(0.));
+                }
+                let changed = self.widths[ix] != width;
+                self.widths[ix] = width;
+                changed
+            }
+            None => {
+                let changed = self.width != width;
+                self.width = width;
+                changed
+            }
+        };
+
+        if changed {
+            cx.notify();
+        }
+    }
+
+    fn render_overflow(&self, visible: usize) -> impl IntoElement {
+        let items = self.entries[visible..]
+            .iter()
+            .filter_map(|entry| match entry {
+                ToolbarEntry::Item(item) => Some(Some((
+                    item.label.clone(),
+                    item.icon.clone(),
+                    item.on_click.clone().filter(|_| !item.disabled),
+                ))),
+                ToolbarEntry::Separator => Some(None),
+                ToolbarEntry::Spacer => None,
+            })
+            .collect::<Vec<_>>();
+
+        Button::new("more")
+            .ghost()
+            .with_size(self.size)
+            .icon(IconName::Ellipsis)
+            .popup_menu(move |mut menu, _| {
+                for item in items.iter() {
+                    let Some((label, icon, on_click)) = item else {
+                        menu = menu.separator();
+                        continue;
+                    };
+                    let on_click = on_click.clone();
+                    menu = menu.menu_with_handler(label.clone(), icon.clone().map(Icon::new), {
+                        move |cx| {
+                            if let Some(on_click) = on_click.as_ref() {
+                                on_click(&ClickEvent::default(), cx);
+                            }
+                        }
+                    });
+                }
+                menu
+            })
+    }
+}
+
+impl Sizable for Toolbar {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl FocusableView for Toolbar {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for Toolbar {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let view = cx.view().clone();
+        let visible = self.visible_count();
+        let focused = self.focus_handle.is_focused(cx);
+        if self.focused_ix.map_or(false, |ix| ix >= visible) {
+            self.focused_ix = None;
+        }
+
+        h_flex()
+            .id(self.id.clone())
+            .key_context(CONTEXT)
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::focus_prev))
+            .on_action(cx.listener(Self::focus_next))
+            .on_action(cx.listener(Self::activate))
+            .relative()
+            .w_full()
+            .min_w_0()
+            .overflow_hidden()
+            .px(PADDING)
+            .py_0p5()
+            .gap(GAP)
+            .child({
+                let view = view.clone();
+                canvas(
+                    move |bounds, cx| {
+                        view.update(cx, |r, cx| r.update_width(None, bounds.size.width, cx))
+                    },
+                    |_, _, _| {},
+                )
+                .absolute()
+                .size_full()
+            })
+            .children(
+                self.entries[..visible]
+                    .iter()
+                    .enumerate()
+                    .map(|(ix, entry)| {
+                        let view = view.clone();
+                        let measure = canvas(
+                            move |bounds, cx| {
+                                view.update(cx, |r, cx| {
+                                    r.update_width(Some(ix), bounds.size.width, cx)
+                                })
+                            },
+                            |_, _, _| {},
+                        )
+                        .absolute()
+                        .size_full();
+
+                        match entry {
+                            ToolbarEntry::Item(item) => div()
+                                .relative()
+                                .flex_shrink_0()
+                                .rounded(px(cx.theme().radius))
+                                .border_1()
+                                .border_color(cx.theme().transparent)
+                                .when(focused && self.focused_ix == Some(ix), |this| {
+                                    this.focus_ring(&self.focus_handle, cx)
+                                })
+                                .child(
+                                    Button::new(SharedString::from(format!(
+                                        "toolbar-item:{}",
+                                        item.id
+                                    )))
+                                    .ghost()
+                                    .with_size(self.size)
+                                    .selected(item.selected)
+                                    .disabled(item.disabled)
+                                    .map(|this| match item.icon.clone() {
+                                        Some(icon) => this.icon(icon).tooltip(item.label.clone()),
+                                        None => this.label(item.label.clone()),
+                                    })
+                                    .when_some(
+                                        item.on_click.clone(),
+                                        |this, on_click| {
+                                            this.on_click(cx.listener(move |this, event, cx| {
+                                                this.focused_ix = Some(ix);
+                                                on_click(event, cx);
+                                            }))
+                                        },
+                                    ),
+                                )
+                                .child(measure)
+                                .into_any_element(),
+                            ToolbarEntry::Separator => div()
+                                .relative()
+                                .flex_shrink_0()
+                                .h_5()
+                                .px_1()
+                                .child(Divider::vertical())
+                                .child(measure)
+                                .into_any_element(),
+                            // The spacer is flexible, so it is not measured.
+                            ToolbarEntry::Spacer => div().flex_1().into_any_element(),
+                        }
+                    }),
+            )
+            .when(visible < self.entries.len(), |this| {
+                this.child(self.render_overflow(visible))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::visible_count;
+
+    #[test]
+    fn test_visible_count() {
+        let widths = [30., 30., 30., 30.];
+        assert_eq!(visible_count(&widths, 200., 2., 30.), 4);
+        assert_eq!(visible_count(&widths, 126., 2., 30.), 4);
+        assert_eq!(visible_count(&widths, 125., 2., 30.), 2);
+        assert_eq!(visible_count(&widths, 40., 2., 30.), 0);
+        // Not measured yet.
+        assert_eq!(visible_count(&widths, 0., 2., 30.), 4);
+        assert_eq!(visible_count(&[], 100., 2., 30.), 0);
+    }
+}
@@ -1,38 +1,332 @@
+use std::{cell::RefCell, rc::Rc, time::Duration};
+
 use gpui::{
-    div, px, AnyView, IntoElement, ParentElement, Render, SharedString, Styled, ViewContext,
-    VisualContext, WindowContext,
+    anchored, deferred, div, point, px, AnyElement, AnyView, AvailableSpace, Bounds, DispatchPhase,
+    Element, ElementId, FocusHandle, GlobalElementId, Hitbox, IntoElement, LayoutId,
+    MouseDownEvent, MouseMoveEvent, ParentElement, Pixels, Point, Render, SharedString, Size,
+    StatefulInteractiveElement, Styled, Task, ViewContext, VisualContext, WindowContext,
 };
 
 use crate::theme::ActiveTheme;
 
+/// The default delay before showing the tooltip when hovering.
+const DEFAULT_DELAY: Duration = Duration::from_millis(500);
+
+enum TooltipContent {
+    Text(SharedString),
+    Element(Rc<dyn Fn(&mut WindowContext) -> AnyElement>),
+}
+
 pub struct Tooltip {
-    text: SharedString,
+    content: TooltipContent,
 }
 
 impl Tooltip {
     pub fn new(text: impl Into<SharedString>, cx: &mut WindowContext) -> AnyView {
-        cx.new_view(|_| Self { text: text.into() }).into()
+        cx.new_view(|_| Self {
+            content: TooltipContent::Text(text.into()),
+        })
+        .into()
+    }
+
+    /// Create a tooltip with a custom element, e.g. a text with the key binding.
+    pub fn element<E, F>(builder: F, cx: &mut WindowContext) -> AnyView
+    where
+        E: IntoElement,
+        F: Fn(&mut WindowContext) -> E + 'static,
+    {
+        cx.new_view(|_| Self {
+            content: TooltipContent::Element(Rc::new(move |cx| builder(cx).into_any_element())),
+        })
+        .into()
     }
 }
 
 impl Render for Tooltip {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let content = match &self.content {
+            TooltipContent::Text(text) => text.clone().into_any_element(),
+            TooltipContent::Element(builder) => builder(cx),
+        };
+
         div().child(
             // Wrap in a child, to ensure the left margin is applied to the tooltip
             div()
-                .font_family(".SystemUIFont")
+                .font_family(cx.theme().font_family.clone())
                 .m_3()
-                .bg(cx.theme().popover)
+                .bg(cx.theme().popover_background())
                 .text_color(cx.theme().popover_foreground)
-                .bg(cx.theme().popover)
                 .border_1()
                 .border_color(cx.theme().border)
                 .shadow_md()
-                .rounded(px(6.))
+                .rounded(px(cx.theme().radius))
                 .py_0p5()
                 .px_2()
                 .text_sm()
-                .child(self.text.clone()),
+                .child(content),
         )
     }
 }
+
+/// Extension trait to attach a [`Tooltip`] to an interactive element.
+///
+/// Unlike the `StatefulInteractiveElement::tooltip`, the delay is configurable,
+/// and the tooltip can be shown when the element is focused by keyboard.
+///
+/// ```ignore
+/// div()
+///     .id("save")
+///     .track_focus(&focus_handle)
+///     .child("Save")
+///     .tooltip_text("Save the file")
+///     .show_on_focus(&focus_handle)
+/// ```
+pub trait TooltipExt: StatefulInteractiveElement + IntoElement + 'static {
+    /// Show a text tooltip when hovering.
+    fn tooltip_text(self, text: impl Into<SharedString>) -> WithTooltip {
+        let text = text.into();
+        WithTooltip::new(self, move |cx| Tooltip::new(text.clone(), cx))
+    }
+
+    /// Show a tooltip with a custom element when hovering.
+    fn tooltip_with<E, F>(self, builder: F) -> WithTooltip
+    where
+        E: IntoElement,
+        F: Fn(&mut WindowContext) -> E + 'static,
+    {
+        let builder = Rc::new(builder);
+        WithTooltip::new(self, move |cx| {
+            let builder = builder.clone();
+            Tooltip::element(move |cx| builder(cx), cx)
+        })
+    }
+}
+
+impl<E: StatefulInteractiveElement + IntoElement + 'static> TooltipExt for E {}
+
+/// An element with a tooltip, see [`TooltipExt`].
+pub struct WithTooltip {
+    id: ElementId,
+    child: AnyElement,
+    builder: Rc<dyn Fn(&mut WindowContext) -> AnyView>,
+    delay: Duration,
+    focus_handle: Option<FocusHandle>,
+}
+
+impl WithTooltip {
+    fn new(
+        child: impl IntoElement,
+        builder: impl Fn(&mut WindowContext) -> AnyView + 'static,
+    ) -> Self {
+        let child = child.into_element();
+        let id = child.id().unwrap_or_else(|| "tooltip".into());
+
+        Self {
+            id,
+            child: child.into_any(),
+            builder: Rc::new(builder),
+            delay: DEFAULT_DELAY,
+            focus_handle: None,
+        }
+    }
+
+    /// Set the delay before showing the tooltip when hovering, default is 500ms.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Set to show the tooltip below the element when the `focus_handle` is focused by keyboard.
+    pub fn show_on_focus(mut self, focus_handle: &FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle.clone());
+        self
+    }
+
+    fn with_state<R>(
+        &mut self,
+        id: &GlobalElementId,
+        cx: &mut WindowContext,
+        f: impl FnOnce(&mut Self, Rc<RefCell<TooltipState>>, &mut WindowContext) -> R,
+    ) -> R {
+        cx.with_optional_element_state::<Rc<RefCell<TooltipState>>, _>(Some(id), |state, cx| {
+            let state = state.unwrap().unwrap_or_default();
+            let result = f(self, state.clone(), cx);
+            (result, Some(state))
+        })
+    }
+}
+
+#[derive(Default)]
+struct TooltipState {
+    hovered: bool,
+    /// The position of the tooltip when hovering, `None` means hidden.
+    position: Option<Point<Pixels>>,
+    /// The tooltip is hidden by mouse down until the mouse leaves.
+    suppressed: bool,
+    /// The element is focused by mouse down, not by keyboard.
+    focused_by_mouse: bool,
+    view: Option<AnyView>,
+    _task: Option<Task<()>>,
+}
+
+impl TooltipState {
+    fn hide(&mut self) {
+        self.position = None;
+        self._task = None;
+    }
+}
+
+impl IntoElement for WithTooltip {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+pub struct WithTooltipPrepaintState {
+    hitbox: Hitbox,
+    tooltip: Option<AnyElement>,
+}
+
+impl Element for WithTooltip {
+    type RequestLayoutState = ();
+    type PrepaintState = WithTooltipPrepaintState;
+
+    fn id(&self) -> Option<ElementId> {
+        Some(self.id.clone())
+    }
+
+    fn request_layout(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        cx: &mut WindowContext,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        (self.child.request_layout(cx), ())
+    }
+
+    fn prepaint(
+        &mut self,
+        id: Option<&GlobalElementId>,
+        bounds: Bounds<Pixels>,
+        _: &mut Self::RequestLayoutState,
+        cx: &mut WindowContext,
+    ) -> Self::PrepaintState {
+        self.child.prepaint(cx);
+        let hitbox = cx.insert_hitbox(bounds, false);
+
+        let tooltip = self.with_state(id.unwrap(), cx, |this, state, cx| {
+            let mut state = state.borrow_mut();
+            let focused = this
+                .focus_handle
+                .as_ref()
+                .map_or(false, |focus_handle| focus_handle.is_focused(cx));
+            if !focused {
+                state.focused_by_mouse = false;
+            }
+
+            let position = state.position.or_else(|| {
+                (focused && !state.focused_by_mouse && !state.suppressed)
+                    .then(|| bounds.bottom_left() + point(px(0.), px(4.)))
+            });
+            let Some(position) = position else {
+                state.view = None;
+                return None;
+            };
+
+            let view = state.view.get_or_insert_with(|| (this.builder)(cx)).clone();
+            let mut element = deferred(
+                anchored()
+                    .snap_to_window_with_margin(px(8.))
+                    .position(position)
+                    .child(view),
+            )
+            .with_priority(2)
+            .into_any_element();
+            element.prepaint_as_root(
+                bounds.origin,
+                Size {
+                    width: AvailableSpace::MinContent,
+                    height: AvailableSpace::MinContent,
+                },
+                cx,
+            );
+            Some(element)
+        });
+
+        WithTooltipPrepaintState { hitbox, tooltip }
+    }
+
+    fn paint(
+        &mut self,
+        id: Option<&GlobalElementId>,
+        _: Bounds<Pixels>,
+        _: &mut Self::RequestLayoutState,
+        prepaint: &mut Self::PrepaintState,
+        cx: &mut WindowContext,
+    ) {
+        self.child.paint(cx);
+        if let Some(tooltip) = prepaint.tooltip.as_mut() {
+            tooltip.paint(cx);
+        }
+
+        let delay = self.delay;
+        let hitbox_id = prepaint.hitbox.id;
+        self.with_state(id.unwrap(), cx, |_, state, cx| {
+            // Show the tooltip after the delay when the mouse enters, and hide it once leaves.
+            cx.on_mouse_event({
+                let state = state.clone();
+                move |_: &MouseMoveEvent, phase, cx| {
+                    if phase != DispatchPhase::Bubble {
+                        return;
+                    }
+
+                    let hovered = hitbox_id.is_hovered(cx);
+                    let mut this = state.borrow_mut();
+                    if this.hovered == hovered {
+                        return;
+                    }
+
+                    this.hovered = hovered;
+                    if !hovered {
+                        this.suppressed = false;
+                        this.hide();
+                        cx.refresh();
+                        return;
+                    }
+                    if this.suppressed {
+                        return;
+                    }
+
+                    let position = cx.mouse_position() + point(px(0.), px(20.));
+                    let state = state.clone();
+                    this._task = Some(cx.spawn(|mut cx| async move {
+                        cx.background_executor().timer(delay).await;
+                        {
+                            let mut this = state.borrow_mut();
+                            if !this.hovered || this.suppressed {
+                                return;
+                            }
+                            this.position = Some(position);
+                        }
+                        _ = cx.update(|cx| cx.refresh());
+                    }));
+                }
+            });
+
+            // Hide the tooltip immediately on mouse down, use the capture phase,
+            // because the element may stop the propagation.
+            cx.on_mouse_event(move |_: &MouseDownEvent, phase, cx| {
+                if phase != DispatchPhase::Capture || !hitbox_id.is_hovered(cx) {
+                    return;
+                }
+
+                let mut this = state.borrow_mut();
+                this.suppressed = true;
+                this.focused_by_mouse = true;
+                this.hide();
+                cx.refresh();
+            });
+        });
+    }
+}
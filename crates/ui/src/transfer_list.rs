@@ -0,0 +1,366 @@
+use std::{collections::HashSet, rc::Rc};
+
+use gpui::{
+    div, prelude::FluentBuilder as _, px, AppContext, ElementId, EventEmitter, InteractiveElement,
+    IntoElement, ParentElement as _, Pixels, Render, SharedString, Styled, Task, View, ViewContext,
+    VisualContext as _, WeakView,
+};
+
+use crate::{
+    button::{Button, ButtonVariants as _},
+    h_flex,
+    list::{List, ListDelegate, ListItem},
+    theme::ActiveTheme as _,
+    v_flex, Disableable as _, IconName, Sizable as _, StyledExt as _,
+};
+
+pub enum TransferListEvent {
+    /// The items are moved by the user, with the items on the selected side.
+    Change(Vec<SharedString>),
+}
+
+/// Returns the indexes of the items on the selected side if `selected`, otherwise the available side.
+fn side_members(selected: &[bool], side: bool) -> Vec<usize> {
+    selected
+        .iter()
+        .enumerate()
+        .filter(|(_, selected)| **selected == side)
+        .map(|(ix, _)| ix)
+        .collect()
+}
+
+/// Returns the `members` that match the `query`, case insensitive.
+fn filter_members(items: &[SharedString], members: &[usize], query: &str) -> Vec<usize> {
+    let query = query.to_lowercase();
+    members
+        .iter()
+        .copied()
+        .filter(|ix| query.is_empty() || items[*ix].to_lowercase().contains(&query))
+        .collect()
+}
+
+struct TransferListDelegate {
+    transfer: WeakView<TransferList>,
+    items: Rc<Vec<SharedString>>,
+    /// The indexes of the items on this side, in the original order.
+    members: Vec<usize>,
+    /// The indexes of the members that match the query.
+    matches: Vec<usize>,
+    checked: HashSet<usize>,
+    query: SharedString,
+    selected_index: Option<usize>,
+}
+
+impl TransferListDelegate {
+    fn set_members(&mut self, members: Vec<usize>) {
+        self.checked.retain(|ix| members.contains(ix));
+        self.members = members;
+        self.matches = filter_members(&self.items, &self.members, &self.query);
+    }
+
+    /// Returns the items to move, only the items in the active filter are moved.
+    fn movable(&self, all: bool) -> Vec<usize> {
+        self.matches
+            .iter()
+            .copied()
+            .filter(|ix| all || self.checked.contains(ix))
+            .collect()
+    }
+
+    fn notify_transfer(&self, cx: &mut ViewContext<List<Self>>) {
+        let transfer = self.transfer.clone();
+        cx.defer(move |_, cx| {
+            _ = transfer.update(cx, |_, cx| cx.notify());
+        });
+    }
+}
+
+impl ListDelegate for TransferListDelegate {
+    type Item = ListItem;
+
+    fn items_count(&self, _: &AppContext) -> usize {
+        self.matches.len()
+    }
+
+    fn render_item(&self, ix: usize, _: &mut ViewContext<List<Self>>) -> Option<Self::Item> {
+        let item_ix = *self.matches.get(ix)?;
+        let label = self.items.get(item_ix)?.clone();
+
+        Some(
+            ListItem::new(("transfer-item", item_ix))
+                .selected(self.selected_index == Some(ix))
+                .checkable(self.checked.contains(&item_ix))
+                .child(div().whitespace_nowrap().child(label)),
+        )
+    }
+
+    fn render_empty(&self, cx: &mut ViewContext<List<Self>>) -> impl IntoElement {
+        h_flex()
+            .size_full()
+            .justify_center()
+            .py_6()
+            .text_sm()
+            .text_color(cx.theme().muted_foreground)
+            .child("No items")
+    }
+
+    fn perform_search(&mut self, query: &str, cx: &mut ViewContext<List<Self>>) -> Task<()> {
+        self.query = query.to_string().into();
+        self.matches = filter_members(&self.items, &self.members, query);
+        self.selected_index = None;
+        self.notify_transfer(cx);
+        Task::ready(())
+    }
+
+    fn set_selected_index(&mut self, ix: Option<usize>, _: &mut ViewContext<List<Self>>) {
+        self.selected_index = ix;
+    }
+
+    fn confirm(&mut self, ix: Option<usize>, cx: &mut ViewContext<List<Self>>) {
+        let Some(item_ix) = ix.and_then(|ix| self.matches.get(ix).copied()) else {
+            return;
+        };
+
+        if !self.checked.remove(&item_ix) {
+            self.checked.insert(item_ix);
+        }
+        self.notify_transfer(cx);
+    }
+}
+
+/// A dual-list to move the items between the "available" and "selected" sides.
+///
+/// Check the items and click the buttons in the middle to move them, each side can be
+/// filtered by its search input, only the items in the active filter are moved.
+pub struct TransferList {
+    id: ElementId,
+    items: Rc<Vec<SharedString>>,
+    /// Whether the item is on the selected side.
+    selected: Vec<bool>,
+    titles: (SharedString, SharedString),
+    list_height: Pixels,
+    available_list: View<List<TransferListDelegate>>,
+    selected_list: View<List<TransferListDelegate>>,
+}
+
+impl TransferList {
+    pub fn new(
+        id: impl Into<ElementId>,
+        items: impl Into<Vec<SharedString>>,
+        cx: &mut ViewContext<Self>,
+    ) -> Self {
+        let items = Rc::new(items.into());
+        let selected = vec![false; items.len()];
+
+        let mut new_list = |side: bool| {
+            let members = side_members(&selected, side);
+            let delegate = TransferListDelegate {
+                transfer: cx.view().downgrade(),
+                items: items.clone(),
+                matches: members.clone(),
+                members,
+                checked: HashSet::new(),
+                query: SharedString::default(),
+                selected_index: None,
+            };
+            cx.new_view(|cx| List::new(delegate, cx))
+        };
+        let available_list = new_list(false);
+        let selected_list = new_list(true);
+
+        Self {
+            id: id.into(),
+            items,
+            selected,
+            titles: ("Available".into(), "Selected".into()),
+            list_height: px(300.),
+            available_list,
+            selected_list,
+        }
+    }
+
+    /// Set the titles of the available and selected sides.
+    pub fn titles(
+        mut self,
+        available: impl Into<SharedString>,
+        selected: impl Into<SharedString>,
+    ) -> Self {
+        self.titles = (available.into(), selected.into());
+        self
+    }
+
+    /// Set the height of the lists, default: 300px
+    pub fn list_height(mut self, height: impl Into<Pixels>) -> Self {
+        self.list_height = height.into();
+        self
+    }
+
+    /// Returns the items on the selected side.
+    pub fn values(&self) -> Vec<SharedString> {
+        side_members(&self.selected, true)
+            .into_iter()
+            .map(|ix| self.items[ix].clone())
+            .collect()
+    }
+
+    /// Set the items on the selected side, the unknown values are ignored.
+    pub fn set_values(&mut self, values: &[SharedString], cx: &mut ViewContext<Self>) {
+        self.selected = self
+            .items
+            .iter()
+            .map(|item| values.contains(item))
+            .collect();
+        self.sync_lists(cx);
+        cx.notify();
+    }
+
+    /// Update the members of both lists, the scroll positions are kept.
+    fn sync_lists(&mut self, cx: &mut ViewContext<Self>) {
+        for (list, side) in [
+            (self.available_list.clone(), false),
+            (self.selected_list.clone(), true),
+        ] {
+            let members = side_members(&self.selected, side);
+            list.update(cx, |list, cx| {
+                list.delegate_mut().set_members(members);
+                list.set_selected_index(None, cx);
+                cx.notify();
+            });
+        }
+    }
+
+    fn transfer(&mut self, to_selected: bool, all: bool, cx: &mut ViewContext<Self>) {
+        let source = if to_selected {
+            &self.available_list
+        } else {
+            &self.selected_list
+        };
+        let moving = source.read(cx).delegate().movable(all);
+        if moving.is_empty() {
+            return;
+        }
+
+        for ix in moving {
+            self.selected[ix] = to_selected;
+        }
+        self.sync_lists(cx);
+        cx.emit(TransferListEvent::Change(self.values()));
+        cx.notify();
+    }
+
+    fn render_side(
+        &self,
+        title: SharedString,
+        list: &View<List<TransferListDelegate>>,
+        cx: &mut ViewContext<Self>,
+    ) -> impl IntoElement {
+        let delegate = list.read(cx).delegate();
+        let count = format!("{}/{}", delegate.checked.len(), delegate.members.len());
+
+        v_flex()
+            .flex_1()
+            .min_w_0()
+            .h(self.list_height)
+            .rounded(px(cx.theme().radius))
+            .border_1()
+            .border_color(cx.theme().border)
+            .overflow_hidden()
+            .child(
+                h_flex()
+                    .justify_between()
+                    .px_3()
+                    .py_2()
+                    .border_b_1()
+                    .border_color(cx.theme().border)
+                    .text_sm()
+                    .child(div().font_semibold().child(title))
+                    .child(div().text_color(cx.theme().muted_foreground).child(count)),
+            )
+            .child(div().flex_1().min_h_0().child(list.clone()))
+    }
+}
+
+impl EventEmitter<TransferListEvent> for TransferList {}
+
+impl Render for TransferList {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let can_select = !self
+            .available_list
+            .read(cx)
+            .delegate()
+            .movable(false)
+            .is_empty();
+        let can_select_all = !self.available_list.read(cx).delegate().matches.is_empty();
+        let can_unselect = !self
+            .selected_list
+            .read(cx)
+            .delegate()
+            .movable(false)
+            .is_empty();
+        let can_unselect_all = !self.selected_list.read(cx).delegate().matches.is_empty();
+
+        h_flex()
+            .id(self.id.clone())
+            .w_full()
+            .gap_3()
+            .child(self.render_side(self.titles.0.clone(), &self.available_list, cx))
+            .child(
+                v_flex()
+                    .gap_2()
+                    .child(
+                        Button::new("select")
+                            .outline()
+                            .small()
+                            .icon(IconName::ChevronRight)
+                            .disabled(!can_select)
+                            .on_click(cx.listener(|this, _, cx| this.transfer(true, false, cx))),
+                    )
+                    .child(
+                        Button::new("select-all")
+                            .outline()
+                            .small()
+                            .icon(IconName::ChevronsRight)
+                            .disabled(!can_select_all)
+                            .on_click(cx.listener(|this, _, cx| this.transfer(true, true, cx))),
+                    )
+                    .child(
+                        Button::new("unselect")
+                            .outline()
+                            .small()
+                            .icon(IconName::ChevronLeft)
+                            .disabled(!can_unselect)
+                            .on_click(cx.listener(|this, _, cx| this.transfer(false, false, cx))),
+                    )
+                    .child(
+                        Button::new("unselect-all")
+                            .outline()
+                            .small()
+                            .icon(IconName::ChevronsLeft)
+                            .disabled(!can_unselect_all)
+                            .on_click(cx.listener(|this, _, cx| this.transfer(false, true, cx))),
+                    ),
+            )
+            .child(self.render_side(self.titles.1.clone(), &self.selected_list, cx))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{filter_members, side_members};
+    use gpui::SharedString;
+
+    #[test]
+    fn test_side_members() {
+        let selected = [false, true, false, true];
+        assert_eq!(side_members(&selected, false), vec![0, 2]);
+        assert_eq!(side_members(&selected, true), vec![1, 3]);
+    }
+
+    #[test]
+    fn test_filter_members() {
+        let items: Vec<SharedString> = vec!["Apple".into(), "Banana".into(), "Pineapple".into()];
+        assert_eq!(filter_members(&items, &[0, 1, 2], ""), vec![0, 1, 2]);
+        assert_eq!(filter_members(&items, &[0, 1, 2], "apple"), vec![0, 2]);
+        assert_eq!(filter_members(&items, &[1, 2], "APPLE"), vec![2]);
+    }
+}
@@ -0,0 +1,559 @@
+use std::{
+    cell::Cell,
+    collections::{HashMap, HashSet},
+    f32::consts::PI,
+    hash::Hash,
+    rc::Rc,
+};
+
+use gpui::{
+    actions, div, prelude::FluentBuilder, px, radians, uniform_list, AppContext, FocusHandle,
+    FocusableView, InteractiveElement, IntoElement, KeyBinding, ListSizingBehavior, MouseButton,
+    ParentElement, Pixels, Render, ScrollStrategy, StatefulInteractiveElement as _, Styled, Task,
+    UniformListScrollHandle, ViewContext,
+};
+
+use crate::{
+    h_flex,
+    scroll::{Scrollbar, ScrollbarState},
+    theme::{ActiveTheme, Colorize as _},
+    v_flex, Icon, IconName, Size, StyledExt as _,
+};
+
+actions!(
+    tree,
+    [Cancel, Confirm, SelectPrev, SelectNext, Expand, Collapse]
+);
+
+const CONTEXT: &str = "Tree";
+/// The width of each level of the indentation.
+const INDENT: Pixels = px(16.);
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("escape", Cancel, Some(CONTEXT)),
+        KeyBinding::new("enter", Confirm, Some(CONTEXT)),
+        KeyBinding::new("up", SelectPrev, Some(CONTEXT)),
+        KeyBinding::new("down", SelectNext, Some(CONTEXT)),
+        KeyBinding::new("right", Expand, Some(CONTEXT)),
+        KeyBinding::new("left", Collapse, Some(CONTEXT)),
+    ]);
+}
+
+/// A delegate for the [`Tree`], like the [`crate::list::ListDelegate`] with the hierarchy.
+///
+/// The nodes are identified by the `NodeId`, `None` means the root of the tree.
+#[allow(unused)]
+pub trait TreeDelegate: Sized + 'static {
+    type NodeId: Clone + Eq + Hash + 'static;
+    type Node: IntoElement;
+
+    /// Return the number of the loaded children of the `parent`.
+    fn children_count(&self, parent: Option<&Self::NodeId>, cx: &AppContext) -> usize;
+
+    /// Return the id of the child at the `ix` of the `parent`.
+    fn child(&self, parent: Option<&Self::NodeId>, ix: usize, cx: &AppContext) -> Self::NodeId;
+
+    /// Return true if the node can be expanded, default is it has the loaded children.
+    ///
+    /// Return true for the nodes that the children are not loaded yet, to load them by `load_children`.
+    fn is_expandable(&self, id: &Self::NodeId, cx: &AppContext) -> bool {
+        self.children_count(Some(id), cx) > 0
+    }
+
+    /// Load the children of the node when it is expanded, the tree is refreshed when the task is done.
+    fn load_children(&mut self, id: &Self::NodeId, cx: &mut ViewContext<Tree<Self>>) -> Task<()> {
+        Task::ready(())
+    }
+
+    /// Render the node with the `depth` from 0, the indentation and the disclosure are rendered by the Tree.
+    ///
+    /// Return None will render an empty row.
+    fn render_node(
+        &self,
+        id: &Self::NodeId,
+        depth: usize,
+        expanded: bool,
+        cx: &mut ViewContext<Tree<Self>>,
+    ) -> Option<Self::Node>;
+
+    /// Return a Element to show when tree is empty.
+    fn render_empty(&self, cx: &mut ViewContext<Tree<Self>>) -> impl IntoElement {
+        div()
+    }
+
+    /// Set the selected node, just store the id, don't confirm.
+    fn set_selected(&mut self, id: Option<&Self::NodeId>, cx: &mut ViewContext<Tree<Self>>) {}
+
+    /// Confirm the selected node, this is means user have clicked the node or pressed Enter.
+    fn confirm(&mut self, id: Option<&Self::NodeId>, cx: &mut ViewContext<Tree<Self>>) {}
+
+    /// Cancel the selection, e.g.: Pressed ESC.
+    fn cancel(&mut self, cx: &mut ViewContext<Tree<Self>>) {}
+}
+
+/// A node in the flattened visible nodes of the tree.
+#[derive(Debug, Clone, PartialEq)]
+struct VisibleNode<T> {
+    id: T,
+    depth: usize,
+    /// The index of the parent in the visible nodes.
+    parent_ix: Option<usize>,
+}
+
+/// Flatten the visible nodes in the pre-order, the children of the `expanded` nodes are visible.
+fn flatten_nodes<T: Clone + Eq + Hash>(
+    children_count: impl Fn(Option<&T>) -> usize,
+    child: impl Fn(Option<&T>, usize) -> T,
+    expanded: &HashSet<T>,
+) -> Vec<VisibleNode<T>> {
+    let mut nodes = Vec::new();
+    // The pending nodes in the reversed order, to pop them in the order.
+    let mut stack = Vec::new();
+    let push_children = |stack: &mut Vec<VisibleNode<T>>, parent: Option<&T>, parent_ix, depth| {
+        stack.extend((0..children_count(parent)).rev().map(|ix| VisibleNode {
+            id: child(parent, ix),
+            depth,
+            parent_ix,
+        }));
+    };
+
+    push_children(&mut stack, None, None, 0);
+    while let Some(node) = stack.pop() {
+        let ix = nodes.len();
+        if expanded.contains(&node.id) {
+            push_children(&mut stack, Some(&node.id), Some(ix), node.depth + 1);
+        }
+        nodes.push(node);
+    }
+    nodes
+}
+
+/// A virtualized tree view, only the visible rows of the expanded nodes are rendered.
+pub struct Tree<D: TreeDelegate> {
+    focus_handle: FocusHandle,
+    delegate: D,
+    expanded: HashSet<D::NodeId>,
+    visible_nodes: Vec<VisibleNode<D::NodeId>>,
+    selected_index: Option<usize>,
+    size: Size,
+
+    enable_scrollbar: bool,
+    vertical_scroll_handle: UniformListScrollHandle,
+    scrollbar_state: Rc<Cell<ScrollbarState>>,
+
+    _load_tasks: HashMap<D::NodeId, Task<()>>,
+}
+
+impl<D> Tree<D>
+where
+    D: TreeDelegate,
+{
+    pub fn new(delegate: D, cx: &mut ViewContext<Self>) -> Self {
+        let mut this = Self {
+            focus_handle: cx.focus_handle(),
+            delegate,
+            expanded: HashSet::new(),
+            visible_nodes: Vec::new(),
+            selected_index: None,
+            size: Size::default(),
+            enable_scrollbar: true,
+            vertical_scroll_handle: UniformListScrollHandle::new(),
+            scrollbar_state: Rc::new(Cell::new(ScrollbarState::new())),
+            _load_tasks: HashMap::new(),
+        };
+        this.update_visible_nodes(cx);
+        this
+    }
+
+    pub fn no_scrollbar(mut self) -> Self {
+        self.enable_scrollbar = false;
+        self
+    }
+
+    pub fn set_size(&mut self, size: Size, cx: &mut ViewContext<Self>) {
+        self.size = size;
+        cx.notify();
+    }
+
+    pub fn delegate(&self) -> &D {
+        &self.delegate
+    }
+
+    pub fn delegate_mut(&mut self) -> &mut D {
+        &mut self.delegate
+    }
+
+    pub fn focus(&mut self, cx: &mut ViewContext<Self>) {
+        self.focus_handle.focus(cx);
+    }
+
+    /// Reload the nodes from the delegate, call this after the data of the delegate is changed.
+    pub fn refresh(&mut self, cx: &mut ViewContext<Self>) {
+        self.update_visible_nodes(cx);
+        cx.notify();
+    }
+
+    fn update_visible_nodes(&mut self, cx: &mut ViewContext<Self>) {
+        let selected = self.selected_node().cloned();
+        let delegate = &self.delegate;
+        let app: &AppContext = cx;
+        self.visible_nodes = flatten_nodes(
+            |parent| delegate.children_count(parent, app),
+            |parent, ix| delegate.child(parent, ix, app),
+            &self.expanded,
+        );
+        self.selected_index =
+            selected.and_then(|id| self.visible_nodes.iter().position(|node| node.id == id));
+    }
+
+    pub fn is_expanded(&self, id: &D::NodeId) -> bool {
+        self.expanded.contains(id)
+    }
+
+    /// Return true if the children of the node are loading.
+    pub fn is_loading(&self, id: &D::NodeId) -> bool {
+        self._load_tasks.contains_key(id)
+    }
+
+    /// Expand the node, and load the children by [`TreeDelegate::load_children`].
+    pub fn expand(&mut self, id: &D::NodeId, cx: &mut ViewContext<Self>) {
+        if self.expanded.contains(id) || !self.delegate.is_expandable(id, cx) {
+            return;
+        }
+
+        self.expanded.insert(id.clone());
+        let load = self.delegate.load_children(id, cx);
+        let task = cx.spawn({
+            let id = id.clone();
+            move |this, mut cx| async move {
+                load.await;
+                _ = this.update(&mut cx, |this, cx| {
+                    this._load_tasks.remove(&id);
+                    this.refresh(cx);
+                });
+            }
+        });
+        self._load_tasks.insert(id.clone(), task);
+        self.refresh(cx);
+    }
+
+    pub fn collapse(&mut self, id: &D::NodeId, cx: &mut ViewContext<Self>) {
+        if self.expanded.remove(id) {
+            self._load_tasks.remove(id);
+            // Select the collapsed node, if the selected node is hidden.
+            if let Some(ix) = self.selected_index {
+                let hidden = self.ancestors(ix).any(|node| &node.id == id);
+                if hidden {
+                    self.selected_index = self.visible_nodes.iter().position(|n| &n.id == id);
+                }
+            }
+            self.refresh(cx);
+        }
+    }
+
+    pub fn toggle(&mut self, id: &D::NodeId, cx: &mut ViewContext<Self>) {
+        if self.is_expanded(id) {
+            self.collapse(id, cx);
+        } else {
+            self.expand(id, cx);
+        }
+    }
+
+    /// Returns the ancestors of the visible node at the `ix`, from the parent to the root.
+    fn ancestors(&self, ix: usize) -> impl Iterator<Item = &VisibleNode<D::NodeId>> {
+        let mut parent_ix = self.visible_nodes.get(ix).and_then(|node| node.parent_ix);
+        std::iter::from_fn(move || {
+            let node = self.visible_nodes.get(parent_ix?)?;
+            parent_ix = node.parent_ix;
+            Some(node)
+        })
+    }
+
+    pub fn selected_node(&self) -> Option<&D::NodeId> {
+        self.selected_index
+            .and_then(|ix| self.visible_nodes.get(ix))
+            .map(|node| &node.id)
+    }
+
+    /// Select the node, if it is visible.
+    pub fn set_selected_node(&mut self, id: Option<&D::NodeId>, cx: &mut ViewContext<Self>) {
+        let ix = id.and_then(|id| self.visible_nodes.iter().position(|node| &node.id == id));
+        self.select_index(ix, cx);
+    }
+
+    fn select_index(&mut self, ix: Option<usize>, cx: &mut ViewContext<Self>) {
+        self.selected_index = ix;
+        let id = self.selected_node().cloned();
+        self.delegate.set_selected(id.as_ref(), cx);
+        if let Some(ix) = ix {
+            self.vertical_scroll_handle
+                .scroll_to_item(ix, ScrollStrategy::Top);
+        }
+        cx.notify();
+    }
+
+    fn on_action_cancel(&mut self, _: &Cancel, cx: &mut ViewContext<Self>) {
+        self.select_index(None, cx);
+        self.delegate.cancel(cx);
+    }
+
+    fn on_action_confirm(&mut self, _: &Confirm, cx: &mut ViewContext<Self>) {
+        if self.visible_nodes.is_empty() {
+            return;
+        }
+
+        let id = self.selected_node().cloned();
+        self.delegate.confirm(id.as_ref(), cx);
+        cx.notify();
+    }
+
+    fn on_action_select_prev(&mut self, _: &SelectPrev, cx: &mut ViewContext<Self>) {
+        let count = self.visible_nodes.len();
+        if count == 0 {
+            return;
+        }
+
+        let ix = match self.selected_index {
+            Some(ix) if ix > 0 => ix - 1,
+            _ => count - 1,
+        };
+        self.select_index(Some(ix), cx);
+    }
+
+    fn on_action_select_next(&mut self, _: &SelectNext, cx: &mut ViewContext<Self>) {
+        let count = self.visible_nodes.len();
+        if count == 0 {
+            return;
+        }
+
+        let ix = match self.selected_index {
+            Some(ix) if ix + 1 < count => ix + 1,
+            _ => 0,
+        };
+        self.select_index(Some(ix), cx);
+    }
+
+    /// Expand the selected node, or move into the first child if it is expanded.
+    fn on_action_expand(&mut self, _: &Expand, cx: &mut ViewContext<Self>) {
+        let Some(ix) = self.selected_index else {
+            return;
+        };
+        let id = self.visible_nodes[ix].id.clone();
+
+        if !self.is_expanded(&id) {
+            self.expand(&id, cx);
+        } else if self
+            .visible_nodes
+            .get(ix + 1)
+            .map_or(false, |node| node.parent_ix == Some(ix))
+        {
+            self.select_index(Some(ix + 1), cx);
+        }
+    }
+
+    /// Collapse the selected node, or move to the parent if it is collapsed.
+    fn on_action_collapse(&mut self, _: &Collapse, cx: &mut ViewContext<Self>) {
+        let Some(ix) = self.selected_index else {
+            return;
+        };
+        let node = &self.visible_nodes[ix];
+
+        if self.is_expanded(&node.id) {
+            let id = node.id.clone();
+            self.collapse(&id, cx);
+        } else if let Some(parent_ix) = node.parent_ix {
+            self.select_index(Some(parent_ix), cx);
+        }
+    }
+
+    fn render_scrollbar(&self, cx: &mut ViewContext<Self>) -> Option<impl IntoElement> {
+        if !self.enable_scrollbar {
+            return None;
+        }
+
+        Some(Scrollbar::uniform_scroll(
+            cx.view().entity_id(),
+            self.scrollbar_state.clone(),
+            self.vertical_scroll_handle.clone(),
+        ))
+    }
+
+    fn render_tree_node(&mut self, ix: usize, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let VisibleNode { id, depth, .. } = self.visible_nodes[ix].clone();
+        let selected = self.selected_index == Some(ix);
+        let expanded = self.is_expanded(&id);
+        let expandable = expanded || self.delegate.is_expandable(&id, cx);
+        let loading = self.is_loading(&id);
+
+        h_flex()
+            .id(("tree-node", ix))
+            .w_full()
+            .relative()
+            .pl_1()
+            .children((0..depth).map(|_| {
+                // The indentation guide of each level.
+                div().flex_none().w(INDENT).h_full().child(
+                    div()
+                        .ml(INDENT / 2.)
+                        .h_full()
+                        .border_l_1()
+                        .border_color(cx.theme().border),
+                )
+            }))
+            .child(
+                h_flex()
+                    .id("disclosure")
+                    .flex_none()
+                    .justify_center()
+                    .w(INDENT)
+                    .text_color(cx.theme().muted_foreground)
+                    .when(loading, |this| {
+                        this.child(Icon::new(IconName::Loader).xsmall().spin(true))
+                    })
+                    .when(expandable && !loading, |this| {
+                        this.child(
+                            Icon::new(IconName::ChevronRight)
+                                .xsmall()
+                                .rotate(radians(if expanded { PI / 2. } else { 0. })),
+                        )
+                        .on_mouse_down(MouseButton::Left, |_, cx| cx.stop_propagation())
+                        .on_click(cx.listener({
+                            let id = id.clone();
+                            move |this, _, cx| this.toggle(&id, cx)
+                        }))
+                    }),
+            )
+            .child(
+                div()
+                    .flex_1()
+                    .overflow_hidden()
+                    .children(self.delegate.render_node(&id, depth, expanded, cx)),
+            )
+            .when(selected, |this| {
+                this.child(
+                    div()
+                        .absolute()
+                        .top(px(0.))
+                        .left(px(0.))
+                        .right(px(0.))
+                        .bottom(px(0.))
+                        .bg(cx.theme().list_active.opacity(cx.theme().opacity))
+                        .rounded(cx.theme().radius())
+                        .border_w(cx.theme().border_width())
+                        .border_color(cx.theme().list_active_border),
+                )
+            })
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener(move |this, _, cx| {
+                    this.focus_handle.focus(cx);
+                    this.select_index(Some(ix), cx);
+                    this.on_action_confirm(&Confirm, cx);
+                }),
+            )
+    }
+}
+
+impl<D> FocusableView for Tree<D>
+where
+    D: TreeDelegate,
+{
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl<D> Render for Tree<D>
+where
+    D: TreeDelegate,
+{
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let view = cx.view().clone();
+        let nodes_count = self.visible_nodes.len();
+
+        v_flex()
+            .key_context(CONTEXT)
+            .id("tree")
+            .track_focus(&self.focus_handle)
+            .size_full()
+            .relative()
+            .overflow_hidden()
+            .text_size(cx.theme().font_size_for(self.size))
+            .focus_ring(&self.focus_handle, cx)
+            .on_action(cx.listener(Self::on_action_cancel))
+            .on_action(cx.listener(Self::on_action_confirm))
+            .on_action(cx.listener(Self::on_action_select_prev))
+            .on_action(cx.listener(Self::on_action_select_next))
+            .on_action(cx.listener(Self::on_action_expand))
+            .on_action(cx.listener(Self::on_action_collapse))
+            .when(nodes_count == 0, |this| {
+                this.child(self.delegate().render_empty(cx))
+            })
+            .when(nodes_count > 0, |this| {
+                this.child(
+                    uniform_list(view, "tree-nodes", nodes_count, {
+                        move |tree, visible_range, cx| {
+                            visible_range
+                                .map(|ix| tree.render_tree_node(ix, cx))
+                                .collect::<Vec<_>>()
+                        }
+                    })
+                    .flex_grow()
+                    .with_sizing_behavior(ListSizingBehavior::Auto)
+                    .track_scroll(self.vertical_scroll_handle.clone())
+                    .into_any_element(),
+                )
+            })
+            .children(self.render_scrollbar(cx))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use super::{flatten_nodes, VisibleNode};
+
+    #[test]
+    fn test_flatten_nodes() {
+        // 1 -> [2 -> [4], 3], 5
+        let children: HashMap<Option<u32>, Vec<u32>> = HashMap::from([
+            (None, vec![1, 5]),
+            (Some(1), vec![2, 3]),
+            (Some(2), vec![4]),
+        ]);
+        let flatten = |expanded: &HashSet<u32>| {
+            flatten_nodes(
+                |parent| children.get(&parent.copied()).map_or(0, |c| c.len()),
+                |parent, ix| children[&parent.copied()][ix],
+                expanded,
+            )
+        };
+        let node = |id, depth, parent_ix| VisibleNode {
+            id,
+            depth,
+            parent_ix,
+        };
+
+        assert_eq!(
+            flatten(&HashSet::new()),
+            vec![node(1, 0, None), node(5, 0, None)]
+        );
+        assert_eq!(
+            flatten(&HashSet::from([1, 2])),
+            vec![
+                node(1, 0, None),
+                node(2, 1, Some(0)),
+                node(4, 2, Some(1)),
+                node(3, 1, Some(0)),
+                node(5, 0, None),
+            ]
+        );
+        // The children of a collapsed node are hidden, even they are expanded.
+        assert_eq!(
+            flatten(&HashSet::from([2])),
+            vec![node(1, 0, None), node(5, 0, None)]
+        );
+    }
+}
@@ -0,0 +1,577 @@
+//! A virtualized masonry grid for the items in varying heights, e.g. the photo thumbnails.
+//!
+//! The items are as wide as the columns, and placed into the shortest column one by one,
+//! only the visible items (with the overscan) are rendered.
+use std::{cell::Cell, ops::Range, rc::Rc};
+
+use gpui::{
+    actions, canvas, div, point, prelude::FluentBuilder as _, px, size, AppContext, Bounds,
+    FocusHandle, FocusableView, InteractiveElement as _, IntoElement, KeyBinding,
+    ParentElement as _, Pixels, Render, ScrollHandle, StatefulInteractiveElement as _, Styled,
+    ViewContext,
+};
+
+use crate::{
+    scroll::{Scrollbar, ScrollbarState},
+    theme::ActiveTheme as _,
+};
+
+actions!(
+    virtual_grid,
+    [SelectUp, SelectDown, SelectLeft, SelectRight, Confirm]
+);
+
+const CONTEXT: &str = "VirtualGrid";
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("up", SelectUp, Some(CONTEXT)),
+        KeyBinding::new("down", SelectDown, Some(CONTEXT)),
+        KeyBinding::new("left", SelectLeft, Some(CONTEXT)),
+        KeyBinding::new("right", SelectRight, Some(CONTEXT)),
+        KeyBinding::new("enter", Confirm, Some(CONTEXT)),
+    ]);
+}
+
+/// A delegate for the [`VirtualGrid`].
+#[allow(unused)]
+pub trait VirtualGridDelegate: Sized + 'static {
+    type Item: IntoElement;
+
+    /// Return the number of items in the grid.
+    fn items_count(&self, cx: &AppContext) -> usize;
+
+    /// Return the aspect ratio (width / height) of the item, default: 1.0
+    ///
+    /// The items are as wide as the columns, so the height is derived from it,
+    /// e.g. return the measured `width / height` of the image.
+    fn aspect_ratio(&self, ix: usize, cx: &AppContext) -> f32 {
+        1.
+    }
+
+    /// Render the item at the given index, it is sized to the cell.
+    fn render_item(
+        &self,
+        ix: usize,
+        selected: bool,
+        cx: &mut ViewContext<VirtualGrid<Self>>,
+    ) -> Option<Self::Item>;
+
+    /// The item is clicked or Enter pressed on the selected item.
+    fn confirm(&mut self, ix: usize, cx: &mut ViewContext<VirtualGrid<Self>>) {}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct GridCell {
+    column: usize,
+    top: f32,
+    height: f32,
+}
+
+/// Returns the number of the columns that fit in the `width`, at least 1.
+fn columns_for(width: f32, min_item_width: f32, gap: f32) -> usize {
+    (((width + gap) / (min_item_width + gap).max(1.)).floor() as usize).max(1)
+}
+
+/// The masonry layout of the items, the items are placed into the shortest column one by one.
+///
+/// So the tops of the cells are in ascending order, the appended items are laid out
+/// incrementally without touching the existing cells.
+#[derive(Debug, Default)]
+struct MasonryLayout {
+    columns: usize,
+    column_width: f32,
+    gap: f32,
+    cells: Vec<GridCell>,
+    /// The max height of the cells, to find the first visible cell.
+    max_cell_height: f32,
+}
+
+impl MasonryLayout {
+    /// Set the columns, the cells are cleared if changed.
+    fn set_columns(&mut self, columns: usize, column_width: f32, gap: f32) {
+        if (columns, column_width, gap) != (self.columns, self.column_width, self.gap) {
+            self.columns = columns;
+            self.column_width = column_width;
+            self.gap = gap;
+            self.invalidate(0);
+        }
+    }
+
+    /// Remove the cells from `ix`, they will be laid out again by the next [`Self::layout`].
+    fn invalidate(&mut self, ix: usize) {
+        self.cells.truncate(ix);
+        self.max_cell_height = self.cells.iter().map(|cell| cell.height).fold(0., f32::max);
+    }
+
+    /// Returns the bottom of each column, with the gap.
+    fn column_heights(&self) -> Vec<f32> {
+        let mut heights = vec![0f32; self.columns];
+        for cell in self.cells.iter() {
+            heights[cell.column] = heights[cell.column].max(cell.top + cell.height + self.gap);
+        }
+        heights
+    }
+
+    /// Lay out the items after the existing cells, up to the `count`.
+    fn layout(&mut self, count: usize, aspect_ratio: impl Fn(usize) -> f32) {
+        if self.cells.len() > count {
+            self.invalidate(count);
+        }
+        if self.columns == 0 || self.cells.len() == count {
+            return;
+        }
+
+        let mut heights = self.column_heights();
+        for ix in self.cells.len()..count {
+            let Some((column, top)) = heights
+                .iter()
+                .copied()
+                .enumerate()
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+            else {
+                return;
+            };
+
+            let ratio = aspect_ratio(ix);
+            let ratio = if ratio.is_finite() && ratio > 0. {
+                ratio.clamp(0.1, 10.)
+            } else {
+                1.
+            };
+            let height = self.column_width / ratio;
+            self.cells.push(GridCell {
+                column,
+                top,
+                height,
+            });
+            heights[column] = top + height + self.gap;
+            self.max_cell_height = self.max_cell_height.max(height);
+        }
+    }
+
+    fn content_height(&self) -> f32 {
+        self.cells
+            .iter()
+            .map(|cell| cell.top + cell.height)
+            .fold(0., f32::max)
+    }
+
+    /// Returns the range of the cells may be visible in the `top..bottom`, the cells
+    /// out of it in the range should be skipped.
+    fn visible_range(&self, top: f32, bottom: f32) -> Range<usize> {
+        let start = self
+            .cells
+            .partition_point(|cell| cell.top + self.max_cell_height < top);
+        let end = self.cells.partition_point(|cell| cell.top < bottom);
+        start..end.max(start)
+    }
+
+    /// Returns the cell next to the `ix` visually in the `direction`.
+    fn neighbor(&self, ix: usize, direction: Direction) -> Option<usize> {
+        let current = self.cells.get(ix)?;
+        match direction {
+            // The cells of a column are in the order of the index.
+            Direction::Up => self.cells[..ix]
+                .iter()
+                .rposition(|cell| cell.column == current.column),
+            Direction::Down => self.cells[ix + 1..]
+                .iter()
+                .position(|cell| cell.column == current.column)
+                .map(|pos| ix + 1 + pos),
+            Direction::Left | Direction::Right => {
+                let column = if direction == Direction::Left {
+                    current.column.checked_sub(1)?
+                } else {
+                    current.column + 1
+                };
+                let center = current.top + current.height / 2.;
+                self.cells
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, cell)| cell.column == column)
+                    .min_by(|(_, a), (_, b)| {
+                        let a = (a.top + a.height / 2. - center).abs();
+                        let b = (b.top + b.height / 2. - center).abs();
+                        a.total_cmp(&b)
+                    })
+                    .map(|(ix, _)| ix)
+            }
+        }
+    }
+}
+
+/// A virtualized masonry grid, the number of the columns is derived from the `min_item_width`.
+///
+/// When focused, the arrow keys move the selection to the visually adjacent item,
+/// and Enter confirms it.
+pub struct VirtualGrid<D: VirtualGridDelegate> {
+    focus_handle: FocusHandle,
+    delegate: D,
+    min_item_width: Pixels,
+    gap: Pixels,
+    overscan: Pixels,
+    selected_index: Option<usize>,
+    layout: MasonryLayout,
+    scroll_handle: ScrollHandle,
+    scrollbar_state: Rc<Cell<ScrollbarState>>,
+    bounds: Bounds<Pixels>,
+}
+
+impl<D> VirtualGrid<D>
+where
+    D: VirtualGridDelegate,
+{
+    pub fn new(delegate: D, cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            delegate,
+            min_item_width: px(160.),
+            gap: px(8.),
+            overscan: px(200.),
+            selected_index: None,
+            layout: MasonryLayout::default(),
+            scroll_handle: ScrollHandle::new(),
+            scrollbar_state: Rc::new(Cell::new(ScrollbarState::new())),
+            bounds: Bounds::default(),
+        }
+    }
+
+    /// Set the min width of the items, default: 160px
+    pub fn min_item_width(mut self, width: impl Into<Pixels>) -> Self {
+        self.min_item_width = width.into();
+        self
+    }
+
+    /// Set the gap between the items, default: 8px
+    pub fn gap(mut self, gap: impl Into<Pixels>) -> Self {
+        self.gap = gap.into();
+        self
+    }
+
+    /// Set the distance to render the items out of the viewport, default: 200px
+    pub fn overscan(mut self, overscan: impl Into<Pixels>) -> Self {
+        self.overscan = overscan.into();
+        self
+    }
+
+    pub fn delegate(&self) -> &D {
+        &self.delegate
+    }
+
+    pub fn delegate_mut(&mut self) -> &mut D {
+        &mut self.delegate
+    }
+
+    pub fn selected_index(&self) -> Option<usize> {
+        self.selected_index
+    }
+
+    /// Set the selected index, and scroll to show it.
+    pub fn set_selected_index(&mut self, ix: Option<usize>, cx: &mut ViewContext<Self>) {
+        self.selected_index = ix;
+        if let Some(ix) = ix {
+            self.scroll_to_item(ix);
+        }
+        cx.notify();
+    }
+
+    /// Notify the items from `ix` are inserted, removed or resized.
+    ///
+    /// Only the items from `ix` are laid out again, the appended items are detected automatically.
+    pub fn items_changed(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        self.layout.invalidate(ix);
+        cx.notify();
+    }
+
+    fn scroll_to_item(&self, ix: usize) {
+        let Some(cell) = self.layout.cells.get(ix) else {
+            return;
+        };
+
+        let view_height = self.bounds.size.height.0;
+        let scroll_top = -self.scroll_handle.offset().y.0;
+        let top = if cell.top < scroll_top {
+            cell.top
+        } else if cell.top + cell.height > scroll_top + view_height {
+            cell.top + cell.height - view_height
+        } else {
+            return;
+        };
+        self.scroll_handle.set_offset(point(px(0.), px(-top)));
+    }
+
+    fn select_neighbor(&mut self, direction: Direction, cx: &mut ViewContext<Self>) {
+        let ix = match self.selected_index {
+            Some(ix) => self.layout.neighbor(ix, direction),
+            None => (!self.layout.cells.is_empty()).then_some(0),
+        };
+        if let Some(ix) = ix {
+            self.set_selected_index(Some(ix), cx);
+        }
+    }
+
+    fn select_up(&mut self, _: &SelectUp, cx: &mut ViewContext<Self>) {
+        self.select_neighbor(Direction::Up, cx);
+    }
+
+    fn select_down(&mut self, _: &SelectDown, cx: &mut ViewContext<Self>) {
+        self.select_neighbor(Direction::Down, cx);
+    }
+
+    fn select_left(&mut self, _: &SelectLeft, cx: &mut ViewContext<Self>) {
+        self.select_neighbor(Direction::Left, cx);
+    }
+
+    fn select_right(&mut self, _: &SelectRight, cx: &mut ViewContext<Self>) {
+        self.select_neighbor(Direction::Right, cx);
+    }
+
+    fn confirm(&mut self, _: &Confirm, cx: &mut ViewContext<Self>) {
+        if let Some(ix) = self.selected_index {
+            self.delegate.confirm(ix, cx);
+        }
+    }
+
+    fn update_bounds(&mut self, bounds: Bounds<Pixels>, cx: &mut ViewContext<Self>) {
+        if self.bounds != bounds {
+            self.bounds = bounds;
+            cx.notify();
+        }
+    }
+
+    fn update_layout(&mut self, cx: &mut ViewContext<Self>) {
+        let width = self.bounds.size.width.0;
+        if width <= 0. {
+            return;
+        }
+
+        let gap = self.gap.0;
+        let columns = columns_for(width, self.min_item_width.0, gap);
+        let column_width = ((width - gap * (columns - 1) as f32) / columns as f32).max(0.);
+        self.layout.set_columns(columns, column_width, gap);
+
+        let count = self.delegate.items_count(cx);
+        let delegate = &self.delegate;
+        self.layout
+            .layout(count, |ix| delegate.aspect_ratio(ix, cx));
+        if self.selected_index.map_or(false, |ix| ix >= count) {
+            self.selected_index = None;
+        }
+    }
+}
+
+impl<D> FocusableView for VirtualGrid<D>
+where
+    D: VirtualGridDelegate,
+{
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl<D> Render for VirtualGrid<D>
+where
+    D: VirtualGridDelegate,
+{
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        self.update_layout(cx);
+
+        let view = cx.view().clone();
+        let scroll_top = -self.scroll_handle.offset().y.0;
+        let range = self.layout.visible_range(
+            scroll_top - self.overscan.0,
+            scroll_top + self.bounds.size.height.0 + self.overscan.0,
+        );
+        let content_size = size(self.bounds.size.width, px(self.layout.content_height()));
+        let column_width = self.layout.column_width;
+        let gap = self.layout.gap;
+        let visible_top = scroll_top - self.overscan.0;
+
+        let items = range
+            .filter(|ix| {
+                let cell = self.layout.cells[*ix];
+                cell.top + cell.height >= visible_top
+            })
+            .filter_map(|ix| {
+                let cell = self.layout.cells[ix];
+                let selected = self.selected_index == Some(ix);
+                let item = self.delegate.render_item(ix, selected, cx)?;
+
+                Some(
+                    div()
+                        .id(("grid-item", ix))
+                        .absolute()
+                        .top(px(cell.top))
+                        .left(px(cell.column as f32 * (column_width + gap)))
+                        .w(px(column_width))
+                        .h(px(cell.height))
+                        .overflow_hidden()
+                        .rounded(px(cx.theme().radius))
+                        .child(item)
+                        .when(selected, |this| {
+                            this.child(
+                                div()
+                                    .absolute()
+                                    .size_full()
+                                    .rounded(px(cx.theme().radius))
+                                    .border_2()
+                                    .border_color(cx.theme().ring),
+                            )
+                        })
+                        .on_click(cx.listener(move |this, _, cx| {
+                            this.focus_handle.focus(cx);
+                            this.set_selected_index(Some(ix), cx);
+                            this.delegate.confirm(ix, cx);
+                        })),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        div()
+            .key_context(CONTEXT)
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::select_up))
+            .on_action(cx.listener(Self::select_down))
+            .on_action(cx.listener(Self::select_left))
+            .on_action(cx.listener(Self::select_right))
+            .on_action(cx.listener(Self::confirm))
+            .relative()
+            .size_full()
+            .overflow_hidden()
+            .child(
+                canvas(
+                    move |bounds, cx| view.update(cx, |r, cx| r.update_bounds(bounds, cx)),
+                    |_, _, _| {},
+                )
+                .absolute()
+                .size_full(),
+            )
+            .child(
+                div()
+                    .id("virtual-grid")
+                    .size_full()
+                    .overflow_y_scroll()
+                    .track_scroll(&self.scroll_handle)
+                    .on_scroll_wheel(cx.listener(|_, _, cx| cx.notify()))
+                    .child(
+                        div()
+                            .relative()
+                            .w_full()
+                            .h(content_size.height)
+                            .children(items),
+                    ),
+            )
+            .child(
+                div()
+                    .absolute()
+                    .top_0()
+                    .left_0()
+                    .right_0()
+                    .bottom_0()
+                    .child(Scrollbar::vertical(
+                        cx.entity_id(),
+                        self.scrollbar_state.clone(),
+                        self.scroll_handle.clone(),
+                        content_size,
+                    )),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{columns_for, Direction, MasonryLayout};
+
+    fn new_layout(ratios: &[f32]) -> MasonryLayout {
+        let mut layout = MasonryLayout::default();
+        layout.set_columns(3, 100., 10.);
+        layout.layout(ratios.len(), |ix| ratios[ix]);
+        layout
+    }
+
+    #[test]
+    fn test_columns_for() {
+        assert_eq!(columns_for(500., 160., 8.), 3);
+        assert_eq!(columns_for(504., 160., 8.), 3);
+        assert_eq!(columns_for(496., 160., 8.), 3);
+        assert_eq!(columns_for(495., 160., 8.), 2);
+        assert_eq!(columns_for(100., 160., 8.), 1);
+        assert_eq!(columns_for(0., 160., 8.), 1);
+    }
+
+    #[test]
+    fn test_masonry_layout() {
+        // Heights: 100, 50, 200, 100, 50
+        let layout = new_layout(&[1., 2., 0.5, 1., 2.]);
+        let cells = layout
+            .cells
+            .iter()
+            .map(|cell| (cell.column, cell.top, cell.height))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            cells,
+            vec![
+                (0, 0., 100.),
+                (1, 0., 50.),
+                (2, 0., 200.),
+                (1, 60., 100.),
+                (0, 110., 50.),
+            ]
+        );
+        assert_eq!(layout.content_height(), 200.);
+    }
+
+    #[test]
+    fn test_incremental_layout() {
+        let ratios = [1., 2., 0.5, 1., 2., 1.];
+        let mut layout = new_layout(&ratios[..4]);
+        layout.layout(ratios.len(), |ix| ratios[ix]);
+        assert_eq!(layout.cells, new_layout(&ratios).cells);
+
+        // Resize the item 3, the items before it are kept.
+        let first = layout.cells[..3].to_vec();
+        layout.invalidate(3);
+        layout.layout(ratios.len(), |ix| if ix == 3 { 0.5 } else { ratios[ix] });
+        assert_eq!(layout.cells[..3], first[..]);
+        assert_eq!(layout.cells[3].height, 200.);
+
+        // Changing the columns clears the layout.
+        layout.set_columns(2, 100., 10.);
+        assert!(layout.cells.is_empty());
+    }
+
+    #[test]
+    fn test_visible_range() {
+        let layout = new_layout(&[1., 2., 0.5, 1., 2.]);
+        assert_eq!(layout.visible_range(0., 50.), 0..3);
+        assert_eq!(layout.visible_range(0., 500.), 0..5);
+        // The tall item 2 may still be visible.
+        assert_eq!(layout.visible_range(170., 300.), 0..5);
+        assert_eq!(layout.visible_range(500., 600.), 5..5);
+    }
+
+    #[test]
+    fn test_neighbor() {
+        // Columns: [0, 4], [1, 3], [2]
+        let layout = new_layout(&[1., 2., 0.5, 1., 2.]);
+        assert_eq!(layout.neighbor(4, Direction::Up), Some(0));
+        assert_eq!(layout.neighbor(0, Direction::Up), None);
+        assert_eq!(layout.neighbor(1, Direction::Down), Some(3));
+        assert_eq!(layout.neighbor(3, Direction::Down), None);
+        assert_eq!(layout.neighbor(0, Direction::Left), None);
+        assert_eq!(layout.neighbor(0, Direction::Right), Some(1));
+        assert_eq!(layout.neighbor(4, Direction::Right), Some(3));
+        assert_eq!(layout.neighbor(3, Direction::Right), Some(2));
+        assert_eq!(layout.neighbor(2, Direction::Right), None);
+        assert_eq!(layout.neighbor(10, Direction::Up), None);
+    }
+}